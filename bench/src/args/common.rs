@@ -1,4 +1,5 @@
 use super::kind::BenchmarkKindCommand;
+use super::output::OutputFormat;
 use super::props::{BenchmarkKindProps, BenchmarkTransportProps};
 use super::{defaults::*, transport::BenchmarkTransportCommand};
 use clap::error::ErrorKind;
@@ -26,9 +27,21 @@ pub struct IggyBenchArgs {
     #[arg(long, short='s', default_value_t = DEFAULT_SERVER_SYSTEM_PATH.to_owned())]
     pub server_system_path: String,
 
+    /// Persister used by the started server to write the segment files, `file` or `io_uring`
+    #[arg(long, default_value = None)]
+    pub server_persister: Option<String>,
+
     /// Server stdout visibility
     #[arg(long, short='v', default_value_t = DEFAULT_SERVER_STDOUT_VISIBILITY)]
     pub verbose: bool,
+
+    /// Format of the machine-readable results (percentile latencies and throughput per producer and consumer), `json` or `csv`
+    #[arg(long, short='o', value_enum, default_value = None)]
+    pub output: Option<OutputFormat>,
+
+    /// Path of the file to write the machine-readable results to, if not provided they are printed to stdout
+    #[arg(long, default_value = None, requires = "output")]
+    pub output_path: Option<String>,
 }
 
 fn validate_server_executable_path(v: &str) -> Result<String, String> {
@@ -88,10 +101,22 @@ impl IggyBenchArgs {
         self.benchmark_kind.inner().message_size()
     }
 
+    pub fn max_message_size(&self) -> Option<u32> {
+        self.benchmark_kind.inner().max_message_size()
+    }
+
+    pub fn message_rate(&self) -> Option<u32> {
+        self.benchmark_kind.inner().message_rate()
+    }
+
     pub fn number_of_streams(&self) -> u32 {
         self.benchmark_kind.inner().number_of_streams()
     }
 
+    pub fn number_of_partitions(&self) -> u32 {
+        self.benchmark_kind.inner().number_of_partitions()
+    }
+
     pub fn consumers(&self) -> u32 {
         self.benchmark_kind.inner().consumers()
     }
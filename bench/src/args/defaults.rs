@@ -23,6 +23,7 @@ pub const DEFAULT_MESSAGE_SIZE: NonZeroU32 = u32!(1000);
 pub const DEFAULT_NUMBER_OF_STREAMS: NonZeroU32 = u32!(10);
 pub const DEFAULT_NUMBER_OF_CONSUMERS: NonZeroU32 = u32!(10);
 pub const DEFAULT_NUMBER_OF_PRODUCERS: NonZeroU32 = u32!(10);
+pub const DEFAULT_NUMBER_OF_PARTITIONS: NonZeroU32 = u32!(10);
 
 pub const DEFAULT_PERFORM_CLEANUP: bool = false;
 pub const DEFAULT_SERVER_SYSTEM_PATH: &str = "local_data";
@@ -29,7 +29,27 @@ const EXAMPLES: &str = r#"EXAMPLES:
 
     $ cargo r --bin iggy-bench -- send --message-size 2000 --messages-per-batch 1000 --message-batches 1000 --producers 5 --streams 5 tcp --server-address 142.250.203.142:8090
 
-4) Other options:
+4) Machine-readable results:
+
+    Percentile latencies (p50, p90, p99, p99.9, max) and throughput time series of each
+    producer and consumer can be written in JSON or CSV format and compared across commits:
+
+    $ cargo r --bin iggy-bench -r -- --output json --output-path results.json send-and-poll tcp
+    $ cargo r --bin iggy-bench -r -- --output csv send tcp > results.csv
+
+5) Consumer group, target rate and mixed message sizes:
+
+    Start consumer group benchmark, where 3 members of the group share 12 partitions of the topic,
+    to which 2 producers send messages at the target rate of 50000 messages per second each,
+    with message sizes randomly picked between 100 and 10000 bytes:
+
+    $ cargo r --bin iggy-bench -r -- consumer-group --producers 2 --consumers 3 --partitions 12 --message-rate 50000 --message-size 100 --max-message-size 10000 tcp
+
+    The `--message-rate` and `--max-message-size` options are also available for `send` and `send-and-poll`.
+    With the target rate, latency is measured from the moment the batch was scheduled to be sent,
+    so it includes the time spent waiting behind the slower requests.
+
+6) Other options:
 
     If more options are needed, please refer to the help menu:
 
@@ -40,10 +60,12 @@ const EXAMPLES: &str = r#"EXAMPLES:
     $ cargo r --bin iggy-bench -r -- send --help
     $ cargo r --bin iggy-bench -r -- poll --help
     $ cargo r --bin iggy-bench -r -- send-and-poll --help
+    $ cargo r --bin iggy-bench -r -- consumer-group --help
 
     $ cargo r --bin iggy-bench -r -- send tcp --help
     $ cargo r --bin iggy-bench -r -- poll tcp --help
     $ cargo r --bin iggy-bench -r -- send-and-poll tcp --help
+    $ cargo r --bin iggy-bench -r -- consumer-group tcp --help
 
 "#;
 
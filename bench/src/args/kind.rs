@@ -1,9 +1,10 @@
 use super::defaults::*;
 use super::examples::print_examples;
-use super::props::BenchmarkKindProps;
+use super::props::{BenchmarkKindProps, BenchmarkTransportProps};
 use super::transport::BenchmarkTransportCommand;
 use super::{common::IggyBenchArgs, simple::BenchmarkKind};
 use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use integration::test_server::Transport;
 use std::num::NonZeroU32;
 
 #[derive(Subcommand, Debug)]
@@ -11,6 +12,7 @@ pub enum BenchmarkKindCommand {
     Send(SendArgs),
     Poll(PollArgs),
     SendAndPoll(SendAndPollArgs),
+    ConsumerGroup(ConsumerGroupArgs),
 
     /// Prints examples
     Examples,
@@ -22,6 +24,7 @@ impl BenchmarkKindCommand {
             BenchmarkKindCommand::Send(_) => BenchmarkKind::Send,
             BenchmarkKindCommand::Poll(_) => BenchmarkKind::Poll,
             BenchmarkKindCommand::SendAndPoll(_) => BenchmarkKind::SendAndPoll,
+            BenchmarkKindCommand::ConsumerGroup(_) => BenchmarkKind::ConsumerGroup,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -35,6 +38,14 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
         self.inner().message_size()
     }
 
+    fn max_message_size(&self) -> Option<u32> {
+        self.inner().max_message_size()
+    }
+
+    fn message_rate(&self) -> Option<u32> {
+        self.inner().message_rate()
+    }
+
     fn messages_per_batch(&self) -> u32 {
         self.inner().messages_per_batch()
     }
@@ -47,6 +58,10 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
         self.inner().number_of_streams()
     }
 
+    fn number_of_partitions(&self) -> u32 {
+        self.inner().number_of_partitions()
+    }
+
     fn consumers(&self) -> u32 {
         self.inner().consumers()
     }
@@ -72,6 +87,7 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
             BenchmarkKindCommand::Send(args) => args,
             BenchmarkKindCommand::Poll(args) => args,
             BenchmarkKindCommand::SendAndPoll(args) => args,
+            BenchmarkKindCommand::ConsumerGroup(args) => args,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -102,6 +118,16 @@ pub struct SendArgs {
     #[arg(long, default_value_t = DEFAULT_MESSAGE_SIZE)]
     pub message_size: NonZeroU32,
 
+    /// Maximum message size in bytes, enables mixed message sizes
+    /// uniformly distributed between message size and maximum message size
+    #[arg(long, default_value = None)]
+    pub max_message_size: Option<NonZeroU32>,
+
+    /// Target rate of messages per second sent by each producer, enables open-loop mode
+    /// in which batches are sent at fixed intervals and latency is measured from the scheduled send time
+    #[arg(long, default_value = None)]
+    pub message_rate: Option<NonZeroU32>,
+
     /// Number of producers
     #[arg(long, default_value_t = DEFAULT_NUMBER_OF_PRODUCERS)]
     pub producers: NonZeroU32,
@@ -116,6 +142,18 @@ pub struct SendArgs {
 }
 
 impl BenchmarkKindProps for SendArgs {
+    fn max_message_size(&self) -> Option<u32> {
+        self.max_message_size.map(|size| size.get())
+    }
+
+    fn message_rate(&self) -> Option<u32> {
+        self.message_rate.map(|rate| rate.get())
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
     fn message_size(&self) -> u32 {
         self.message_size.get()
     }
@@ -157,6 +195,10 @@ impl BenchmarkKindProps for SendArgs {
         let producers = self.producers.get();
         let mut cmd = IggyBenchArgs::command();
 
+        if let Some(max_message_size) = self.max_message_size {
+            validate_max_message_size(self.message_size.get(), max_message_size.get());
+        }
+
         if self.disable_parallel_producers && streams < producers {
             cmd.error(
                 ErrorKind::ArgumentConflict,
@@ -199,6 +241,18 @@ pub struct PollArgs {
 }
 
 impl BenchmarkKindProps for PollArgs {
+    fn max_message_size(&self) -> Option<u32> {
+        None
+    }
+
+    fn message_rate(&self) -> Option<u32> {
+        None
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
     fn message_size(&self) -> u32 {
         self.message_size.get()
     }
@@ -268,6 +322,16 @@ pub struct SendAndPollArgs {
     #[arg(long, default_value_t = DEFAULT_MESSAGE_SIZE)]
     pub message_size: NonZeroU32,
 
+    /// Maximum message size in bytes, enables mixed message sizes
+    /// uniformly distributed between message size and maximum message size
+    #[arg(long, default_value = None)]
+    pub max_message_size: Option<NonZeroU32>,
+
+    /// Target rate of messages per second sent by each producer, enables open-loop mode
+    /// in which batches are sent at fixed intervals and latency is measured from the scheduled send time
+    #[arg(long, default_value = None)]
+    pub message_rate: Option<NonZeroU32>,
+
     /// Number of producers
     #[arg(long, default_value_t = DEFAULT_NUMBER_OF_PRODUCERS)]
     pub producers: NonZeroU32,
@@ -290,6 +354,18 @@ pub struct SendAndPollArgs {
 }
 
 impl BenchmarkKindProps for SendAndPollArgs {
+    fn max_message_size(&self) -> Option<u32> {
+        self.max_message_size.map(|size| size.get())
+    }
+
+    fn message_rate(&self) -> Option<u32> {
+        self.message_rate.map(|rate| rate.get())
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
     fn message_size(&self) -> u32 {
         self.message_size.get()
     }
@@ -332,6 +408,10 @@ impl BenchmarkKindProps for SendAndPollArgs {
         let producers = self.producers.get();
         let mut cmd = IggyBenchArgs::command();
 
+        if let Some(max_message_size) = self.max_message_size {
+            validate_max_message_size(self.message_size.get(), max_message_size.get());
+        }
+
         if self.disable_parallel_consumers && streams < consumers {
             cmd.error(
                 ErrorKind::ArgumentConflict,
@@ -349,3 +429,132 @@ impl BenchmarkKindProps for SendAndPollArgs {
         }
     }
 }
+
+/// Parallel sending and polling benchmark, in which consumers are the members
+/// of a single consumer group sharing the partitions of a single topic
+#[derive(Parser, Debug)]
+pub struct ConsumerGroupArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Number of messages per batch
+    #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
+    pub messages_per_batch: NonZeroU32,
+
+    /// Number of message batches
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
+    pub message_batches: NonZeroU32,
+
+    /// Message size in bytes
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_SIZE)]
+    pub message_size: NonZeroU32,
+
+    /// Maximum message size in bytes, enables mixed message sizes
+    /// uniformly distributed between message size and maximum message size
+    #[arg(long, default_value = None)]
+    pub max_message_size: Option<NonZeroU32>,
+
+    /// Target rate of messages per second sent by each producer, enables open-loop mode
+    /// in which batches are sent at fixed intervals and latency is measured from the scheduled send time
+    #[arg(long, default_value = None)]
+    pub message_rate: Option<NonZeroU32>,
+
+    /// Number of producers
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_PRODUCERS)]
+    pub producers: NonZeroU32,
+
+    /// Number of consumers (consumer group members)
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_CONSUMERS)]
+    pub consumers: NonZeroU32,
+
+    /// Number of partitions shared by the consumer group members
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_PARTITIONS)]
+    pub partitions: NonZeroU32,
+}
+
+impl BenchmarkKindProps for ConsumerGroupArgs {
+    fn message_size(&self) -> u32 {
+        self.message_size.get()
+    }
+
+    fn max_message_size(&self) -> Option<u32> {
+        self.max_message_size.map(|size| size.get())
+    }
+
+    fn message_rate(&self) -> Option<u32> {
+        self.message_rate.map(|rate| rate.get())
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        self.partitions.get()
+    }
+
+    fn number_of_streams(&self) -> u32 {
+        1
+    }
+
+    fn message_batches(&self) -> u32 {
+        self.message_batches.get()
+    }
+
+    fn messages_per_batch(&self) -> u32 {
+        self.messages_per_batch.get()
+    }
+
+    fn consumers(&self) -> u32 {
+        self.consumers.get()
+    }
+
+    fn producers(&self) -> u32 {
+        self.producers.get()
+    }
+
+    fn disable_parallel_producer_streams(&self) -> bool {
+        true
+    }
+
+    fn disable_parallel_consumer_streams(&self) -> bool {
+        true
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn validate(&self) {
+        let mut cmd = IggyBenchArgs::command();
+
+        if let Some(max_message_size) = self.max_message_size {
+            validate_max_message_size(self.message_size.get(), max_message_size.get());
+        }
+
+        if self.transport.transport() == &Transport::Http {
+            cmd.error(
+                ErrorKind::InvalidValue,
+                "Consumer group benchmark is not supported for HTTP transport, as HTTP client cannot join consumer group.",
+            )
+            .exit();
+        }
+
+        let consumers = self.consumers.get();
+        let partitions = self.partitions.get();
+        if consumers > partitions {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                format!("The number of consumers ({consumers}) must be less than or equal to the number of partitions ({partitions}), as each consumer group member must be assigned at least one partition."),
+            )
+            .exit();
+        }
+    }
+}
+
+fn validate_max_message_size(message_size: u32, max_message_size: u32) {
+    if max_message_size < message_size {
+        IggyBenchArgs::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("Maximum message size ({max_message_size}) must be greater than or equal to the message size ({message_size})."),
+            )
+            .exit();
+    }
+}
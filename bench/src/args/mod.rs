@@ -1,4 +1,5 @@
 pub mod common;
+pub mod output;
 pub mod simple;
 
 mod defaults;
@@ -0,0 +1,10 @@
+use clap::ValueEnum;
+use derive_more::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Display)]
+pub enum OutputFormat {
+    #[display(fmt = "json")]
+    Json,
+    #[display(fmt = "csv")]
+    Csv,
+}
@@ -1,6 +1,8 @@
 use derive_more::Display;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BenchmarkKind {
     #[display(fmt = "send messages")]
     Send,
@@ -8,4 +10,17 @@ pub enum BenchmarkKind {
     Poll,
     #[display(fmt = "send and poll messages")]
     SendAndPoll,
+    #[display(fmt = "send and poll messages in consumer group")]
+    ConsumerGroup,
+}
+
+impl BenchmarkKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BenchmarkKind::Send => "send",
+            BenchmarkKind::Poll => "poll",
+            BenchmarkKind::SendAndPoll => "send_and_poll",
+            BenchmarkKind::ConsumerGroup => "consumer_group",
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use crate::args::simple::BenchmarkKind;
+use crate::benchmark_result::{BenchmarkResult, ThroughputSample};
+use hdrhistogram::Histogram;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Number of significant value digits kept by the latency histogram.
+const LATENCY_SIGNIFICANT_DIGITS: u8 = 3;
+
+/// Records the latency of each batch (in microseconds) and the number of messages
+/// and bytes processed in each second of the benchmark for a single actor.
+pub struct BenchmarkRecorder {
+    kind: BenchmarkKind,
+    actor_id: u32,
+    start_timestamp: Instant,
+    latencies: Histogram<u64>,
+    throughput: Vec<ThroughputSample>,
+    total_size_bytes: u64,
+    total_messages: u64,
+}
+
+impl BenchmarkRecorder {
+    pub fn new(kind: BenchmarkKind, actor_id: u32) -> Self {
+        Self {
+            kind,
+            actor_id,
+            start_timestamp: Instant::now(),
+            latencies: Histogram::new(LATENCY_SIGNIFICANT_DIGITS)
+                .expect("Failed to create latency histogram"),
+            throughput: Vec::new(),
+            total_size_bytes: 0,
+            total_messages: 0,
+        }
+    }
+
+    pub fn record_batch(&mut self, latency: Duration, messages: u64, size_bytes: u64) {
+        self.latencies
+            .saturating_record(latency.as_micros().max(1) as u64);
+        self.total_messages += messages;
+        self.total_size_bytes += size_bytes;
+
+        let second = self.start_timestamp.elapsed().as_secs();
+        while self.throughput.len() as u64 <= second {
+            self.throughput.push(ThroughputSample {
+                second: self.throughput.len() as u64,
+                messages: 0,
+                size_bytes: 0,
+            });
+        }
+        let sample = &mut self.throughput[second as usize];
+        sample.messages += messages;
+        sample.size_bytes += size_bytes;
+    }
+
+    pub fn finish(self) -> BenchmarkResult {
+        BenchmarkResult {
+            kind: self.kind,
+            actor_id: self.actor_id,
+            start_timestamp: self.start_timestamp,
+            end_timestamp: Instant::now(),
+            latencies: self.latencies,
+            throughput: self.throughput,
+            total_size_bytes: self.total_size_bytes,
+            total_messages: self.total_messages,
+        }
+    }
+}
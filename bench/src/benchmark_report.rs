@@ -0,0 +1,137 @@
+use crate::args::output::OutputFormat;
+use crate::args::simple::BenchmarkKind;
+use crate::benchmark_result::{
+    BenchmarkResults, BenchmarkStatistics, LatencyStatistics, ThroughputSample,
+};
+use serde::Serialize;
+use std::fmt::Write;
+
+const CSV_HEADER: &str = "scope,kind,actor_id,total_messages,total_size_bytes,duration_s,throughput_mb_s,messages_per_second,latency_avg_ms,latency_p50_ms,latency_p90_ms,latency_p99_ms,latency_p999_ms,latency_max_ms";
+
+/// Machine-readable representation of the benchmark results,
+/// which can be stored and compared across different runs.
+#[derive(Debug, Serialize)]
+pub struct BenchmarkReport {
+    pub kind: Option<BenchmarkKind>,
+    pub summary: Option<BenchmarkStatistics>,
+    pub producers: Option<BenchmarkStatistics>,
+    pub consumers: Option<BenchmarkStatistics>,
+    pub actors: Vec<ActorReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ActorReport {
+    pub kind: BenchmarkKind,
+    pub actor_id: u32,
+    pub total_messages: u64,
+    pub total_size_bytes: u64,
+    pub duration: f64,
+    pub throughput: f64,
+    pub messages_per_second: f64,
+    pub latency: LatencyStatistics,
+    pub throughput_series: Vec<ThroughputSample>,
+}
+
+impl From<&BenchmarkResults> for BenchmarkReport {
+    fn from(results: &BenchmarkResults) -> Self {
+        let actors = results
+            .results()
+            .iter()
+            .map(|result| {
+                let duration = result.duration().as_secs_f64();
+                ActorReport {
+                    kind: result.kind,
+                    actor_id: result.actor_id,
+                    total_messages: result.total_messages,
+                    total_size_bytes: result.total_size_bytes,
+                    duration,
+                    throughput: result.total_size_bytes as f64 / duration / 1e6,
+                    messages_per_second: result.total_messages as f64 / duration,
+                    latency: result.latency_statistics(),
+                    throughput_series: result.throughput.clone(),
+                }
+            })
+            .collect();
+
+        Self {
+            kind: results.get_test_type().ok(),
+            summary: results.calculate_statistics(|_| true),
+            producers: results.calculate_statistics(|x| x.kind == BenchmarkKind::Send),
+            consumers: results.calculate_statistics(|x| x.kind == BenchmarkKind::Poll),
+            actors,
+        }
+    }
+}
+
+impl BenchmarkReport {
+    pub fn format(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(self).expect("Failed to serialize benchmark report")
+            }
+            OutputFormat::Csv => self.to_csv(),
+        }
+    }
+
+    /// Writes a single row per aggregated scope (summary, producers and consumers) and per actor.
+    /// Throughput time series are available only in the JSON format.
+    fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        writeln!(csv, "{CSV_HEADER}").unwrap();
+        let kind = self.kind.map(|kind| kind.as_str()).unwrap_or_default();
+        let scopes = [
+            ("summary", kind, &self.summary),
+            ("producers", BenchmarkKind::Send.as_str(), &self.producers),
+            ("consumers", BenchmarkKind::Poll.as_str(), &self.consumers),
+        ];
+        for (scope, kind, statistics) in scopes {
+            let Some(statistics) = statistics else {
+                continue;
+            };
+            let total_messages = statistics
+                .throughput
+                .iter()
+                .map(|sample| sample.messages)
+                .sum::<u64>();
+            let total_size_bytes = statistics
+                .throughput
+                .iter()
+                .map(|sample| sample.size_bytes)
+                .sum::<u64>();
+            writeln!(
+                csv,
+                "{scope},{kind},,{total_messages},{total_size_bytes},{:.3},{:.3},{:.0},{}",
+                statistics.total_duration,
+                statistics.total_throughput,
+                statistics.messages_per_second,
+                Self::latency_to_csv(&statistics.latency)
+            )
+            .unwrap();
+        }
+
+        for actor in &self.actors {
+            writeln!(
+                csv,
+                "actor,{},{},{},{},{:.3},{:.3},{:.0},{}",
+                actor.kind.as_str(),
+                actor.actor_id,
+                actor.total_messages,
+                actor.total_size_bytes,
+                actor.duration,
+                actor.throughput,
+                actor.messages_per_second,
+                Self::latency_to_csv(&actor.latency)
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+
+    fn latency_to_csv(latency: &LatencyStatistics) -> String {
+        format!(
+            "{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}",
+            latency.average, latency.p50, latency.p90, latency.p99, latency.p999, latency.max
+        )
+    }
+}
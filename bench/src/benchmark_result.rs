@@ -1,5 +1,7 @@
 use crate::args::simple::BenchmarkKind;
 use colored::Colorize;
+use hdrhistogram::Histogram;
+use serde::Serialize;
 use std::collections::HashSet;
 use std::{
     fmt::{Display, Formatter},
@@ -10,13 +12,24 @@ use tokio::time::Instant;
 #[derive(Debug, Clone, PartialEq)]
 pub struct BenchmarkResult {
     pub kind: BenchmarkKind,
+    pub actor_id: u32,
     pub start_timestamp: Instant,
     pub end_timestamp: Instant,
-    pub average_latency: Duration,
+    /// Latencies of the sent or polled batches in microseconds.
+    pub latencies: Histogram<u64>,
+    /// Number of messages and bytes processed in each second since the start of the actor.
+    pub throughput: Vec<ThroughputSample>,
     pub total_size_bytes: u64,
     pub total_messages: u64,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThroughputSample {
+    pub second: u64,
+    pub messages: u64,
+    pub size_bytes: u64,
+}
+
 pub struct BenchmarkResults {
     results: Vec<BenchmarkResult>,
 }
@@ -26,20 +39,71 @@ impl From<Vec<BenchmarkResult>> for BenchmarkResults {
         Self { results }
     }
 }
-#[derive(Debug, Clone, PartialEq)]
-struct BenchmarkStatistics {
-    total_throughput: f64,
-    messages_per_second: f64,
-    average_latency: f64,
-    average_throughput: f64,
-    total_duration: f64,
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkStatistics {
+    pub total_throughput: f64,
+    pub messages_per_second: f64,
+    pub average_throughput: f64,
+    pub total_duration: f64,
+    pub latency: LatencyStatistics,
+    pub throughput: Vec<ThroughputSample>,
+}
+
+/// Latency statistics in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyStatistics {
+    pub average: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub p999: f64,
+    pub max: f64,
 }
 
 #[derive(Debug, Clone)]
-struct ImpossibleBenchmarkKind;
+pub struct ImpossibleBenchmarkKind;
+
+impl From<&Histogram<u64>> for LatencyStatistics {
+    fn from(latencies: &Histogram<u64>) -> Self {
+        let to_millis = |micros: u64| micros as f64 / 1000.0;
+        Self {
+            average: latencies.mean() / 1000.0,
+            p50: to_millis(latencies.value_at_quantile(0.5)),
+            p90: to_millis(latencies.value_at_quantile(0.9)),
+            p99: to_millis(latencies.value_at_quantile(0.99)),
+            p999: to_millis(latencies.value_at_quantile(0.999)),
+            max: to_millis(latencies.max()),
+        }
+    }
+}
+
+impl Display for LatencyStatistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "average latency: {:.2} ms, p50: {:.2} ms, p90: {:.2} ms, p99: {:.2} ms, p99.9: {:.2} ms, max: {:.2} ms",
+            self.average, self.p50, self.p90, self.p99, self.p999, self.max
+        )
+    }
+}
+
+impl BenchmarkResult {
+    pub fn duration(&self) -> Duration {
+        self.end_timestamp - self.start_timestamp
+    }
+
+    pub fn latency_statistics(&self) -> LatencyStatistics {
+        LatencyStatistics::from(&self.latencies)
+    }
+}
 
 impl BenchmarkResults {
-    fn get_test_type(&self) -> Result<BenchmarkKind, ImpossibleBenchmarkKind> {
+    pub fn results(&self) -> &[BenchmarkResult] {
+        &self.results
+    }
+
+    pub fn get_test_type(&self) -> Result<BenchmarkKind, ImpossibleBenchmarkKind> {
         let result_kinds = self
             .results
             .iter()
@@ -56,77 +120,157 @@ impl BenchmarkResults {
         }
     }
 
-    fn calculate_statitics<F>(&self, mut predicate: F) -> BenchmarkStatistics
+    /// Calculates the statistics of the results matching the predicate,
+    /// returns `None` if there are no such results.
+    pub fn calculate_statistics<F>(&self, predicate: F) -> Option<BenchmarkStatistics>
     where
         F: FnMut(&&BenchmarkResult) -> bool,
     {
-        let total_size_bytes = self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.total_size_bytes)
-            .sum::<u64>();
-        let total_duration = (self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.end_timestamp - r.start_timestamp)
-            .sum::<Duration>()
-            / self.results.len() as u32)
+        let results = self.results.iter().filter(predicate).collect::<Vec<_>>();
+        if results.is_empty() {
+            return None;
+        }
+
+        let results_count = results.len();
+        let total_size_bytes = results.iter().map(|r| r.total_size_bytes).sum::<u64>();
+        let total_duration = (results.iter().map(|r| r.duration()).sum::<Duration>()
+            / results_count as u32)
             .as_secs_f64();
-        let total_messages = self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.total_messages)
-            .sum::<u64>();
-        let average_latency = (self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.average_latency)
-            .sum::<Duration>()
-            / self.results.len() as u32)
-            .as_secs_f64()
-            * 1000.0;
+        let total_messages = results.iter().map(|r| r.total_messages).sum::<u64>();
+        let mut latencies = results[0].latencies.clone();
+        for result in &results[1..] {
+            latencies
+                .add(&result.latencies)
+                .expect("Failed to merge latency histograms");
+        }
+
+        // The samples are counted from the start of each actor, so they're shifted to the seconds
+        // since the start of the first one, before summing up the actors running at the same time.
+        let benchmark_start = results.iter().map(|r| r.start_timestamp).min().unwrap();
+        let mut throughput: Vec<ThroughputSample> = Vec::new();
+        for result in &results {
+            let start_offset = result.start_timestamp - benchmark_start;
+            for sample in &result.throughput {
+                let second = (start_offset + Duration::from_secs(sample.second)).as_secs();
+                while throughput.len() as u64 <= second {
+                    throughput.push(ThroughputSample {
+                        second: throughput.len() as u64,
+                        messages: 0,
+                        size_bytes: 0,
+                    });
+                }
+                let total = &mut throughput[second as usize];
+                total.messages += sample.messages;
+                total.size_bytes += sample.size_bytes;
+            }
+        }
+
         let average_throughput =
-            total_size_bytes as f64 / total_duration / 1e6 / self.results.len() as f64;
+            total_size_bytes as f64 / total_duration / 1e6 / results_count as f64;
         let total_throughput = total_size_bytes as f64 / total_duration / 1e6;
         let messages_per_second = total_messages as f64 / total_duration;
 
-        BenchmarkStatistics {
+        Some(BenchmarkStatistics {
             total_throughput,
             messages_per_second,
-            average_latency,
             average_throughput,
             total_duration,
-        }
+            latency: LatencyStatistics::from(&latencies),
+            throughput,
+        })
     }
 }
 
 impl Display for BenchmarkResults {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for result in &self.results {
+            let actor = match result.kind {
+                BenchmarkKind::Send => "Producer",
+                _ => "Consumer",
+            };
+            writeln!(
+                f,
+                "{} #{} → {}",
+                actor,
+                result.actor_id,
+                result.latency_statistics()
+            )?;
+        }
+
         if let Ok(test_type) = self.get_test_type() {
             if test_type == BenchmarkKind::SendAndPoll {
-                let producer_statics = self.calculate_statitics(|x| x.kind == BenchmarkKind::Send);
-                let consumer_statics = self.calculate_statitics(|x| x.kind == BenchmarkKind::Poll);
+                let producer_statics = self
+                    .calculate_statistics(|x| x.kind == BenchmarkKind::Send)
+                    .unwrap();
+                let consumer_statics = self
+                    .calculate_statistics(|x| x.kind == BenchmarkKind::Poll)
+                    .unwrap();
 
-                let producer_info = format!("Producer results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average throughput: {:.2} MB/s, total duration: {:.2} s",
-                producer_statics.total_throughput, producer_statics.messages_per_second, producer_statics.average_latency, producer_statics.average_throughput, producer_statics.total_duration).green();
+                let producer_info = format!("Producer results: total throughput: {:.2} MB/s, {:.0} messages/s, {}, average throughput: {:.2} MB/s, total duration: {:.2} s",
+                producer_statics.total_throughput, producer_statics.messages_per_second, producer_statics.latency, producer_statics.average_throughput, producer_statics.total_duration).green();
 
-                let consumer_info = format!("Consumer results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average  throughput: {:.2} MB/s, total duration: {:.2} s",
-                consumer_statics.total_throughput, consumer_statics.messages_per_second, consumer_statics.average_latency, consumer_statics.average_throughput, consumer_statics.total_duration).green();
-                writeln!(f, "{}, {}", producer_info, consumer_info)?;
+                let consumer_info = format!("Consumer results: total throughput: {:.2} MB/s, {:.0} messages/s, {}, average throughput: {:.2} MB/s, total duration: {:.2} s",
+                consumer_statics.total_throughput, consumer_statics.messages_per_second, consumer_statics.latency, consumer_statics.average_throughput, consumer_statics.total_duration).green();
+                writeln!(f, "{}", producer_info)?;
+                writeln!(f, "{}", consumer_info)?;
             }
         }
 
-        let results = self.calculate_statitics(|x| {
+        let Some(results) = self.calculate_statistics(|x| {
             x.kind == BenchmarkKind::Send || x.kind == BenchmarkKind::Poll
-        });
+        }) else {
+            return Ok(());
+        };
 
-        let summary_info = format!("Results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average  throughput: {:.2} MB/s, total duration: {:.2} s",
-        results.total_throughput, results.messages_per_second, results.average_latency, results.average_throughput, results.total_duration).green();
+        let summary_info = format!("Results: total throughput: {:.2} MB/s, {:.0} messages/s, {}, average throughput: {:.2} MB/s, total duration: {:.2} s",
+        results.total_throughput, results.messages_per_second, results.latency, results.average_throughput, results.total_duration).green();
 
         writeln!(f, "{}", summary_info)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throughput_should_be_aggregated_by_seconds_since_benchmark_start() {
+        let start_timestamp = Instant::now();
+        let results = BenchmarkResults::from(vec![
+            get_result(1, start_timestamp, 2),
+            get_result(2, start_timestamp + Duration::from_millis(1500), 2),
+        ]);
+
+        let statistics = results.calculate_statistics(|_| true).unwrap();
+
+        assert_eq!(
+            statistics.throughput,
+            vec![
+                get_sample(0, 100),
+                get_sample(1, 200),
+                get_sample(2, 100),
+            ]
+        );
+    }
+
+    fn get_result(actor_id: u32, start_timestamp: Instant, seconds: u64) -> BenchmarkResult {
+        BenchmarkResult {
+            kind: BenchmarkKind::Send,
+            actor_id,
+            start_timestamp,
+            end_timestamp: start_timestamp + Duration::from_secs(seconds),
+            latencies: Histogram::new(3).unwrap(),
+            throughput: (0..seconds).map(|second| get_sample(second, 100)).collect(),
+            total_size_bytes: seconds * 100,
+            total_messages: seconds * 100,
+        }
+    }
+
+    fn get_sample(second: u64, messages: u64) -> ThroughputSample {
+        ThroughputSample {
+            second,
+            messages,
+            size_bytes: messages,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::args::common::IggyBenchArgs;
+use crate::benchmark_report::BenchmarkReport;
 use crate::benchmark_result::BenchmarkResults;
 use crate::benchmarks::benchmark::Benchmarkable;
 use crate::server_starter::start_server_if_needed;
@@ -29,6 +30,8 @@ impl BenchmarkRunner {
         let transport = args.transport();
         let server_addr = args.server_address();
         info!("Starting to benchmark: {transport} with server: {server_addr}",);
+        let output = args.output;
+        let output_path = args.output_path.take();
 
         let mut benchmark: Box<dyn Benchmarkable> = args.into();
         let mut join_handles = benchmark.run().await?;
@@ -53,6 +56,17 @@ impl BenchmarkRunner {
             .to_string()
             .split('\n')
             .for_each(|result| info!("{}", result));
+
+        if let Some(output) = output {
+            let report = BenchmarkReport::from(&results).format(output);
+            match output_path {
+                Some(output_path) => {
+                    std::fs::write(&output_path, report)?;
+                    info!("Benchmark results in {output} format written to: {output_path}");
+                }
+                None => println!("{report}"),
+            }
+        }
         Ok(())
     }
 }
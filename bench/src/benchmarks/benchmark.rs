@@ -1,6 +1,6 @@
 use super::{
-    poll_benchmark::PollMessagesBenchmark, send_and_poll_benchmark::SendAndPollMessagesBenchmark,
-    send_benchmark::SendMessagesBenchmark,
+    consumer_group_benchmark::ConsumerGroupBenchmark, poll_benchmark::PollMessagesBenchmark,
+    send_and_poll_benchmark::SendAndPollMessagesBenchmark, send_benchmark::SendMessagesBenchmark,
 };
 use crate::{
     args::{common::IggyBenchArgs, simple::BenchmarkKind},
@@ -18,7 +18,7 @@ use iggy::{
     topics::create_topic::CreateTopic,
 };
 use integration::test_server::{login_root, ClientFactory};
-use std::{pin::Pin, sync::Arc};
+use std::{collections::HashMap, pin::Pin, sync::Arc};
 use tracing::info;
 
 pub type BenchmarkFutures =
@@ -39,6 +39,9 @@ impl From<IggyBenchArgs> for Box<dyn Benchmarkable> {
                 Arc::new(args),
                 client_factory,
             )),
+            BenchmarkKind::ConsumerGroup => {
+                Box::new(ConsumerGroupBenchmark::new(Arc::new(args), client_factory))
+            }
         }
     }
 }
@@ -59,7 +62,7 @@ pub trait Benchmarkable {
         let start_stream_id = self.args().start_stream_id();
         let number_of_streams = self.args().number_of_streams();
         let topic_id: u32 = 1;
-        let partitions_count: u32 = 1;
+        let partitions_count = self.args().number_of_partitions();
         let client = self.client_factory().create_client().await;
         let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
         login_root(&client).await;
@@ -85,6 +88,7 @@ pub trait Benchmarkable {
                         partitions_count,
                         name,
                         message_expiry: None,
+                        config: HashMap::new(),
                     })
                     .await?;
             }
@@ -0,0 +1,208 @@
+use super::benchmark::{BenchmarkFutures, Benchmarkable};
+use crate::args::common::IggyBenchArgs;
+use crate::args::simple::BenchmarkKind;
+use crate::group_consumer::GroupConsumer;
+use crate::producer::Producer;
+use async_trait::async_trait;
+use colored::Colorize;
+use human_bytes::human_bytes;
+use human_format::Formatter;
+use iggy::client::{ConsumerGroupClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{login_root, ClientFactory};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tracing::info;
+
+const CONSUMER_GROUP_ID: u32 = 1;
+
+pub struct ConsumerGroupBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+}
+
+impl ConsumerGroupBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>, client_factory: Arc<dyn ClientFactory>) -> Self {
+        Self {
+            args,
+            client_factory,
+        }
+    }
+
+    /// Recreates the stream with a single topic and the consumer group,
+    /// so that the members always start polling from the beginning of the partitions.
+    async fn init_consumer_group(&self) -> Result<(), Error> {
+        let stream_id = self.args.start_stream_id() + 1;
+        let topic_id: u32 = 1;
+        let partitions_count = self.args.number_of_partitions();
+        let client = self.client_factory.create_client().await;
+        let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+        login_root(&client).await;
+        let streams = client.get_streams(&GetStreams {}).await?;
+        if streams.iter().any(|s| s.id == stream_id) {
+            info!("Deleting the existing test stream {}", stream_id);
+            client
+                .delete_stream(&DeleteStream {
+                    stream_id: Identifier::numeric(stream_id)?,
+                })
+                .await?;
+        }
+
+        info!("Creating the test stream {}", stream_id);
+        let name = format!("stream {}", stream_id);
+        client
+            .create_stream(&CreateStream { stream_id, name })
+            .await?;
+
+        info!(
+            "Creating the test topic {} with {} partitions for stream {}",
+            topic_id, partitions_count, stream_id
+        );
+        let name = format!("topic {}", topic_id);
+        client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(stream_id)?,
+                topic_id,
+                partitions_count,
+                name,
+                message_expiry: None,
+                config: HashMap::new(),
+            })
+            .await?;
+
+        info!(
+            "Creating the test consumer group {} for topic {} in stream {}",
+            CONSUMER_GROUP_ID, topic_id, stream_id
+        );
+        client
+            .create_consumer_group(&CreateConsumerGroup {
+                stream_id: Identifier::numeric(stream_id)?,
+                topic_id: Identifier::numeric(topic_id)?,
+                consumer_group_id: CONSUMER_GROUP_ID,
+                name: format!("consumer group {}", CONSUMER_GROUP_ID),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Total number of messages sent by all the producers to the single stream.
+    fn total_group_messages(&self) -> u64 {
+        let messages_per_batch = self.args.messages_per_batch() as u64;
+        let message_batches = self.args.message_batches() as u64;
+        let producers = self.args.producers() as u64;
+        messages_per_batch * message_batches * producers
+    }
+}
+
+impl Display for ConsumerGroupBenchmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let total_messages = self.total_group_messages();
+        let total_size_bytes = total_messages * self.args().message_size() as u64;
+        let total_messages_human_readable = Formatter::new().format(total_messages as f64);
+        let total_size_human_readable = human_bytes(total_size_bytes as f64);
+        let message_size = match self.args().max_message_size() {
+            Some(max_message_size) => {
+                format!("{}-{}", self.args().message_size(), max_message_size)
+            }
+            None => self.args().message_size().to_string(),
+        };
+        let message_rate = match self.args().message_rate() {
+            Some(message_rate) => format!("{} messages/s per producer", message_rate),
+            None => "unlimited".to_string(),
+        };
+        let info = format!("Benchmark: {}, transport: {}, total messages: {}, total size: at least {} bytes, {} partitions, {} messages per batch, {} batches, {} bytes per message, rate: {}, {} producers, {} consumers",
+                self.kind(),
+                self.args().transport(),
+                total_messages_human_readable,
+                total_size_human_readable,
+                self.args().number_of_partitions(),
+                self.args().messages_per_batch(),
+                self.args().message_batches(),
+                message_size,
+                message_rate,
+                self.args().producers(),
+                self.args().consumers(),
+            ).green();
+
+        writeln!(f, "{}", info)
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for ConsumerGroupBenchmark {
+    async fn run(&mut self) -> BenchmarkFutures {
+        self.init_consumer_group().await?;
+        let stream_id = self.args.start_stream_id() + 1;
+        let producers = self.args.producers();
+        let consumers = self.args.consumers();
+        let messages_per_batch = self.args.messages_per_batch();
+        let message_batches = self.args.message_batches();
+        let message_size = self.args.message_size();
+        let max_message_size = self.args.max_message_size();
+        let message_rate = self.args.message_rate();
+        let partitions_count = self.args.number_of_partitions();
+        let total_messages = self.total_group_messages();
+        let polled_messages = Arc::new(AtomicU64::new(0));
+        let mut futures: BenchmarkFutures =
+            Ok(Vec::with_capacity((producers + consumers) as usize));
+        for producer_id in 1..=producers {
+            let producer = Producer::new(
+                self.client_factory.clone(),
+                producer_id,
+                stream_id,
+                partitions_count,
+                messages_per_batch,
+                message_batches,
+                message_size,
+                max_message_size,
+                message_rate,
+            );
+            let future = Box::pin(async move { producer.run().await });
+            futures.as_mut().unwrap().push(future);
+        }
+
+        for consumer_id in 1..=consumers {
+            let consumer = GroupConsumer::new(
+                self.client_factory.clone(),
+                consumer_id,
+                stream_id,
+                CONSUMER_GROUP_ID,
+                messages_per_batch,
+                total_messages,
+                polled_messages.clone(),
+            );
+            let future = Box::pin(async move { consumer.run().await });
+            futures.as_mut().unwrap().push(future);
+        }
+        info!(
+            "Starting to send and poll {} messages in consumer group",
+            total_messages
+        );
+        futures
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        BenchmarkKind::ConsumerGroup
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+
+    fn display_settings(&self) {
+        info!("{}", self.to_string());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod benchmark;
+pub mod consumer_group_benchmark;
 pub mod poll_benchmark;
 pub mod send_and_poll_benchmark;
 pub mod send_benchmark;
@@ -61,6 +61,9 @@ impl Benchmarkable for SendAndPollMessagesBenchmark {
         let messages_per_batch = self.args.messages_per_batch();
         let message_batches = self.args.message_batches();
         let message_size = self.args.message_size();
+        let max_message_size = self.args.max_message_size();
+        let message_rate = self.args.message_rate();
+        let partitions_count = self.args.number_of_partitions();
         let mut futures: BenchmarkFutures =
             Ok(Vec::with_capacity((producers + consumers) as usize));
         for producer_id in 1..=producers {
@@ -73,9 +76,12 @@ impl Benchmarkable for SendAndPollMessagesBenchmark {
                 self.client_factory.clone(),
                 producer_id,
                 stream_id,
+                partitions_count,
                 messages_per_batch,
                 message_batches,
                 message_size,
+                max_message_size,
+                message_rate,
             );
             let future = Box::pin(async move { producer.run().await });
             futures.as_mut().unwrap().push(future);
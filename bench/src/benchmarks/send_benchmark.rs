@@ -30,6 +30,9 @@ impl Benchmarkable for SendMessagesBenchmark {
         let messages_per_batch = self.args.messages_per_batch();
         let message_batches = self.args.message_batches();
         let message_size = self.args.message_size();
+        let max_message_size = self.args.max_message_size();
+        let message_rate = self.args.message_rate();
+        let partitions_count = self.args.number_of_partitions();
 
         let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(clients_count as usize));
         for client_id in 1..=clients_count {
@@ -50,9 +53,12 @@ impl Benchmarkable for SendMessagesBenchmark {
                 client_factory,
                 client_id,
                 stream_id,
+                partitions_count,
                 messages_per_batch,
                 message_batches,
                 message_size,
+                max_message_size,
+                message_rate,
             );
             let future = Box::pin(async move { producer.run().await });
             futures.as_mut().unwrap().push(future);
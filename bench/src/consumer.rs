@@ -1,14 +1,14 @@
 use crate::args::simple::BenchmarkKind;
+use crate::benchmark_recorder::BenchmarkRecorder;
 use crate::benchmark_result::BenchmarkResult;
 use iggy::client::MessageClient;
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer as IggyConsumer;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use integration::test_server::{login_root, ClientFactory};
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::time::Instant;
 use tracing::{info, trace};
 
@@ -61,13 +61,12 @@ impl Consumer {
             strategy: PollingStrategy::offset(0),
             count: self.messages_per_batch,
             auto_commit: false,
+            isolation: IsolationLevel::ReadUncommitted,
         };
 
-        let mut latencies: Vec<Duration> = Vec::with_capacity(self.message_batches as usize);
-        let mut total_size_bytes = 0;
         let mut current_iteration = 0;
         let mut received_messages = 0;
-        let start_timestamp = Instant::now();
+        let mut recorder = BenchmarkRecorder::new(BenchmarkKind::Poll, self.consumer_id);
         while received_messages < total_messages {
             let offset = (current_iteration * self.messages_per_batch) as u64;
             poll_messages.strategy.value = offset;
@@ -96,38 +95,36 @@ impl Consumer {
                 continue;
             }
 
-            latencies.push(latency_end);
+            let batch_size_bytes = polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum::<u64>();
             received_messages += polled_messages.messages.len() as u64;
-            for message in polled_messages.messages {
-                total_size_bytes += message.get_size_bytes() as u64;
-            }
+            recorder.record_batch(
+                latency_end,
+                polled_messages.messages.len() as u64,
+                batch_size_bytes,
+            );
             current_iteration += 1;
         }
 
-        let end_timestamp = Instant::now();
-        let duration = end_timestamp - start_timestamp;
-        let average_latency: Duration = latencies.iter().sum::<Duration>() / latencies.len() as u32;
-        let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
+        let result = recorder.finish();
+        let duration = result.duration();
+        let average_throughput = result.total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
 
         info!(
-        "Consumer #{} → polled {} messages ({} batches of {} messages in {} ms, total size: {} bytes, average latency: {:.2} ms, average throughput: {:.2} MB/s",
+        "Consumer #{} → polled {} messages ({} batches of {} messages in {} ms, total size: {} bytes, {}, average throughput: {:.2} MB/s",
         self.consumer_id,
         total_messages,
         self.message_batches,
         self.messages_per_batch,
         duration.as_millis(),
-        total_size_bytes,
-        average_latency.as_millis(),
+        result.total_size_bytes,
+        result.latency_statistics(),
         average_throughput
     );
 
-        Ok(BenchmarkResult {
-            kind: BenchmarkKind::Poll,
-            start_timestamp,
-            end_timestamp,
-            average_latency,
-            total_size_bytes,
-            total_messages,
-        })
+        Ok(result)
     }
 }
@@ -0,0 +1,155 @@
+use crate::args::simple::BenchmarkKind;
+use crate::benchmark_recorder::BenchmarkRecorder;
+use crate::benchmark_result::BenchmarkResult;
+use iggy::client::{ConsumerGroupClient, MessageClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer as IggyConsumer;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use integration::test_server::{login_root, ClientFactory};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::{info, trace};
+
+const MIN_EMPTY_POLL_BACKOFF: Duration = Duration::from_millis(1);
+const MAX_EMPTY_POLL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Consumer polling the messages as a member of the consumer group, the partitions are assigned
+/// by the server. All the members share the counter of the polled messages and stop once
+/// the expected number of messages has been polled by the whole group.
+pub struct GroupConsumer {
+    client_factory: Arc<dyn ClientFactory>,
+    consumer_id: u32,
+    stream_id: u32,
+    consumer_group_id: u32,
+    messages_per_batch: u32,
+    total_messages: u64,
+    polled_messages: Arc<AtomicU64>,
+}
+
+impl GroupConsumer {
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        consumer_id: u32,
+        stream_id: u32,
+        consumer_group_id: u32,
+        messages_per_batch: u32,
+        total_messages: u64,
+        polled_messages: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            client_factory,
+            consumer_id,
+            stream_id,
+            consumer_group_id,
+            messages_per_batch,
+            total_messages,
+            polled_messages,
+        }
+    }
+
+    pub async fn run(&self) -> Result<BenchmarkResult, Error> {
+        let topic_id: u32 = 1;
+        let client = self.client_factory.create_client().await;
+        let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+        login_root(&client).await;
+        let stream_id = Identifier::numeric(self.stream_id)?;
+        let topic_id = Identifier::numeric(topic_id)?;
+        let consumer_group_id = Identifier::numeric(self.consumer_group_id)?;
+        client
+            .join_consumer_group(&JoinConsumerGroup {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                consumer_group_id: consumer_group_id.clone(),
+            })
+            .await?;
+        info!(
+            "Consumer #{} → joined consumer group {}, polling {} messages in batches of {} messages together with the other members...",
+            self.consumer_id, self.consumer_group_id, self.total_messages, self.messages_per_batch
+        );
+
+        let poll_messages = PollMessages {
+            consumer: IggyConsumer::group(consumer_group_id),
+            stream_id,
+            topic_id,
+            partition_id: None,
+            strategy: PollingStrategy::next(),
+            count: self.messages_per_batch,
+            auto_commit: true,
+            isolation: IsolationLevel::ReadUncommitted,
+        };
+
+        let mut recorder = BenchmarkRecorder::new(BenchmarkKind::Poll, self.consumer_id);
+        let mut backoff = MIN_EMPTY_POLL_BACKOFF;
+        while self.polled_messages.load(Ordering::SeqCst) < self.total_messages {
+            let latency_start = Instant::now();
+            let polled_messages = client.poll_messages(&poll_messages).await?;
+            let latency_end = latency_start.elapsed();
+            if polled_messages.messages.is_empty() {
+                trace!(
+                    "Consumer #{} → no messages available yet, retrying in {} ms...",
+                    self.consumer_id,
+                    backoff.as_millis()
+                );
+                sleep(backoff).await;
+                backoff = next_backoff(backoff);
+                continue;
+            }
+
+            backoff = MIN_EMPTY_POLL_BACKOFF;
+
+            let batch_size_bytes = polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum::<u64>();
+            let messages_count = polled_messages.messages.len() as u64;
+            self.polled_messages
+                .fetch_add(messages_count, Ordering::SeqCst);
+            recorder.record_batch(latency_end, messages_count, batch_size_bytes);
+        }
+
+        let result = recorder.finish();
+        let duration = result.duration();
+        let average_throughput = result.total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
+
+        info!(
+        "Consumer #{} → polled {} messages in consumer group {} in {} ms, total size: {} bytes, {}, average throughput: {:.2} MB/s",
+        self.consumer_id,
+        result.total_messages,
+        self.consumer_group_id,
+        duration.as_millis(),
+        result.total_size_bytes,
+        result.latency_statistics(),
+        average_throughput
+    );
+
+        Ok(result)
+    }
+}
+
+/// Doubles the backoff after each empty poll, up to the maximum backoff.
+fn next_backoff(backoff: Duration) -> Duration {
+    (backoff * 2).min(MAX_EMPTY_POLL_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_should_be_doubled_up_to_max_backoff() {
+        let mut backoff = MIN_EMPTY_POLL_BACKOFF;
+        let mut backoffs = Vec::new();
+        for _ in 0..10 {
+            backoff = next_backoff(backoff);
+            backoffs.push(backoff.as_millis());
+        }
+
+        assert_eq!(backoffs, vec![2, 4, 8, 16, 32, 64, 100, 100, 100, 100]);
+    }
+}
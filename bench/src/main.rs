@@ -1,9 +1,12 @@
 mod args;
+mod benchmark_recorder;
+mod benchmark_report;
 mod benchmark_result;
 mod benchmark_runner;
 mod benchmarks;
 mod client_factory;
 mod consumer;
+mod group_consumer;
 mod producer;
 mod server_starter;
 
@@ -15,14 +18,18 @@ use tracing::{error, info};
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
-    let standard_font = FIGfont::standard().unwrap();
-    let figure = standard_font.convert("Iggy Bench");
-    println!("{}", figure.unwrap());
-    tracing_subscriber::fmt::Subscriber::builder()
-        .with_ansi(true)
-        .init();
-
     let args = IggyBenchArgs::parse();
+    let standard_font = FIGfont::standard().unwrap();
+    let figure = standard_font.convert("Iggy Bench").unwrap();
+    let subscriber = tracing_subscriber::fmt::Subscriber::builder().with_ansi(true);
+    // Keep stdout clean when machine-readable results are printed to it
+    if args.output.is_some() && args.output_path.is_none() {
+        eprintln!("{}", figure);
+        subscriber.with_writer(std::io::stderr).init();
+    } else {
+        println!("{}", figure);
+        subscriber.init();
+    }
     args.validate();
 
     let mut benchmark_runner = BenchmarkRunner::new(args);
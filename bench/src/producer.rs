@@ -1,48 +1,64 @@
 use crate::args::simple::BenchmarkKind;
+use crate::benchmark_recorder::BenchmarkRecorder;
 use crate::benchmark_result::BenchmarkResult;
 use iggy::client::MessageClient;
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use integration::test_server::{login_root, ClientFactory};
+use rand::Rng;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::Instant;
+use tokio::time::{sleep_until, Instant};
 use tracing::info;
 
 pub struct Producer {
     client_factory: Arc<dyn ClientFactory>,
     producer_id: u32,
     stream_id: u32,
+    partitions_count: u32,
     messages_per_batch: u32,
     message_batches: u32,
     message_size: u32,
+    max_message_size: Option<u32>,
+    message_rate: Option<u32>,
 }
 
 impl Producer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         client_factory: Arc<dyn ClientFactory>,
         producer_id: u32,
         stream_id: u32,
+        partitions_count: u32,
         messages_per_batch: u32,
         message_batches: u32,
         message_size: u32,
+        max_message_size: Option<u32>,
+        message_rate: Option<u32>,
     ) -> Self {
         Producer {
             client_factory,
             producer_id,
             stream_id,
+            partitions_count,
             messages_per_batch,
             message_batches,
             message_size,
+            max_message_size,
+            message_rate,
         }
     }
 
     pub async fn run(&self) -> Result<BenchmarkResult, Error> {
         let topic_id: u32 = 1;
         let partition_id: u32 = 1;
+        let partitioning = match self.partitions_count {
+            1 => Partitioning::partition_id(partition_id),
+            _ => Partitioning::balanced(),
+        };
         let total_messages = (self.messages_per_batch * self.message_batches) as u64;
         let client = self.client_factory.create_client().await;
         let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
@@ -51,18 +67,20 @@ impl Producer {
             "Producer #{} → preparing the test messages...",
             self.producer_id
         );
-        let payload = Self::create_payload(self.message_size);
-        let mut messages = Vec::with_capacity(self.messages_per_batch as usize);
-        for _ in 0..self.messages_per_batch {
-            let message = Message::from_str(&payload).unwrap();
-            messages.push(message);
-        }
+        let (messages, mut batch_size_bytes) = Self::create_messages(
+            self.messages_per_batch,
+            self.message_size,
+            self.max_message_size,
+        );
 
         let mut send_messages = SendMessages {
             stream_id: Identifier::numeric(self.stream_id)?,
             topic_id: Identifier::numeric(topic_id)?,
-            partitioning: Partitioning::partition_id(partition_id),
+            partitioning,
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
             messages,
+            transaction_id: None,
         };
 
         info!(
@@ -70,40 +88,79 @@ impl Producer {
             self.producer_id, total_messages, self.message_batches, self.messages_per_batch
         );
 
+        // In the open-loop mode, batches are sent at fixed intervals regardless of the server
+        // response time and latency includes the time spent waiting behind the delayed batches.
+        let batch_interval = self.message_rate.map(|message_rate| {
+            Duration::from_secs_f64(self.messages_per_batch as f64 / message_rate as f64)
+        });
+        let mut recorder = BenchmarkRecorder::new(BenchmarkKind::Send, self.producer_id);
         let start_timestamp = Instant::now();
-        let mut latencies: Vec<Duration> = Vec::with_capacity(self.message_batches as usize);
-        for _ in 0..self.message_batches {
-            let latency_start = Instant::now();
+        for batch in 0..self.message_batches {
+            // With the mixed message sizes, each batch gets the new sizes, prepared before the latency is measured.
+            if batch > 0 && self.max_message_size.is_some() {
+                (send_messages.messages, batch_size_bytes) = Self::create_messages(
+                    self.messages_per_batch,
+                    self.message_size,
+                    self.max_message_size,
+                );
+            }
+            let latency_start = match batch_interval {
+                Some(batch_interval) => {
+                    let scheduled_timestamp = start_timestamp + batch_interval * batch;
+                    sleep_until(scheduled_timestamp).await;
+                    scheduled_timestamp
+                }
+                None => Instant::now(),
+            };
             client.send_messages(&mut send_messages).await?;
             let latency_end = latency_start.elapsed();
-            latencies.push(latency_end);
+            recorder.record_batch(
+                latency_end,
+                self.messages_per_batch as u64,
+                batch_size_bytes,
+            );
         }
-        let end_timestamp = Instant::now();
-        let duration = end_timestamp - start_timestamp;
-        let average_latency: Duration = latencies.iter().sum::<Duration>() / latencies.len() as u32;
-        let total_size_bytes = total_messages * self.message_size as u64;
-        let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
+        let result = recorder.finish();
+        let duration = result.duration();
+        let average_throughput = result.total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
 
         info!(
-        "Producer #{} → sent {} messages in {} batches of {} messages in {:.2} s, total size: {} bytes, average latency: {:.2} ms, average throughput: {:.2} MB/s",
+        "Producer #{} → sent {} messages in {} batches of {} messages in {:.2} s, total size: {} bytes, {}, average throughput: {:.2} MB/s",
         self.producer_id,
-        total_messages,
+        result.total_messages,
         self.message_batches,
         self.messages_per_batch,
         duration.as_secs_f64(),
-        total_size_bytes,
-        average_latency.as_secs_f64() * 1000.0,
+        result.total_size_bytes,
+        result.latency_statistics(),
         average_throughput
     );
 
-        Ok(BenchmarkResult {
-            kind: BenchmarkKind::Send,
-            start_timestamp,
-            end_timestamp,
-            average_latency,
-            total_size_bytes,
-            total_messages,
-        })
+        Ok(result)
+    }
+
+    /// Creates the batch of messages, which sizes are randomly picked from the range
+    /// between message size and max message size, if the latter is provided.
+    fn create_messages(
+        messages_per_batch: u32,
+        message_size: u32,
+        max_message_size: Option<u32>,
+    ) -> (Vec<Message>, u64) {
+        let mut rng = rand::thread_rng();
+        let mut messages = Vec::with_capacity(messages_per_batch as usize);
+        let mut batch_size_bytes = 0;
+        for _ in 0..messages_per_batch {
+            let message_size = match max_message_size {
+                Some(max_message_size) => rng.gen_range(message_size..=max_message_size),
+                None => message_size,
+            };
+            let payload = Self::create_payload(message_size);
+            let message = Message::from_str(&payload).unwrap();
+            messages.push(message);
+            batch_size_bytes += message_size as u64;
+        }
+
+        (messages, batch_size_bytes)
     }
 
     fn create_payload(size: u32) -> String {
@@ -116,3 +173,49 @@ impl Producer {
         payload
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_should_have_the_message_size_given_no_max_message_size() {
+        let (messages, batch_size_bytes) = Producer::create_messages(10, 100, None);
+
+        assert_eq!(messages.len(), 10);
+        assert_eq!(batch_size_bytes, 1000);
+        assert!(messages.iter().all(|message| message.payload.len() == 100));
+    }
+
+    #[test]
+    fn messages_should_have_sizes_between_message_size_and_max_message_size() {
+        let (messages, batch_size_bytes) = Producer::create_messages(1000, 10, Some(20));
+
+        assert_eq!(messages.len(), 1000);
+        assert!(messages
+            .iter()
+            .all(|message| (10..=20).contains(&message.payload.len())));
+        assert_eq!(
+            batch_size_bytes,
+            messages
+                .iter()
+                .map(|message| message.payload.len() as u64)
+                .sum::<u64>()
+        );
+    }
+
+    #[test]
+    fn each_batch_should_get_new_message_sizes_given_max_message_size() {
+        let batches = (0..10)
+            .map(|_| {
+                Producer::create_messages(10, 1, Some(1000))
+                    .0
+                    .iter()
+                    .map(|message| message.payload.len())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(batches.iter().any(|sizes| sizes != &batches[0]));
+    }
+}
@@ -83,6 +83,14 @@ pub async fn start_server_if_needed(args: &mut IggyBenchArgs) -> Option<TestServ
             args.server_system_path.clone(),
         );
 
+        if let Some(persister) = &args.server_persister {
+            envs.insert(
+                "IGGY_SYSTEM_PARTITION_PERSISTER".to_owned(),
+                persister.clone(),
+            );
+            info!("Using {persister} persister in iggy-server");
+        }
+
         if args.verbose {
             envs.insert("IGGY_TEST_VERBOSE".to_owned(), "true".to_owned());
             info!("Enabling verbose output - iggy-server will logs print to stdout")
@@ -1,6 +1,6 @@
 use crate::{
     consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, streams,
-    system, topics, users,
+    system, topics, transactions, users,
 };
 use iggy::client_error::ClientError;
 use iggy::clients::client::IggyClient;
@@ -15,6 +15,9 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
     match command {
         Command::Ping(payload) => system::ping(&payload, client).await,
         Command::GetStats(payload) => system::get_stats(&payload, client).await,
+        Command::CreateBackup(payload) => system::create_backup(&payload, client).await,
+        Command::SetLogLevel(payload) => system::set_log_level(&payload, client).await,
+        Command::ReloadConfig(payload) => system::reload_config(&payload, client).await,
         Command::GetMe(payload) => system::get_me(&payload, client).await,
         Command::GetClient(payload) => system::get_client(&payload, client).await,
         Command::GetClients(payload) => system::get_clients(&payload, client).await,
@@ -40,6 +43,16 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
             personal_access_tokens::login_with_personal_access_token(&payload, client).await
         }
         Command::SendMessages(mut payload) => messages::send_messages(&mut payload, client).await,
+        Command::RegisterProducer(payload) => messages::register_producer(&payload, client).await,
+        Command::BeginTransaction(payload) => {
+            transactions::begin_transaction(&payload, client).await
+        }
+        Command::CommitTransaction(payload) => {
+            transactions::commit_transaction(&payload, client).await
+        }
+        Command::AbortTransaction(payload) => {
+            transactions::abort_transaction(&payload, client).await
+        }
         Command::PollMessages(payload) => {
             let format = match input.split('|').last() {
                 Some(format) => match format {
@@ -64,12 +77,15 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
         Command::DeleteStream(payload) => streams::delete_stream(&payload, client).await,
         Command::UpdateStream(payload) => streams::update_stream(&payload, client).await,
         Command::PurgeStream(payload) => streams::purge_stream(&payload, client).await,
+        Command::RestoreStream(payload) => streams::restore_stream(&payload, client).await,
         Command::GetTopic(payload) => topics::get_topic(&payload, client).await,
         Command::GetTopics(payload) => topics::get_topics(&payload, client).await,
         Command::CreateTopic(payload) => topics::create_topic(&payload, client).await,
         Command::DeleteTopic(payload) => topics::delete_topic(&payload, client).await,
         Command::UpdateTopic(payload) => topics::update_topic(&payload, client).await,
         Command::PurgeTopic(payload) => topics::purge_topic(&payload, client).await,
+        Command::RestoreTopic(payload) => topics::restore_topic(&payload, client).await,
+        Command::RepartitionTopic(payload) => topics::repartition_topic(&payload, client).await,
         Command::CreatePartitions(payload) => partitions::create_partitions(&payload, client).await,
         Command::DeletePartitions(payload) => partitions::delete_partitions(&payload, client).await,
         Command::GetConsumerGroup(payload) => {
@@ -7,6 +7,7 @@ mod personal_access_tokens;
 mod streams;
 mod system;
 mod topics;
+mod transactions;
 mod users;
 
 use anyhow::Result;
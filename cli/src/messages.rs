@@ -1,6 +1,7 @@
 use crate::command::{Format, PollMessagesWithFormat};
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::messages::register_producer::RegisterProducer;
 use iggy::messages::send_messages::SendMessages;
 use tracing::info;
 
@@ -12,6 +13,15 @@ pub async fn send_messages(
     Ok(())
 }
 
+pub async fn register_producer(
+    command: &RegisterProducer,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let producer = client.register_producer(command).await?;
+    info!("Producer: {:#?}", producer);
+    Ok(())
+}
+
 pub async fn poll_messages(
     command: &PollMessagesWithFormat,
     client: &dyn Client,
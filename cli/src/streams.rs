@@ -5,6 +5,7 @@ use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
+use iggy::streams::restore_stream::RestoreStream;
 use iggy::streams::update_stream::UpdateStream;
 use tracing::info;
 
@@ -44,3 +45,11 @@ pub async fn purge_stream(command: &PurgeStream, client: &dyn Client) -> Result<
     client.purge_stream(command).await?;
     Ok(())
 }
+
+pub async fn restore_stream(
+    command: &RestoreStream,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.restore_stream(command).await?;
+    Ok(())
+}
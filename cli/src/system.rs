@@ -1,10 +1,13 @@
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::system::create_backup::CreateBackup;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::reload_config::ReloadConfig;
+use iggy::system::set_log_level::SetLogLevel;
 use tracing::info;
 
 pub async fn ping(command: &Ping, client: &dyn Client) -> Result<(), ClientError> {
@@ -18,6 +21,24 @@ pub async fn get_stats(command: &GetStats, client: &dyn Client) -> Result<(), Cl
     Ok(())
 }
 
+pub async fn create_backup(command: &CreateBackup, client: &dyn Client) -> Result<(), ClientError> {
+    let backup = client.create_backup(command).await?;
+    info!("Backup: {:#?}", backup);
+    Ok(())
+}
+
+pub async fn set_log_level(command: &SetLogLevel, client: &dyn Client) -> Result<(), ClientError> {
+    client.set_log_level(command).await?;
+    info!("Log level set to: {}", command.level);
+    Ok(())
+}
+
+pub async fn reload_config(command: &ReloadConfig, client: &dyn Client) -> Result<(), ClientError> {
+    client.reload_config(command).await?;
+    info!("Server configuration reloaded.");
+    Ok(())
+}
+
 pub async fn get_me(command: &GetMe, client: &dyn Client) -> Result<(), ClientError> {
     let me = client.get_me(command).await?;
     info!("Me: {:#?}", me);
@@ -5,6 +5,8 @@ use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::topics::get_topics::GetTopics;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::repartition_topic::RepartitionTopic;
+use iggy::topics::restore_topic::RestoreTopic;
 use iggy::topics::update_topic::UpdateTopic;
 use tracing::info;
 
@@ -44,3 +46,16 @@ pub async fn purge_topic(command: &PurgeTopic, client: &dyn Client) -> Result<()
     client.purge_topic(command).await?;
     Ok(())
 }
+
+pub async fn restore_topic(command: &RestoreTopic, client: &dyn Client) -> Result<(), ClientError> {
+    client.restore_topic(command).await?;
+    Ok(())
+}
+
+pub async fn repartition_topic(
+    command: &RepartitionTopic,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.repartition_topic(command).await?;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ApplyArgs {
+    /// Path to the document describing desired server state
+    ///
+    /// Document format is detected using file extension,
+    /// supported formats are TOML (.toml), YAML (.yaml, .yml) and JSON (.json).
+    #[arg(verbatim_doc_comment)]
+    pub(crate) file: PathBuf,
+    /// Only print changes which would be made, without applying them
+    #[arg(long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+    /// Delete resources which exist on the server but are missing in the document
+    ///
+    /// Root user and currently logged in user are never deleted.
+    #[arg(long, default_value_t = false, verbatim_doc_comment)]
+    pub(crate) delete: bool,
+}
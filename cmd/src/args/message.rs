@@ -0,0 +1,204 @@
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use iggy::cmd::messages::poll_messages::PollMessagesOutput;
+use iggy::cmd::utils::message_header::parse_message_header;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum MessageAction {
+    /// Send messages to given topic ID and given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Messages can be provided as command line arguments (each argument is
+    /// a separate message), as an input file (whole file is a single message)
+    /// or read from standard input (each line is a separate message).
+    ///
+    /// Examples
+    ///  iggy message send 1 2 message
+    ///  iggy message send stream topic "hello world" "second message"
+    ///  iggy message send --partition-id 3 1 2 message
+    ///  iggy message send --message-key key --header source:string:cli 1 topic message
+    ///  iggy message send --input-file payload.bin stream 2
+    ///  echo "message" | iggy message send --acknowledgement persisted stream topic
+    #[clap(verbatim_doc_comment, visible_alias = "s")]
+    Send(SendMessagesArgs),
+    /// Poll messages from given partition ID of given topic ID and given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy message poll 1 2 1
+    ///  iggy message poll --offset 100 --message-count 20 stream topic 1
+    ///  iggy message poll --next --auto-commit --consumer reader stream topic 2
+    ///  iggy message poll --last --output json 1 topic 1
+    ///  iggy message poll --follow --output hex stream 2 1
+    #[clap(verbatim_doc_comment, visible_alias = "p")]
+    Poll(PollMessagesArgs),
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum MessageAcknowledgement {
+    /// Acknowledged as soon as received by the server
+    None,
+    /// Acknowledged once appended to the partition buffer
+    Memory,
+    /// Acknowledged once saved on disk
+    Persisted,
+}
+
+impl From<MessageAcknowledgement> for Acknowledgement {
+    fn from(acknowledgement: MessageAcknowledgement) -> Self {
+        match acknowledgement {
+            MessageAcknowledgement::None => Acknowledgement::None,
+            MessageAcknowledgement::Memory => Acknowledgement::Memory,
+            MessageAcknowledgement::Persisted => Acknowledgement::Persisted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum MessageOutput {
+    /// Message payload as text
+    Raw,
+    /// Whole message (with offset, timestamp, ID, headers and base64 payload) as JSON
+    Json,
+    /// Message payload as hexadecimal string
+    Hex,
+}
+
+impl From<MessageOutput> for PollMessagesOutput {
+    fn from(output: MessageOutput) -> Self {
+        match output {
+            MessageOutput::Raw => PollMessagesOutput::Raw,
+            MessageOutput::Json => PollMessagesOutput::Json,
+            MessageOutput::Hex => PollMessagesOutput::Hex,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group(ArgGroup::new("partitioning").args(&["partition_id", "message_key"])))]
+pub(crate) struct SendMessagesArgs {
+    /// Stream ID to send messages to
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to send messages to
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Messages to be sent
+    ///
+    /// If neither messages nor input file are provided,
+    /// messages are read from the standard input (one per line)
+    #[arg(verbatim_doc_comment, conflicts_with = "input_file")]
+    pub(crate) messages: Option<Vec<String>>,
+    /// ID of the partition to which messages are sent
+    ///
+    /// If neither partition ID nor message key are provided,
+    /// messages are balanced between the partitions by the server
+    #[arg(verbatim_doc_comment, short, long)]
+    pub(crate) partition_id: Option<u32>,
+    /// Messages key used to calculate the partition to which messages are sent
+    #[arg(short, long, value_parser = parse_message_key)]
+    pub(crate) message_key: Option<String>,
+    /// Message header in key:kind:value format (can be used multiple times)
+    ///
+    /// Kind is one of: raw, string, bool, int8, int16, int32, int64, int128,
+    /// uint8, uint16, uint32, uint64, uint128, float32 and float64.
+    /// If kind is omitted (key:value), string is used.
+    #[arg(verbatim_doc_comment, short = 'H', long = "header", value_parser = parse_message_header)]
+    pub(crate) headers: Vec<(HeaderKey, HeaderValue)>,
+    /// Input file with the payload of a single message
+    #[arg(short, long)]
+    pub(crate) input_file: Option<String>,
+    /// When the server acknowledges the messages
+    #[arg(short, long, value_enum, default_value_t = MessageAcknowledgement::Memory)]
+    pub(crate) acknowledgement: MessageAcknowledgement,
+}
+
+impl SendMessagesArgs {
+    pub(crate) fn get_partitioning(&self) -> Partitioning {
+        match (&self.partition_id, &self.message_key) {
+            (Some(partition_id), _) => Partitioning::partition_id(*partition_id),
+            // Message key length is already validated by the argument parser.
+            (_, Some(message_key)) => Partitioning::messages_key_str(message_key).unwrap(),
+            (None, None) => Partitioning::balanced(),
+        }
+    }
+}
+
+fn parse_message_key(message_key: &str) -> Result<String, String> {
+    Partitioning::messages_key_str(message_key)
+        .map(|_| message_key.to_string())
+        .map_err(|_| String::from("Message key must be between 1 and 255 bytes long"))
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group(ArgGroup::new("polling_strategy").args(&["offset", "timestamp", "first", "last", "next"])))]
+pub(crate) struct PollMessagesArgs {
+    /// Stream ID from which messages are polled
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID from which messages are polled
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which messages are polled
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Number of messages to poll
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) message_count: u32,
+    /// Poll messages starting from given offset
+    #[arg(short, long)]
+    pub(crate) offset: Option<u64>,
+    /// Poll messages starting from given timestamp (in microseconds)
+    #[arg(short, long)]
+    pub(crate) timestamp: Option<u64>,
+    /// Poll messages starting from the first message in the partition (default)
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) first: bool,
+    /// Poll the last messages in the partition
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) last: bool,
+    /// Poll messages starting from the next one after the offset stored by the consumer
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) next: bool,
+    /// Consumer ID used for polling and storing the offset
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[arg(short, long, default_value = "1", value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer: Identifier,
+    /// Store the offset of the last polled message for the consumer
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) auto_commit: bool,
+    /// Keep polling the new messages until interrupted (Ctrl+C)
+    #[arg(short = 'F', long, default_value_t = false)]
+    pub(crate) follow: bool,
+    /// Output format of the polled messages
+    #[arg(short = 'O', long, value_enum, default_value_t = MessageOutput::Raw)]
+    pub(crate) output: MessageOutput,
+}
+
+impl PollMessagesArgs {
+    pub(crate) fn get_polling_strategy(&self) -> PollingStrategy {
+        match (self.offset, self.timestamp) {
+            (Some(offset), _) => PollingStrategy::offset(offset),
+            (_, Some(timestamp)) => PollingStrategy::timestamp(timestamp),
+            _ if self.last => PollingStrategy::last(),
+            _ if self.next => PollingStrategy::next(),
+            _ => PollingStrategy::first(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
+pub(crate) mod apply;
 pub(crate) mod client;
 pub(crate) mod common;
 pub(crate) mod consumer_group;
+pub(crate) mod consumer_offset;
+pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
@@ -11,8 +14,15 @@ pub(crate) mod user;
 
 use self::user::UserAction;
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction, partition::PartitionAction,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, system::PingArgs,
+    apply::ApplyArgs,
+    client::ClientAction,
+    consumer_group::ConsumerGroupAction,
+    consumer_offset::ConsumerOffsetAction,
+    message::MessageAction,
+    partition::PartitionAction,
+    personal_access_token::PersonalAccessTokenAction,
+    stream::StreamAction,
+    system::{LogLevelArgs, PingArgs},
     topic::TopicAction,
 };
 use clap::{Args, Command as ClapCommand};
@@ -112,6 +122,31 @@ pub(crate) enum Command {
     /// Collect basic Iggy server statistics like number of streams, topics, partitions, etc.
     /// Server OS name, version, etc. are also collected.
     Stats,
+    /// create iggy server backup
+    ///
+    /// Create a consistent, point-in-time backup of the whole Iggy server, including
+    /// users, personal access tokens, consumer offsets, streams, topics, partitions
+    /// and all the segments. Backup is created in the directory configured on the server
+    /// and can be restored by starting the server with --restore <path> option.
+    Backup,
+    /// set iggy server log level
+    ///
+    /// Change the level of the Iggy server logs (both stdout and file) at runtime,
+    /// without restarting the server. The level is reset to the configured one
+    /// after the server restart.
+    LogLevel(LogLevelArgs),
+    /// reload iggy server configuration
+    ///
+    /// Reload the configuration of the Iggy server and apply the sections which
+    /// can be changed at runtime (message cleaner and saver, retention policy,
+    /// cache size, log level and CORS). Other changes require the server restart.
+    ReloadConfig,
+    /// apply declarative document with desired server state
+    ///
+    /// Compare streams, topics, partitions, consumer groups, users and personal
+    /// access tokens described in TOML, YAML or JSON document with the current
+    /// server state and create, update or (optionally) delete resources to match it.
+    Apply(ApplyArgs),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
@@ -124,6 +159,12 @@ pub(crate) enum Command {
     /// consumer group operations
     #[command(subcommand, visible_alias = "g")]
     ConsumerGroup(ConsumerGroupAction),
+    /// consumer offset operations
+    #[command(subcommand, visible_alias = "o")]
+    ConsumerOffset(ConsumerOffsetAction),
+    /// message operations
+    #[command(subcommand, visible_alias = "m")]
+    Message(MessageAction),
 }
 
 impl IggyConsoleArgs {
@@ -20,6 +20,17 @@ pub(crate) enum StreamAction {
     ///  iggy stream delete test
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(StreamDeleteArgs),
+    /// Restore soft-deleted stream with given ID
+    ///
+    /// Stream ID can be specified as a stream name or ID.
+    /// The stream can be restored only if the server has soft delete enabled
+    /// and the grace period of the deleted stream has not elapsed yet.
+    ///
+    /// Examples:
+    ///  iggy stream restore 1
+    ///  iggy stream restore test
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Restore(StreamRestoreArgs),
     /// Update stream name for given stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
@@ -64,6 +75,14 @@ pub(crate) struct StreamDeleteArgs {
     pub(crate) stream_id: Identifier,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamRestoreArgs {
+    /// Stream ID to restore
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    pub(crate) stream_id: Identifier,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct StreamUpdateArgs {
     /// Stream ID to update
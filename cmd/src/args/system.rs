@@ -1,4 +1,5 @@
 use clap::Args;
+use iggy::system::set_log_level::LogLevel;
 
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PingArgs {
@@ -6,3 +7,12 @@ pub(crate) struct PingArgs {
     #[arg(short, long, default_value_t = 1)]
     pub(crate) count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct LogLevelArgs {
+    /// Log level to be set on the server
+    ///
+    /// Possible values: off, error, warn, info, debug, trace
+    #[arg(value_parser = clap::value_parser!(LogLevel))]
+    pub(crate) level: LogLevel,
+}
@@ -2,6 +2,9 @@ use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::cmd::utils::message_expiry::MessageExpiry;
 use iggy::identifier::Identifier;
+use iggy::models::header::HeaderKey;
+use iggy::topics::config;
+use std::collections::HashMap;
 use std::convert::From;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -15,6 +18,7 @@ pub(crate) enum TopicAction {
     ///  iggy topic create 1 1 2 sensor1 15days
     ///  iggy topic create prod 2 2 sensor2
     ///  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+    ///  iggy topic create prod 4 2 orders -c segment.size=256MB -c partition.enforce_fsync=true
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(TopicCreateArgs),
     /// Delete topic with given ID in given stream ID
@@ -29,6 +33,19 @@ pub(crate) enum TopicAction {
     ///  iggy topic delete 2 debugs
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(TopicDeleteArgs),
+    /// Restore soft-deleted topic with given ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// The topic can be restored only if the server has soft delete enabled
+    /// and the grace period of the deleted topic has not elapsed yet.
+    ///
+    /// Examples
+    ///  iggy topic restore 1 1
+    ///  iggy topic restore prod 2
+    ///  iggy topic restore test debugs
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Restore(TopicRestoreArgs),
     /// Update topic name an message expiry time for given topic ID in given stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
@@ -40,8 +57,25 @@ pub(crate) enum TopicAction {
     ///  iggy update test debugs ready 15days
     ///  iggy update 1 1 new-name
     ///  iggy update 1 2 new-name 1day 1hour 1min 1sec
+    ///  iggy update prod orders orders -c cache.enabled=false
+    ///  iggy update prod orders orders --reset-config
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Update(TopicUpdateArgs),
+    /// Change number of partitions for given topic ID in given stream ID,
+    /// re-appending its messages to the new partitions by their key
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// The key of the messages is read from the given header, which all
+    /// the messages must have. The stored consumer and consumer group
+    /// offsets are deleted, as they don't apply to the new partitions.
+    /// The topic with key ranges can't be repartitioned until they are reset.
+    ///
+    /// Examples
+    ///  iggy topic repartition 1 1 2 key
+    ///  iggy topic repartition prod orders 4 order-id
+    #[clap(verbatim_doc_comment, visible_alias = "rp")]
+    Repartition(TopicRepartitionArgs),
     /// Get topic detail for given topic ID and stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
@@ -82,6 +116,15 @@ pub(crate) struct TopicCreateArgs {
     /// ("none" or skipping parameter disables message expiry functionality in topic)
     #[arg(value_parser = clap::value_parser!(MessageExpiry))]
     pub(crate) message_expiry: Option<Vec<MessageExpiry>>,
+    /// Topic config override in key=value format (can be used multiple times)
+    ///
+    /// Key is one of: segment.size, partition.enforce_fsync,
+    /// partition.validate_checksum, partition.messages_required_to_save,
+    /// message_deduplication.enabled, message_deduplication.max_entries,
+    /// message_deduplication.expiry, cache.enabled, topic.partitioning,
+    /// topic.sticky_batch_interval and topic.key_ranges.
+    #[arg(verbatim_doc_comment, short, long = "config", value_parser = parse_config_entry)]
+    pub(crate) config: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -98,6 +141,40 @@ pub(crate) struct TopicDeleteArgs {
     pub(crate) topic_id: Identifier,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicRestoreArgs {
+    /// Stream ID to restore topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to restore
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicRepartitionArgs {
+    /// Stream ID to repartition topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to repartition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Number of partitions inside the topic after the repartitioning
+    #[arg(value_parser = clap::value_parser!(u32).range(1..1001))]
+    pub(crate) partitions_count: u32,
+    /// Header of the messages holding their key
+    #[arg(value_parser = clap::value_parser!(HeaderKey))]
+    pub(crate) key_header: HeaderKey,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct TopicUpdateArgs {
     /// Stream ID to update topic
@@ -116,6 +193,38 @@ pub(crate) struct TopicUpdateArgs {
     /// ("none" or skipping parameter causes removal of expiry parameter in topic)
     #[arg(value_parser = clap::value_parser!(MessageExpiry))]
     pub(crate) message_expiry: Option<Vec<MessageExpiry>>,
+    /// Topic config override in key=value format (can be used multiple times)
+    ///
+    /// Replaces all the current config overrides of the topic,
+    /// which are kept unchanged if the option is not used.
+    /// Key is one of: segment.size, partition.enforce_fsync,
+    /// partition.validate_checksum, partition.messages_required_to_save,
+    /// message_deduplication.enabled, message_deduplication.max_entries,
+    /// message_deduplication.expiry, cache.enabled, topic.partitioning,
+    /// topic.sticky_batch_interval and topic.key_ranges.
+    #[arg(verbatim_doc_comment, short, long = "config", value_parser = parse_config_entry)]
+    pub(crate) config: Vec<(String, String)>,
+    /// Remove all the config overrides of the topic
+    #[arg(long, default_value_t = false, conflicts_with = "config")]
+    pub(crate) reset_config: bool,
+}
+
+impl TopicUpdateArgs {
+    pub(crate) fn get_config(&self) -> Option<HashMap<String, String>> {
+        match (self.reset_config, self.config.is_empty()) {
+            (true, _) => Some(HashMap::new()),
+            (false, true) => None,
+            (false, false) => Some(self.config.iter().cloned().collect()),
+        }
+    }
+}
+
+fn parse_config_entry(entry: &str) -> Result<(String, String), String> {
+    config::from_str(entry)
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| String::from("Config entry must be in key=value format"))
 }
 
 #[derive(Debug, Clone, Args)]
@@ -4,7 +4,8 @@ mod error;
 mod logging;
 
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction, permissions::PermissionsArgs,
+    client::ClientAction, consumer_group::ConsumerGroupAction,
+    consumer_offset::ConsumerOffsetAction, message::MessageAction, permissions::PermissionsArgs,
     personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
     Command, IggyConsoleArgs,
 };
@@ -18,12 +19,17 @@ use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::cmd::{
+    apply::apply_document::ApplyCmd,
     client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
     consumer_group::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
         get_consumer_groups::GetConsumerGroupsCmd,
     },
+    consumer_offset::{
+        get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
+    },
+    messages::{poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd},
     partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
@@ -32,12 +38,17 @@ use iggy::cmd::{
     },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
-        get_streams::GetStreamsCmd, update_stream::UpdateStreamCmd,
+        get_streams::GetStreamsCmd, restore_stream::RestoreStreamCmd,
+        update_stream::UpdateStreamCmd,
+    },
+    system::{
+        backup::CreateBackupCmd, log_level::SetLogLevelCmd, me::GetMeCmd, ping::PingCmd,
+        reload_config::ReloadConfigCmd, stats::GetStatsCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, update_topic::UpdateTopicCmd,
+        get_topics::GetTopicsCmd, repartition_topic::RepartitionTopicCmd,
+        restore_topic::RestoreTopicCmd, update_topic::UpdateTopicCmd,
     },
     users::{
         change_password::ChangePasswordCmd,
@@ -64,6 +75,7 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 Box::new(CreateStreamCmd::new(args.stream_id, args.name.clone()))
             }
             StreamAction::Delete(args) => Box::new(DeleteStreamCmd::new(args.stream_id.clone())),
+            StreamAction::Restore(args) => Box::new(RestoreStreamCmd::new(args.stream_id.clone())),
             StreamAction::Update(args) => Box::new(UpdateStreamCmd::new(
                 args.stream_id.clone(),
                 args.name.clone(),
@@ -78,16 +90,28 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 args.partitions_count,
                 args.name.clone(),
                 MessageExpiry::new(args.message_expiry.clone()),
+                args.config.iter().cloned().collect(),
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
             )),
+            TopicAction::Restore(args) => Box::new(RestoreTopicCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+            )),
             TopicAction::Update(args) => Box::new(UpdateTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
                 args.name.clone(),
                 MessageExpiry::new(args.message_expiry.clone()),
+                args.get_config(),
+            )),
+            TopicAction::Repartition(args) => Box::new(RepartitionTopicCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partitions_count,
+                args.key_header.clone(),
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -113,6 +137,10 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats => Box::new(GetStatsCmd::new()),
+        Command::Backup => Box::new(CreateBackupCmd::new()),
+        Command::LogLevel(args) => Box::new(SetLogLevelCmd::new(args.level)),
+        Command::ReloadConfig => Box::new(ReloadConfigCmd::new()),
+        Command::Apply(args) => Box::new(ApplyCmd::new(args.file, args.dry_run, args.delete)),
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -200,6 +228,43 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 list_args.list_mode.into(),
             )),
         },
+        Command::ConsumerOffset(command) => match command {
+            ConsumerOffsetAction::Get(get_args) => Box::new(GetConsumerOffsetCmd::new(
+                get_args.consumer_id.clone(),
+                get_args.stream_id.clone(),
+                get_args.topic_id.clone(),
+                get_args.partition_id,
+            )),
+            ConsumerOffsetAction::Set(set_args) => Box::new(SetConsumerOffsetCmd::new(
+                set_args.consumer_id.clone(),
+                set_args.stream_id.clone(),
+                set_args.topic_id.clone(),
+                set_args.partition_id,
+                set_args.offset,
+            )),
+        },
+        Command::Message(command) => match command {
+            MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
+                send_args.stream_id.clone(),
+                send_args.topic_id.clone(),
+                send_args.get_partitioning(),
+                send_args.acknowledgement.into(),
+                send_args.messages.clone(),
+                send_args.input_file.clone(),
+                send_args.headers.clone(),
+            )),
+            MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
+                poll_args.consumer.clone(),
+                poll_args.stream_id.clone(),
+                poll_args.topic_id.clone(),
+                poll_args.partition_id,
+                poll_args.get_polling_strategy(),
+                poll_args.message_count,
+                poll_args.auto_commit,
+                poll_args.output.into(),
+                poll_args.follow,
+            )),
+        },
     }
 }
 
@@ -3,7 +3,7 @@ use iggy::client::Client;
 use iggy::client_provider;
 use iggy::client_provider::ClientProviderConfig;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy_examples::shared::args::Args;
 use iggy_examples::shared::system;
 use std::error::Error;
@@ -55,7 +55,10 @@ async fn produce_messages(args: &Args, client: &dyn Client) -> Result<(), Box<dy
                 stream_id: Identifier::numeric(args.stream_id)?,
                 topic_id: Identifier::numeric(args.topic_id)?,
                 partitioning: Partitioning::partition_id(args.partition_id),
+                acknowledgement: Acknowledgement::default(),
+                producer: None,
                 messages,
+                transaction_id: None,
             })
             .await?;
         sent_batches += 1;
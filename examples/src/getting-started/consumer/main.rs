@@ -2,7 +2,7 @@ use iggy::client::{Client, UserClient};
 use iggy::clients::client::IggyClient;
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::models::messages::Message;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
@@ -58,6 +58,7 @@ async fn consume_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 strategy: PollingStrategy::offset(offset),
                 count: messages_per_batch,
                 auto_commit: false,
+                isolation: IsolationLevel::ReadUncommitted,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -1,11 +1,12 @@
 use iggy::client::{Client, StreamClient, TopicClient, UserClient};
 use iggy::clients::client::IggyClient;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
+use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
 use std::time::Duration;
@@ -51,6 +52,7 @@ async fn init_system(client: &IggyClient) {
             partitions_count: 1,
             name: "sample-topic".to_string(),
             message_expiry: None,
+            config: HashMap::new(),
         })
         .await
     {
@@ -90,7 +92,10 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 stream_id: Identifier::numeric(STREAM_ID)?,
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
+                acknowledgement: Acknowledgement::default(),
+                producer: None,
                 messages,
+                transaction_id: None,
             })
             .await?;
         sent_batches += 1;
@@ -3,7 +3,7 @@ use iggy::client::Client;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::models::messages::Message;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::get_stream::GetStream;
@@ -11,6 +11,7 @@ use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
+use std::collections::HashMap;
 use tracing::info;
 type MessageHandler = dyn Fn(&Message) -> Result<(), Box<dyn std::error::Error>>;
 
@@ -90,6 +91,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Er
             partitions_count: args.partitions_count,
             name: "orders".to_string(),
             message_expiry: None,
+            config: HashMap::new(),
         })
         .await?;
     Ok(())
@@ -123,6 +125,7 @@ pub async fn consume_messages(
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                isolation: IsolationLevel::ReadUncommitted,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -132,6 +132,62 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Cannot remove runtime directory, Path: {0}".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_create_backup_directory".to_string(),
+            code: 13,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot create backup directory, Path: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_create_backup".to_string(),
+            code: 14,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "anyhow::Error".to_string(),
+            template: "Cannot create backup. Reason: {0:#}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_restore_backup".to_string(),
+            code: 15,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "anyhow::Error".to_string(),
+            template: "Cannot restore backup. Reason: {0:#}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_log_level".to_string(),
+            code: 16,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid log level".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_set_log_level".to_string(),
+            code: 17,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "anyhow::Error".to_string(),
+            template: "Cannot set log level. Reason: {0:#}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_reload_config".to_string(),
+            code: 18,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "anyhow::Error".to_string(),
+            template: "Cannot reload config. Reason: {0:#}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "server_shutting_down".to_string(),
+            code: 19,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Server is shutting down".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "resource_not_found".to_string(),
             code: 20,
@@ -316,6 +372,22 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Request error".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_load_master_key".to_string(),
+            code: 68,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Cannot load master key from file: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "duplicate_encryption_key_id".to_string(),
+            code: 69,
+            signature: "u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Duplicate encryption key ID: {0}".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "invalid_encryption_key".to_string(),
             code: 70,
@@ -804,6 +876,62 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Cannot read topics for stream with ID: {0}".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_topic_config".to_string(),
+            code: 2018,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid topic config entry with key: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "topic_has_open_transactions".to_string(),
+            code: 2019,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Topic with ID: {0} for stream with ID: {1} has open transactions.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "topic_partitions_changed".to_string(),
+            code: 2020,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Partitions of topic with ID: {0} for stream with ID: {1} have changed during the repartitioning.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "topic_key_ranges_not_set".to_string(),
+            code: 2021,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Key ranges are not set for topic with ID: {0} for stream with ID: {1}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "message_key_header_not_found".to_string(),
+            code: 2022,
+            signature: "u128, String, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Message with ID: {0} has no key header: {1} for topic with ID: {2} for stream with ID: {3}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_key_ranges_count".to_string(),
+            code: 2023,
+            signature: "u32, u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Key ranges with: {0} boundaries don't match {1} partitions of topic with ID: {2} for stream with ID: {3}, there must be one boundary less than the partitions.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "topic_has_key_ranges".to_string(),
+            code: 2024,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Topic with ID: {0} for stream with ID: {1} has key ranges, which must be reset before the repartitioning.".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "cannot_create_partition".to_string(),
             code: 3000,
@@ -876,6 +1004,14 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Topic with ID: {0} for stream with ID: {1} has no partitions.".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "partition_id_required".to_string(),
+            code: 3009,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Partition ID is required.".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "segment_not_found".to_string(),
             code: 4000,
@@ -1108,6 +1244,14 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Invalid key value length".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_message_ttl".to_string(),
+            code: 4029,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid message TTL, it must be a positive uint64 value in microseconds".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "invalid_offset".to_string(),
             code: 4100,
@@ -1196,6 +1340,70 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             source: "".to_string(),
             template: "Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "producer_not_found".to_string(),
+            code: 6000,
+            signature: "u64".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Producer with ID: {0} was not found.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "producer_fenced".to_string(),
+            code: 6001,
+            signature: "u64, u32".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Producer with ID: {0} and epoch: {1} has been fenced by a newer epoch.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_producer_sequence".to_string(),
+            code: 6002,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Invalid producer sequence".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "partition_id_required_for_idempotent_producer".to_string(),
+            code: 6003,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Idempotent producer requires partition ID partitioning".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "producer_sequence_gap".to_string(),
+            code: 6004,
+            signature: "u64, u64, u64".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Producer with ID: {0} sent sequence: {2}, but the expected sequence is: {1}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "producer_sequence_out_of_order".to_string(),
+            code: 6005,
+            signature: "u64, u64, u64".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Producer with ID: {0} sent sequence: {1}, which is older than the last appended sequence: {2}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "transaction_not_found".to_string(),
+            code: 6100,
+            signature: "u64".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Transaction with ID: {0} was not found.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "delayed_messages_in_transaction".to_string(),
+            code: 6101,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            source: "".to_string(),
+            template: "Delayed messages cannot be sent within the transaction".to_string(),
+        },
     ];
 
     error_codes
@@ -1,5 +1,6 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::Error;
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
@@ -8,11 +9,14 @@ use crate::models::messages::{Message, MessageState, PolledMessages};
 use crate::models::partition::Partition;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::topics::config;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -92,6 +96,36 @@ pub fn map_stats(payload: &[u8]) -> Result<Stats, Error> {
     })
 }
 
+pub fn map_backup_info(payload: &[u8]) -> Result<BackupInfo, Error> {
+    let created_at = u64::from_le_bytes(payload[..8].try_into()?);
+    let size_bytes = u64::from_le_bytes(payload[8..16].try_into()?);
+    let files_count = u32::from_le_bytes(payload[16..20].try_into()?);
+    let name_length = payload[20] as usize;
+    let name = from_utf8(&payload[21..21 + name_length])?.to_string();
+    let mut position = 21 + name_length;
+    let path_length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    position += 4;
+    let path = from_utf8(&payload[position..position + path_length])?.to_string();
+    Ok(BackupInfo {
+        name,
+        path,
+        created_at,
+        size_bytes,
+        files_count,
+    })
+}
+
+pub fn map_producer_info(payload: &[u8]) -> Result<ProducerInfo, Error> {
+    let producer_id = u64::from_le_bytes(payload[..8].try_into()?);
+    let epoch = u32::from_le_bytes(payload[8..12].try_into()?);
+    Ok(ProducerInfo { producer_id, epoch })
+}
+
+pub fn map_transaction_info(payload: &[u8]) -> Result<TransactionInfo, Error> {
+    let transaction_id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(TransactionInfo { transaction_id })
+}
+
 pub fn map_consumer_offset(payload: &[u8]) -> Result<ConsumerOffsetInfo, Error> {
     let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
     let current_offset = u64::from_le_bytes(payload[4..12].try_into()?);
@@ -368,13 +402,18 @@ pub fn map_topics(payload: &[u8]) -> Result<Vec<Topic>, Error> {
 pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
     let (topic, mut position) = map_to_topic(payload, 0)?;
     let mut partitions = Vec::new();
-    let length = payload.len();
-    while position < length {
+    for _ in 0..topic.partitions_count {
         let (partition, read_bytes) = map_to_partition(payload, position)?;
         partitions.push(partition);
         position += read_bytes;
     }
 
+    // The config overrides follow the partitions, they are missing if the topic has none or the server does not support them.
+    let config = match position < payload.len() {
+        true => config::from_bytes(&payload[position..])?.0,
+        false => HashMap::new(),
+    };
+
     partitions.sort_by(|x, y| x.id.cmp(&y.id));
     let topic = TopicDetails {
         id: topic.id,
@@ -385,6 +424,7 @@ pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
         message_expiry: topic.message_expiry,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
+        config,
         partitions,
     };
     Ok(topic)
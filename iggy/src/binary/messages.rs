@@ -1,11 +1,13 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{POLL_MESSAGES_CODE, REGISTER_PRODUCER_CODE};
 use crate::error::Error;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::register_producer::RegisterProducer;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 
 pub async fn poll_messages(
     client: &dyn BinaryClient,
@@ -21,7 +23,18 @@ pub async fn poll_messages(
 pub async fn send_messages(client: &dyn BinaryClient, command: &SendMessages) -> Result<(), Error> {
     fail_if_not_authenticated(client).await?;
     client
-        .send_with_response(SEND_MESSAGES_CODE, &command.as_bytes())
+        .send_with_response(command.get_code(), &command.as_bytes())
         .await?;
     Ok(())
 }
+
+pub async fn register_producer(
+    client: &dyn BinaryClient,
+    command: &RegisterProducer,
+) -> Result<ProducerInfo, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(REGISTER_PRODUCER_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_producer_info(&response)
+}
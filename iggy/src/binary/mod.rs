@@ -11,6 +11,7 @@ pub mod personal_access_tokens;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 async fn fail_if_not_authenticated(client: &dyn BinaryClient) -> Result<(), Error> {
@@ -3,7 +3,7 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{
     CREATE_STREAM_CODE, DELETE_STREAM_CODE, GET_STREAMS_CODE, GET_STREAM_CODE, PURGE_STREAM_CODE,
-    UPDATE_STREAM_CODE,
+    RESTORE_STREAM_CODE, UPDATE_STREAM_CODE,
 };
 use crate::error::Error;
 use crate::models::stream::{Stream, StreamDetails};
@@ -12,6 +12,7 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
 
 pub async fn get_stream(
@@ -67,3 +68,14 @@ pub async fn purge_stream(client: &dyn BinaryClient, command: &PurgeStream) -> R
         .await?;
     Ok(())
 }
+
+pub async fn restore_stream(
+    client: &dyn BinaryClient,
+    command: &RestoreStream,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(RESTORE_STREAM_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
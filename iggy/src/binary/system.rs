@@ -1,15 +1,22 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    CREATE_BACKUP_CODE, GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE,
+    RELOAD_CONFIG_CODE, SET_LOG_LEVEL_CODE,
+};
 use crate::error::Error;
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 
 pub async fn get_stats(client: &dyn BinaryClient, command: &GetStats) -> Result<Stats, Error> {
     fail_if_not_authenticated(client).await?;
@@ -19,6 +26,33 @@ pub async fn get_stats(client: &dyn BinaryClient, command: &GetStats) -> Result<
     mapper::map_stats(&response)
 }
 
+pub async fn create_backup(
+    client: &dyn BinaryClient,
+    command: &CreateBackup,
+) -> Result<BackupInfo, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(CREATE_BACKUP_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_backup_info(&response)
+}
+
+pub async fn set_log_level(client: &dyn BinaryClient, command: &SetLogLevel) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(SET_LOG_LEVEL_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn reload_config(client: &dyn BinaryClient, command: &ReloadConfig) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(RELOAD_CONFIG_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn get_me(
     client: &dyn BinaryClient,
     command: &GetMe,
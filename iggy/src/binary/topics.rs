@@ -3,7 +3,7 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{
     CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE, PURGE_TOPIC_CODE,
-    UPDATE_TOPIC_CODE,
+    REPARTITION_TOPIC_CODE, RESTORE_TOPIC_CODE, UPDATE_TOPIC_CODE,
 };
 use crate::error::Error;
 use crate::models::topic::{Topic, TopicDetails};
@@ -12,6 +12,8 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
 
 pub async fn get_topic(
@@ -66,3 +68,22 @@ pub async fn purge_topic(client: &dyn BinaryClient, command: &PurgeTopic) -> Res
         .await?;
     Ok(())
 }
+
+pub async fn restore_topic(client: &dyn BinaryClient, command: &RestoreTopic) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(RESTORE_TOPIC_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn repartition_topic(
+    client: &dyn BinaryClient,
+    command: &RepartitionTopic,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(REPARTITION_TOPIC_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{ABORT_TRANSACTION_CODE, BEGIN_TRANSACTION_CODE, COMMIT_TRANSACTION_CODE};
+use crate::error::Error;
+use crate::models::transaction_info::TransactionInfo;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
+
+pub async fn begin_transaction(
+    client: &dyn BinaryClient,
+    command: &BeginTransaction,
+) -> Result<TransactionInfo, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(BEGIN_TRANSACTION_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_transaction_info(&response)
+}
+
+pub async fn commit_transaction(
+    client: &dyn BinaryClient,
+    command: &CommitTransaction,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(COMMIT_TRANSACTION_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn abort_transaction(
+    client: &dyn BinaryClient,
+    command: &AbortTransaction,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(ABORT_TRANSACTION_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
@@ -8,16 +8,20 @@ use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::register_producer::RegisterProducer;
 use crate::messages::send_messages::SendMessages;
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
@@ -30,18 +34,27 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -66,6 +79,7 @@ pub trait Client:
     + TopicClient
     + PartitionClient
     + MessageClient
+    + TransactionClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
     + Sync
@@ -87,6 +101,20 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_stats(&self, command: &GetStats) -> Result<Stats, Error>;
+    /// Create a consistent, point-in-time backup of the whole server (metadata database and all the segments)
+    /// in the backup directory configured on the server, while it keeps serving traffic.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn create_backup(&self, command: &CreateBackup) -> Result<BackupInfo, Error>;
+    /// Change the level of the server logs at runtime, without restarting the server.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_log_level(&self, command: &SetLogLevel) -> Result<(), Error>;
+    /// Reload the server configuration sections which can be changed at runtime, without restarting the server
+    /// (e.g. the message cleaner and saver, the retention policy, the cache size, the log level and CORS).
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<(), Error>;
     /// Get the info about the currently connected client (not to be confused with the user).
     ///
     /// Authentication is required.
@@ -192,6 +220,10 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn purge_stream(&self, command: &PurgeStream) -> Result<(), Error>;
+    /// Restore a soft-deleted stream by unique ID or name, before its grace period has elapsed.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn restore_stream(&self, command: &RestoreStream) -> Result<(), Error>;
 }
 
 /// This trait defines the methods to interact with the topic module.
@@ -221,6 +253,15 @@ pub trait TopicClient {
     ///
     /// Authentication is required, and the permission to manage the topics.
     async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error>;
+    /// Restore a soft-deleted topic by unique ID or name, before its grace period has elapsed.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn restore_topic(&self, command: &RestoreTopic) -> Result<(), Error>;
+    /// Change the number of partitions in the topic by unique ID or name, while keeping its messages.
+    /// The messages are re-appended to the new partitions by the hash of their key, in the same order.
+    ///
+    /// Authentication is required, and the permission to manage the partitions.
+    async fn repartition_topic(&self, command: &RepartitionTopic) -> Result<(), Error>;
 }
 
 /// This trait defines the methods to interact with the partition module.
@@ -251,6 +292,33 @@ pub trait MessageClient {
     ///
     /// Authentication is required, and the permission to send the messages.
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error>;
+    /// Register the idempotent producer, or the new instance of the already registered one, which fences its previous instances.
+    /// The returned producer ID and epoch along with the increasing sequence number can be attached to the sent messages,
+    /// so that the retried batches are appended to the partition only once.
+    ///
+    /// Authentication is required.
+    async fn register_producer(&self, command: &RegisterProducer) -> Result<ProducerInfo, Error>;
+}
+
+/// This trait defines the methods to interact with the transaction module.
+#[async_trait]
+pub trait TransactionClient {
+    /// Begin the new transaction. The returned transaction ID can be attached to the sent messages and stored consumer offsets,
+    /// which become visible to the read-committed consumers only once the transaction is committed.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(&self, command: &BeginTransaction)
+        -> Result<TransactionInfo, Error>;
+    /// Commit the open transaction, which makes the messages appended within the transaction visible to the read-committed consumers
+    /// and stores the consumer offsets staged within the transaction, all at once.
+    ///
+    /// Authentication is required, and the transaction must have been opened by the same user.
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error>;
+    /// Abort the open transaction, which hides the messages appended within the transaction from the read-committed consumers
+    /// and discards the consumer offsets staged within the transaction.
+    ///
+    /// Authentication is required, and the transaction must have been opened by the same user.
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -1,6 +1,7 @@
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, TransactionClient,
+    UserClient,
 };
 use crate::consumer::Consumer;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
@@ -15,16 +16,22 @@ use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
-use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::register_producer::RegisterProducer;
+use crate::messages::send_messages::{
+    Partitioning, PartitioningKind, ProducerSequence, SendMessages,
+};
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, PolledMessages};
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
@@ -38,19 +45,28 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 use crate::tcp::client::TcpClient;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -65,7 +81,7 @@ use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use bytes::Bytes;
 use flume::{Receiver, Sender};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::sync::Arc;
 use std::time::Duration;
@@ -85,6 +101,7 @@ pub struct IggyClient {
     encryptor: Option<Box<dyn Encryptor>>,
     message_handler: Option<Arc<Box<dyn MessageHandler>>>,
     message_channel_sender: Option<Arc<Sender<Message>>>,
+    producer_state: Arc<Mutex<ProducerState>>,
 }
 
 /// The builder for the `IggyClient` instance, which allows to configure and provide custom implementations for the partitioner, encryptor or message handler.
@@ -137,6 +154,24 @@ struct SendMessagesBatch {
     pub commands: VecDeque<SendMessages>,
 }
 
+#[derive(Debug, Default)]
+struct ProducerState {
+    info: Option<ProducerInfo>,
+    /// The last sequence number per stream, topic and partition, as each partition tracks the sequence of the producer on its own.
+    sequences: HashMap<(String, String, Vec<u8>), u64>,
+    /// Whether the producer has been fenced by a newer epoch while sending the messages in the background.
+    fenced: bool,
+}
+
+impl ProducerState {
+    fn ensure_not_fenced(&self) -> Result<(), Error> {
+        match (self.fenced, self.info) {
+            (true, Some(info)) => Err(Error::ProducerFenced(info.producer_id, info.epoch)),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// The optional configuration for the `IggyClient` instance, consisting of the optional configuration for sending and polling the messages in the background.
 #[derive(Debug, Default)]
 pub struct IggyClientConfig {
@@ -144,6 +179,8 @@ pub struct IggyClientConfig {
     pub send_messages: SendMessagesConfig,
     /// The configuration for polling the messages in the background.
     pub poll_messages: PollMessagesConfig,
+    /// The configuration for the idempotent producer.
+    pub idempotent_producer: IdempotentProducerConfig,
 }
 
 /// The configuration for sending the messages in the background. It allows to configure the interval between sending the messages as batches in the background and the maximum number of messages in the batch.
@@ -157,6 +194,19 @@ pub struct SendMessagesConfig {
     pub max_messages: u32,
 }
 
+/// The configuration for the idempotent producer. When enabled, the producer is registered on the server before sending the first messages,
+/// and each sent batch gets the next sequence number for its partition, so that the retried batches are ignored by the server instead of being appended again.
+/// The sequence number is cleared from the command once it has been sent successfully, while the failed command keeps it, so sending it again is treated as a retry.
+/// The batches are sent one at a time, each one only after the previous one has been acknowledged, thus the messages must be sent with the partition ID partitioning
+/// (or the partitioner) and the acknowledgement, so the server can reject the out-of-order sequences and the gaps.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct IdempotentProducerConfig {
+    /// Whether the idempotent producer is enabled.
+    pub enabled: bool,
+    /// The ID of the already registered producer (e.g. after the application restart), or 0 to register a new producer.
+    pub producer_id: u64,
+}
+
 /// The configuration for polling the messages in the background. It allows to configure the interval between polling the messages and the offset storing strategy.
 #[derive(Debug, Copy, Clone)]
 pub struct PollMessagesConfig {
@@ -220,6 +270,7 @@ impl IggyClient {
             encryptor: None,
             message_handler: None,
             message_channel_sender: None,
+            producer_state: Arc::new(Mutex::new(ProducerState::default())),
         }
     }
 
@@ -239,17 +290,24 @@ impl IggyClient {
             info!("Client-side encryption is enabled.");
         }
 
+        if config.idempotent_producer.enabled {
+            info!("Idempotent producer is enabled.");
+        }
+
         let client = Arc::new(RwLock::new(client));
         let send_messages_batch = Arc::new(Mutex::new(SendMessagesBatch {
             commands: VecDeque::new(),
         }));
+        let producer_state = Arc::new(Mutex::new(ProducerState::default()));
         if config.send_messages.enabled && config.send_messages.interval > 0 {
             info!("Messages will be sent in background.");
             Self::send_messages_in_background(
                 config.send_messages.interval,
                 config.send_messages.max_messages,
+                config.idempotent_producer,
                 client.clone(),
                 send_messages_batch.clone(),
+                producer_state.clone(),
             );
         }
 
@@ -261,6 +319,7 @@ impl IggyClient {
             message_channel_sender: None,
             partitioner,
             encryptor,
+            producer_state,
         }
     }
 
@@ -384,6 +443,7 @@ impl IggyClient {
                 topic_id: Identifier::from_identifier(&poll_messages.topic_id),
                 partition_id: poll_messages.partition_id,
                 offset,
+                transaction_id: None,
             })
             .await;
         if let Err(error) = result {
@@ -391,104 +451,191 @@ impl IggyClient {
         }
     }
 
+    /// Assigns the next sequence number of the idempotent producer for the partition to the command (unless it already has one, which means that it's being retried),
+    /// registering the producer on the server first if needed. Returns `true` if the sequence number has been assigned.
+    /// The caller must hold the lock of the producer state until the command is sent, so the next sequence isn't sent before the current one is acknowledged.
+    async fn assign_producer_sequence(
+        client: &dyn Client,
+        config: &IdempotentProducerConfig,
+        producer_state: &mut ProducerState,
+        command: &mut SendMessages,
+    ) -> Result<bool, Error> {
+        producer_state.ensure_not_fenced()?;
+        if command.producer.is_some() {
+            return Ok(false);
+        }
+
+        let producer_info = match producer_state.info {
+            Some(producer_info) => producer_info,
+            None => {
+                let producer_info = client
+                    .register_producer(&RegisterProducer {
+                        producer_id: config.producer_id,
+                    })
+                    .await?;
+                info!(
+                    "Registered idempotent producer with ID: {} and epoch: {}.",
+                    producer_info.producer_id, producer_info.epoch
+                );
+                producer_state.info = Some(producer_info);
+                producer_state.sequences.clear();
+                producer_info
+            }
+        };
+
+        let sequence = producer_state
+            .sequences
+            .entry((
+                command.stream_id.to_string(),
+                command.topic_id.to_string(),
+                command.partitioning.value.clone(),
+            ))
+            .or_default();
+        *sequence += 1;
+        command.producer = Some(ProducerSequence {
+            producer_id: producer_info.producer_id,
+            epoch: producer_info.epoch,
+            sequence: *sequence,
+        });
+        Ok(true)
+    }
+
     fn send_messages_in_background(
         interval: u64,
         max_messages: u32,
+        idempotent_producer: IdempotentProducerConfig,
         client: Arc<RwLock<Box<dyn Client>>>,
         send_messages_batch: Arc<Mutex<SendMessagesBatch>>,
+        producer_state: Arc<Mutex<ProducerState>>,
     ) {
         tokio::spawn(async move {
             let max_messages = max_messages as usize;
             let interval = Duration::from_millis(interval);
             loop {
                 sleep(interval).await;
-                let mut send_messages_batch = send_messages_batch.lock().await;
-                if send_messages_batch.commands.is_empty() {
+                let commands = {
+                    let mut send_messages_batch = send_messages_batch.lock().await;
+                    std::mem::take(&mut send_messages_batch.commands)
+                };
+                if commands.is_empty() {
                     continue;
                 }
 
-                let mut initialized = false;
-                let mut stream_id = Identifier::numeric(1).unwrap();
-                let mut topic_id = Identifier::numeric(1).unwrap();
-                let mut key = Partitioning::partition_id(1);
-                let mut batch_messages = true;
-
-                for send_messages in &send_messages_batch.commands {
-                    if !initialized {
-                        if send_messages.partitioning.kind != PartitioningKind::PartitionId {
-                            batch_messages = false;
-                            break;
+                let mut commands = Self::batch_commands(commands, max_messages);
+                while let Some(mut send_messages) = commands.pop_front() {
+                    let client = client.read().await;
+                    let result = if idempotent_producer.enabled {
+                        let mut producer_state = producer_state.lock().await;
+                        match Self::assign_producer_sequence(
+                            client.as_ref(),
+                            &idempotent_producer,
+                            &mut producer_state,
+                            &mut send_messages,
+                        )
+                        .await
+                        {
+                            Ok(_) => client.send_messages(&mut send_messages).await,
+                            Err(error) => Err(error),
                         }
+                        .inspect_err(|error| {
+                            let code = error.as_server_code();
+                            if code == Error::ProducerFenced(0, 0).as_code() {
+                                producer_state.fenced = true;
+                            } else if code == Error::ProducerSequenceGap(0, 0, 0).as_code()
+                                || code == Error::ProducerSequenceOutOfOrder(0, 0, 0).as_code()
+                            {
+                                // The sequence can only be rejected if the previous batch has been lost, so the producer
+                                // is registered again and the batch is sent with the sequence of the new epoch.
+                                producer_state.info = None;
+                                send_messages.producer = None;
+                            }
+                        })
+                    } else {
+                        client.send_messages(&mut send_messages).await
+                    };
 
-                        stream_id = Identifier::from_identifier(&send_messages.stream_id);
-                        topic_id = Identifier::from_identifier(&send_messages.topic_id);
-                        key.value = send_messages.partitioning.value.clone();
-                        initialized = true;
-                    }
-
-                    // Batching the messages is only possible for the same stream, topic and partition.
-                    if send_messages.stream_id != stream_id
-                        || send_messages.topic_id != topic_id
-                        || send_messages.partitioning.kind != PartitioningKind::PartitionId
-                        || send_messages.partitioning.value != key.value
-                    {
-                        batch_messages = false;
-                        break;
-                    }
-                }
-
-                if !batch_messages {
-                    for send_messages in &mut send_messages_batch.commands {
-                        if let Err(error) = client.read().await.send_messages(send_messages).await {
-                            error!("There was an error when sending the messages: {:?}", error);
+                    match result {
+                        Ok(()) => {}
+                        Err(error)
+                            if error.as_server_code() == Error::ProducerFenced(0, 0).as_code() =>
+                        {
+                            error!(
+                                "Producer has been fenced by a newer epoch, the messages will no longer be sent in the background. Error: {:?}",
+                                error
+                            );
+                            return;
+                        }
+                        Err(error) => {
+                            error!(
+                                "There was an error when sending the messages batch: {:?}",
+                                error
+                            );
+
+                            // The failed batch is retried as a whole before the next ones, keeping the assigned producer sequence (if any),
+                            // so the server can ignore it if the previous attempt was appended but the response was lost.
+                            commands.push_front(send_messages);
+                            drop(client);
+                            sleep(interval).await;
                         }
-                    }
-                    send_messages_batch.commands.clear();
-                    continue;
-                }
-
-                let mut batches = VecDeque::new();
-                let mut messages = Vec::new();
-                while let Some(send_messages) = send_messages_batch.commands.pop_front() {
-                    messages.extend(send_messages.messages);
-                    if messages.len() >= max_messages {
-                        batches.push_back(messages);
-                        messages = Vec::new();
                     }
                 }
+            }
+        });
+    }
 
-                if !messages.is_empty() {
-                    batches.push_back(messages);
-                }
+    /// Merges the commands into the batches of up to the maximum number of messages, which is only possible for the same stream, topic,
+    /// partition and acknowledgement, and without the producer sequence already assigned by the caller. Otherwise, the commands are returned as they are.
+    fn batch_commands(
+        commands: VecDeque<SendMessages>,
+        max_messages: usize,
+    ) -> VecDeque<SendMessages> {
+        let first = commands.front().unwrap();
+        if first.partitioning.kind != PartitioningKind::PartitionId {
+            return commands;
+        }
 
-                while let Some(messages) = batches.pop_front() {
-                    let mut send_messages = SendMessages {
-                        stream_id: Identifier::from_identifier(&stream_id),
-                        topic_id: Identifier::from_identifier(&topic_id),
-                        partitioning: Partitioning {
-                            kind: PartitioningKind::PartitionId,
-                            length: 4,
-                            value: key.value.clone(),
-                        },
-                        messages,
-                    };
+        let stream_id = Identifier::from_identifier(&first.stream_id);
+        let topic_id = Identifier::from_identifier(&first.topic_id);
+        let key = Partitioning::from_partitioning(&first.partitioning);
+        let acknowledgement = first.acknowledgement;
+        let batch_messages = commands.iter().all(|send_messages| {
+            send_messages.producer.is_none()
+                && send_messages.stream_id == stream_id
+                && send_messages.topic_id == topic_id
+                && send_messages.partitioning.kind == PartitioningKind::PartitionId
+                && send_messages.partitioning.value == key.value
+                && send_messages.acknowledgement == acknowledgement
+        });
+        if !batch_messages {
+            return commands;
+        }
 
-                    if let Err(error) = client.read().await.send_messages(&mut send_messages).await
-                    {
-                        error!(
-                            "There was an error when sending the messages batch: {:?}",
-                            error
-                        );
+        let mut batches = VecDeque::new();
+        let mut messages = Vec::new();
+        for send_messages in commands {
+            messages.extend(send_messages.messages);
+            if messages.len() >= max_messages {
+                batches.push_back(messages);
+                messages = Vec::new();
+            }
+        }
 
-                        if !send_messages.messages.is_empty() {
-                            batches.push_back(send_messages.messages);
-                        }
-                    }
-                }
+        if !messages.is_empty() {
+            batches.push_back(messages);
+        }
 
-                send_messages_batch.commands.clear();
-            }
-        });
+        batches
+            .into_iter()
+            .map(|messages| SendMessages {
+                stream_id: Identifier::from_identifier(&stream_id),
+                topic_id: Identifier::from_identifier(&topic_id),
+                partitioning: Partitioning::from_partitioning(&key),
+                acknowledgement,
+                producer: None,
+                messages,
+                transaction_id: None,
+            })
+            .collect()
     }
 }
 
@@ -595,6 +742,18 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_stats(command).await
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<BackupInfo, Error> {
+        self.client.read().await.create_backup(command).await
+    }
+
+    async fn set_log_level(&self, command: &SetLogLevel) -> Result<(), Error> {
+        self.client.read().await.set_log_level(command).await
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<(), Error> {
+        self.client.read().await.reload_config(command).await
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error> {
         self.client.read().await.get_me(command).await
     }
@@ -637,6 +796,10 @@ impl StreamClient for IggyClient {
     async fn purge_stream(&self, command: &PurgeStream) -> Result<(), Error> {
         self.client.read().await.purge_stream(command).await
     }
+
+    async fn restore_stream(&self, command: &RestoreStream) -> Result<(), Error> {
+        self.client.read().await.restore_stream(command).await
+    }
 }
 
 #[async_trait]
@@ -664,6 +827,14 @@ impl TopicClient for IggyClient {
     async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error> {
         self.client.read().await.purge_topic(command).await
     }
+
+    async fn restore_topic(&self, command: &RestoreTopic) -> Result<(), Error> {
+        self.client.read().await.restore_topic(command).await
+    }
+
+    async fn repartition_topic(&self, command: &RepartitionTopic) -> Result<(), Error> {
+        self.client.read().await.repartition_topic(command).await
+    }
 }
 
 #[async_trait]
@@ -712,14 +883,43 @@ impl MessageClient for IggyClient {
             }
         }
 
+        // The transactional messages must be appended before the transaction is committed, thus they're never sent in the background.
         let send_messages_now = self.send_messages_batch.is_none()
+            || command.transaction_id.is_some()
             || match &self.config {
                 Some(config) => !config.send_messages.enabled || config.send_messages.interval == 0,
                 None => true,
             };
 
+        let idempotent_producer = self
+            .config
+            .as_ref()
+            .map(|config| config.idempotent_producer)
+            .unwrap_or_default();
         if send_messages_now {
-            return self.client.read().await.send_messages(command).await;
+            let client = self.client.read().await;
+            if !idempotent_producer.enabled {
+                return client.send_messages(command).await;
+            }
+
+            // The lock is held until the messages are sent, so the next sequence can't be sent before the current one is acknowledged.
+            let mut producer_state = self.producer_state.lock().await;
+            let assigned = Self::assign_producer_sequence(
+                client.as_ref(),
+                &idempotent_producer,
+                &mut producer_state,
+                command,
+            )
+            .await?;
+            client.send_messages(command).await?;
+            if assigned {
+                command.producer = None;
+            }
+            return Ok(());
+        }
+
+        if idempotent_producer.enabled {
+            self.producer_state.lock().await.ensure_not_fenced()?;
         }
 
         let mut messages = Vec::with_capacity(command.messages.len());
@@ -729,6 +929,7 @@ impl MessageClient for IggyClient {
                 length: message.length,
                 payload: message.payload.clone(),
                 headers: message.headers.clone(),
+                deliver_at: message.deliver_at,
             };
             messages.push(message);
         }
@@ -736,13 +937,38 @@ impl MessageClient for IggyClient {
             stream_id: Identifier::from_identifier(&command.stream_id),
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
+            acknowledgement: command.acknowledgement,
+            producer: command.producer,
             messages,
+            transaction_id: None,
         };
 
         let mut batch = self.send_messages_batch.as_ref().unwrap().lock().await;
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn register_producer(&self, command: &RegisterProducer) -> Result<ProducerInfo, Error> {
+        self.client.read().await.register_producer(command).await
+    }
+}
+
+#[async_trait]
+impl TransactionClient for IggyClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, Error> {
+        self.client.read().await.begin_transaction(command).await
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error> {
+        self.client.read().await.commit_transaction(command).await
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error> {
+        self.client.read().await.abort_transaction(command).await
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,234 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::cmd::apply::document::ApplyDocument;
+use crate::cmd::apply::plan::{
+    plan, ApplyAction, ConsumerGroupState, ServerState, StreamState, TopicState,
+};
+use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::identifier::Identifier;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use crate::streams::get_stream::GetStream;
+use crate::streams::get_streams::GetStreams;
+use crate::system::get_me::GetMe;
+use crate::topics::get_topic::GetTopic;
+use crate::users::get_user::GetUser;
+use crate::users::get_users::GetUsers;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ApplyCmd {
+    file: PathBuf,
+    dry_run: bool,
+    delete: bool,
+}
+
+impl ApplyCmd {
+    pub fn new(file: PathBuf, dry_run: bool, delete: bool) -> Self {
+        Self {
+            file,
+            dry_run,
+            delete,
+        }
+    }
+
+    async fn get_server_state(&self, client: &dyn Client) -> anyhow::Result<ServerState> {
+        let mut state = ServerState::default();
+        let streams = client
+            .get_streams(&GetStreams {})
+            .await
+            .with_context(|| "Problem getting list of streams".to_owned())?;
+        for stream in streams {
+            let stream_id = Identifier::numeric(stream.id)?;
+            let stream = client
+                .get_stream(&GetStream {
+                    stream_id: stream_id.clone(),
+                })
+                .await
+                .with_context(|| format!("Problem getting stream with ID: {}", stream.id))?;
+            let mut topics = Vec::new();
+            for topic in stream.topics {
+                let consumer_groups = client
+                    .get_consumer_groups(&GetConsumerGroups {
+                        stream_id: stream_id.clone(),
+                        topic_id: Identifier::numeric(topic.id)?,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem getting consumer groups for topic with ID: {} and stream with ID: {}",
+                            topic.id, stream.id
+                        )
+                    })?;
+                let topic_details = client
+                    .get_topic(&GetTopic {
+                        stream_id: stream_id.clone(),
+                        topic_id: Identifier::numeric(topic.id)?,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem getting topic with ID: {} for stream with ID: {}",
+                            topic.id, stream.id
+                        )
+                    })?;
+                topics.push(TopicState {
+                    id: topic.id,
+                    name: topic.name,
+                    partitions_count: topic.partitions_count,
+                    message_expiry: topic.message_expiry,
+                    config: topic_details.config,
+                    consumer_groups: consumer_groups
+                        .into_iter()
+                        .map(|consumer_group| ConsumerGroupState {
+                            id: consumer_group.id,
+                            name: consumer_group.name,
+                        })
+                        .collect(),
+                });
+            }
+            state.streams.push(StreamState {
+                id: stream.id,
+                name: stream.name,
+                topics,
+            });
+        }
+
+        let users = client
+            .get_users(&GetUsers {})
+            .await
+            .with_context(|| "Problem getting list of users".to_owned())?;
+        for user in users {
+            let user = client
+                .get_user(&GetUser {
+                    user_id: Identifier::numeric(user.id)?,
+                })
+                .await
+                .with_context(|| format!("Problem getting user with ID: {}", user.id))?;
+            state.users.push(user);
+        }
+
+        state.personal_access_tokens = client
+            .get_personal_access_tokens(&GetPersonalAccessTokens {})
+            .await
+            .with_context(|| "Problem getting list of personal access tokens".to_owned())?;
+        state.current_user_id = client
+            .get_me(&GetMe {})
+            .await
+            .with_context(|| "Problem getting current client info".to_owned())?
+            .user_id;
+
+        Ok(state)
+    }
+
+    async fn execute_action(
+        &self,
+        client: &dyn Client,
+        action: &ApplyAction,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        match action {
+            ApplyAction::CreateStream(command) => client.create_stream(command).await?,
+            ApplyAction::UpdateStream(command) => client.update_stream(command).await?,
+            ApplyAction::DeleteStream(command) => client.delete_stream(command).await?,
+            ApplyAction::CreateTopic(command) => client.create_topic(command).await?,
+            ApplyAction::UpdateTopic(command) => client.update_topic(command).await?,
+            ApplyAction::DeleteTopic(command) => client.delete_topic(command).await?,
+            ApplyAction::CreatePartitions(command) => client.create_partitions(command).await?,
+            ApplyAction::DeletePartitions(command) => client.delete_partitions(command).await?,
+            ApplyAction::CreateConsumerGroup(command) => {
+                client.create_consumer_group(command).await?
+            }
+            ApplyAction::DeleteConsumerGroup(command) => {
+                client.delete_consumer_group(command).await?
+            }
+            ApplyAction::CreateUser(command) => client.create_user(command).await?,
+            ApplyAction::UpdateUser(command) => client.update_user(command).await?,
+            ApplyAction::UpdatePermissions(command) => client.update_permissions(command).await?,
+            ApplyAction::DeleteUser(command) => client.delete_user(command).await?,
+            ApplyAction::CreatePersonalAccessToken(command) => {
+                let token = client.create_personal_access_token(command).await?;
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Personal access token with name: {} created, token: {}",
+                    command.name,
+                    token.token
+                );
+            }
+            ApplyAction::DeletePersonalAccessToken(command) => {
+                client.delete_personal_access_token(command).await?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CliCommand for ApplyCmd {
+    fn explain(&self) -> String {
+        let mode = match (self.dry_run, self.delete) {
+            (true, true) => " (dry run, with deletion)",
+            (true, false) => " (dry run)",
+            (false, true) => " (with deletion)",
+            (false, false) => "",
+        };
+        format!("apply document from file: {}{mode}", self.file.display())
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let document = ApplyDocument::from_file(&self.file)?;
+        let state = self.get_server_state(client).await?;
+        let plan = plan(&document, &state, self.delete).with_context(|| {
+            format!(
+                "Problem planning changes for document from file: {}",
+                self.file.display()
+            )
+        })?;
+        for warning in &plan.warnings {
+            event!(target: PRINT_TARGET, Level::INFO, "Warning: {warning}");
+        }
+
+        let actions = plan.actions;
+        if actions.is_empty() {
+            if plan.warnings.is_empty() {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "No changes required, server state matches document from file: {}",
+                    self.file.display()
+                );
+            } else {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "No changes planned, server state doesn't fully match document from file: {}",
+                    self.file.display()
+                );
+            }
+            return Ok(());
+        }
+
+        if self.dry_run {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Dry run, {} change(s) planned for document from file: {}",
+                actions.len(),
+                self.file.display()
+            );
+            for action in &actions {
+                event!(target: PRINT_TARGET, Level::INFO, "Planned: {action}");
+            }
+            return Ok(());
+        }
+
+        for action in &actions {
+            self.execute_action(client, action)
+                .await
+                .with_context(|| format!("Problem executing action: {action}"))?;
+            event!(target: PRINT_TARGET, Level::INFO, "Applied: {action}");
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Applied {} change(s) from document from file: {}",
+            actions.len(),
+            self.file.display()
+        );
+
+        Ok(())
+    }
+}
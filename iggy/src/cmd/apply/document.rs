@@ -0,0 +1,196 @@
+use crate::models::permissions::Permissions;
+use crate::models::user_status::UserStatus;
+use crate::utils::duration::IggyDuration;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// `ApplyDocument` is a declarative description of the server resources which should exist
+/// after the document is applied. It can be loaded from a TOML, YAML or JSON file.
+/// Streams, topics and consumer groups are matched with the existing ones by their IDs,
+/// users by their usernames and personal access tokens (of the current user) by their names.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ApplyDocument {
+    #[serde(default)]
+    pub streams: Vec<StreamDocument>,
+    #[serde(default)]
+    pub users: Vec<UserDocument>,
+    #[serde(default)]
+    pub personal_access_tokens: Vec<PersonalAccessTokenDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct StreamDocument {
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub topics: Vec<TopicDocument>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TopicDocument {
+    pub id: u32,
+    pub name: String,
+    pub partitions_count: u32,
+    /// Message expiry in the human readable format, e.g. "1h 30m", no expiry if not specified.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub message_expiry: Option<IggyDuration>,
+    /// Per-topic overrides of the server-side settings, e.g. `segment.size = "512 MB"`, none if not specified.
+    #[serde(default)]
+    pub config: HashMap<String, String>,
+    #[serde(default)]
+    pub consumer_groups: Vec<ConsumerGroupDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConsumerGroupDocument {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct UserDocument {
+    pub username: String,
+    /// Password is used only when the user is created, it's never updated for the existing user.
+    pub password: String,
+    #[serde(default)]
+    pub status: UserStatus,
+    pub permissions: Option<Permissions>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PersonalAccessTokenDocument {
+    pub name: String,
+    /// Token expiry in the human readable format, e.g. "30days", no expiry if not specified.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub expiry: Option<IggyDuration>,
+}
+
+impl ApplyDocument {
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Problem reading document from file: {}", path.display()))?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        Self::from_str(&content, extension)
+            .with_context(|| format!("Problem parsing document from file: {}", path.display()))
+    }
+
+    pub fn from_str(content: &str, format: &str) -> anyhow::Result<Self> {
+        let document = match format.to_lowercase().as_str() {
+            "toml" => toml::from_str(content)?,
+            "yaml" | "yml" => serde_yaml::from_str(content)?,
+            "json" => serde_json::from_str(content)?,
+            _ => bail!("Unsupported document format: '{format}', expected TOML, YAML or JSON"),
+        };
+        Ok(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_should_be_parsed_from_toml() {
+        let content = r#"
+            [[streams]]
+            id = 1
+            name = "orders"
+
+            [[streams.topics]]
+            id = 2
+            name = "events"
+            partitions_count = 3
+            message_expiry = "1h"
+
+            [streams.topics.config]
+            "segment.size" = "512 MB"
+
+            [[streams.topics.consumer_groups]]
+            id = 3
+            name = "workers"
+
+            [[users]]
+            username = "app"
+            password = "secret"
+            status = "inactive"
+
+            [users.permissions.global]
+            manage_servers = false
+            read_servers = true
+            manage_users = false
+            read_users = false
+            manage_streams = false
+            read_streams = true
+            manage_topics = false
+            read_topics = true
+            poll_messages = true
+            send_messages = true
+
+            [[personal_access_tokens]]
+            name = "ci"
+            expiry = "30days"
+        "#;
+
+        let document = ApplyDocument::from_str(content, "toml").unwrap();
+        assert_eq!(document.streams.len(), 1);
+        let stream = &document.streams[0];
+        assert_eq!(stream.id, 1);
+        assert_eq!(stream.name, "orders");
+        let topic = &stream.topics[0];
+        assert_eq!(topic.id, 2);
+        assert_eq!(topic.partitions_count, 3);
+        assert_eq!(topic.message_expiry.unwrap().as_secs(), 3600);
+        assert_eq!(topic.config.get("segment.size").unwrap(), "512 MB");
+        assert_eq!(topic.consumer_groups[0].name, "workers");
+        let user = &document.users[0];
+        assert_eq!(user.status, UserStatus::Inactive);
+        assert!(user.permissions.as_ref().unwrap().global.read_servers);
+        let personal_access_token = &document.personal_access_tokens[0];
+        assert_eq!(personal_access_token.name, "ci");
+        assert_eq!(
+            personal_access_token.expiry.unwrap().as_secs(),
+            30 * 24 * 3600
+        );
+    }
+
+    #[test]
+    fn document_should_be_parsed_from_yaml_and_json() {
+        let yaml = r#"
+streams:
+  - id: 1
+    name: orders
+    topics:
+      - id: 1
+        name: events
+        partitions_count: 2
+"#;
+        let json = r#"{"streams": [{"id": 1, "name": "orders", "topics": [{"id": 1, "name": "events", "partitions_count": 2}]}]}"#;
+
+        let yaml_document = ApplyDocument::from_str(yaml, "yaml").unwrap();
+        let json_document = ApplyDocument::from_str(json, "json").unwrap();
+        assert_eq!(yaml_document, json_document);
+        assert_eq!(yaml_document.streams[0].topics[0].message_expiry, None);
+    }
+
+    #[test]
+    fn document_with_unknown_format_or_field_should_be_rejected() {
+        assert!(ApplyDocument::from_str("", "xml").is_err());
+        assert!(ApplyDocument::from_str(r#"{"queues": []}"#, "json").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod apply_document;
+pub mod document;
+pub mod plan;
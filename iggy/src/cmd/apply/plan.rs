@@ -0,0 +1,775 @@
+use crate::cmd::apply::document::{
+    ApplyDocument, ConsumerGroupDocument, StreamDocument, TopicDocument,
+};
+use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::models::personal_access_token::PersonalAccessTokenInfo;
+use crate::models::user_info::{UserId, UserInfoDetails};
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::streams::create_stream::CreateStream;
+use crate::streams::delete_stream::DeleteStream;
+use crate::streams::update_stream::UpdateStream;
+use crate::topics::config;
+use crate::topics::create_topic::CreateTopic;
+use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::update_topic::UpdateTopic;
+use crate::users::create_user::CreateUser;
+use crate::users::defaults::DEFAULT_ROOT_USER_ID;
+use crate::users::delete_user::DeleteUser;
+use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_user::UpdateUser;
+use crate::utils::duration::IggyDuration;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// `ServerState` is a snapshot of the server resources which can be described by the `ApplyDocument`.
+#[derive(Debug, Default)]
+pub struct ServerState {
+    pub streams: Vec<StreamState>,
+    pub users: Vec<UserInfoDetails>,
+    pub personal_access_tokens: Vec<PersonalAccessTokenInfo>,
+    pub current_user_id: Option<UserId>,
+}
+
+#[derive(Debug)]
+pub struct StreamState {
+    pub id: u32,
+    pub name: String,
+    pub topics: Vec<TopicState>,
+}
+
+#[derive(Debug)]
+pub struct TopicState {
+    pub id: u32,
+    pub name: String,
+    pub partitions_count: u32,
+    pub message_expiry: Option<u32>,
+    pub config: HashMap<String, String>,
+    pub consumer_groups: Vec<ConsumerGroupState>,
+}
+
+#[derive(Debug)]
+pub struct ConsumerGroupState {
+    pub id: u32,
+    pub name: String,
+}
+
+/// `ApplyAction` is a single change which has to be made on the server to match the `ApplyDocument`.
+#[derive(Debug, PartialEq)]
+pub enum ApplyAction {
+    CreateStream(CreateStream),
+    UpdateStream(UpdateStream),
+    DeleteStream(DeleteStream),
+    CreateTopic(CreateTopic),
+    UpdateTopic(UpdateTopic),
+    DeleteTopic(DeleteTopic),
+    CreatePartitions(CreatePartitions),
+    DeletePartitions(DeletePartitions),
+    CreateConsumerGroup(CreateConsumerGroup),
+    DeleteConsumerGroup(DeleteConsumerGroup),
+    CreateUser(CreateUser),
+    UpdateUser(UpdateUser),
+    UpdatePermissions(UpdatePermissions),
+    DeleteUser(DeleteUser),
+    CreatePersonalAccessToken(CreatePersonalAccessToken),
+    DeletePersonalAccessToken(DeletePersonalAccessToken),
+}
+
+/// `ApplyPlan` is the list of actions to be executed, along with the warnings about
+/// the differences between the document and the server state which won't be resolved by them.
+#[derive(Debug, Default, PartialEq)]
+pub struct ApplyPlan {
+    pub actions: Vec<ApplyAction>,
+    pub warnings: Vec<String>,
+}
+
+/// Computes the list of actions required to make the server state match the document.
+/// Resources missing in the document are deleted only when `delete` is set, the root user
+/// and the current user are never deleted. All the deletions are placed before the other
+/// actions, so that the names of the deleted resources can be reused by the new ones.
+pub fn plan(
+    document: &ApplyDocument,
+    state: &ServerState,
+    delete: bool,
+) -> Result<ApplyPlan, Error> {
+    let mut deletions = Vec::new();
+    let mut actions = Vec::new();
+    let mut warnings = Vec::new();
+    if delete {
+        plan_streams_deletion(document, state, &mut deletions)?;
+        plan_users_deletion(document, state, &mut deletions)?;
+        plan_personal_access_tokens_deletion(document, state, &mut deletions);
+    }
+
+    for stream in &document.streams {
+        plan_stream(stream, state, delete, &mut actions, &mut warnings)?;
+    }
+    plan_users(document, state, &mut actions)?;
+    plan_personal_access_tokens(document, state, &mut actions);
+
+    deletions.extend(actions);
+    Ok(ApplyPlan {
+        actions: deletions,
+        warnings,
+    })
+}
+
+fn plan_stream(
+    stream: &StreamDocument,
+    state: &ServerState,
+    delete: bool,
+    actions: &mut Vec<ApplyAction>,
+    warnings: &mut Vec<String>,
+) -> Result<(), Error> {
+    let stream_id = Identifier::numeric(stream.id)?;
+    let current_stream = state.streams.iter().find(|current| current.id == stream.id);
+    match current_stream {
+        None => actions.push(ApplyAction::CreateStream(CreateStream {
+            stream_id: stream.id,
+            name: stream.name.clone(),
+        })),
+        Some(current_stream) if current_stream.name != stream.name => {
+            actions.push(ApplyAction::UpdateStream(UpdateStream {
+                stream_id: stream_id.clone(),
+                name: stream.name.clone(),
+            }))
+        }
+        Some(_) => {}
+    }
+
+    for topic in &stream.topics {
+        let current_topic = current_stream.and_then(|current_stream| {
+            current_stream
+                .topics
+                .iter()
+                .find(|current| current.id == topic.id)
+        });
+        plan_topic(&stream_id, topic, current_topic, delete, actions, warnings)?;
+    }
+
+    Ok(())
+}
+
+fn plan_topic(
+    stream_id: &Identifier,
+    topic: &TopicDocument,
+    current_topic: Option<&TopicState>,
+    delete: bool,
+    actions: &mut Vec<ApplyAction>,
+    warnings: &mut Vec<String>,
+) -> Result<(), Error> {
+    let topic_id = Identifier::numeric(topic.id)?;
+    let message_expiry = get_message_expiry(&topic.message_expiry);
+    config::validate(&topic.config)?;
+    let Some(current_topic) = current_topic else {
+        actions.push(ApplyAction::CreateTopic(CreateTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic.id,
+            partitions_count: topic.partitions_count,
+            message_expiry,
+            name: topic.name.clone(),
+            config: topic.config.clone(),
+        }));
+        for consumer_group in &topic.consumer_groups {
+            plan_consumer_group(stream_id, &topic_id, consumer_group, actions);
+        }
+        return Ok(());
+    };
+
+    let config_changed = current_topic.config != topic.config;
+    if current_topic.name != topic.name
+        || current_topic.message_expiry != message_expiry
+        || config_changed
+    {
+        actions.push(ApplyAction::UpdateTopic(UpdateTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            message_expiry,
+            name: topic.name.clone(),
+            config: config_changed.then(|| topic.config.clone()),
+        }));
+    }
+
+    if topic.partitions_count > current_topic.partitions_count {
+        actions.push(ApplyAction::CreatePartitions(CreatePartitions {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partitions_count: topic.partitions_count - current_topic.partitions_count,
+        }));
+    } else if topic.partitions_count < current_topic.partitions_count {
+        let partitions_count = current_topic.partitions_count - topic.partitions_count;
+        if delete {
+            actions.push(ApplyAction::DeletePartitions(DeletePartitions {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions_count,
+            }));
+        } else {
+            warnings.push(format!(
+                "partition decrease requires --delete, {partitions_count} partition(s) of topic with ID: {} in stream with ID: {} won't be deleted",
+                topic_id, stream_id
+            ));
+        }
+    }
+
+    for consumer_group in &topic.consumer_groups {
+        if !current_topic
+            .consumer_groups
+            .iter()
+            .any(|current| current.id == consumer_group.id)
+        {
+            plan_consumer_group(stream_id, &topic_id, consumer_group, actions);
+        }
+    }
+
+    Ok(())
+}
+
+fn plan_consumer_group(
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    consumer_group: &ConsumerGroupDocument,
+    actions: &mut Vec<ApplyAction>,
+) {
+    actions.push(ApplyAction::CreateConsumerGroup(CreateConsumerGroup {
+        stream_id: stream_id.clone(),
+        topic_id: topic_id.clone(),
+        consumer_group_id: consumer_group.id,
+        name: consumer_group.name.clone(),
+    }));
+}
+
+fn plan_streams_deletion(
+    document: &ApplyDocument,
+    state: &ServerState,
+    deletions: &mut Vec<ApplyAction>,
+) -> Result<(), Error> {
+    for current_stream in &state.streams {
+        let stream_id = Identifier::numeric(current_stream.id)?;
+        let Some(stream) = document
+            .streams
+            .iter()
+            .find(|stream| stream.id == current_stream.id)
+        else {
+            deletions.push(ApplyAction::DeleteStream(DeleteStream { stream_id }));
+            continue;
+        };
+
+        for current_topic in &current_stream.topics {
+            let topic_id = Identifier::numeric(current_topic.id)?;
+            let Some(topic) = stream
+                .topics
+                .iter()
+                .find(|topic| topic.id == current_topic.id)
+            else {
+                deletions.push(ApplyAction::DeleteTopic(DeleteTopic {
+                    stream_id: stream_id.clone(),
+                    topic_id,
+                }));
+                continue;
+            };
+
+            for current_consumer_group in &current_topic.consumer_groups {
+                if !topic
+                    .consumer_groups
+                    .iter()
+                    .any(|consumer_group| consumer_group.id == current_consumer_group.id)
+                {
+                    deletions.push(ApplyAction::DeleteConsumerGroup(DeleteConsumerGroup {
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
+                        consumer_group_id: Identifier::numeric(current_consumer_group.id)?,
+                    }));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn plan_users(
+    document: &ApplyDocument,
+    state: &ServerState,
+    actions: &mut Vec<ApplyAction>,
+) -> Result<(), Error> {
+    for user in &document.users {
+        let Some(current_user) = state
+            .users
+            .iter()
+            .find(|current| current.username == user.username)
+        else {
+            actions.push(ApplyAction::CreateUser(CreateUser {
+                username: user.username.clone(),
+                password: user.password.clone(),
+                status: user.status,
+                permissions: user.permissions.clone(),
+            }));
+            continue;
+        };
+
+        let user_id = Identifier::numeric(current_user.id)?;
+        if current_user.status != user.status {
+            actions.push(ApplyAction::UpdateUser(UpdateUser {
+                user_id: user_id.clone(),
+                username: None,
+                status: Some(user.status),
+            }));
+        }
+
+        if current_user.permissions != user.permissions {
+            actions.push(ApplyAction::UpdatePermissions(UpdatePermissions {
+                user_id,
+                permissions: user.permissions.clone(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+fn plan_users_deletion(
+    document: &ApplyDocument,
+    state: &ServerState,
+    deletions: &mut Vec<ApplyAction>,
+) -> Result<(), Error> {
+    for current_user in &state.users {
+        if current_user.id == DEFAULT_ROOT_USER_ID
+            || Some(current_user.id) == state.current_user_id
+            || document
+                .users
+                .iter()
+                .any(|user| user.username == current_user.username)
+        {
+            continue;
+        }
+
+        deletions.push(ApplyAction::DeleteUser(DeleteUser {
+            user_id: Identifier::numeric(current_user.id)?,
+        }));
+    }
+
+    Ok(())
+}
+
+fn plan_personal_access_tokens(
+    document: &ApplyDocument,
+    state: &ServerState,
+    actions: &mut Vec<ApplyAction>,
+) {
+    for personal_access_token in &document.personal_access_tokens {
+        if state
+            .personal_access_tokens
+            .iter()
+            .any(|current| current.name == personal_access_token.name)
+        {
+            continue;
+        }
+
+        actions.push(ApplyAction::CreatePersonalAccessToken(
+            CreatePersonalAccessToken {
+                name: personal_access_token.name.clone(),
+                expiry: personal_access_token
+                    .expiry
+                    .filter(|expiry| !expiry.is_zero())
+                    .map(|expiry| expiry.as_secs()),
+            },
+        ));
+    }
+}
+
+fn plan_personal_access_tokens_deletion(
+    document: &ApplyDocument,
+    state: &ServerState,
+    deletions: &mut Vec<ApplyAction>,
+) {
+    for current_personal_access_token in &state.personal_access_tokens {
+        if !document
+            .personal_access_tokens
+            .iter()
+            .any(|personal_access_token| {
+                personal_access_token.name == current_personal_access_token.name
+            })
+        {
+            deletions.push(ApplyAction::DeletePersonalAccessToken(
+                DeletePersonalAccessToken {
+                    name: current_personal_access_token.name.clone(),
+                },
+            ));
+        }
+    }
+}
+
+fn get_message_expiry(message_expiry: &Option<IggyDuration>) -> Option<u32> {
+    message_expiry
+        .filter(|message_expiry| !message_expiry.is_zero())
+        .map(|message_expiry| message_expiry.as_secs())
+}
+
+fn format_expiry(expiry: &Option<u32>) -> String {
+    match expiry {
+        Some(expiry) => {
+            IggyDuration::new(Duration::from_secs(*expiry as u64)).as_human_time_string()
+        }
+        None => "unlimited".to_string(),
+    }
+}
+
+impl Display for ApplyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplyAction::CreateStream(command) => write!(
+                f,
+                "create stream with ID: {} and name: {}",
+                command.stream_id, command.name
+            ),
+            ApplyAction::UpdateStream(command) => write!(
+                f,
+                "update stream with ID: {}, set name: {}",
+                command.stream_id, command.name
+            ),
+            ApplyAction::DeleteStream(command) => {
+                write!(f, "delete stream with ID: {}", command.stream_id)
+            }
+            ApplyAction::CreateTopic(command) => write!(
+                f,
+                "create topic with ID: {}, name: {}, partitions count: {} and message expiry: {} in stream with ID: {}",
+                command.topic_id,
+                command.name,
+                command.partitions_count,
+                format_expiry(&command.message_expiry),
+                command.stream_id
+            ),
+            ApplyAction::UpdateTopic(command) => write!(
+                f,
+                "update topic with ID: {} in stream with ID: {}, set name: {} and message expiry: {}",
+                command.topic_id,
+                command.stream_id,
+                command.name,
+                format_expiry(&command.message_expiry)
+            ),
+            ApplyAction::DeleteTopic(command) => write!(
+                f,
+                "delete topic with ID: {} in stream with ID: {}",
+                command.topic_id, command.stream_id
+            ),
+            ApplyAction::CreatePartitions(command) => write!(
+                f,
+                "create {} partition(s) for topic with ID: {} in stream with ID: {}",
+                command.partitions_count, command.topic_id, command.stream_id
+            ),
+            ApplyAction::DeletePartitions(command) => write!(
+                f,
+                "delete {} partition(s) for topic with ID: {} in stream with ID: {}",
+                command.partitions_count, command.topic_id, command.stream_id
+            ),
+            ApplyAction::CreateConsumerGroup(command) => write!(
+                f,
+                "create consumer group with ID: {} and name: {} for topic with ID: {} in stream with ID: {}",
+                command.consumer_group_id, command.name, command.topic_id, command.stream_id
+            ),
+            ApplyAction::DeleteConsumerGroup(command) => write!(
+                f,
+                "delete consumer group with ID: {} for topic with ID: {} in stream with ID: {}",
+                command.consumer_group_id, command.topic_id, command.stream_id
+            ),
+            ApplyAction::CreateUser(command) => write!(
+                f,
+                "create user with username: {} and status: {}",
+                command.username, command.status
+            ),
+            ApplyAction::UpdateUser(command) => write!(
+                f,
+                "update user with ID: {}, set status: {}",
+                command.user_id,
+                command.status.unwrap_or_default()
+            ),
+            ApplyAction::UpdatePermissions(command) => {
+                write!(f, "update permissions for user with ID: {}", command.user_id)
+            }
+            ApplyAction::DeleteUser(command) => {
+                write!(f, "delete user with ID: {}", command.user_id)
+            }
+            ApplyAction::CreatePersonalAccessToken(command) => write!(
+                f,
+                "create personal access token with name: {} and expiry: {}",
+                command.name,
+                format_expiry(&command.expiry)
+            ),
+            ApplyAction::DeletePersonalAccessToken(command) => write!(
+                f,
+                "delete personal access token with name: {}",
+                command.name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::apply::document::{PersonalAccessTokenDocument, UserDocument};
+    use crate::models::permissions::Permissions;
+    use crate::models::user_status::UserStatus;
+
+    #[test]
+    fn all_resources_should_be_created_given_empty_server() {
+        let document = get_document();
+        let actions = plan(&document, &ServerState::default(), false)
+            .unwrap()
+            .actions;
+
+        assert_eq!(
+            actions,
+            vec![
+                ApplyAction::CreateStream(CreateStream {
+                    stream_id: 1,
+                    name: "orders".to_string(),
+                }),
+                ApplyAction::CreateTopic(CreateTopic {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: 2,
+                    partitions_count: 3,
+                    message_expiry: Some(60),
+                    name: "events".to_string(),
+                    config: HashMap::new(),
+                }),
+                ApplyAction::CreateConsumerGroup(CreateConsumerGroup {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    consumer_group_id: 3,
+                    name: "workers".to_string(),
+                }),
+                ApplyAction::CreateUser(CreateUser {
+                    username: "app".to_string(),
+                    password: "secret".to_string(),
+                    status: UserStatus::Active,
+                    permissions: None,
+                }),
+                ApplyAction::CreatePersonalAccessToken(CreatePersonalAccessToken {
+                    name: "ci".to_string(),
+                    expiry: None,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_actions_should_be_planned_given_matching_server_state() {
+        let document = get_document();
+        let state = get_matching_state();
+
+        let plan = plan(&document, &state, true).unwrap();
+
+        assert_eq!(plan, ApplyPlan::default());
+    }
+
+    #[test]
+    fn changed_resources_should_be_updated() {
+        let mut document = get_document();
+        let stream = &mut document.streams[0];
+        stream.name = "purchases".to_string();
+        let topic = &mut stream.topics[0];
+        topic.message_expiry = None;
+        topic.partitions_count = 5;
+        topic.config = HashMap::from([(config::CACHE_ENABLED.to_string(), "false".to_string())]);
+        let user = &mut document.users[0];
+        user.status = UserStatus::Inactive;
+        user.permissions = Some(Permissions::default());
+        let state = get_matching_state();
+
+        let actions = plan(&document, &state, false).unwrap().actions;
+
+        assert_eq!(
+            actions,
+            vec![
+                ApplyAction::UpdateStream(UpdateStream {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    name: "purchases".to_string(),
+                }),
+                ApplyAction::UpdateTopic(UpdateTopic {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    message_expiry: None,
+                    name: "events".to_string(),
+                    config: Some(HashMap::from([(
+                        config::CACHE_ENABLED.to_string(),
+                        "false".to_string(),
+                    )])),
+                }),
+                ApplyAction::CreatePartitions(CreatePartitions {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    partitions_count: 2,
+                }),
+                ApplyAction::UpdateUser(UpdateUser {
+                    user_id: Identifier::numeric(2).unwrap(),
+                    username: None,
+                    status: Some(UserStatus::Inactive),
+                }),
+                ApplyAction::UpdatePermissions(UpdatePermissions {
+                    user_id: Identifier::numeric(2).unwrap(),
+                    permissions: Some(Permissions::default()),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_resources_should_be_deleted_only_when_requested() {
+        let mut document = get_document();
+        document.streams[0].topics[0].partitions_count = 1;
+        document.streams[0].topics[0].consumer_groups.clear();
+        document.users.clear();
+        document.personal_access_tokens.clear();
+        let mut state = get_matching_state();
+        state.streams.push(StreamState {
+            id: 10,
+            name: "legacy".to_string(),
+            topics: Vec::new(),
+        });
+
+        let actions = plan(&document, &state, false).unwrap().actions;
+        assert!(actions.is_empty());
+
+        let actions = plan(&document, &state, true).unwrap().actions;
+        assert_eq!(
+            actions,
+            vec![
+                ApplyAction::DeleteConsumerGroup(DeleteConsumerGroup {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    consumer_group_id: Identifier::numeric(3).unwrap(),
+                }),
+                ApplyAction::DeleteStream(DeleteStream {
+                    stream_id: Identifier::numeric(10).unwrap(),
+                }),
+                ApplyAction::DeleteUser(DeleteUser {
+                    user_id: Identifier::numeric(2).unwrap(),
+                }),
+                ApplyAction::DeletePersonalAccessToken(DeletePersonalAccessToken {
+                    name: "ci".to_string(),
+                }),
+                ApplyAction::DeletePartitions(DeletePartitions {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    partitions_count: 2,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn partitions_decrease_should_be_reported_as_warning_when_deletion_is_not_requested() {
+        let mut document = get_document();
+        document.streams[0].topics[0].partitions_count = 1;
+        let state = get_matching_state();
+
+        let plan = plan(&document, &state, false).unwrap();
+
+        assert!(plan.actions.is_empty());
+        assert_eq!(
+            plan.warnings,
+            vec!["partition decrease requires --delete, 2 partition(s) of topic with ID: 2 in stream with ID: 1 won't be deleted".to_string()]
+        );
+    }
+
+    #[test]
+    fn root_and_current_user_should_never_be_deleted() {
+        let document = ApplyDocument::default();
+        let mut state = ServerState::default();
+        state.users.push(get_user(DEFAULT_ROOT_USER_ID, "iggy"));
+        state.users.push(get_user(2, "admin"));
+        state.users.push(get_user(3, "app"));
+        state.current_user_id = Some(2);
+
+        let actions = plan(&document, &state, true).unwrap().actions;
+
+        assert_eq!(
+            actions,
+            vec![ApplyAction::DeleteUser(DeleteUser {
+                user_id: Identifier::numeric(3).unwrap(),
+            })]
+        );
+    }
+
+    #[test]
+    fn plan_should_fail_given_invalid_stream_id() {
+        let mut document = get_document();
+        document.streams[0].id = 0;
+
+        assert!(plan(&document, &ServerState::default(), false).is_err());
+    }
+
+    fn get_document() -> ApplyDocument {
+        ApplyDocument {
+            streams: vec![StreamDocument {
+                id: 1,
+                name: "orders".to_string(),
+                topics: vec![TopicDocument {
+                    id: 2,
+                    name: "events".to_string(),
+                    partitions_count: 3,
+                    message_expiry: Some("1m".parse().unwrap()),
+                    config: HashMap::new(),
+                    consumer_groups: vec![ConsumerGroupDocument {
+                        id: 3,
+                        name: "workers".to_string(),
+                    }],
+                }],
+            }],
+            users: vec![UserDocument {
+                username: "app".to_string(),
+                password: "secret".to_string(),
+                status: UserStatus::Active,
+                permissions: None,
+            }],
+            personal_access_tokens: vec![PersonalAccessTokenDocument {
+                name: "ci".to_string(),
+                expiry: None,
+            }],
+        }
+    }
+
+    fn get_matching_state() -> ServerState {
+        ServerState {
+            streams: vec![StreamState {
+                id: 1,
+                name: "orders".to_string(),
+                topics: vec![TopicState {
+                    id: 2,
+                    name: "events".to_string(),
+                    partitions_count: 3,
+                    message_expiry: Some(60),
+                    config: HashMap::new(),
+                    consumer_groups: vec![ConsumerGroupState {
+                        id: 3,
+                        name: "workers".to_string(),
+                    }],
+                }],
+            }],
+            users: vec![get_user(DEFAULT_ROOT_USER_ID, "iggy"), get_user(2, "app")],
+            personal_access_tokens: vec![PersonalAccessTokenInfo {
+                name: "ci".to_string(),
+                expiry: None,
+            }],
+            current_user_id: Some(DEFAULT_ROOT_USER_ID),
+        }
+    }
+
+    fn get_user(id: UserId, username: &str) -> UserInfoDetails {
+        UserInfoDetails {
+            id,
+            created_at: 0,
+            status: UserStatus::Active,
+            username: username.to_string(),
+            permissions: None,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_consumer_offset;
+pub mod set_consumer_offset;
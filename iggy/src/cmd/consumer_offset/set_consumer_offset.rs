@@ -0,0 +1,73 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetConsumerOffsetCmd {
+    store_consumer_offset: StoreConsumerOffset,
+}
+
+impl SetConsumerOffsetCmd {
+    pub fn new(
+        consumer_id: Identifier,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Self {
+        Self {
+            store_consumer_offset: StoreConsumerOffset {
+                consumer: Consumer::new(consumer_id),
+                stream_id,
+                topic_id,
+                partition_id: Some(partition_id),
+                offset,
+                transaction_id: None,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetConsumerOffsetCmd {
+    fn explain(&self) -> String {
+        format!(
+            "set consumer offset for consumer with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} to {}",
+            self.store_consumer_offset.consumer.id,
+            self.store_consumer_offset.partition_id.unwrap_or_default(),
+            self.store_consumer_offset.topic_id,
+            self.store_consumer_offset.stream_id,
+            self.store_consumer_offset.offset,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .store_consumer_offset(&self.store_consumer_offset)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting consumer offset for consumer with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.store_consumer_offset.consumer.id,
+                    self.store_consumer_offset.partition_id.unwrap_or_default(),
+                    self.store_consumer_offset.topic_id,
+                    self.store_consumer_offset.stream_id,
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Consumer offset for consumer with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} set to {}",
+            self.store_consumer_offset.consumer.id,
+            self.store_consumer_offset.partition_id.unwrap_or_default(),
+            self.store_consumer_offset.topic_id,
+            self.store_consumer_offset.stream_id,
+            self.store_consumer_offset.offset,
+        );
+
+        Ok(())
+    }
+}
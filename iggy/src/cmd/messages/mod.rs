@@ -0,0 +1,2 @@
+pub mod poll_messages;
+pub mod send_messages;
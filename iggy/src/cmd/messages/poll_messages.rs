@@ -0,0 +1,158 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use crate::models::messages::Message;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+use tracing::{event, Level};
+
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+pub enum PollMessagesOutput {
+    Raw,
+    Json,
+    Hex,
+}
+
+impl Display for PollMessagesOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PollMessagesOutput::Raw => write!(f, "raw"),
+            PollMessagesOutput::Json => write!(f, "json"),
+            PollMessagesOutput::Hex => write!(f, "hex"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct PollMessagesCmd {
+    poll_messages: PollMessages,
+    output: PollMessagesOutput,
+    follow: bool,
+}
+
+impl PollMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        consumer_id: Identifier,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        output: PollMessagesOutput,
+        follow: bool,
+    ) -> Self {
+        Self {
+            poll_messages: PollMessages {
+                consumer: Consumer::new(consumer_id),
+                stream_id,
+                topic_id,
+                partition_id: Some(partition_id),
+                strategy,
+                count,
+                auto_commit,
+                isolation: IsolationLevel::ReadUncommitted,
+            },
+            output,
+            follow,
+        }
+    }
+
+    fn format_message(&self, message: &Message) -> anyhow::Result<String, anyhow::Error> {
+        Ok(match self.output {
+            PollMessagesOutput::Raw => String::from_utf8_lossy(&message.payload).to_string(),
+            PollMessagesOutput::Json => serde_json::to_string(message).with_context(|| {
+                format!(
+                    "Problem serializing message with offset: {}",
+                    message.offset
+                )
+            })?,
+            PollMessagesOutput::Hex => message
+                .payload
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+        })
+    }
+
+    async fn poll(&mut self, client: &dyn Client) -> anyhow::Result<usize, anyhow::Error> {
+        let polled_messages = client
+            .poll_messages(&self.poll_messages)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem polling messages from partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.poll_messages.partition_id.unwrap_or_default(),
+                    self.poll_messages.topic_id,
+                    self.poll_messages.stream_id
+                )
+            })?;
+
+        for message in &polled_messages.messages {
+            event!(target: PRINT_TARGET, Level::INFO, "{}", self.format_message(message)?);
+        }
+
+        // Continue from the message following the last polled one, regardless of the initial strategy.
+        if let Some(message) = polled_messages.messages.last() {
+            self.poll_messages.strategy = PollingStrategy::offset(message.offset + 1);
+        }
+
+        Ok(polled_messages.messages.len())
+    }
+}
+
+#[async_trait]
+impl CliCommand for PollMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "poll {} messages using {} strategy with value: {} by consumer with ID: {} from partition with ID: {} for topic with ID: {} and stream with ID: {} in {} mode",
+            self.poll_messages.count,
+            self.poll_messages.strategy.kind,
+            self.poll_messages.strategy.value,
+            self.poll_messages.consumer.id,
+            self.poll_messages.partition_id.unwrap_or_default(),
+            self.poll_messages.topic_id,
+            self.poll_messages.stream_id,
+            self.output
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let polled_messages_count = self.poll(client).await?;
+        if !self.follow {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Polled {} messages from partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                polled_messages_count,
+                self.poll_messages.partition_id.unwrap_or_default(),
+                self.poll_messages.topic_id,
+                self.poll_messages.stream_id
+            );
+            return Ok(());
+        }
+
+        // Keep polling the new messages until interrupted.
+        loop {
+            let polled_messages_count = tokio::select! {
+                _ = tokio::signal::ctrl_c() => break,
+                result = self.poll(client) => result?,
+            };
+            if polled_messages_count > 0 {
+                continue;
+            }
+
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => break,
+                _ = tokio::time::sleep(FOLLOW_INTERVAL) => {},
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
+use crate::models::header::{HeaderKey, HeaderValue};
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::HashMap;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tracing::{event, Level};
+
+pub struct SendMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partitioning: Partitioning,
+    acknowledgement: Acknowledgement,
+    messages: Option<Vec<String>>,
+    input_file: Option<String>,
+    headers: Vec<(HeaderKey, HeaderValue)>,
+}
+
+impl SendMessagesCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitioning: Partitioning,
+        acknowledgement: Acknowledgement,
+        messages: Option<Vec<String>>,
+        input_file: Option<String>,
+        headers: Vec<(HeaderKey, HeaderValue)>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partitioning,
+            acknowledgement,
+            messages,
+            input_file,
+            headers,
+        }
+    }
+
+    async fn read_payloads(&self) -> anyhow::Result<Vec<Bytes>, anyhow::Error> {
+        if let Some(messages) = &self.messages {
+            return Ok(messages
+                .iter()
+                .map(|message| Bytes::from(message.clone()))
+                .collect());
+        }
+
+        if let Some(input_file) = &self.input_file {
+            let payload = tokio::fs::read(input_file)
+                .await
+                .with_context(|| format!("Problem reading messages from file: {input_file}"))?;
+            return Ok(vec![Bytes::from(payload)]);
+        }
+
+        // Each non-empty line read from the standard input is sent as a separate message.
+        let mut payloads = Vec::new();
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .with_context(|| "Problem reading messages from standard input")?
+        {
+            if !line.is_empty() {
+                payloads.push(Bytes::from(line));
+            }
+        }
+        Ok(payloads)
+    }
+}
+
+#[async_trait]
+impl CliCommand for SendMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "send messages to topic with ID: {} and stream with ID: {}",
+            self.topic_id, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let headers = match self.headers.is_empty() {
+            true => None,
+            false => Some(self.headers.iter().cloned().collect::<HashMap<_, _>>()),
+        };
+        let messages = self
+            .read_payloads()
+            .await?
+            .into_iter()
+            .map(|payload| Message::new(None, payload, headers.clone()))
+            .collect::<Vec<_>>();
+        if messages.is_empty() {
+            anyhow::bail!(
+                "No messages to send, provide them as arguments, input file or standard input"
+            );
+        }
+
+        let messages_count = messages.len();
+        client
+            .send_messages(&mut SendMessages {
+                stream_id: self.stream_id.clone(),
+                topic_id: self.topic_id.clone(),
+                partitioning: Partitioning::from_partitioning(&self.partitioning),
+                acknowledgement: self.acknowledgement,
+                producer: None,
+                messages,
+                transaction_id: None,
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem sending messages to topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Sent {} messages to topic with ID: {} and stream with ID: {}",
+            messages_count,
+            self.topic_id,
+            self.stream_id
+        );
+
+        Ok(())
+    }
+}
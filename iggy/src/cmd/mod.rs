@@ -1,5 +1,8 @@
+pub mod apply;
 pub mod client;
 pub mod consumer_group;
+pub mod consumer_offset;
+pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod streams;
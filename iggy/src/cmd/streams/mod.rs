@@ -2,4 +2,5 @@ pub mod create_stream;
 pub mod delete_stream;
 pub mod get_stream;
 pub mod get_streams;
+pub mod restore_stream;
 pub mod update_stream;
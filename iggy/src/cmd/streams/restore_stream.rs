@@ -0,0 +1,42 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::streams::restore_stream::RestoreStream;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RestoreStreamCmd {
+    restore_stream: RestoreStream,
+}
+
+impl RestoreStreamCmd {
+    pub fn new(stream_id: Identifier) -> Self {
+        Self {
+            restore_stream: RestoreStream { stream_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestoreStreamCmd {
+    fn explain(&self) -> String {
+        format!("restore stream with ID: {}", self.restore_stream.stream_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .restore_stream(&self.restore_stream)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restoring stream with ID: {}",
+                    self.restore_stream.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} restored", self.restore_stream.stream_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::create_backup::CreateBackup;
+use anyhow::Context;
+use async_trait::async_trait;
+use byte_unit::{Byte, UnitType};
+use tracing::{event, Level};
+
+pub struct CreateBackupCmd {
+    create_backup: CreateBackup,
+}
+
+impl CreateBackupCmd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for CreateBackupCmd {
+    fn default() -> Self {
+        Self {
+            create_backup: CreateBackup {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateBackupCmd {
+    fn explain(&self) -> String {
+        "backup command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .create_backup(&self.create_backup)
+            .await
+            .with_context(|| "Problem creating backup".to_owned())?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Backup: {} with {} segment files ({}) created at path: {}",
+            backup.name,
+            backup.files_count,
+            Byte::from_u64(backup.size_bytes).get_appropriate_unit(UnitType::Decimal),
+            backup.path
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::set_log_level::{LogLevel, SetLogLevel};
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetLogLevelCmd {
+    set_log_level: SetLogLevel,
+}
+
+impl SetLogLevelCmd {
+    pub fn new(level: LogLevel) -> Self {
+        Self {
+            set_log_level: SetLogLevel { level },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetLogLevelCmd {
+    fn explain(&self) -> String {
+        format!("set log level to: {}", self.set_log_level.level)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_log_level(&self.set_log_level)
+            .await
+            .with_context(|| {
+                format!("Problem setting log level to: {}", self.set_log_level.level)
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Log level set to: {}",
+            self.set_log_level.level
+        );
+
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
+pub mod backup;
+pub mod log_level;
 pub mod me;
 pub mod ping;
+pub mod reload_config;
 pub mod stats;
@@ -0,0 +1,42 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::reload_config::ReloadConfig;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct ReloadConfigCmd {
+    reload_config: ReloadConfig,
+}
+
+impl ReloadConfigCmd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ReloadConfigCmd {
+    fn default() -> Self {
+        Self {
+            reload_config: ReloadConfig {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ReloadConfigCmd {
+    fn explain(&self) -> String {
+        "reload config command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .reload_config(&self.reload_config)
+            .await
+            .with_context(|| "Problem reloading server configuration")?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Server configuration reloaded");
+
+        Ok(())
+    }
+}
@@ -2,9 +2,11 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::cmd::utils::message_expiry::MessageExpiry;
 use crate::identifier::Identifier;
+use crate::topics::config;
 use crate::topics::create_topic::CreateTopic;
 use anyhow::Context;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use tracing::{event, Level};
 
 pub struct CreateTopicCmd {
@@ -19,6 +21,7 @@ impl CreateTopicCmd {
         partitions_count: u32,
         name: String,
         message_expiry: Option<MessageExpiry>,
+        config: HashMap<String, String>,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -30,10 +33,21 @@ impl CreateTopicCmd {
                     None => None,
                     Some(value) => value.into(),
                 },
+                config,
             },
             message_expiry,
         }
     }
+
+    fn config_text(&self) -> String {
+        match self.create_topic.config.is_empty() {
+            true => String::from(""),
+            false => format!(
+                " with config: {}",
+                config::to_string(&self.create_topic.config)
+            ),
+        }
+    }
 }
 
 #[async_trait]
@@ -44,11 +58,12 @@ impl CliCommand for CreateTopicCmd {
             None => String::from("without message expire time"),
         };
         format!(
-            "create topic with ID: {}, name: {}, partitions count: {} and {} in stream with ID: {}",
+            "create topic with ID: {}, name: {}, partitions count: {} and {}{} in stream with ID: {}",
             self.create_topic.topic_id,
             self.create_topic.name,
             self.create_topic.partitions_count,
             expiry_text,
+            self.config_text(),
             self.create_topic.stream_id
         )
     }
@@ -65,7 +80,7 @@ impl CliCommand for CreateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {}, name: {}, partitions count: {} and {} created in stream with ID: {}",
+            "Topic with ID: {}, name: {}, partitions count: {} and {}{} created in stream with ID: {}",
             self.create_topic.topic_id,
             self.create_topic.name,
             self.create_topic.partitions_count,
@@ -73,6 +88,7 @@ impl CliCommand for CreateTopicCmd {
                 Some(value) => format!("message expire time: {}", value),
                 None => String::from("without message expire time"),
             },
+            self.config_text(),
             self.create_topic.stream_id,
         );
 
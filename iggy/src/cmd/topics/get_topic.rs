@@ -1,6 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::topics::config;
 use crate::topics::get_topic::GetTopic;
 use crate::utils::timestamp::TimeStamp;
 use anyhow::Context;
@@ -68,6 +69,14 @@ impl CliCommand for GetTopicCmd {
             "Partitions count",
             format!("{}", topic.partitions_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Config",
+            match topic.config.is_empty() {
+                true => String::from("None"),
+                false => config::to_string(&topic.config),
+            }
+            .as_str(),
+        ]);
 
         event!(target: PRINT_TARGET, Level::INFO,"{table}");
 
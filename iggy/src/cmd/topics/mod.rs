@@ -2,4 +2,6 @@ pub mod create_topic;
 pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
+pub mod repartition_topic;
+pub mod restore_topic;
 pub mod update_topic;
@@ -0,0 +1,65 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::header::HeaderKey;
+use crate::topics::repartition_topic::RepartitionTopic;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RepartitionTopicCmd {
+    repartition_topic: RepartitionTopic,
+}
+
+impl RepartitionTopicCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions_count: u32,
+        key_header: HeaderKey,
+    ) -> Self {
+        Self {
+            repartition_topic: RepartitionTopic {
+                stream_id,
+                topic_id,
+                partitions_count,
+                key_header,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RepartitionTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "repartition topic with ID: {} in stream with ID: {} into {} partitions by key from header: {}",
+            self.repartition_topic.topic_id,
+            self.repartition_topic.stream_id,
+            self.repartition_topic.partitions_count,
+            self.repartition_topic.key_header.as_str()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .repartition_topic(&self.repartition_topic)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem repartitioning topic with ID: {} in stream {}",
+                    self.repartition_topic.topic_id, self.repartition_topic.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} repartitioned into {} partitions by key from header: {}",
+            self.repartition_topic.topic_id,
+            self.repartition_topic.stream_id,
+            self.repartition_topic.partitions_count,
+            self.repartition_topic.key_header.as_str()
+        );
+
+        Ok(())
+    }
+}
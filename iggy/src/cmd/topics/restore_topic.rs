@@ -0,0 +1,51 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::restore_topic::RestoreTopic;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RestoreTopicCmd {
+    restore_topic: RestoreTopic,
+}
+
+impl RestoreTopicCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+        Self {
+            restore_topic: RestoreTopic {
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestoreTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "restore topic with ID: {} in stream with ID: {}",
+            self.restore_topic.topic_id, self.restore_topic.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .restore_topic(&self.restore_topic)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restoring topic with ID: {} in stream {}",
+                    self.restore_topic.topic_id, self.restore_topic.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} restored",
+            self.restore_topic.topic_id, self.restore_topic.stream_id
+        );
+
+        Ok(())
+    }
+}
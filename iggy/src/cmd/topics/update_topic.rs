@@ -2,9 +2,11 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::cmd::utils::message_expiry::MessageExpiry;
 use crate::identifier::Identifier;
+use crate::topics::config;
 use crate::topics::update_topic::UpdateTopic;
 use anyhow::Context;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use tracing::{event, Level};
 
 pub struct UpdateTopicCmd {
@@ -18,6 +20,7 @@ impl UpdateTopicCmd {
         topic_id: Identifier,
         name: String,
         message_expiry: Option<MessageExpiry>,
+        config: Option<HashMap<String, String>>,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -28,10 +31,19 @@ impl UpdateTopicCmd {
                     None => None,
                     Some(value) => value.into(),
                 },
+                config,
             },
             message_expiry,
         }
     }
+
+    fn config_text(&self) -> String {
+        match &self.update_topic.config {
+            None => String::from(""),
+            Some(config) if config.is_empty() => String::from(" and without config overrides"),
+            Some(config) => format!(" and config: {}", config::to_string(config)),
+        }
+    }
 }
 
 #[async_trait]
@@ -42,10 +54,11 @@ impl CliCommand for UpdateTopicCmd {
             None => String::from(""),
         };
         format!(
-            "update topic with ID: {}, name: {}{} in stream with ID: {}",
+            "update topic with ID: {}, name: {}{}{} in stream with ID: {}",
             self.update_topic.topic_id,
             self.update_topic.name,
             expiry_text,
+            self.config_text(),
             self.update_topic.stream_id
         )
     }
@@ -68,13 +81,14 @@ impl CliCommand for UpdateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {} updated name: {}{} in stream with ID: {}",
+            "Topic with ID: {} updated name: {}{}{} in stream with ID: {}",
             self.update_topic.topic_id,
             self.update_topic.name,
             match &self.message_expiry {
                 Some(value) => format!(" and message expire time: {}", value),
                 None => String::from(""),
             },
+            self.config_text(),
             self.update_topic.stream_id,
         );
 
@@ -0,0 +1,75 @@
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use std::str::FromStr;
+
+/// Parses the message header provided in the `key:kind:value` format, e.g. `source:string:sensor`
+/// or `priority:uint8:1`. The kind is optional and defaults to `string`, so `key:value` is accepted too.
+/// The supported kinds are the same as in `HeaderKind`: raw, string, bool, int8-int128, uint8-uint128, float32 and float64.
+pub fn parse_message_header(input: &str) -> Result<(HeaderKey, HeaderValue), String> {
+    let (key, rest) = input
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid header: {input}, expected format is key:kind:value"))?;
+    // The kind can be skipped, in such a case the whole remaining part is a string value (which can contain ':').
+    let (kind, value) = match rest.split_once(':') {
+        Some((kind, value)) => match HeaderKind::from_str(kind) {
+            Ok(kind) => (kind, value),
+            Err(_) => (HeaderKind::String, rest),
+        },
+        None => (HeaderKind::String, rest),
+    };
+
+    let key = HeaderKey::new(key).map_err(|error| format!("Invalid header key: {error}"))?;
+    let value = get_header_value(kind, value)
+        .ok_or_else(|| format!("Invalid header value: {value} for kind: {kind}"))?;
+    Ok((key, value))
+}
+
+fn get_header_value(kind: HeaderKind, value: &str) -> Option<HeaderValue> {
+    let header_value = match kind {
+        HeaderKind::Raw => HeaderValue::from_raw(value.as_bytes()),
+        HeaderKind::String => HeaderValue::from_str(value),
+        HeaderKind::Bool => HeaderValue::from_bool(value.parse().ok()?),
+        HeaderKind::Int8 => HeaderValue::from_int8(value.parse().ok()?),
+        HeaderKind::Int16 => HeaderValue::from_int16(value.parse().ok()?),
+        HeaderKind::Int32 => HeaderValue::from_int32(value.parse().ok()?),
+        HeaderKind::Int64 => HeaderValue::from_int64(value.parse().ok()?),
+        HeaderKind::Int128 => HeaderValue::from_int128(value.parse().ok()?),
+        HeaderKind::Uint8 => HeaderValue::from_uint8(value.parse().ok()?),
+        HeaderKind::Uint16 => HeaderValue::from_uint16(value.parse().ok()?),
+        HeaderKind::Uint32 => HeaderValue::from_uint32(value.parse().ok()?),
+        HeaderKind::Uint64 => HeaderValue::from_uint64(value.parse().ok()?),
+        HeaderKind::Uint128 => HeaderValue::from_uint128(value.parse().ok()?),
+        HeaderKind::Float32 => HeaderValue::from_float32(value.parse().ok()?),
+        HeaderKind::Float64 => HeaderValue::from_float64(value.parse().ok()?),
+    };
+    header_value.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_header_with_kind() {
+        let (key, value) = parse_message_header("priority:uint8:7").unwrap();
+        assert_eq!(key.as_str(), "priority");
+        assert_eq!(value.as_uint8().unwrap(), 7);
+    }
+
+    #[test]
+    fn should_parse_header_without_kind_as_string() {
+        let (key, value) = parse_message_header("url:http://localhost:3000").unwrap();
+        assert_eq!(key.as_str(), "url");
+        assert_eq!(value.as_str().unwrap(), "http://localhost:3000");
+
+        let (key, value) = parse_message_header("source:sensor").unwrap();
+        assert_eq!(key.as_str(), "source");
+        assert_eq!(value.as_str().unwrap(), "sensor");
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_header() {
+        assert!(parse_message_header("source").is_err());
+        assert!(parse_message_header("priority:uint8:abc").is_err());
+        assert!(parse_message_header(":string:value").is_err());
+    }
+}
@@ -1,2 +1,3 @@
 pub mod message_expiry;
+pub mod message_header;
 pub mod personal_access_token_expiry;
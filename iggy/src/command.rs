@@ -9,6 +9,7 @@ use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::register_producer::RegisterProducer;
 use crate::messages::send_messages::SendMessages;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
@@ -21,18 +22,27 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -50,6 +60,12 @@ pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
+pub const CREATE_BACKUP: &str = "backup.create";
+pub const CREATE_BACKUP_CODE: u32 = 11;
+pub const SET_LOG_LEVEL: &str = "log_level.set";
+pub const SET_LOG_LEVEL_CODE: u32 = 12;
+pub const RELOAD_CONFIG: &str = "config.reload";
+pub const RELOAD_CONFIG_CODE: u32 = 13;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -86,6 +102,16 @@ pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+/// The code of `SendMessages` with the acknowledgement, producer or transaction options, which aren't part of the original payload.
+pub const SEND_MESSAGES_WITH_OPTIONS_CODE: u32 = 102;
+pub const REGISTER_PRODUCER: &str = "producer.register";
+pub const REGISTER_PRODUCER_CODE: u32 = 110;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 111;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 112;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 113;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
@@ -102,6 +128,8 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const RESTORE_STREAM: &str = "stream.restore";
+pub const RESTORE_STREAM_CODE: u32 = 206;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -114,6 +142,10 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const RESTORE_TOPIC: &str = "topic.restore";
+pub const RESTORE_TOPIC_CODE: u32 = 306;
+pub const REPARTITION_TOPIC: &str = "topic.repartition";
+pub const REPARTITION_TOPIC_CODE: u32 = 307;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
@@ -135,6 +167,9 @@ pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
 pub enum Command {
     Ping(Ping),
     GetStats(GetStats),
+    CreateBackup(CreateBackup),
+    SetLogLevel(SetLogLevel),
+    ReloadConfig(ReloadConfig),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
@@ -152,6 +187,10 @@ pub enum Command {
     DeletePersonalAccessToken(DeletePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
+    RegisterProducer(RegisterProducer),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
     PollMessages(PollMessages),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
@@ -161,12 +200,15 @@ pub enum Command {
     DeleteStream(DeleteStream),
     UpdateStream(UpdateStream),
     PurgeStream(PurgeStream),
+    RestoreStream(RestoreStream),
     GetTopic(GetTopic),
     GetTopics(GetTopics),
     CreateTopic(CreateTopic),
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    RestoreTopic(RestoreTopic),
+    RepartitionTopic(RepartitionTopic),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
     GetConsumerGroup(GetConsumerGroup),
@@ -185,6 +227,9 @@ impl BytesSerializable for Command {
         match self {
             Command::Ping(payload) => as_bytes(PING_CODE, &payload.as_bytes()),
             Command::GetStats(payload) => as_bytes(GET_STATS_CODE, &payload.as_bytes()),
+            Command::CreateBackup(payload) => as_bytes(CREATE_BACKUP_CODE, &payload.as_bytes()),
+            Command::SetLogLevel(payload) => as_bytes(SET_LOG_LEVEL_CODE, &payload.as_bytes()),
+            Command::ReloadConfig(payload) => as_bytes(RELOAD_CONFIG_CODE, &payload.as_bytes()),
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, &payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, &payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
@@ -211,7 +256,19 @@ impl BytesSerializable for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 as_bytes(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
             }
-            Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
+            Command::SendMessages(payload) => as_bytes(payload.get_code(), &payload.as_bytes()),
+            Command::RegisterProducer(payload) => {
+                as_bytes(REGISTER_PRODUCER_CODE, &payload.as_bytes())
+            }
+            Command::BeginTransaction(payload) => {
+                as_bytes(BEGIN_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::CommitTransaction(payload) => {
+                as_bytes(COMMIT_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::AbortTransaction(payload) => {
+                as_bytes(ABORT_TRANSACTION_CODE, &payload.as_bytes())
+            }
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, &payload.as_bytes())
@@ -225,12 +282,17 @@ impl BytesSerializable for Command {
             Command::DeleteStream(payload) => as_bytes(DELETE_STREAM_CODE, &payload.as_bytes()),
             Command::UpdateStream(payload) => as_bytes(UPDATE_STREAM_CODE, &payload.as_bytes()),
             Command::PurgeStream(payload) => as_bytes(PURGE_STREAM_CODE, &payload.as_bytes()),
+            Command::RestoreStream(payload) => as_bytes(RESTORE_STREAM_CODE, &payload.as_bytes()),
             Command::GetTopic(payload) => as_bytes(GET_TOPIC_CODE, &payload.as_bytes()),
             Command::GetTopics(payload) => as_bytes(GET_TOPICS_CODE, &payload.as_bytes()),
             Command::CreateTopic(payload) => as_bytes(CREATE_TOPIC_CODE, &payload.as_bytes()),
             Command::DeleteTopic(payload) => as_bytes(DELETE_TOPIC_CODE, &payload.as_bytes()),
             Command::UpdateTopic(payload) => as_bytes(UPDATE_TOPIC_CODE, &payload.as_bytes()),
             Command::PurgeTopic(payload) => as_bytes(PURGE_TOPIC_CODE, &payload.as_bytes()),
+            Command::RestoreTopic(payload) => as_bytes(RESTORE_TOPIC_CODE, &payload.as_bytes()),
+            Command::RepartitionTopic(payload) => {
+                as_bytes(REPARTITION_TOPIC_CODE, &payload.as_bytes())
+            }
             Command::CreatePartitions(payload) => {
                 as_bytes(CREATE_PARTITIONS_CODE, &payload.as_bytes())
             }
@@ -264,6 +326,9 @@ impl BytesSerializable for Command {
         match command {
             PING_CODE => Ok(Command::Ping(Ping::from_bytes(payload)?)),
             GET_STATS_CODE => Ok(Command::GetStats(GetStats::from_bytes(payload)?)),
+            CREATE_BACKUP_CODE => Ok(Command::CreateBackup(CreateBackup::from_bytes(payload)?)),
+            SET_LOG_LEVEL_CODE => Ok(Command::SetLogLevel(SetLogLevel::from_bytes(payload)?)),
+            RELOAD_CONFIG_CODE => Ok(Command::ReloadConfig(ReloadConfig::from_bytes(payload)?)),
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
@@ -293,6 +358,21 @@ impl BytesSerializable for Command {
                 LoginWithPersonalAccessToken::from_bytes(payload)?,
             )),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
+            SEND_MESSAGES_WITH_OPTIONS_CODE => Ok(Command::SendMessages(
+                SendMessages::from_bytes_with_options(payload)?,
+            )),
+            REGISTER_PRODUCER_CODE => Ok(Command::RegisterProducer(RegisterProducer::from_bytes(
+                payload,
+            )?)),
+            BEGIN_TRANSACTION_CODE => Ok(Command::BeginTransaction(BeginTransaction::from_bytes(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION_CODE => Ok(Command::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(Command::AbortTransaction(AbortTransaction::from_bytes(
+                payload,
+            )?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
@@ -306,12 +386,17 @@ impl BytesSerializable for Command {
             DELETE_STREAM_CODE => Ok(Command::DeleteStream(DeleteStream::from_bytes(payload)?)),
             UPDATE_STREAM_CODE => Ok(Command::UpdateStream(UpdateStream::from_bytes(payload)?)),
             PURGE_STREAM_CODE => Ok(Command::PurgeStream(PurgeStream::from_bytes(payload)?)),
+            RESTORE_STREAM_CODE => Ok(Command::RestoreStream(RestoreStream::from_bytes(payload)?)),
             GET_TOPIC_CODE => Ok(Command::GetTopic(GetTopic::from_bytes(payload)?)),
             GET_TOPICS_CODE => Ok(Command::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(Command::CreateTopic(CreateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(Command::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             UPDATE_TOPIC_CODE => Ok(Command::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             PURGE_TOPIC_CODE => Ok(Command::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            RESTORE_TOPIC_CODE => Ok(Command::RestoreTopic(RestoreTopic::from_bytes(payload)?)),
+            REPARTITION_TOPIC_CODE => Ok(Command::RepartitionTopic(RepartitionTopic::from_bytes(
+                payload,
+            )?)),
             CREATE_PARTITIONS_CODE => Ok(Command::CreatePartitions(CreatePartitions::from_bytes(
                 payload,
             )?)),
@@ -355,6 +440,9 @@ impl FromStr for Command {
         match command {
             PING => Ok(Command::Ping(Ping::from_str(payload)?)),
             GET_STATS => Ok(Command::GetStats(GetStats::from_str(payload)?)),
+            CREATE_BACKUP => Ok(Command::CreateBackup(CreateBackup::from_str(payload)?)),
+            SET_LOG_LEVEL => Ok(Command::SetLogLevel(SetLogLevel::from_str(payload)?)),
+            RELOAD_CONFIG => Ok(Command::ReloadConfig(ReloadConfig::from_str(payload)?)),
             GET_ME => Ok(Command::GetMe(GetMe::from_str(payload)?)),
             GET_CLIENT => Ok(Command::GetClient(GetClient::from_str(payload)?)),
             GET_CLIENTS => Ok(Command::GetClients(GetClients::from_str(payload)?)),
@@ -382,6 +470,18 @@ impl FromStr for Command {
                 LoginWithPersonalAccessToken::from_str(payload)?,
             )),
             SEND_MESSAGES => Ok(Command::SendMessages(SendMessages::from_str(payload)?)),
+            REGISTER_PRODUCER => Ok(Command::RegisterProducer(RegisterProducer::from_str(
+                payload,
+            )?)),
+            BEGIN_TRANSACTION => Ok(Command::BeginTransaction(BeginTransaction::from_str(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION => Ok(Command::CommitTransaction(CommitTransaction::from_str(
+                payload,
+            )?)),
+            ABORT_TRANSACTION => Ok(Command::AbortTransaction(AbortTransaction::from_str(
+                payload,
+            )?)),
             POLL_MESSAGES => Ok(Command::PollMessages(PollMessages::from_str(payload)?)),
             STORE_CONSUMER_OFFSET => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_str(payload)?,
@@ -395,12 +495,17 @@ impl FromStr for Command {
             DELETE_STREAM => Ok(Command::DeleteStream(DeleteStream::from_str(payload)?)),
             UPDATE_STREAM => Ok(Command::UpdateStream(UpdateStream::from_str(payload)?)),
             PURGE_STREAM => Ok(Command::PurgeStream(PurgeStream::from_str(payload)?)),
+            RESTORE_STREAM => Ok(Command::RestoreStream(RestoreStream::from_str(payload)?)),
             GET_TOPIC => Ok(Command::GetTopic(GetTopic::from_str(payload)?)),
             GET_TOPICS => Ok(Command::GetTopics(GetTopics::from_str(payload)?)),
             CREATE_TOPIC => Ok(Command::CreateTopic(CreateTopic::from_str(payload)?)),
             DELETE_TOPIC => Ok(Command::DeleteTopic(DeleteTopic::from_str(payload)?)),
             UPDATE_TOPIC => Ok(Command::UpdateTopic(UpdateTopic::from_str(payload)?)),
             PURGE_TOPIC => Ok(Command::PurgeTopic(PurgeTopic::from_str(payload)?)),
+            RESTORE_TOPIC => Ok(Command::RestoreTopic(RestoreTopic::from_str(payload)?)),
+            REPARTITION_TOPIC => Ok(Command::RepartitionTopic(RepartitionTopic::from_str(
+                payload,
+            )?)),
             CREATE_PARTITIONS => Ok(Command::CreatePartitions(CreatePartitions::from_str(
                 payload,
             )?)),
@@ -435,6 +540,9 @@ impl Display for Command {
         match self {
             Command::Ping(_) => write!(formatter, "{PING}"),
             Command::GetStats(_) => write!(formatter, "{GET_STATS}"),
+            Command::CreateBackup(_) => write!(formatter, "{CREATE_BACKUP}"),
+            Command::SetLogLevel(payload) => write!(formatter, "{SET_LOG_LEVEL}|{payload}"),
+            Command::ReloadConfig(_) => write!(formatter, "{RELOAD_CONFIG}"),
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
@@ -469,12 +577,17 @@ impl Display for Command {
             Command::DeleteStream(payload) => write!(formatter, "{DELETE_STREAM}|{payload}"),
             Command::UpdateStream(payload) => write!(formatter, "{UPDATE_STREAM}|{payload}"),
             Command::PurgeStream(payload) => write!(formatter, "{PURGE_STREAM}|{payload}"),
+            Command::RestoreStream(payload) => write!(formatter, "{RESTORE_STREAM}|{payload}"),
             Command::GetTopic(payload) => write!(formatter, "{GET_TOPIC}|{payload}"),
             Command::GetTopics(payload) => write!(formatter, "{GET_TOPICS}|{payload}"),
             Command::CreateTopic(payload) => write!(formatter, "{CREATE_TOPIC}|{payload}"),
             Command::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             Command::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             Command::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            Command::RestoreTopic(payload) => write!(formatter, "{RESTORE_TOPIC}|{payload}"),
+            Command::RepartitionTopic(payload) => {
+                write!(formatter, "{REPARTITION_TOPIC}|{payload}")
+            }
             Command::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
@@ -483,6 +596,18 @@ impl Display for Command {
             }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            Command::RegisterProducer(payload) => {
+                write!(formatter, "{REGISTER_PRODUCER}|{payload}")
+            }
+            Command::BeginTransaction(payload) => {
+                write!(formatter, "{BEGIN_TRANSACTION}|{payload}")
+            }
+            Command::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            Command::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
@@ -514,6 +639,7 @@ impl Display for Command {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::send_messages::Acknowledgement;
 
     #[test]
     fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
@@ -527,6 +653,21 @@ mod tests {
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SetLogLevel(SetLogLevel::default()),
+            SET_LOG_LEVEL_CODE,
+            &SetLogLevel::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ReloadConfig(ReloadConfig::default()),
+            RELOAD_CONFIG_CODE,
+            &ReloadConfig::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -612,6 +753,38 @@ mod tests {
             SEND_MESSAGES_CODE,
             &SendMessages::default(),
         );
+        let send_messages_with_options = SendMessages {
+            acknowledgement: Acknowledgement::Persisted,
+            ..SendMessages::default()
+        };
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SendMessages(SendMessages {
+                acknowledgement: Acknowledgement::Persisted,
+                ..SendMessages::default()
+            }),
+            SEND_MESSAGES_WITH_OPTIONS_CODE,
+            &send_messages_with_options,
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RegisterProducer(RegisterProducer::default()),
+            REGISTER_PRODUCER_CODE,
+            &RegisterProducer::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::PollMessages(PollMessages::default()),
             POLL_MESSAGES_CODE,
@@ -657,6 +830,11 @@ mod tests {
             PURGE_STREAM_CODE,
             &PurgeStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RestoreStream(RestoreStream::default()),
+            RESTORE_STREAM_CODE,
+            &RestoreStream::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
@@ -687,6 +865,16 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RestoreTopic(RestoreTopic::default()),
+            RESTORE_TOPIC_CODE,
+            &RestoreTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RepartitionTopic(RepartitionTopic::default()),
+            REPARTITION_TOPIC_CODE,
+            &RepartitionTopic::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -737,6 +925,21 @@ mod tests {
             GET_STATS,
             &GetStats::default(),
         );
+        assert_read_from_string(
+            &Command::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP,
+            &CreateBackup::default(),
+        );
+        assert_read_from_string(
+            &Command::SetLogLevel(SetLogLevel::default()),
+            SET_LOG_LEVEL,
+            &SetLogLevel::default(),
+        );
+        assert_read_from_string(
+            &Command::ReloadConfig(ReloadConfig::default()),
+            RELOAD_CONFIG,
+            &ReloadConfig::default(),
+        );
         assert_read_from_string(&Command::GetMe(GetMe::default()), GET_ME, &GetMe::default());
         assert_read_from_string(
             &Command::GetClient(GetClient::default()),
@@ -818,6 +1021,26 @@ mod tests {
             SEND_MESSAGES,
             &SendMessages::default(),
         );
+        assert_read_from_string(
+            &Command::RegisterProducer(RegisterProducer::default()),
+            REGISTER_PRODUCER,
+            &RegisterProducer::default(),
+        );
+        assert_read_from_string(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION,
+            &BeginTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION,
+            &CommitTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION,
+            &AbortTransaction::default(),
+        );
         assert_read_from_string(
             &Command::PollMessages(PollMessages::default()),
             POLL_MESSAGES,
@@ -863,6 +1086,11 @@ mod tests {
             PURGE_STREAM,
             &PurgeStream::default(),
         );
+        assert_read_from_string(
+            &Command::RestoreStream(RestoreStream::default()),
+            RESTORE_STREAM,
+            &RestoreStream::default(),
+        );
         assert_read_from_string(
             &Command::GetTopic(GetTopic::default()),
             GET_TOPIC,
@@ -893,6 +1121,16 @@ mod tests {
             PURGE_TOPIC,
             &PurgeTopic::default(),
         );
+        assert_read_from_string(
+            &Command::RestoreTopic(RestoreTopic::default()),
+            RESTORE_TOPIC,
+            &RestoreTopic::default(),
+        );
+        assert_read_from_string(
+            &Command::RepartitionTopic(RepartitionTopic::default()),
+            REPARTITION_TOPIC,
+            &RepartitionTopic::default(),
+        );
         assert_read_from_string(
             &Command::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS,
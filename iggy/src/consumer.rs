@@ -15,6 +15,7 @@ use std::str::FromStr;
 /// - `id`: the unique identifier of the consumer.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Consumer {
     /// The type of consumer. It can be either `Consumer` or `ConsumerGroup`.
     #[serde(skip)]
@@ -22,6 +23,7 @@ pub struct Consumer {
     /// The unique identifier of the consumer.
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "default_id")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub id: Identifier,
 }
 
@@ -17,6 +17,7 @@ use std::str::{from_utf8, FromStr};
 /// - `consumer_group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
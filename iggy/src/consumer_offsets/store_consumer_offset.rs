@@ -16,7 +16,9 @@ use std::str::FromStr;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partition_id` - partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
 /// - `offset` - offset to store.
+/// - `transaction_id` - optional ID of the open transaction, the offset is stored only once the transaction is committed.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StoreConsumerOffset {
     /// The consumer that is storing the offset, either the regular consumer or the consumer group.
     #[serde(flatten)]
@@ -31,6 +33,9 @@ pub struct StoreConsumerOffset {
     pub partition_id: Option<u32>,
     /// Offset to store.
     pub offset: u64,
+    /// Optional ID of the open transaction, the offset is stored only once the transaction is committed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<u64>,
 }
 
 impl Default for StoreConsumerOffset {
@@ -41,6 +46,7 @@ impl Default for StoreConsumerOffset {
             topic_id: Identifier::default(),
             partition_id: Some(1),
             offset: 0,
+            transaction_id: None,
         }
     }
 }
@@ -57,7 +63,7 @@ impl FromStr for StoreConsumerOffset {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 6 {
+        if parts.len() != 6 && parts.len() != 7 {
             return Err(Error::InvalidCommand);
         }
 
@@ -71,12 +77,17 @@ impl FromStr for StoreConsumerOffset {
         let topic_id = parts[3].parse::<Identifier>()?;
         let partition_id = parts[4].parse::<u32>()?;
         let offset = parts[5].parse::<u64>()?;
+        let transaction_id = match parts.get(6) {
+            Some(transaction_id) => Some(transaction_id.parse::<u64>()?),
+            None => None,
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id: Some(partition_id),
             offset,
+            transaction_id,
         };
         command.validate()?;
         Ok(command)
@@ -89,7 +100,7 @@ impl BytesSerializable for StoreConsumerOffset {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            20 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
@@ -100,6 +111,9 @@ impl BytesSerializable for StoreConsumerOffset {
             bytes.put_u32_le(0);
         }
         bytes.put_u64_le(self.offset);
+        if let Some(transaction_id) = self.transaction_id {
+            bytes.put_u64_le(transaction_id);
+        }
         bytes
     }
 
@@ -127,12 +141,20 @@ impl BytesSerializable for StoreConsumerOffset {
             Some(partition_id)
         };
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        // The transaction ID is optional, so that the payloads without it are still supported.
+        let transaction_id = match bytes.len() >= position + 20 {
+            true => Some(u64::from_le_bytes(
+                bytes[position + 12..position + 20].try_into()?,
+            )),
+            false => None,
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id,
             offset,
+            transaction_id,
         };
         command.validate()?;
         Ok(command)
@@ -149,7 +171,11 @@ impl Display for StoreConsumerOffset {
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.offset
-        )
+        )?;
+        if let Some(transaction_id) = self.transaction_id {
+            write!(f, "|{transaction_id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -165,6 +191,7 @@ mod tests {
             topic_id: Identifier::numeric(3).unwrap(),
             partition_id: Some(4),
             offset: 5,
+            transaction_id: Some(6),
         };
 
         let bytes = command.as_bytes();
@@ -182,6 +209,8 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let transaction_id =
+            u64::from_le_bytes(bytes[position + 12..position + 20].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -189,6 +218,31 @@ mod tests {
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(Some(partition_id), command.partition_id);
         assert_eq!(offset, command.offset);
+        assert_eq!(Some(transaction_id), command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes_without_transaction_id() {
+        let command = StoreConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            offset: 5,
+            transaction_id: None,
+        };
+
+        let bytes = command.as_bytes();
+        let deserialized_command = StoreConsumerOffset::from_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            bytes.len(),
+            command.consumer.as_bytes().len()
+                + command.stream_id.as_bytes().len()
+                + command.topic_id.as_bytes().len()
+                + 12
+        );
+        assert_eq!(deserialized_command, command);
     }
 
     #[test]
@@ -198,18 +252,20 @@ mod tests {
         let topic_id = Identifier::numeric(3).unwrap();
         let partition_id = 4u32;
         let offset = 5u64;
+        let transaction_id = 6u64;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            20 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(partition_id);
         bytes.put_u64_le(offset);
+        bytes.put_u64_le(transaction_id);
 
         let command = StoreConsumerOffset::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -220,6 +276,7 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert_eq!(command.transaction_id, Some(transaction_id));
     }
 
     #[test]
@@ -239,5 +296,24 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert_eq!(command.transaction_id, None);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_transaction_id() {
+        let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let offset = 5u64;
+        let transaction_id = 6u64;
+        let input =
+            format!("{consumer}|{stream_id}|{topic_id}|{partition_id}|{offset}|{transaction_id}");
+        let command = StoreConsumerOffset::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.offset, offset);
+        assert_eq!(command.transaction_id, Some(transaction_id));
     }
 }
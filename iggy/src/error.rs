@@ -1 +1,44 @@
 include!(concat!(env!("OUT_DIR"), "/error.rs"));
+
+impl Error {
+    /// Returns the code of the error returned by the server, which is wrapped by the clients as the invalid response (binary transports)
+    /// or the HTTP response error with the error ID in its body, otherwise the code of the error itself.
+    pub fn as_server_code(&self) -> u32 {
+        match self {
+            Error::InvalidResponse(code) => *code,
+            Error::HttpResponseError(_, body) => serde_json::from_str::<serde_json::Value>(body)
+                .ok()
+                .and_then(|body| body.get("id")?.as_u64())
+                .map(|id| id as u32)
+                .unwrap_or_else(|| self.as_code()),
+            _ => self.as_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_code_should_be_read_from_invalid_response() {
+        let code = Error::ProducerFenced(1, 1).as_code();
+
+        assert_eq!(Error::InvalidResponse(code).as_server_code(), code);
+    }
+
+    #[test]
+    fn server_code_should_be_read_from_http_response_error_body() {
+        let code = Error::ProducerFenced(1, 1).as_code();
+        let body = format!(r#"{{"id":{code},"code":"producer_fenced","reason":"","field":null}}"#);
+
+        assert_eq!(Error::HttpResponseError(400, body).as_server_code(), code);
+    }
+
+    #[test]
+    fn server_code_should_be_the_error_code_given_other_error() {
+        let error = Error::HttpResponseError(500, "error".to_string());
+
+        assert_eq!(error.as_server_code(), error.as_code());
+    }
+}
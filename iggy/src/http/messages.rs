@@ -2,10 +2,14 @@ use crate::client::MessageClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::register_producer::RegisterProducer;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 use async_trait::async_trait;
 
+const PRODUCERS: &str = "/producers";
+
 #[async_trait]
 impl MessageClient for HttpClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, Error> {
@@ -33,6 +37,12 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn register_producer(&self, command: &RegisterProducer) -> Result<ProducerInfo, Error> {
+        let response = self.post(PRODUCERS, &command).await?;
+        let producer = response.json().await?;
+        Ok(producer)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
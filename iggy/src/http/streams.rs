@@ -7,6 +7,7 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
 
@@ -50,6 +51,12 @@ impl StreamClient for HttpClient {
         self.delete(&path).await?;
         Ok(())
     }
+
+    async fn restore_stream(&self, command: &RestoreStream) -> Result<(), Error> {
+        let path = format!("{}/{}/restore", PATH, command.stream_id.as_string());
+        self.post(&path, &command).await?;
+        Ok(())
+    }
 }
 
 fn get_details_path(stream_id: &str) -> String {
@@ -1,18 +1,25 @@
 use crate::client::SystemClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const BACKUPS: &str = "/backups";
+const LOG_LEVEL: &str = "/log-level";
+const CONFIG_RELOAD: &str = "/config/reload";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -22,6 +29,22 @@ impl SystemClient for HttpClient {
         Ok(stats)
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<BackupInfo, Error> {
+        let response = self.post(BACKUPS, &command).await?;
+        let backup = response.json().await?;
+        Ok(backup)
+    }
+
+    async fn set_log_level(&self, command: &SetLogLevel) -> Result<(), Error> {
+        self.put(LOG_LEVEL, &command).await?;
+        Ok(())
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<(), Error> {
+        self.post(CONFIG_RELOAD, &command).await?;
+        Ok(())
+    }
+
     async fn get_me(&self, _command: &GetMe) -> Result<ClientInfoDetails, Error> {
         Err(Error::FeatureUnavailable)
     }
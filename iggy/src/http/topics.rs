@@ -7,6 +7,8 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 
@@ -68,6 +70,36 @@ impl TopicClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn restore_topic(&self, command: &RestoreTopic) -> Result<(), Error> {
+        self.post(
+            &format!(
+                "{}/restore",
+                &get_details_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string(),
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn repartition_topic(&self, command: &RepartitionTopic) -> Result<(), Error> {
+        self.post(
+            &format!(
+                "{}/repartition",
+                &get_details_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string(),
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str) -> String {
@@ -28,6 +28,7 @@ pub mod streams;
 pub mod system;
 pub mod tcp;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
 pub mod validatable;
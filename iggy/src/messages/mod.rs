@@ -1,4 +1,5 @@
 pub mod poll_messages;
+pub mod register_producer;
 pub mod send_messages;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
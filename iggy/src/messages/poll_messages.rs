@@ -19,6 +19,7 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `isolation` - whether the messages appended within the open or aborted transactions should be returned.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -42,6 +43,23 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    #[serde(default)]
+    /// Whether the messages appended within the open or aborted transactions should be returned.
+    pub isolation: IsolationLevel,
+}
+
+/// `IsolationLevel` specifies which messages appended within the transactions are visible to the consumer.
+/// It has the following kinds:
+/// - `ReadUncommitted` - all the appended messages are returned, including the ones appended within the open or aborted transactions.
+/// - `ReadCommitted` - only the messages up to the first open transaction are returned, skipping the ones appended within the aborted transactions.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationLevel {
+    #[default]
+    /// Return all the appended messages, including the ones appended within the open or aborted transactions.
+    ReadUncommitted,
+    /// Return only the messages up to the first open transaction, skipping the ones appended within the aborted transactions.
+    ReadCommitted,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -91,6 +109,7 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            isolation: IsolationLevel::default(),
         }
     }
 }
@@ -199,6 +218,45 @@ impl PollingKind {
     }
 }
 
+impl IsolationLevel {
+    /// Returns code of the isolation level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            IsolationLevel::ReadUncommitted => 0,
+            IsolationLevel::ReadCommitted => 1,
+        }
+    }
+
+    /// Returns isolation level from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(IsolationLevel::ReadUncommitted),
+            1 => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for IsolationLevel {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "u" | "read_uncommitted" => Ok(IsolationLevel::ReadUncommitted),
+            "c" | "read_committed" => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "read_uncommitted"),
+            IsolationLevel::ReadCommitted => write!(f, "read_committed"),
+        }
+    }
+}
+
 impl FromStr for PollingKind {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -257,6 +315,11 @@ impl FromStr for PollMessages {
             },
             None => false,
         };
+        // The isolation level is optional and might be followed (or replaced) by the output format used by the CLI.
+        let isolation = parts
+            .get(9)
+            .and_then(|isolation| IsolationLevel::from_str(isolation).ok())
+            .unwrap_or_default();
 
         let command = PollMessages {
             consumer,
@@ -266,6 +329,7 @@ impl FromStr for PollMessages {
             strategy,
             count,
             auto_commit,
+            isolation,
         };
         command.validate()?;
         Ok(command)
@@ -279,7 +343,7 @@ impl BytesSerializable for PollMessages {
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            10 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -299,12 +363,13 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        bytes.put_u8(self.isolation.as_code());
 
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() < 29 {
+        if bytes.len() < 30 {
             return Err(Error::InvalidCommand);
         }
 
@@ -335,6 +400,11 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        // The isolation level is optional, so that the payloads without it are still supported.
+        let isolation = match bytes.get(position + 13) {
+            Some(code) => IsolationLevel::from_code(*code)?,
+            None => IsolationLevel::ReadUncommitted,
+        };
         let command = PollMessages {
             consumer,
             stream_id,
@@ -343,6 +413,7 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            isolation,
         };
         command.validate()?;
         Ok(command)
@@ -353,14 +424,15 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            self.isolation
         )
     }
 }
@@ -413,6 +485,7 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            isolation: IsolationLevel::ReadCommitted,
         };
 
         let bytes = command.as_bytes();
@@ -439,6 +512,7 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let isolation = IsolationLevel::from_code(bytes[position + 13]);
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -448,6 +522,7 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert!(matches!(isolation, Ok(isolation) if isolation == command.isolation));
     }
 
     #[test]
@@ -459,13 +534,14 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let isolation = IsolationLevel::ReadCommitted;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let strategy_bytes = strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            10 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -477,6 +553,7 @@ mod tests {
         bytes.extend(strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u8(isolation.as_code());
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -491,6 +568,43 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.isolation, isolation);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_isolation_level() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::offset(2),
+            count: 3,
+            auto_commit: true,
+            isolation: IsolationLevel::ReadCommitted,
+        };
+        let mut bytes = command.as_bytes();
+        assert_eq!(
+            PollMessages::from_bytes(&bytes).unwrap().isolation,
+            IsolationLevel::ReadCommitted
+        );
+
+        // The payload of the client, which doesn't send the isolation level yet.
+        bytes.pop();
+        assert_eq!(bytes.len(), 37);
+        let deserialized_command = PollMessages::from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized_command.consumer, command.consumer);
+        assert_eq!(deserialized_command.stream_id, command.stream_id);
+        assert_eq!(deserialized_command.topic_id, command.topic_id);
+        assert_eq!(deserialized_command.partition_id, command.partition_id);
+        assert_eq!(deserialized_command.strategy, command.strategy);
+        assert_eq!(deserialized_command.count, command.count);
+        assert_eq!(deserialized_command.auto_commit, command.auto_commit);
+        assert_eq!(
+            deserialized_command.isolation,
+            IsolationLevel::ReadUncommitted
+        );
     }
 
     #[test]
@@ -521,4 +635,23 @@ mod tests {
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
     }
+    #[test]
+    fn should_be_read_from_string_with_isolation_level() {
+        let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::offset(2);
+        let count = 3u32;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|n|read_committed",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(!command.auto_commit);
+        assert_eq!(command.isolation, IsolationLevel::ReadCommitted);
+    }
 }
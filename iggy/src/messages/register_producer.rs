@@ -0,0 +1,119 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `RegisterProducer` command is used to register the idempotent producer on the server,
+/// which returns the producer ID and epoch to be attached to the sent messages.
+/// It has additional payload:
+/// - `producer_id` - unique ID of the already registered producer (e.g. after the application restart), which will get the new epoch and fence its previous instances, or 0 to register a new producer.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RegisterProducer {
+    /// Unique ID of the already registered producer, or 0 to register a new producer.
+    #[serde(default)]
+    pub producer_id: u64,
+}
+
+impl CommandPayload for RegisterProducer {}
+
+impl Validatable<Error> for RegisterProducer {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for RegisterProducer {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.is_empty() {
+            return Ok(RegisterProducer::default());
+        }
+
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let producer_id = parts[0].parse::<u64>()?;
+        let command = RegisterProducer { producer_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for RegisterProducer {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u64_le(self.producer_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RegisterProducer, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let producer_id = u64::from_le_bytes(bytes.try_into()?);
+        let command = RegisterProducer { producer_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RegisterProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.producer_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterProducer { producer_id: 1 };
+
+        let bytes = command.as_bytes();
+        let producer_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(producer_id, command.producer_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let producer_id = 1u64;
+        let bytes = producer_id.to_le_bytes();
+        let command = RegisterProducer::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.producer_id, producer_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let producer_id = 1u64;
+        let input = producer_id.to_string();
+        let command = RegisterProducer::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.producer_id, producer_id);
+    }
+
+    #[test]
+    fn should_be_read_from_empty_string_as_new_producer() {
+        let command = RegisterProducer::from_str("");
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.producer_id, 0);
+    }
+}
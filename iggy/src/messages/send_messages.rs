@@ -1,10 +1,12 @@
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::CommandPayload;
+use crate::command::{CommandPayload, SEND_MESSAGES_CODE, SEND_MESSAGES_WITH_OPTIONS_CODE};
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::TimeStamp;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes};
 use serde::{Deserialize, Serialize};
@@ -21,8 +23,12 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
+/// - `acknowledgement` - when the server should acknowledge the messages - right away, once they're appended in memory or persisted on disk.
+/// - `producer` - optional idempotent producer ID, epoch and sequence number of the batch, so that the retried batch is appended only once.
+/// - `transaction_id` - optional ID of the open transaction, the messages are hidden from the read-committed consumers until the transaction is committed.
 /// - `messages` - collection of messages to be sent.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -32,7 +38,17 @@ pub struct SendMessages {
     pub topic_id: Identifier,
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
+    /// When the server should acknowledge the messages - right away, once they're appended in memory or persisted on disk.
+    #[serde(default)]
+    pub acknowledgement: Acknowledgement,
+    /// Optional idempotent producer ID, epoch and sequence number of the batch, so that the retried batch is appended only once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ProducerSequence>,
+    /// Optional ID of the open transaction, the messages are hidden from the read-committed consumers until the transaction is committed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<u64>,
     /// Collection of messages to be sent.
+    #[cfg_attr(feature = "openapi", schema(value_type = Vec<SendMessage>))]
     pub messages: Vec<Message>,
 }
 
@@ -41,8 +57,12 @@ pub struct SendMessages {
 /// - `Balanced` - the partition ID is calculated by the server using the round-robin algorithm.
 /// - `PartitionId` - the partition ID is provided by the client.
 /// - `MessagesKey` - the partition ID is calculated by the server using the hash of the provided messages key.
+/// - `StickyBatch` - the server keeps appending the messages to the same partition for the topic sticky batch interval, then rotates to the next one.
+/// - `LeastLoaded` - the server picks the partition with the least bytes of the messages which aren't saved on disk yet.
+/// - `KeyRange` - the partition ID is calculated by the server using the topic key ranges the provided messages key falls into.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partitioning {
     /// The kind of partitioning.
     pub kind: PartitioningKind,
@@ -51,25 +71,51 @@ pub struct Partitioning {
     pub length: u8,
     #[serde_as(as = "Base64")]
     /// The binary value payload.
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Vec<u8>,
 }
 
+/// `ProducerSequence` identifies the batch of messages sent by the idempotent producer and is used by `SendMessages`.
+/// Each partition keeps the last sequence number appended by the producer, and ignores the batch with the same sequence number,
+/// as it's the retry of the last appended batch (e.g. when the response was lost), while the lower sequence numbers and the gaps are rejected.
+/// It has the following payload:
+/// - `producer_id` - unique producer ID returned by the server on registration.
+/// - `epoch` - producer epoch returned by the server on registration, the batches sent with the older epoch are rejected.
+/// - `sequence` - sequence number of the batch, starting from 1 and increasing by 1 with each batch sent to the same partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ProducerSequence {
+    /// Unique producer ID returned by the server on registration.
+    pub producer_id: u64,
+    /// Producer epoch returned by the server on registration.
+    pub epoch: u32,
+    /// Sequence number of the batch, starting from 1 and increasing by 1 with each batch sent to the same partition.
+    pub sequence: u64,
+}
+
 /// The single message to be sent. It has the following payload:
 /// - `id` - unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
+/// - `deliver_at` - optional timestamp (in microseconds), until which the message is staged by the server and not visible to the consumers.
 /// - `length` - length of the payload.
 /// - `payload` - binary message payload.
 /// - `headers` - optional collection of headers.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = SendMessage))]
 pub struct Message {
     /// Unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
     #[serde(default = "default_message_id")]
     pub id: u128,
+    /// Optional timestamp (in microseconds), until which the message is staged by the server and not visible to the consumers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deliver_at: Option<u64>,
     #[serde(skip)]
     /// Length of the payload.
     pub length: u32,
     #[serde_as(as = "Base64")]
     /// Binary message payload.
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub payload: Bytes,
     /// Optional collection of headers.
     pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
@@ -77,6 +123,7 @@ pub struct Message {
 
 /// `PartitioningKind` is an enum which specifies the kind of partitioning and is used by `Partitioning`.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PartitioningKind {
     /// The partition ID is calculated by the server using the round-robin algorithm.
@@ -86,6 +133,30 @@ pub enum PartitioningKind {
     PartitionId,
     /// The partition ID is calculated by the server using the hash of the provided messages key.
     MessagesKey,
+    /// The partition ID is kept by the server for the topic sticky batch interval, then rotated to the next one.
+    StickyBatch,
+    /// The partition ID is picked by the server as the one with the least unsaved bytes.
+    LeastLoaded,
+    /// The partition ID is calculated by the server using the topic key ranges the provided messages key falls into.
+    KeyRange,
+}
+
+/// `Acknowledgement` specifies when the server should acknowledge the sent messages and is used by `SendMessages`.
+/// It has the following kinds:
+/// - `None` - the messages are acknowledged as soon as they're received, before being appended (fire and forget), so any error is only logged by the server.
+/// - `Memory` - the messages are acknowledged once they're appended to the partition buffer, and saved on disk later on.
+/// - `Persisted` - the messages are acknowledged once they're saved on disk (and synced, if `enforce_fsync` is enabled).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Acknowledgement {
+    /// The messages are acknowledged as soon as they're received, before being appended.
+    None,
+    /// The messages are acknowledged once they're appended to the partition buffer.
+    #[default]
+    Memory,
+    /// The messages are acknowledged once they're saved on disk.
+    Persisted,
 }
 
 fn default_message_id() -> u128 {
@@ -98,6 +169,9 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
+            transaction_id: None,
             messages: vec![Message::default()],
         }
     }
@@ -175,6 +249,36 @@ impl Partitioning {
         }
     }
 
+    /// Partition the messages by filling the same partition for the topic sticky batch interval on the server.
+    pub fn sticky_batch() -> Self {
+        Partitioning {
+            kind: PartitioningKind::StickyBatch,
+            length: 0,
+            value: EMPTY_KEY_VALUE,
+        }
+    }
+
+    /// Partition the messages using the partition with the least unsaved bytes on the server.
+    pub fn least_loaded() -> Self {
+        Partitioning {
+            kind: PartitioningKind::LeastLoaded,
+            length: 0,
+            value: EMPTY_KEY_VALUE,
+        }
+    }
+
+    /// Partition the messages using the topic key ranges the provided messages key falls into.
+    pub fn key_range(value: &[u8]) -> Result<Self, Error> {
+        let mut partitioning = Self::messages_key(value)?;
+        partitioning.kind = PartitioningKind::KeyRange;
+        Ok(partitioning)
+    }
+
+    /// Partition the messages using the topic key ranges the provided messages key as str falls into.
+    pub fn key_range_str(value: &str) -> Result<Self, Error> {
+        Self::key_range(value.as_bytes())
+    }
+
     /// Create the partitioning from the provided partitioning.
     pub fn from_partitioning(partitioning: &Partitioning) -> Self {
         Partitioning {
@@ -200,7 +304,7 @@ impl Validatable<Error> for SendMessages {
 
         let key_value_length = self.partitioning.value.len();
         if key_value_length > 255
-            || (self.partitioning.kind != PartitioningKind::Balanced && key_value_length == 0)
+            || (!self.partitioning.kind.is_balanced() && key_value_length == 0)
         {
             return Err(Error::InvalidKeyValueLength);
         }
@@ -215,6 +319,11 @@ impl Validatable<Error> for SendMessages {
                         return Err(Error::TooBigHeadersPayload);
                     }
                 }
+                if let Some(ttl) = headers.get(&HeaderKey::message_ttl()) {
+                    if ttl.as_uint64().unwrap_or_default() == 0 {
+                        return Err(Error::InvalidMessageTtl);
+                    }
+                }
             }
             payload_size += message.payload.len() as u32;
             if payload_size > MAX_PAYLOAD_SIZE {
@@ -226,10 +335,49 @@ impl Validatable<Error> for SendMessages {
             return Err(Error::EmptyMessagePayload);
         }
 
+        if self.transaction_id.is_some()
+            && self
+                .messages
+                .iter()
+                .any(|message| message.deliver_at.is_some())
+        {
+            return Err(Error::DelayedMessagesInTransaction);
+        }
+
+        if let Some(producer) = &self.producer {
+            producer.validate()?;
+            // Each partition tracks the sequence of the producer on its own, so the consecutive sequence numbers
+            // must be sent to the same partition, which is only guaranteed when the partition ID is provided.
+            if self.partitioning.kind != PartitioningKind::PartitionId {
+                return Err(Error::PartitionIdRequiredForIdempotentProducer);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<Error> for ProducerSequence {
+    fn validate(&self) -> Result<(), Error> {
+        if self.producer_id == 0 {
+            return Err(Error::ProducerNotFound(self.producer_id));
+        }
+
+        if self.sequence == 0 {
+            return Err(Error::InvalidProducerSequence);
+        }
+
         Ok(())
     }
 }
 
+impl ProducerSequence {
+    /// Get the size of the producer sequence in bytes.
+    pub fn get_size_bytes(&self) -> u32 {
+        8 + 4 + 8
+    }
+}
+
 impl PartitioningKind {
     /// Get the code of the partitioning kind.
     pub fn as_code(&self) -> u8 {
@@ -237,6 +385,9 @@ impl PartitioningKind {
             PartitioningKind::Balanced => 1,
             PartitioningKind::PartitionId => 2,
             PartitioningKind::MessagesKey => 3,
+            PartitioningKind::StickyBatch => 4,
+            PartitioningKind::LeastLoaded => 5,
+            PartitioningKind::KeyRange => 6,
         }
     }
 
@@ -246,6 +397,52 @@ impl PartitioningKind {
             1 => Ok(PartitioningKind::Balanced),
             2 => Ok(PartitioningKind::PartitionId),
             3 => Ok(PartitioningKind::MessagesKey),
+            4 => Ok(PartitioningKind::StickyBatch),
+            5 => Ok(PartitioningKind::LeastLoaded),
+            6 => Ok(PartitioningKind::KeyRange),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+
+    /// Whether the partition is picked by the server on its own, without any value provided by the client.
+    pub fn is_balanced(&self) -> bool {
+        matches!(
+            self,
+            PartitioningKind::Balanced
+                | PartitioningKind::StickyBatch
+                | PartitioningKind::LeastLoaded
+        )
+    }
+}
+
+impl Acknowledgement {
+    /// Get the code of the acknowledgement.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            Acknowledgement::None => 1,
+            Acknowledgement::Memory => 2,
+            Acknowledgement::Persisted => 3,
+        }
+    }
+
+    /// Get the acknowledgement from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(Acknowledgement::None),
+            2 => Ok(Acknowledgement::Memory),
+            3 => Ok(Acknowledgement::Persisted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for Acknowledgement {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "n" | "none" => Ok(Acknowledgement::None),
+            "m" | "memory" => Ok(Acknowledgement::Memory),
+            "p" | "persisted" => Ok(Acknowledgement::Persisted),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -258,6 +455,9 @@ impl FromStr for PartitioningKind {
             "b" | "balanced" => Ok(PartitioningKind::Balanced),
             "p" | "partition_id" => Ok(PartitioningKind::PartitionId),
             "k" | "messages_key" => Ok(PartitioningKind::MessagesKey),
+            "s" | "sticky_batch" => Ok(PartitioningKind::StickyBatch),
+            "l" | "least_loaded" => Ok(PartitioningKind::LeastLoaded),
+            "r" | "key_range" => Ok(PartitioningKind::KeyRange),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -272,6 +472,7 @@ impl Message {
     ) -> Self {
         Message {
             id: id.unwrap_or(0),
+            deliver_at: None,
             #[allow(clippy::cast_possible_truncation)]
             length: payload.len() as u32,
             payload,
@@ -279,10 +480,36 @@ impl Message {
         }
     }
 
+    /// Delay the delivery of the message until the provided timestamp (in microseconds).
+    pub fn with_deliver_at(mut self, deliver_at: u64) -> Self {
+        self.deliver_at = Some(deliver_at);
+        self
+    }
+
+    /// Delay the delivery of the message by the provided duration, counting from now.
+    pub fn with_delay(self, delay: IggyDuration) -> Self {
+        let deliver_at = TimeStamp::now().to_micros() + delay.get_duration().as_micros() as u64;
+        self.with_deliver_at(deliver_at)
+    }
+
+    /// Expire the message after the provided duration, counting from the moment it's appended to the partition.
+    /// The time to live is stored in the reserved header, and the expired message is no longer delivered to the consumers.
+    pub fn with_ttl(mut self, ttl: IggyDuration) -> Self {
+        let ttl = HeaderValue::from_uint64(ttl.get_duration().as_micros() as u64).unwrap();
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(HeaderKey::message_ttl(), ttl);
+        self
+    }
+
     /// Get the size of the message in bytes.
     pub fn get_size_bytes(&self) -> u32 {
-        // ID + Length + Payload + Headers
-        16 + 4 + self.payload.len() as u32 + header::get_headers_size_bytes(&self.headers)
+        // ID + Length + Payload + Headers + Deliver at (optional)
+        let deliver_at_size_bytes = if self.deliver_at.is_some() { 8 } else { 0 };
+        16 + 4
+            + self.payload.len() as u32
+            + header::get_headers_size_bytes(&self.headers)
+            + deliver_at_size_bytes
     }
 }
 
@@ -291,6 +518,7 @@ impl Default for Message {
         let payload = Bytes::from("hello world");
         Message {
             id: 0,
+            deliver_at: None,
             length: payload.len() as u32,
             payload,
             headers: None,
@@ -336,17 +564,26 @@ impl BytesSerializable for Partitioning {
     }
 }
 
+/// The highest bit of the headers length, which marks the message with the deliver at timestamp following the headers length,
+/// so that the messages without it keep the same binary format.
+const DELIVER_AT_FLAG: u32 = 1 << 31;
+
 impl BytesSerializable for Message {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
         bytes.put_u128_le(self.id);
-        if let Some(headers) = &self.headers {
-            let headers_bytes = headers.as_bytes();
-            bytes.put_u32_le(headers_bytes.len() as u32);
-            bytes.extend(&headers_bytes);
-        } else {
-            bytes.put_u32_le(0);
+        let headers_bytes = match &self.headers {
+            Some(headers) => headers.as_bytes(),
+            None => Vec::new(),
+        };
+        match self.deliver_at {
+            Some(deliver_at) => {
+                bytes.put_u32_le(headers_bytes.len() as u32 | DELIVER_AT_FLAG);
+                bytes.put_u64_le(deliver_at);
+            }
+            None => bytes.put_u32_le(headers_bytes.len() as u32),
         }
+        bytes.extend(&headers_bytes);
         bytes.put_u32_le(self.length);
         bytes.extend(&self.payload);
         bytes
@@ -359,32 +596,42 @@ impl BytesSerializable for Message {
 
         let id = u128::from_le_bytes(bytes[..16].try_into()?);
         let headers_length = u32::from_le_bytes(bytes[16..20].try_into()?);
+        let mut position = 20;
+        let deliver_at = match headers_length & DELIVER_AT_FLAG {
+            0 => None,
+            _ => {
+                if bytes.len() < 32 {
+                    return Err(Error::InvalidCommand);
+                }
+
+                position += 8;
+                Some(u64::from_le_bytes(bytes[20..28].try_into()?))
+            }
+        };
+        let headers_length = (headers_length & !DELIVER_AT_FLAG) as usize;
         let headers = if headers_length > 0 {
             Some(HashMap::from_bytes(
-                &bytes[20..20 + headers_length as usize],
+                &bytes[position..position + headers_length],
             )?)
         } else {
             None
         };
+        position += headers_length;
 
-        let payload_length = u32::from_le_bytes(
-            bytes[20 + headers_length as usize..24 + headers_length as usize].try_into()?,
-        );
+        let payload_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         if payload_length == 0 {
             return Err(Error::EmptyMessagePayload);
         }
 
-        let payload = Bytes::from(
-            bytes[24 + headers_length as usize
-                ..24 + headers_length as usize + payload_length as usize]
-                .to_vec(),
-        );
+        position += 4;
+        let payload = Bytes::from(bytes[position..position + payload_length as usize].to_vec());
         if payload.len() != payload_length as usize {
             return Err(Error::InvalidMessagePayloadLength);
         }
 
         Ok(Message {
             id,
+            deliver_at,
             length: payload_length,
             payload,
             headers,
@@ -411,6 +658,7 @@ impl FromStr for Message {
 
         Ok(Message {
             id,
+            deliver_at: None,
             length,
             payload,
             headers: None,
@@ -431,9 +679,11 @@ impl FromStr for SendMessages {
         let key_kind = parts[2];
         let key_kind = PartitioningKind::from_str(key_kind)?;
         let (key_value, key_length) = match key_kind {
-            PartitioningKind::Balanced => (EMPTY_KEY_VALUE, 0),
+            PartitioningKind::Balanced
+            | PartitioningKind::StickyBatch
+            | PartitioningKind::LeastLoaded => (EMPTY_KEY_VALUE, 0),
             PartitioningKind::PartitionId => (parts[3].parse::<u32>()?.to_le_bytes().to_vec(), 4),
-            PartitioningKind::MessagesKey => {
+            PartitioningKind::MessagesKey | PartitioningKind::KeyRange => {
                 let key_value = parts[3].as_bytes().to_vec();
                 #[allow(clippy::cast_possible_truncation)]
                 let key_length = parts[3].len() as u8;
@@ -449,6 +699,7 @@ impl FromStr for SendMessages {
             length: payload.len() as u32,
             payload,
             headers: None,
+            deliver_at: None,
         };
 
         let command = SendMessages {
@@ -459,6 +710,9 @@ impl FromStr for SendMessages {
                 length: key_length,
                 value: key_value,
             },
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
+            transaction_id: None,
             messages: vec![message],
         };
         command.validate()?;
@@ -466,31 +720,30 @@ impl FromStr for SendMessages {
     }
 }
 
-impl BytesSerializable for SendMessages {
-    fn as_bytes(&self) -> Vec<u8> {
-        let messages_size = self
-            .messages
-            .iter()
-            .map(Message::get_size_bytes)
-            .sum::<u32>();
+impl SendMessages {
+    /// Returns true if any of the acknowledgement, producer or transaction options differs from the default.
+    pub fn has_options(&self) -> bool {
+        self.acknowledgement != Acknowledgement::default()
+            || self.producer.is_some()
+            || self.transaction_id.is_some()
+    }
 
-        let key_bytes = self.partitioning.as_bytes();
-        let stream_id_bytes = self.stream_id.as_bytes();
-        let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = Vec::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
-        );
-        bytes.extend(stream_id_bytes);
-        bytes.extend(topic_id_bytes);
-        bytes.extend(key_bytes);
-        for message in &self.messages {
-            bytes.extend(message.as_bytes());
+    /// Returns the binary command code, the command with the options is sent as `SEND_MESSAGES_WITH_OPTIONS_CODE`,
+    /// so that the payload without the options stays the same as before and is understood by the older servers.
+    pub fn get_code(&self) -> u32 {
+        if self.has_options() {
+            SEND_MESSAGES_WITH_OPTIONS_CODE
+        } else {
+            SEND_MESSAGES_CODE
         }
+    }
 
-        bytes
+    /// Deserializes the command sent as `SEND_MESSAGES_WITH_OPTIONS_CODE`.
+    pub fn from_bytes_with_options(bytes: &[u8]) -> Result<SendMessages, Error> {
+        Self::from_bytes_with(bytes, true)
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<SendMessages, Error> {
+    fn from_bytes_with(bytes: &[u8], with_options: bool) -> Result<SendMessages, Error> {
         if bytes.len() < 11 {
             return Err(Error::InvalidCommand);
         }
@@ -502,6 +755,34 @@ impl BytesSerializable for SendMessages {
         position += topic_id.get_size_bytes() as usize;
         let key = Partitioning::from_bytes(&bytes[position..])?;
         position += key.get_size_bytes() as usize;
+        let mut acknowledgement = Acknowledgement::default();
+        let mut producer = None;
+        let mut transaction_id = None;
+        if with_options {
+            if bytes.len() < position + 10 {
+                return Err(Error::InvalidCommand);
+            }
+            acknowledgement = Acknowledgement::from_code(bytes[position])?;
+            position += 1;
+            producer = match bytes[position] {
+                0 => None,
+                1 => {
+                    let producer = ProducerSequence::from_bytes(&bytes[position + 1..])?;
+                    position += producer.get_size_bytes() as usize;
+                    Some(producer)
+                }
+                _ => return Err(Error::InvalidCommand),
+            };
+            position += 1;
+            if bytes.len() < position + 8 {
+                return Err(Error::InvalidCommand);
+            }
+            transaction_id = match u64::from_le_bytes(bytes[position..position + 8].try_into()?) {
+                0 => None,
+                transaction_id => Some(transaction_id),
+            };
+            position += 8;
+        }
         let messages_payloads = &bytes[position..];
         position = 0;
         let mut messages = Vec::new();
@@ -515,6 +796,9 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            acknowledgement,
+            producer,
+            transaction_id,
             messages,
         };
         command.validate()?;
@@ -522,6 +806,79 @@ impl BytesSerializable for SendMessages {
     }
 }
 
+impl BytesSerializable for SendMessages {
+    fn as_bytes(&self) -> Vec<u8> {
+        let messages_size = self
+            .messages
+            .iter()
+            .map(Message::get_size_bytes)
+            .sum::<u32>();
+
+        let key_bytes = self.partitioning.as_bytes();
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let producer_bytes = match &self.producer {
+            Some(producer) => producer.as_bytes(),
+            None => vec![],
+        };
+        let mut bytes = Vec::with_capacity(
+            stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + key_bytes.len()
+                + 10
+                + producer_bytes.len()
+                + messages_size as usize,
+        );
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.extend(key_bytes);
+        // The options are only present in the payload of the command sent as `SEND_MESSAGES_WITH_OPTIONS_CODE`.
+        if self.has_options() {
+            bytes.put_u8(self.acknowledgement.as_code());
+            bytes.put_u8(u8::from(self.producer.is_some()));
+            bytes.extend(producer_bytes);
+            bytes.put_u64_le(self.transaction_id.unwrap_or(0));
+        }
+        for message in &self.messages {
+            bytes.extend(message.as_bytes());
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<SendMessages, Error> {
+        Self::from_bytes_with(bytes, false)
+    }
+}
+
+impl BytesSerializable for ProducerSequence {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
+        bytes.put_u64_le(self.producer_id);
+        bytes.put_u32_le(self.epoch);
+        bytes.put_u64_le(self.sequence);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 20 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let producer_id = u64::from_le_bytes(bytes[..8].try_into()?);
+        let epoch = u32::from_le_bytes(bytes[8..12].try_into()?);
+        let sequence = u64::from_le_bytes(bytes[12..20].try_into()?);
+        Ok(ProducerSequence {
+            producer_id,
+            epoch,
+            sequence,
+        })
+    }
+}
+
 impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -542,26 +899,41 @@ impl Display for SendMessages {
 impl Display for Partitioning {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.kind {
-            PartitioningKind::Balanced => write!(f, "{}|0", self.kind),
+            PartitioningKind::Balanced
+            | PartitioningKind::StickyBatch
+            | PartitioningKind::LeastLoaded => write!(f, "{}|0", self.kind),
             PartitioningKind::PartitionId => write!(
                 f,
                 "{}|{}",
                 self.kind,
                 u32::from_le_bytes(self.value[..4].try_into().unwrap())
             ),
-            PartitioningKind::MessagesKey => {
+            PartitioningKind::MessagesKey | PartitioningKind::KeyRange => {
                 write!(f, "{}|{}", self.kind, String::from_utf8_lossy(&self.value))
             }
         }
     }
 }
 
+impl Display for Acknowledgement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acknowledgement::None => write!(f, "none"),
+            Acknowledgement::Memory => write!(f, "memory"),
+            Acknowledgement::Persisted => write!(f, "persisted"),
+        }
+    }
+}
+
 impl Display for PartitioningKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PartitioningKind::Balanced => write!(f, "balanced"),
             PartitioningKind::PartitionId => write!(f, "partition_id"),
             PartitioningKind::MessagesKey => write!(f, "messages_key"),
+            PartitioningKind::StickyBatch => write!(f, "sticky_batch"),
+            PartitioningKind::LeastLoaded => write!(f, "least_loaded"),
+            PartitioningKind::KeyRange => write!(f, "key_range"),
         }
     }
 }
@@ -580,10 +952,18 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            acknowledgement: Acknowledgement::Persisted,
+            producer: Some(ProducerSequence {
+                producer_id: 5,
+                epoch: 6,
+                sequence: 7,
+            }),
+            transaction_id: Some(8),
             messages,
         };
 
         let bytes = command.as_bytes();
+        assert_eq!(command.get_code(), SEND_MESSAGES_WITH_OPTIONS_CODE);
 
         let mut position = 0;
         let stream_id = Identifier::from_bytes(&bytes).unwrap();
@@ -592,6 +972,14 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let key = Partitioning::from_bytes(&bytes[position..]).unwrap();
         position += key.get_size_bytes() as usize;
+        let acknowledgement = Acknowledgement::from_code(bytes[position]).unwrap();
+        position += 1;
+        let has_producer = bytes[position];
+        position += 1;
+        let producer = ProducerSequence::from_bytes(&bytes[position..]).unwrap();
+        position += producer.get_size_bytes() as usize;
+        let transaction_id = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        position += 8;
         let messages = &bytes[position..];
         let command_messages = &command
             .messages
@@ -604,14 +992,44 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(acknowledgement, command.acknowledgement);
+        assert_eq!(has_producer, 1);
+        assert_eq!(Some(producer), command.producer);
+        assert_eq!(Some(transaction_id), command.transaction_id);
         assert_eq!(messages, command_messages);
     }
 
     #[test]
-    fn should_be_deserialized_from_bytes() {
+    fn command_without_options_should_be_serialized_without_options_and_deserialized_from_bytes() {
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            messages: vec![Message::from_str("hello").unwrap()],
+            ..SendMessages::default()
+        };
+
+        let bytes = command.as_bytes();
+        let expected_bytes = [
+            command.stream_id.as_bytes(),
+            command.topic_id.as_bytes(),
+            command.partitioning.as_bytes(),
+            command.messages[0].as_bytes(),
+        ]
+        .concat();
+
+        assert!(!command.has_options());
+        assert_eq!(command.get_code(), SEND_MESSAGES_CODE);
+        assert_eq!(bytes, expected_bytes);
+        assert_eq!(SendMessages::from_bytes(&bytes).unwrap(), command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_options() {
         let stream_id = Identifier::numeric(1).unwrap();
         let topic_id = Identifier::numeric(2).unwrap();
         let key = Partitioning::partition_id(4);
+        let acknowledgement = Acknowledgement::Persisted;
 
         let message_1 = Message::from_str("hello 1").unwrap();
         let message_2 = Message::from_str("2|hello 2").unwrap();
@@ -626,14 +1044,17 @@ mod tests {
         let key_bytes = key.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
-        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
+        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + 10;
         let mut bytes = Vec::with_capacity(current_position);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.extend(key_bytes);
+        bytes.put_u8(acknowledgement.as_code());
+        bytes.put_u8(0);
+        bytes.put_u64_le(0);
         bytes.extend(messages);
 
-        let command = SendMessages::from_bytes(&bytes);
+        let command = SendMessages::from_bytes_with_options(&bytes);
         assert!(command.is_ok());
 
         let messages_payloads = &bytes[current_position..];
@@ -649,6 +1070,9 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.acknowledgement, acknowledgement);
+        assert_eq!(command.producer, None);
+        assert_eq!(command.transaction_id, None);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -657,6 +1081,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_be_deserialized_from_bytes_with_producer_sequence() {
+        let producer = ProducerSequence {
+            producer_id: 1,
+            epoch: 2,
+            sequence: 3,
+        };
+        let command = SendMessages {
+            partitioning: Partitioning::partition_id(1),
+            producer: Some(producer),
+            ..SendMessages::default()
+        };
+
+        let deserialized_command =
+            SendMessages::from_bytes_with_options(&command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_transaction_id() {
+        let command = SendMessages {
+            transaction_id: Some(1),
+            ..SendMessages::default()
+        };
+
+        let deserialized_command =
+            SendMessages::from_bytes_with_options(&command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_delayed_message() {
+        let command = SendMessages {
+            messages: vec![Message::from_str("hello").unwrap().with_deliver_at(1)],
+            ..SendMessages::default()
+        };
+
+        let deserialized_command = SendMessages::from_bytes(&command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+        assert_eq!(deserialized_command.messages[0].deliver_at, Some(1));
+    }
+
+    #[test]
+    fn message_should_have_deliver_at_only_if_delayed() {
+        let message = Message::from_str("hello").unwrap();
+        let bytes = message.as_bytes();
+        assert_eq!(bytes.len(), 16 + 4 + 4 + 5);
+        assert_eq!(bytes.len() as u32, message.get_size_bytes());
+
+        let message = message.with_deliver_at(1);
+        let bytes = message.as_bytes();
+        assert_eq!(bytes.len(), 16 + 4 + 8 + 4 + 5);
+        assert_eq!(bytes.len() as u32, message.get_size_bytes());
+        assert_eq!(Message::from_bytes(&bytes).unwrap(), message);
+    }
+
+    #[test]
+    fn message_with_zero_ttl_should_be_invalid() {
+        let ttl = IggyDuration::from_str("1s").unwrap();
+        let command = SendMessages {
+            messages: vec![Message::from_str("hello").unwrap().with_ttl(ttl)],
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_ok());
+
+        let ttl = IggyDuration::from_str("0s").unwrap();
+        let command = SendMessages {
+            messages: vec![Message::from_str("hello").unwrap().with_ttl(ttl)],
+            ..SendMessages::default()
+        };
+        assert!(matches!(command.validate(), Err(Error::InvalidMessageTtl)));
+    }
+
+    #[test]
+    fn delayed_message_within_transaction_should_be_invalid() {
+        let command = SendMessages {
+            transaction_id: Some(1),
+            messages: vec![Message::from_str("hello").unwrap().with_deliver_at(1)],
+            ..SendMessages::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn producer_sequence_without_partition_id_should_be_invalid() {
+        let command = SendMessages {
+            partitioning: Partitioning::messages_key_str("key").unwrap(),
+            producer: Some(ProducerSequence {
+                producer_id: 1,
+                epoch: 1,
+                sequence: 1,
+            }),
+            ..SendMessages::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn producer_sequence_equal_to_zero_should_be_invalid() {
+        let producer = ProducerSequence {
+            producer_id: 1,
+            epoch: 1,
+            sequence: 0,
+        };
+
+        assert!(producer.validate().is_err());
+    }
+
     // For now, we only support a single payload.
     #[test]
     fn should_be_read_from_string() {
@@ -675,6 +1212,7 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.acknowledgement, Acknowledgement::Memory);
         assert_eq!(message.id, message_id);
         assert_eq!(message.length, payload.len() as u32);
         assert_eq!(message.payload, payload.as_bytes());
@@ -718,6 +1256,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn key_of_type_sticky_batch_and_least_loaded_should_have_empty_value() {
+        let key = Partitioning::sticky_batch();
+        assert_eq!(key.kind, PartitioningKind::StickyBatch);
+        assert_eq!(key.length, 0);
+        assert_eq!(key.value, EMPTY_KEY_VALUE);
+        assert_eq!(
+            PartitioningKind::from_code(4).unwrap(),
+            PartitioningKind::StickyBatch
+        );
+
+        let key = Partitioning::least_loaded();
+        assert_eq!(key.kind, PartitioningKind::LeastLoaded);
+        assert_eq!(key.length, 0);
+        assert_eq!(key.value, EMPTY_KEY_VALUE);
+        assert_eq!(
+            PartitioningKind::from_code(5).unwrap(),
+            PartitioningKind::LeastLoaded
+        );
+    }
+
+    #[test]
+    fn key_of_type_key_range_should_have_value_of_dynamic_length() {
+        let messages_key = "customer-42";
+        let key = Partitioning::key_range_str(messages_key).unwrap();
+        assert_eq!(key.kind, PartitioningKind::KeyRange);
+        assert_eq!(key.length, messages_key.len() as u8);
+        assert_eq!(key.value, messages_key.as_bytes());
+        assert_eq!(
+            PartitioningKind::from_code(6).unwrap(),
+            PartitioningKind::KeyRange
+        );
+    }
+
     #[test]
     fn key_of_type_messages_key_that_has_length_0_should_fail() {
         let messages_key = "";
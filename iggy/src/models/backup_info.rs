@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// `BackupInfo` represents the details of a point-in-time backup of the server.
+/// It consists of the following fields:
+/// - `name`: the unique name of the backup, which is also the name of its directory.
+/// - `path`: the path to the backup directory on the server.
+/// - `created_at`: the timestamp when the backup was created.
+/// - `size_bytes`: the total size of the backed up files in bytes.
+/// - `files_count`: the total number of the backed up segment files.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BackupInfo {
+    /// The unique name of the backup, which is also the name of its directory.
+    pub name: String,
+    /// The path to the backup directory on the server.
+    pub path: String,
+    /// The timestamp when the backup was created.
+    pub created_at: u64,
+    /// The total size of the backed up files in bytes.
+    pub size_bytes: u64,
+    /// The total number of the backed up segment files.
+    pub files_count: u32,
+}
@@ -11,8 +11,13 @@ use std::str::FromStr;
 
 const EMPTY_BYTES: Vec<u8> = vec![];
 
+/// The reserved header key holding the time to live of the message (as `uint64` value in microseconds),
+/// counting from the message timestamp, after which the message is no longer delivered to the consumers.
+pub const MESSAGE_TTL_HEADER_KEY: &str = "iggy-message-ttl";
+
 /// Represents a header key with a unique name. The name is case-insensitive and wraps a string.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderKey(String);
 
 impl HeaderKey {
@@ -24,6 +29,11 @@ impl HeaderKey {
         Ok(Self(key.to_lowercase().to_string()))
     }
 
+    /// Returns the reserved header key holding the time to live of the message.
+    pub fn message_ttl() -> Self {
+        Self(MESSAGE_TTL_HEADER_KEY.to_string())
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -55,16 +65,19 @@ impl TryFrom<&str> for HeaderKey {
 /// - `value`: the value of the header.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderValue {
     /// The kind of the header value.
     pub kind: HeaderKind,
     /// The binary value of the header payload.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Vec<u8>,
 }
 
 /// Represents the kind of a header value.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum HeaderKind {
     Raw,
@@ -603,6 +616,15 @@ pub fn get_headers_size_bytes(headers: &Option<HashMap<HeaderKey, HeaderValue>>)
     size
 }
 
+/// Returns the time to live of the message in microseconds, if it's set via the reserved header.
+pub fn get_message_ttl(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<u64> {
+    headers
+        .as_ref()?
+        .get(&HeaderKey::message_ttl())?
+        .as_uint64()
+        .ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,8 +6,10 @@ use serde::{Deserialize, Serialize};
 /// - `user_id`: the unique identifier (numeric) of the user.
 /// - `tokens`: the optional tokens, used only by HTTP transport.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct IdentityInfo {
     /// The unique identifier (numeric) of the user.
+    #[cfg_attr(feature = "openapi", schema(value_type = u32))]
     pub user_id: UserId,
     /// The optional tokens, used only by HTTP transport.
     pub tokens: Option<IdentityTokens>,
@@ -18,6 +20,7 @@ pub struct IdentityInfo {
 /// - `access_token`: the access token used for the authentication.
 /// - `refresh_token`: the refresh token used to refresh the access token.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct IdentityTokens {
     /// The access token used for the authentication.
     pub access_token: TokenInfo,
@@ -30,6 +33,7 @@ pub struct IdentityTokens {
 /// - `token`: the value of token.
 /// - `expiry`: the expiry of token.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TokenInfo {
     /// The value of token.
     pub token: String,
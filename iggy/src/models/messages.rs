@@ -20,6 +20,7 @@ use std::sync::Arc;
 /// - `current_offset`: the current offset of the partition.
 /// - `messages`: the collection of messages.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PolledMessages {
     /// The identifier of the partition.
     pub partition_id: u32,
@@ -41,6 +42,7 @@ pub struct PolledMessages {
 /// - `payload`: the binary payload of the message.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Message {
     /// The offset of the message.
     pub offset: u64,
@@ -59,11 +61,13 @@ pub struct Message {
     pub length: u32,
     /// The binary payload of the message.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub payload: Bytes,
 }
 
 /// The state of the message, currently only the `Available` state is used.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum MessageState {
     /// The message is available.
@@ -181,6 +185,12 @@ impl Message {
         }
     }
 
+    /// Returns `true` if the time to live set via the reserved header has elapsed at the provided timestamp (in microseconds).
+    pub fn is_expired(&self, now: u64) -> bool {
+        header::get_message_ttl(&self.headers)
+            .is_some_and(|ttl| self.timestamp.saturating_add(ttl) <= now)
+    }
+
     /// Returns the size of the message in bytes.
     pub fn get_size_bytes(&self) -> u32 {
         // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers
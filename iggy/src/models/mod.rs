@@ -1,3 +1,4 @@
+pub mod backup_info;
 pub mod client_info;
 pub mod consumer_group;
 pub mod consumer_offset_info;
@@ -7,8 +8,10 @@ pub mod messages;
 pub mod partition;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod producer_info;
 pub mod stats;
 pub mod stream;
 pub mod topic;
+pub mod transaction_info;
 pub mod user_info;
 pub mod user_status;
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// - `size_bytes`: the size of the partition in bytes.
 /// - `messages_count`: the number of messages in the partition.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partition {
     /// Unique identifier of the partition.
     pub id: u32,
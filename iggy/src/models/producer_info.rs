@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// `ProducerInfo` represents the identity of the registered idempotent producer.
+/// It consists of the following fields:
+/// - `producer_id`: the unique identifier of the producer.
+/// - `epoch`: the epoch of the producer, which is incremented on each registration of the same producer ID.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ProducerInfo {
+    /// The unique identifier of the producer.
+    pub producer_id: u64,
+    /// The epoch of the producer, which is incremented on each registration of the same producer ID.
+    pub epoch: u32,
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// `Stats` represents the statistics and details of the server and running process.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Stats {
     /// The unique identifier of the process.
     pub process_id: u32,
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Stream {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
@@ -35,6 +36,7 @@ pub struct Stream {
 /// - `topics_count`: the total number of topics in the stream.
 /// - `topics`: the list of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StreamDetails {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
@@ -1,5 +1,6 @@
 use crate::models::partition::Partition;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// `Topic` represents the medium level of logical separation of data as it's a part of the stream.
 /// It consists of the following fields:
@@ -11,6 +12,7 @@ use serde::{Deserialize, Serialize};
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Topic {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
@@ -37,8 +39,10 @@ pub struct Topic {
 /// - `message_expiry`: the optional expiry of the messages in the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `config`: the per-topic overrides of the server-side settings.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopicDetails {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
@@ -54,6 +58,9 @@ pub struct TopicDetails {
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The per-topic overrides of the server-side settings.
+    #[serde(default)]
+    pub config: HashMap<String, String>,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
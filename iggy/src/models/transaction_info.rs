@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// `TransactionInfo` represents the transaction opened on the server.
+/// It consists of the following fields:
+/// - `transaction_id`: the unique identifier of the transaction, which should be attached to the sent messages and stored consumer offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TransactionInfo {
+    /// The unique identifier of the transaction.
+    pub transaction_id: u64,
+}
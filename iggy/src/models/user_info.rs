@@ -12,8 +12,10 @@ pub type UserId = u32;
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfo {
     /// The unique identifier (numeric) of the user.
+    #[cfg_attr(feature = "openapi", schema(value_type = u32))]
     pub id: UserId,
     /// The timestamp when the user was created.
     pub created_at: u64,
@@ -31,8 +33,10 @@ pub struct UserInfo {
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
+    #[cfg_attr(feature = "openapi", schema(value_type = u32))]
     pub id: UserId,
     /// The timestamp when the user was created.
     pub created_at: u64,
@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 /// `UserStatus` represents the status of the user.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum UserStatus {
     /// The user is active.
@@ -15,6 +15,7 @@ use std::str::FromStr;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitions_count` - number of partitions in the topic to create, max value is 1000.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreatePartitions {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
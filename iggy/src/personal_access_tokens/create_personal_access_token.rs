@@ -14,6 +14,7 @@ use std::str::{from_utf8, FromStr};
 /// - `name` - unique name of the token, must be between 3 and 3 characters long.
 /// - `expiry` - expiry in seconds (optional), if provided, must be between 1 and 4294967295. Otherwise, the token will never expire.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 3 characters long.
     pub name: String,
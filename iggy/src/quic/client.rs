@@ -4,7 +4,7 @@ use crate::error::Error;
 use crate::quic::config::QuicClientConfig;
 use async_trait::async_trait;
 use bytes::BufMut;
-use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
+use quinn::{ClientConfig, Connection, ConnectionError, Endpoint, IdleTimeout, RecvStream, VarInt};
 use rustls::client::{ServerCertVerified, ServerCertVerifier};
 use rustls::{Certificate, ServerName};
 use std::net::SocketAddr;
@@ -12,7 +12,7 @@ use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use tokio::sync::Mutex;
 use tokio::time::sleep;
-use tracing::{error, info, trace};
+use tracing::{error, info, trace, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
@@ -118,8 +118,8 @@ impl BinaryClient for QuicClient {
             return Err(Error::NotConnected);
         }
 
-        let connection = self.connection.lock().await;
-        if let Some(connection) = connection.as_ref() {
+        let mut connection = self.connection.lock().await;
+        if let Some(active_connection) = connection.as_ref() {
             let payload_length = payload.len() + 4;
             let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
             #[allow(clippy::cast_possible_truncation)]
@@ -127,10 +127,13 @@ impl BinaryClient for QuicClient {
             buffer.put_u32_le(command);
             buffer.extend(payload);
 
-            let (mut send, mut recv) = connection.open_bi().await?;
-            send.write_all(&buffer).await?;
-            send.finish().await?;
-            return self.handle_response(&mut recv).await;
+            let response = self.send_request(active_connection, &buffer).await;
+            if matches!(response, Err(Error::ServerShuttingDown)) {
+                warn!("Server is shutting down, {NAME} client has been disconnected.");
+                connection.take();
+                self.set_state(ClientState::Disconnected).await;
+            }
+            return response;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -178,6 +181,26 @@ impl QuicClient {
         })
     }
 
+    async fn send_request(&self, connection: &Connection, buffer: &[u8]) -> Result<Vec<u8>, Error> {
+        let response = async {
+            let (mut send, mut recv) = connection.open_bi().await?;
+            send.write_all(buffer).await?;
+            send.finish().await?;
+            self.handle_response(&mut recv).await
+        }
+        .await;
+
+        // The server closes the connection with the dedicated error code when it's shutting down.
+        match (response, connection.close_reason()) {
+            (Err(_), Some(ConnectionError::ApplicationClosed(close)))
+                if close.error_code == VarInt::from_u32(Error::ServerShuttingDown.as_code()) =>
+            {
+                Err(Error::ServerShuttingDown)
+            }
+            (response, _) => response,
+        }
+    }
+
     async fn handle_response(&self, recv: &mut RecvStream) -> Result<Vec<u8>, Error> {
         let buffer = recv
             .read_to_end(self.config.response_buffer_size as usize)
@@ -187,6 +210,10 @@ impl QuicClient {
         }
 
         let status = u32::from_le_bytes(buffer[..4].try_into().unwrap());
+        if status == Error::ServerShuttingDown.as_code() {
+            return Err(Error::ServerShuttingDown);
+        }
+
         if status != 0 {
             error!(
                 "Received an invalid response with status: {} ({}).",
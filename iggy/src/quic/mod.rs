@@ -8,4 +8,5 @@ pub mod personal_access_tokens;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
@@ -8,6 +8,7 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::restore_stream::RestoreStream;
 use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
 
@@ -36,4 +37,8 @@ impl StreamClient for QuicClient {
     async fn purge_stream(&self, command: &PurgeStream) -> Result<(), Error> {
         binary::streams::purge_stream(self, command).await
     }
+
+    async fn restore_stream(&self, command: &RestoreStream) -> Result<(), Error> {
+        binary::streams::restore_stream(self, command).await
+    }
 }
@@ -1,14 +1,18 @@
 use crate::binary;
 use crate::client::SystemClient;
 use crate::error::Error;
+use crate::models::backup_info::BackupInfo;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
 use crate::quic::client::QuicClient;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
+use crate::system::set_log_level::SetLogLevel;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -17,6 +21,18 @@ impl SystemClient for QuicClient {
         binary::system::get_stats(self, command).await
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<BackupInfo, Error> {
+        binary::system::create_backup(self, command).await
+    }
+
+    async fn set_log_level(&self, command: &SetLogLevel) -> Result<(), Error> {
+        binary::system::set_log_level(self, command).await
+    }
+
+    async fn reload_config(&self, command: &ReloadConfig) -> Result<(), Error> {
+        binary::system::reload_config(self, command).await
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error> {
         binary::system::get_me(self, command).await
     }
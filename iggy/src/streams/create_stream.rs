@@ -14,6 +14,7 @@ use std::str::{from_utf8, FromStr};
 /// - `stream_id` - unique stream ID (numeric)
 /// - `name` - unique stream name (string), max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateStream {
     /// Unique stream ID (numeric).
     pub stream_id: u32,
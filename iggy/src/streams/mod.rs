@@ -3,6 +3,7 @@ pub mod delete_stream;
 pub mod get_stream;
 pub mod get_streams;
 pub mod purge_stream;
+pub mod restore_stream;
 pub mod update_stream;
 
 const MAX_NAME_LENGTH: usize = 255;
@@ -15,6 +15,7 @@ use std::str::{from_utf8, FromStr};
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `name` - unique stream name (string), max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateStream {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
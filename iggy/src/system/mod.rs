@@ -1,5 +1,8 @@
+pub mod create_backup;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
 pub mod get_stats;
 pub mod ping;
+pub mod reload_config;
+pub mod set_log_level;
@@ -0,0 +1,197 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SetLogLevel` command is used to change the level of the server logs (both stdout and file) at runtime, without restarting the server.
+/// It has additional payload:
+/// - `level` - the new level of the logs.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetLogLevel {
+    /// The new level of the logs.
+    pub level: LogLevel,
+}
+
+/// `LogLevel` specifies the most verbose level of the events logged by the server.
+/// It has the following kinds:
+/// - `Off` - no events are logged.
+/// - `Error` - only the errors are logged.
+/// - `Warn` - the warnings and errors are logged.
+/// - `Info` - the informational events, warnings and errors are logged.
+/// - `Debug` - the debug events and all the above are logged.
+/// - `Trace` - all the events are logged.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum LogLevel {
+    /// No events are logged.
+    Off,
+    /// Only the errors are logged.
+    Error,
+    /// The warnings and errors are logged.
+    Warn,
+    #[default]
+    /// The informational events, warnings and errors are logged.
+    Info,
+    /// The debug events, informational events, warnings and errors are logged.
+    Debug,
+    /// All the events are logged.
+    Trace,
+}
+
+impl LogLevel {
+    /// Returns the code of the log level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            LogLevel::Off => 0,
+            LogLevel::Error => 1,
+            LogLevel::Warn => 2,
+            LogLevel::Info => 3,
+            LogLevel::Debug => 4,
+            LogLevel::Trace => 5,
+        }
+    }
+
+    /// Returns the log level from the code.
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(LogLevel::Off),
+            1 => Ok(LogLevel::Error),
+            2 => Ok(LogLevel::Warn),
+            3 => Ok(LogLevel::Info),
+            4 => Ok(LogLevel::Debug),
+            5 => Ok(LogLevel::Trace),
+            _ => Err(Error::InvalidLogLevel),
+        }
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "off" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(Error::InvalidLogLevel),
+        }
+    }
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Off => write!(f, "off"),
+            LogLevel::Error => write!(f, "error"),
+            LogLevel::Warn => write!(f, "warn"),
+            LogLevel::Info => write!(f, "info"),
+            LogLevel::Debug => write!(f, "debug"),
+            LogLevel::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+impl CommandPayload for SetLogLevel {}
+
+impl Validatable<Error> for SetLogLevel {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for SetLogLevel {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let level = LogLevel::from_str(parts[0])?;
+        let command = SetLogLevel { level };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for SetLogLevel {
+    fn as_bytes(&self) -> Vec<u8> {
+        vec![self.level.as_code()]
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<SetLogLevel, Error> {
+        if bytes.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let level = LogLevel::from_code(bytes[0])?;
+        let command = SetLogLevel { level };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for SetLogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetLogLevel {
+            level: LogLevel::Debug,
+        };
+
+        let bytes = command.as_bytes();
+
+        assert_eq!(bytes, vec![LogLevel::Debug.as_code()]);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let bytes = vec![LogLevel::Trace.as_code()];
+
+        let command = SetLogLevel::from_bytes(&bytes).unwrap();
+
+        assert_eq!(command.level, LogLevel::Trace);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_level_code() {
+        let bytes = vec![10];
+
+        let command = SetLogLevel::from_bytes(&bytes);
+
+        assert!(matches!(command, Err(Error::InvalidLogLevel)));
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let input = "warn";
+
+        let command = SetLogLevel::from_str(input).unwrap();
+
+        assert_eq!(command.level, LogLevel::Warn);
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_string() {
+        let input = "verbose";
+
+        let command = SetLogLevel::from_str(input);
+
+        assert!(command.is_err());
+    }
+}
@@ -15,7 +15,7 @@ use tokio::time::sleep;
 use tokio_native_tls::native_tls::TlsConnector;
 use tokio_native_tls::TlsStream;
 use tracing::log::trace;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
@@ -205,7 +205,7 @@ impl BinaryClient for TcpClient {
         }
 
         let mut stream = self.stream.lock().await;
-        if let Some(stream) = stream.as_mut() {
+        if let Some(active_stream) = stream.as_mut() {
             let payload_length = payload.len() + 4;
             let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
             #[allow(clippy::cast_possible_truncation)]
@@ -214,11 +214,11 @@ impl BinaryClient for TcpClient {
             buffer.extend(payload);
 
             trace!("Sending a TCP request...");
-            stream.write(&buffer).await?;
+            active_stream.write(&buffer).await?;
             trace!("Sent a TCP request, waiting for a response...");
 
             let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await?;
+            let read_bytes = active_stream.read(&mut response_buffer).await?;
             if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
                 error!("Received an invalid or empty response.");
                 return Err(Error::EmptyResponse);
@@ -226,7 +226,16 @@ impl BinaryClient for TcpClient {
 
             let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
             let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
-            return self.handle_response(status, length, stream.as_mut()).await;
+            let response = self
+                .handle_response(status, length, active_stream.as_mut())
+                .await;
+            if matches!(response, Err(Error::ServerShuttingDown)) {
+                // The server closes the connection right after sending the notice, so the client can fail over.
+                warn!("Server is shutting down, {NAME} client has been disconnected.");
+                stream.take();
+                self.set_state(ClientState::Disconnected).await;
+            }
+            return response;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -271,6 +280,10 @@ impl TcpClient {
         length: u32,
         stream: &mut dyn ConnectionStream,
     ) -> Result<Vec<u8>, Error> {
+        if status == Error::ServerShuttingDown.as_code() {
+            return Err(Error::ServerShuttingDown);
+        }
+
         if status != 0 {
             error!(
                 "Received an invalid response with status: {} ({}).",
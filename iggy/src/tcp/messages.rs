@@ -2,8 +2,10 @@ use crate::binary;
 use crate::client::MessageClient;
 use crate::error::Error;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::register_producer::RegisterProducer;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 use crate::tcp::client::TcpClient;
 use async_trait::async_trait;
 
@@ -16,4 +18,8 @@ impl MessageClient for TcpClient {
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error> {
         binary::messages::send_messages(self, command).await
     }
+
+    async fn register_producer(&self, command: &RegisterProducer) -> Result<ProducerInfo, Error> {
+        binary::messages::register_producer(self, command).await
+    }
 }
@@ -8,6 +8,8 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::repartition_topic::RepartitionTopic;
+use crate::topics::restore_topic::RestoreTopic;
 use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 
@@ -36,4 +38,12 @@ impl TopicClient for TcpClient {
     async fn purge_topic(&self, _command: &PurgeTopic) -> Result<(), Error> {
         binary::topics::purge_topic(self, _command).await
     }
+
+    async fn restore_topic(&self, command: &RestoreTopic) -> Result<(), Error> {
+        binary::topics::restore_topic(self, command).await
+    }
+
+    async fn repartition_topic(&self, command: &RepartitionTopic) -> Result<(), Error> {
+        binary::topics::repartition_topic(self, command).await
+    }
 }
@@ -0,0 +1,241 @@
+use crate::error::Error;
+use crate::topics::partitioning_strategy::PartitioningStrategy;
+use crate::utils::duration::IggyDuration;
+use bytes::BufMut;
+use std::collections::HashMap;
+use std::str::from_utf8;
+
+/// The maximum size of the segment, e.g. `512 MB`.
+pub const SEGMENT_SIZE: &str = "segment.size";
+/// Whether to enforce fsync when saving the messages on disk, e.g. `true`.
+pub const PARTITION_ENFORCE_FSYNC: &str = "partition.enforce_fsync";
+/// Whether to validate the checksums of the messages when loading the partitions, e.g. `true`.
+pub const PARTITION_VALIDATE_CHECKSUM: &str = "partition.validate_checksum";
+/// The number of the buffered messages after which they are saved on disk, e.g. `1000`.
+pub const PARTITION_MESSAGES_REQUIRED_TO_SAVE: &str = "partition.messages_required_to_save";
+/// Whether to enable the message deduplication, e.g. `true`.
+pub const MESSAGE_DEDUPLICATION_ENABLED: &str = "message_deduplication.enabled";
+/// The maximum number of the message IDs tracked by the deduplicator, e.g. `10000`.
+pub const MESSAGE_DEDUPLICATION_MAX_ENTRIES: &str = "message_deduplication.max_entries";
+/// The expiry of the message IDs tracked by the deduplicator, e.g. `1m`.
+pub const MESSAGE_DEDUPLICATION_EXPIRY: &str = "message_deduplication.expiry";
+/// Whether to cache the messages in memory, e.g. `false`.
+pub const CACHE_ENABLED: &str = "cache.enabled";
+/// The strategy used to pick the partition for the balanced partitioning, e.g. `sticky_batch`.
+pub const TOPIC_PARTITIONING: &str = "topic.partitioning";
+/// The time for which the sticky batch strategy keeps appending to the same partition, e.g. `100ms`.
+pub const TOPIC_STICKY_BATCH_INTERVAL: &str = "topic.sticky_batch_interval";
+/// The ascending boundaries of the key ranges mapped to the consecutive partitions, separated by `;`, e.g. `g;n;t` for 4 partitions.
+/// There must be one boundary less than the partitions of the topic, so each key range is mapped to its own partition.
+pub const TOPIC_KEY_RANGES: &str = "topic.key_ranges";
+
+/// The keys of the server-side settings which can be overridden per topic.
+pub const CONFIG_KEYS: [&str; 11] = [
+    SEGMENT_SIZE,
+    PARTITION_ENFORCE_FSYNC,
+    PARTITION_VALIDATE_CHECKSUM,
+    PARTITION_MESSAGES_REQUIRED_TO_SAVE,
+    MESSAGE_DEDUPLICATION_ENABLED,
+    MESSAGE_DEDUPLICATION_MAX_ENTRIES,
+    MESSAGE_DEDUPLICATION_EXPIRY,
+    CACHE_ENABLED,
+    TOPIC_PARTITIONING,
+    TOPIC_STICKY_BATCH_INTERVAL,
+    TOPIC_KEY_RANGES,
+];
+
+const MAX_VALUE_LENGTH: usize = 255;
+const ENTRIES_SEPARATOR: char = ',';
+const KEY_VALUE_SEPARATOR: char = '=';
+const KEY_RANGES_SEPARATOR: char = ';';
+
+/// Validates the per-topic config overrides, the key must be one of `CONFIG_KEYS` and the value must match its type.
+/// The segment size is only checked for being non-empty, as it's parsed by the server.
+pub fn validate(config: &HashMap<String, String>) -> Result<(), Error> {
+    for (key, value) in config {
+        if value.is_empty()
+            || value.len() > MAX_VALUE_LENGTH
+            || value.contains(ENTRIES_SEPARATOR)
+            || value.contains(KEY_VALUE_SEPARATOR)
+        {
+            return Err(Error::InvalidTopicConfig(key.to_string()));
+        }
+
+        let is_valid = match key.as_str() {
+            SEGMENT_SIZE => true,
+            PARTITION_ENFORCE_FSYNC
+            | PARTITION_VALIDATE_CHECKSUM
+            | MESSAGE_DEDUPLICATION_ENABLED
+            | CACHE_ENABLED => value.parse::<bool>().is_ok(),
+            PARTITION_MESSAGES_REQUIRED_TO_SAVE => value.parse::<u32>().is_ok(),
+            MESSAGE_DEDUPLICATION_MAX_ENTRIES => value.parse::<u64>().is_ok(),
+            MESSAGE_DEDUPLICATION_EXPIRY | TOPIC_STICKY_BATCH_INTERVAL => {
+                value.parse::<IggyDuration>().is_ok()
+            }
+            TOPIC_PARTITIONING => value.parse::<PartitioningStrategy>().is_ok(),
+            TOPIC_KEY_RANGES => parse_key_ranges(value).is_ok(),
+            _ => false,
+        };
+        if !is_valid {
+            return Err(Error::InvalidTopicConfig(key.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the per-topic config overrides from the string in the format `key=value,key=value`.
+pub fn from_str(input: &str) -> Result<HashMap<String, String>, Error> {
+    let mut config = HashMap::new();
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(config);
+    }
+
+    for entry in input.split(ENTRIES_SEPARATOR) {
+        let Some((key, value)) = entry.split_once(KEY_VALUE_SEPARATOR) else {
+            return Err(Error::InvalidTopicConfig(entry.trim().to_string()));
+        };
+        config.insert(key.trim().to_string(), value.trim().to_string());
+    }
+
+    validate(&config)?;
+    Ok(config)
+}
+
+/// Parses the key ranges in the format `boundary;boundary`, where the boundaries must be non-empty and strictly ascending.
+/// The keys lower than the first boundary are mapped to the first partition, the keys from the first boundary
+/// up to the second one to the second partition and so on, comparing the keys and the boundaries byte by byte.
+pub fn parse_key_ranges(input: &str) -> Result<Vec<Vec<u8>>, Error> {
+    let mut boundaries: Vec<Vec<u8>> = Vec::new();
+    for boundary in input.split(KEY_RANGES_SEPARATOR) {
+        let boundary = boundary.trim().as_bytes().to_vec();
+        if boundary.is_empty()
+            || boundaries
+                .last()
+                .is_some_and(|previous| *previous >= boundary)
+        {
+            return Err(Error::InvalidTopicConfig(TOPIC_KEY_RANGES.to_string()));
+        }
+        boundaries.push(boundary);
+    }
+
+    Ok(boundaries)
+}
+
+/// Formats the per-topic config overrides as the string in the format `key=value,key=value`, sorted by the key.
+pub fn to_string(config: &HashMap<String, String>) -> String {
+    sorted_entries(config)
+        .iter()
+        .map(|(key, value)| format!("{key}{KEY_VALUE_SEPARATOR}{value}"))
+        .collect::<Vec<String>>()
+        .join(&ENTRIES_SEPARATOR.to_string())
+}
+
+/// Appends the per-topic config overrides to the bytes as the entries count (u8),
+/// followed by the length-prefixed (u8) key and value of each entry.
+pub fn extend_bytes(config: &HashMap<String, String>, bytes: &mut Vec<u8>) {
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u8(config.len() as u8);
+    for (key, value) in sorted_entries(config) {
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(key.len() as u8);
+        bytes.extend(key.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(value.len() as u8);
+        bytes.extend(value.as_bytes());
+    }
+}
+
+/// Reads the per-topic config overrides appended by `extend_bytes`, returning them along with the number of read bytes.
+pub fn from_bytes(bytes: &[u8]) -> Result<(HashMap<String, String>, usize), Error> {
+    let mut config = HashMap::new();
+    if bytes.is_empty() {
+        return Err(Error::InvalidCommand);
+    }
+
+    let entries_count = bytes[0];
+    let mut position = 1;
+    for _ in 0..entries_count {
+        let (key, read_bytes) = read_text(bytes, position)?;
+        position += read_bytes;
+        let (value, read_bytes) = read_text(bytes, position)?;
+        position += read_bytes;
+        config.insert(key, value);
+    }
+
+    Ok((config, position))
+}
+
+fn read_text(bytes: &[u8], position: usize) -> Result<(String, usize), Error> {
+    if position >= bytes.len() {
+        return Err(Error::InvalidCommand);
+    }
+
+    let length = bytes[position] as usize;
+    if position + 1 + length > bytes.len() {
+        return Err(Error::InvalidCommand);
+    }
+
+    let text = from_utf8(&bytes[position + 1..position + 1 + length])?.to_string();
+    Ok((text, 1 + length))
+}
+
+fn sorted_entries(config: &HashMap<String, String>) -> Vec<(&String, &String)> {
+    let mut entries = config.iter().collect::<Vec<_>>();
+    entries.sort_by(|x, y| x.0.cmp(y.0));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let config = HashMap::from([
+            (SEGMENT_SIZE.to_string(), "512 MB".to_string()),
+            (PARTITION_ENFORCE_FSYNC.to_string(), "true".to_string()),
+        ]);
+        let mut bytes = Vec::new();
+        extend_bytes(&config, &mut bytes);
+
+        let (deserialized_config, read_bytes) = from_bytes(&bytes).unwrap();
+        assert_eq!(read_bytes, bytes.len());
+        assert_eq!(deserialized_config, config);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let config = from_str("segment.size=512 MB, cache.enabled=false").unwrap();
+        assert_eq!(config.len(), 2);
+        assert_eq!(config.get(SEGMENT_SIZE).unwrap(), "512 MB");
+        assert_eq!(config.get(CACHE_ENABLED).unwrap(), "false");
+        assert_eq!(
+            to_string(&config),
+            "cache.enabled=false,segment.size=512 MB"
+        );
+    }
+
+    #[test]
+    fn should_not_be_read_from_string_given_unknown_key_or_invalid_value() {
+        assert!(from_str("segment.cache_indexes=false").is_err());
+        assert!(from_str("partition.messages_required_to_save=many").is_err());
+        assert!(from_str("cache.enabled").is_err());
+        assert!(from_str("topic.partitioning=random").is_err());
+        assert!(from_str("topic.key_ranges=n;g").is_err());
+    }
+
+    #[test]
+    fn should_parse_key_ranges() {
+        let config = from_str("topic.partitioning=sticky_batch, topic.key_ranges=g;n;t").unwrap();
+        assert_eq!(config.get(TOPIC_PARTITIONING).unwrap(), "sticky_batch");
+
+        let key_ranges = parse_key_ranges(config.get(TOPIC_KEY_RANGES).unwrap()).unwrap();
+        assert_eq!(
+            key_ranges,
+            vec![b"g".to_vec(), b"n".to_vec(), b"t".to_vec()]
+        );
+        assert!(parse_key_ranges("g;;t").is_err());
+    }
+}
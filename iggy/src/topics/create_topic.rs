@@ -2,11 +2,12 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
-use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
+use crate::topics::{config, MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::{from_utf8, FromStr};
 
@@ -17,7 +18,9 @@ use std::str::{from_utf8, FromStr};
 /// - `partitions_count` - number of partitions in the topic, max value is 1000.
 /// - `message_expiry` - message expiry in seconds (optional), if `None` then messages will not expire.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `config` - per-topic overrides of the server-side settings (optional), see `topics::config` for the supported keys.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -30,6 +33,9 @@ pub struct CreateTopic {
     pub message_expiry: Option<u32>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Per-topic overrides of the server-side settings (optional), see `topics::config` for the supported keys.
+    #[serde(default)]
+    pub config: HashMap<String, String>,
 }
 
 impl CommandPayload for CreateTopic {}
@@ -42,6 +48,7 @@ impl Default for CreateTopic {
             partitions_count: 1,
             message_expiry: None,
             name: "topic".to_string(),
+            config: HashMap::new(),
         }
     }
 }
@@ -64,6 +71,7 @@ impl Validatable<Error> for CreateTopic {
             return Err(Error::TooManyPartitions);
         }
 
+        config::validate(&self.config)?;
         Ok(())
     }
 }
@@ -72,7 +80,7 @@ impl FromStr for CreateTopic {
     type Err = Error;
     fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 5 {
+        if parts.len() != 5 && parts.len() != 6 {
             return Err(Error::InvalidCommand);
         }
 
@@ -88,12 +96,17 @@ impl FromStr for CreateTopic {
             Err(_) => None,
         };
         let name = parts[4].to_string();
+        let config = match parts.get(5) {
+            Some(config) => config::from_str(config)?,
+            None => HashMap::new(),
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
             message_expiry,
             name,
+            config,
         };
         command.validate()?;
         Ok(command)
@@ -114,6 +127,7 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        config::extend_bytes(&self.config, &mut bytes);
         bytes
     }
 
@@ -138,12 +152,19 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        position += 13 + name_length as usize;
+        // The config overrides are optional, so that the payloads without them are still supported.
+        let config = match bytes.len() > position {
+            true => config::from_bytes(&bytes[position..])?.0,
+            false => HashMap::new(),
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
             message_expiry,
             name,
+            config,
         };
         command.validate()?;
         Ok(command)
@@ -160,7 +181,11 @@ impl Display for CreateTopic {
             self.partitions_count,
             self.message_expiry.unwrap_or(0),
             self.name
-        )
+        )?;
+        if !self.config.is_empty() {
+            write!(f, "|{}", config::to_string(&self.config))?;
+        }
+        Ok(())
     }
 }
 
@@ -177,6 +202,7 @@ mod tests {
             partitions_count: 3,
             message_expiry: Some(10),
             name: "test".to_string(),
+            config: HashMap::from([(config::SEGMENT_SIZE.to_string(), "512 MB".to_string())]),
         };
 
         let bytes = command.as_bytes();
@@ -196,6 +222,8 @@ mod tests {
         let name = from_utf8(&bytes[position + 13..position + 13 + name_length as usize])
             .unwrap()
             .to_string();
+        let (config, _) =
+            config::from_bytes(&bytes[position + 13 + name_length as usize..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -204,6 +232,7 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(config, command.config);
     }
 
     #[test]
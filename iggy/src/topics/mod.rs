@@ -1,8 +1,12 @@
+pub mod config;
 pub mod create_topic;
 pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
+pub mod partitioning_strategy;
 pub mod purge_topic;
+pub mod repartition_topic;
+pub mod restore_topic;
 pub mod update_topic;
 
 const MAX_NAME_LENGTH: usize = 255;
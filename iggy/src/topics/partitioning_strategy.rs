@@ -0,0 +1,43 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `PartitioningStrategy` specifies how the server picks the partition for the messages sent with the `Balanced` partitioning.
+/// It has the following kinds:
+/// - `RoundRobin` - each batch of messages is appended to the next partition.
+/// - `StickyBatch` - the messages are appended to the same partition for the sticky batch interval, then to the next one.
+/// - `LeastLoaded` - the messages are appended to the partition with the least bytes of the messages which aren't saved on disk yet.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitioningStrategy {
+    /// Each batch of messages is appended to the next partition.
+    #[default]
+    RoundRobin,
+    /// The messages are appended to the same partition for the sticky batch interval, then to the next one.
+    StickyBatch,
+    /// The messages are appended to the partition with the least unsaved bytes.
+    LeastLoaded,
+}
+
+impl FromStr for PartitioningStrategy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "round_robin" => Ok(PartitioningStrategy::RoundRobin),
+            "sticky_batch" => Ok(PartitioningStrategy::StickyBatch),
+            "least_loaded" => Ok(PartitioningStrategy::LeastLoaded),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PartitioningStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitioningStrategy::RoundRobin => write!(f, "round_robin"),
+            PartitioningStrategy::StickyBatch => write!(f, "sticky_batch"),
+            PartitioningStrategy::LeastLoaded => write!(f, "least_loaded"),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::models::header::HeaderKey;
+use crate::topics::MAX_PARTITIONS_COUNT;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+/// `RepartitionTopic` command is used to change the number of partitions in a topic, while keeping its messages.
+/// The messages are re-appended to the new partitions by the hash of their key, in the same order,
+/// and the current partitions stay readable until the new ones replace them.
+/// The stored consumer and consumer group offsets are deleted, as they don't apply to the new partitions,
+/// so the consumers have to start polling the new partitions from the beginning (or any other offset).
+/// The topic with the `topic.key_ranges` config override can't be repartitioned, until it's reset.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partitions_count` - number of partitions in the topic after the repartitioning, max value is 1000.
+/// - `key_header` - the header of the messages holding their key, the repartitioning fails if any message doesn't have it.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RepartitionTopic {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Number of partitions in the topic after the repartitioning, max value is 1000.
+    pub partitions_count: u32,
+    /// The header of the messages holding their key, which is hashed the same way as the messages key partitioning.
+    pub key_header: HeaderKey,
+}
+
+impl CommandPayload for RepartitionTopic {}
+
+impl Default for RepartitionTopic {
+    fn default() -> Self {
+        RepartitionTopic {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partitions_count: 1,
+            key_header: HeaderKey::new("key").unwrap(),
+        }
+    }
+}
+
+impl Validatable<Error> for RepartitionTopic {
+    fn validate(&self) -> Result<(), Error> {
+        if !(1..=MAX_PARTITIONS_COUNT).contains(&self.partitions_count) {
+            return Err(Error::TooManyPartitions);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RepartitionTopic {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = parts[0].parse::<Identifier>()?;
+        let topic_id = parts[1].parse::<Identifier>()?;
+        let partitions_count = parts[2].parse::<u32>()?;
+        let key_header = parts[3].parse::<HeaderKey>()?;
+        let command = RepartitionTopic {
+            stream_id,
+            topic_id,
+            partitions_count,
+            key_header,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for RepartitionTopic {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let key_header = self.key_header.as_str();
+        let mut bytes =
+            Vec::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len() + key_header.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(self.partitions_count);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(key_header.len() as u8);
+        bytes.extend(key_header.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RepartitionTopic, Error> {
+        if bytes.len() < 11 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 5 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partitions_count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let key_header_length = bytes[position + 4] as usize;
+        position += 5;
+        if bytes.len() < position + key_header_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let key_header =
+            HeaderKey::new(from_utf8(&bytes[position..position + key_header_length])?)?;
+        let command = RepartitionTopic {
+            stream_id,
+            topic_id,
+            partitions_count,
+            key_header,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RepartitionTopic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partitions_count,
+            self.key_header.as_str()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RepartitionTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitions_count: 3,
+            key_header: HeaderKey::new("order-id").unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partitions_count =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let key_header_length = bytes[position + 4] as usize;
+        let key_header = from_utf8(&bytes[position + 5..position + 5 + key_header_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partitions_count, command.partitions_count);
+        assert_eq!(key_header, "order-id");
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partitions_count = 3u32;
+        let mut bytes = Vec::new();
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
+        bytes.put_u32_le(partitions_count);
+        bytes.put_u8(8);
+        bytes.extend("order-id".as_bytes());
+        let command = RepartitionTopic::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.key_header, HeaderKey::new("order-id").unwrap());
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partitions_count = 3u32;
+        let input = format!("{stream_id}|{topic_id}|{partitions_count}|order-id");
+        let command = RepartitionTopic::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.key_header, HeaderKey::new("order-id").unwrap());
+    }
+}
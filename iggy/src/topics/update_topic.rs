@@ -2,11 +2,12 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
-use crate::topics::MAX_NAME_LENGTH;
+use crate::topics::{config, MAX_NAME_LENGTH};
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::{from_utf8, FromStr};
 
@@ -16,7 +17,9 @@ use std::str::{from_utf8, FromStr};
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `message_expiry` - message expiry in seconds (optional), if `None` then messages will never expire.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `config` - per-topic overrides of the server-side settings (optional), if `None` then the current ones are kept.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -28,6 +31,9 @@ pub struct UpdateTopic {
     pub message_expiry: Option<u32>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Per-topic overrides of the server-side settings (optional), if `None` then the current ones are kept.
+    #[serde(default)]
+    pub config: Option<HashMap<String, String>>,
 }
 
 impl CommandPayload for UpdateTopic {}
@@ -39,6 +45,7 @@ impl Default for UpdateTopic {
             topic_id: Identifier::default(),
             message_expiry: None,
             name: "topic".to_string(),
+            config: None,
         }
     }
 }
@@ -53,6 +60,10 @@ impl Validatable<Error> for UpdateTopic {
             return Err(Error::InvalidTopicName);
         }
 
+        if let Some(config) = &self.config {
+            config::validate(config)?;
+        }
+
         Ok(())
     }
 }
@@ -61,7 +72,7 @@ impl FromStr for UpdateTopic {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 4 {
+        if parts.len() != 4 && parts.len() != 5 {
             return Err(Error::InvalidCommand);
         }
 
@@ -76,11 +87,16 @@ impl FromStr for UpdateTopic {
             Err(_) => None,
         };
         let name = parts[3].to_string();
+        let config = match parts.get(4) {
+            Some(config) => Some(config::from_str(config)?),
+            None => None,
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
             name,
+            config,
         };
         command.validate()?;
         Ok(command)
@@ -102,6 +118,9 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        if let Some(config) = &self.config {
+            config::extend_bytes(config, &mut bytes);
+        }
         bytes
     }
 
@@ -126,11 +145,18 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        position += 5 + name_length as usize;
+        // The config overrides are optional, so that the payloads without them are still supported.
+        let config = match bytes.len() > position {
+            true => Some(config::from_bytes(&bytes[position..])?.0),
+            false => None,
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
             name,
+            config,
         };
         command.validate()?;
         Ok(command)
@@ -146,7 +172,11 @@ impl Display for UpdateTopic {
             self.topic_id,
             self.message_expiry.unwrap_or(0),
             self.name
-        )
+        )?;
+        if let Some(config) = &self.config {
+            write!(f, "|{}", config::to_string(config))?;
+        }
+        Ok(())
     }
 }
 
@@ -162,6 +192,10 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             message_expiry: Some(10),
             name: "test".to_string(),
+            config: Some(HashMap::from([(
+                config::CACHE_ENABLED.to_string(),
+                "false".to_string(),
+            )])),
         };
 
         let bytes = command.as_bytes();
@@ -179,6 +213,8 @@ mod tests {
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize])
             .unwrap()
             .to_string();
+        let (config, _) =
+            config::from_bytes(&bytes[position + 5 + name_length as usize..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -186,6 +222,7 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(Some(config), command.config);
     }
 
     #[test]
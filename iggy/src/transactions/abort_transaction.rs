@@ -0,0 +1,122 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `AbortTransaction` command is used to abort the open transaction, which hides the messages appended within the transaction
+/// from the read-committed consumers forever and discards the consumer offsets staged within the transaction.
+/// It has additional payload:
+/// - `transaction_id` - unique ID of the open transaction.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AbortTransaction {
+    /// Unique ID of the open transaction.
+    #[serde(skip)]
+    pub transaction_id: u64,
+}
+
+impl Default for AbortTransaction {
+    fn default() -> Self {
+        AbortTransaction { transaction_id: 1 }
+    }
+}
+
+impl CommandPayload for AbortTransaction {}
+
+impl Validatable<Error> for AbortTransaction {
+    fn validate(&self) -> Result<(), Error> {
+        if self.transaction_id == 0 {
+            return Err(Error::TransactionNotFound(self.transaction_id));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for AbortTransaction {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let transaction_id = parts[0].parse::<u64>()?;
+        let command = AbortTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for AbortTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u64_le(self.transaction_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<AbortTransaction, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(bytes.try_into()?);
+        let command = AbortTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for AbortTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AbortTransaction { transaction_id: 1 };
+
+        let bytes = command.as_bytes();
+        let transaction_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(transaction_id, command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let transaction_id = 1u64;
+        let bytes = transaction_id.to_le_bytes();
+        let command = AbortTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let transaction_id = 1u64;
+        let input = transaction_id.to_string();
+        let command = AbortTransaction::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+
+    #[test]
+    fn transaction_id_equal_to_zero_should_be_invalid() {
+        let command = AbortTransaction { transaction_id: 0 };
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `BeginTransaction` command is used to open the new transaction on the server,
+/// which returns the transaction ID to be attached to the sent messages and stored consumer offsets.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BeginTransaction {}
+
+impl CommandPayload for BeginTransaction {}
+
+impl Validatable<Error> for BeginTransaction {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for BeginTransaction {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if !input.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = BeginTransaction {};
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for BeginTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        Vec::with_capacity(0)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<BeginTransaction, Error> {
+        if !bytes.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = BeginTransaction {};
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for BeginTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = BeginTransaction {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = BeginTransaction::from_bytes(&[]);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = BeginTransaction::from_bytes(&[0]);
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_be_read_from_empty_string() {
+        let command = BeginTransaction::from_str("");
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_read_from_non_empty_string() {
+        let command = BeginTransaction::from_str(" ");
+        assert!(command.is_err());
+    }
+}
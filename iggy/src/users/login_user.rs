@@ -14,6 +14,7 @@ use std::str::{from_utf8, FromStr};
 /// - `username` - username, must be between 3 and 50 characters long.
 /// - `password` - password, must be between 3 and 100 characters long.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LoginUser {
     /// Username, must be between 3 and 50 characters long.
     pub username: String,
@@ -9,6 +9,7 @@ use std::str::FromStr;
 /// `LogoutUser` command is used to logout the authenticated user.
 /// It has no additional payload.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LogoutUser {}
 
 impl CommandPayload for LogoutUser {}
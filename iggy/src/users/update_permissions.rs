@@ -14,6 +14,7 @@ use std::str::FromStr;
 /// - `user_id` - unique user ID (numeric or name).
 /// - `permissions` - new permissions (optional)
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdatePermissions {
     /// Unique user ID (numeric or name).
     #[serde(skip)]
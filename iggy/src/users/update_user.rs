@@ -17,6 +17,7 @@ use std::str::{from_utf8, FromStr};
 /// - `username` - new username (optional), if provided, must be between 3 and 50 characters long.
 /// - `status` - new status (optional)
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateUser {
     #[serde(skip)]
     pub user_id: Identifier,
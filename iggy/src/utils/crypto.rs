@@ -3,11 +3,20 @@ use crate::utils::text;
 use aes_gcm::aead::generic_array::GenericArray;
 use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, KeyInit};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
+const NONCE_SIZE: usize = 12;
+const KEY_ID_MAGIC: [u8; 4] = *b"IGK1";
+const KEY_ID_HEADER_SIZE: usize = KEY_ID_MAGIC.len() + 4;
+
 pub trait Encryptor: Send + Sync + Debug {
     fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Returns true if the data has not been encrypted with the key currently used for the encryption.
+    fn requires_reencryption(&self, _data: &[u8]) -> bool {
+        false
+    }
 }
 
 pub struct Aes256GcmEncryptor {
@@ -36,6 +45,11 @@ impl Aes256GcmEncryptor {
     pub fn from_base64_key(key: &str) -> Result<Self, Error> {
         Self::new(&text::from_base64_as_bytes(key)?)
     }
+
+    /// Generates a new random 256-bit key.
+    pub fn generate_key() -> Vec<u8> {
+        Aes256Gcm::generate_key(&mut OsRng).to_vec()
+    }
 }
 
 impl Encryptor for Aes256GcmEncryptor {
@@ -50,8 +64,12 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 
     fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
-        let nonce = GenericArray::from_slice(&data[0..12]);
-        let payload = self.cipher.decrypt(nonce, &data[12..]);
+        if data.len() < NONCE_SIZE {
+            return Err(Error::CannotDecryptData);
+        }
+
+        let nonce = GenericArray::from_slice(&data[0..NONCE_SIZE]);
+        let payload = self.cipher.decrypt(nonce, &data[NONCE_SIZE..]);
         if payload.is_err() {
             return Err(Error::CannotDecryptData);
         }
@@ -59,6 +77,106 @@ impl Encryptor for Aes256GcmEncryptor {
     }
 }
 
+/// The set of keys, one of which (active) is used to encrypt the data, while the other ones are used only to decrypt it,
+/// so that the key can be rotated without losing access to the data encrypted before the rotation.
+/// The encrypted payload consists of the magic bytes, the ID of the key (u32 LE), the nonce and the ciphertext.
+/// The payloads without the key ID (nonce and ciphertext) are decrypted by trying each key, starting from the active one.
+#[derive(Debug)]
+pub struct Aes256GcmKeyring {
+    active_key_id: u32,
+    keys: BTreeMap<u32, Aes256GcmEncryptor>,
+}
+
+impl Aes256GcmKeyring {
+    pub fn new(active_key_id: u32, active_key: Aes256GcmEncryptor) -> Self {
+        Self {
+            active_key_id,
+            keys: BTreeMap::from([(active_key_id, active_key)]),
+        }
+    }
+
+    pub fn from_base64_keys(
+        active_key_id: u32,
+        active_key: &str,
+        decryption_keys: &[(u32, &str)],
+    ) -> Result<Self, Error> {
+        let mut keyring = Self::new(
+            active_key_id,
+            Aes256GcmEncryptor::from_base64_key(active_key)?,
+        );
+        for (key_id, key) in decryption_keys {
+            keyring.add_decryption_key(*key_id, Aes256GcmEncryptor::from_base64_key(key)?)?;
+        }
+        Ok(keyring)
+    }
+
+    pub fn add_decryption_key(
+        &mut self,
+        key_id: u32,
+        key: Aes256GcmEncryptor,
+    ) -> Result<(), Error> {
+        if self.keys.contains_key(&key_id) {
+            return Err(Error::DuplicateEncryptionKeyId(key_id));
+        }
+
+        self.keys.insert(key_id, key);
+        Ok(())
+    }
+
+    pub fn get_active_key_id(&self) -> u32 {
+        self.active_key_id
+    }
+
+    fn get_key_id(data: &[u8]) -> Option<u32> {
+        if data.len() < KEY_ID_HEADER_SIZE + NONCE_SIZE
+            || data[..KEY_ID_MAGIC.len()] != KEY_ID_MAGIC
+        {
+            return None;
+        }
+
+        Some(u32::from_le_bytes(
+            data[KEY_ID_MAGIC.len()..KEY_ID_HEADER_SIZE]
+                .try_into()
+                .unwrap(),
+        ))
+    }
+}
+
+impl Encryptor for Aes256GcmKeyring {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let encrypted_data = self.keys[&self.active_key_id].encrypt(data)?;
+        let mut payload = Vec::with_capacity(KEY_ID_HEADER_SIZE + encrypted_data.len());
+        payload.extend_from_slice(&KEY_ID_MAGIC);
+        payload.extend_from_slice(&self.active_key_id.to_le_bytes());
+        payload.extend_from_slice(&encrypted_data);
+        Ok(payload)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        if let Some(key) = Self::get_key_id(data).and_then(|key_id| self.keys.get(&key_id)) {
+            if let Ok(payload) = key.decrypt(&data[KEY_ID_HEADER_SIZE..]) {
+                return Ok(payload);
+            }
+        }
+
+        // The nonce of the payload encrypted without the key ID might start with the magic bytes by chance.
+        let active_key = &self.keys[&self.active_key_id];
+        if let Ok(payload) = active_key.decrypt(data) {
+            return Ok(payload);
+        }
+
+        self.keys
+            .iter()
+            .filter(|(key_id, _)| **key_id != self.active_key_id)
+            .find_map(|(_, key)| key.decrypt(data).ok())
+            .ok_or(Error::CannotDecryptData)
+    }
+
+    fn requires_reencryption(&self, data: &[u8]) -> bool {
+        Self::get_key_id(data) != Some(self.active_key_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +210,59 @@ mod tests {
         let error = decrypted_data.err().unwrap();
         assert_eq!(error.as_code(), Error::CannotDecryptData.as_code());
     }
+
+    #[test]
+    fn given_the_rotated_key_data_should_be_decrypted_with_the_previous_key() {
+        let first_keyring = Aes256GcmKeyring::new(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+        let mut second_keyring =
+            Aes256GcmKeyring::new(2, Aes256GcmEncryptor::new(&[2; 32]).unwrap());
+        second_keyring
+            .add_decryption_key(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap())
+            .unwrap();
+        let data = b"Hello World!";
+
+        let encrypted_data = first_keyring.encrypt(data).unwrap();
+
+        assert!(!first_keyring.requires_reencryption(&encrypted_data));
+        assert!(second_keyring.requires_reencryption(&encrypted_data));
+        let decrypted_data = second_keyring.decrypt(&encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+        let reencrypted_data = second_keyring.encrypt(&decrypted_data).unwrap();
+        assert!(!second_keyring.requires_reencryption(&reencrypted_data));
+        assert!(first_keyring.decrypt(&reencrypted_data).is_err());
+    }
+
+    #[test]
+    fn given_the_data_encrypted_without_key_id_it_should_be_decrypted_by_keyring() {
+        let encryptor = Aes256GcmEncryptor::new(&[1; 32]).unwrap();
+        let mut keyring = Aes256GcmKeyring::new(2, Aes256GcmEncryptor::new(&[2; 32]).unwrap());
+        keyring
+            .add_decryption_key(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap())
+            .unwrap();
+        let data = b"Hello World!";
+
+        let encrypted_data = encryptor.encrypt(data).unwrap();
+
+        assert!(keyring.requires_reencryption(&encrypted_data));
+        let decrypted_data = keyring.decrypt(&encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn given_the_duplicate_key_id_it_should_not_be_added_to_keyring() {
+        let mut keyring = Aes256GcmKeyring::new(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+
+        let result = keyring.add_decryption_key(1, Aes256GcmEncryptor::new(&[2; 32]).unwrap());
+
+        assert!(matches!(result, Err(Error::DuplicateEncryptionKeyId(1))));
+    }
+
+    #[test]
+    fn generated_key_should_be_valid_and_random() {
+        let first_key = Aes256GcmEncryptor::generate_key();
+        let second_key = Aes256GcmEncryptor::generate_key();
+        assert_eq!(first_key.len(), 32);
+        assert_ne!(first_key, second_key);
+        assert!(Aes256GcmEncryptor::new(&first_key).is_ok());
+    }
 }
@@ -6,7 +6,7 @@ use std::{
     time::Duration,
 };
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct IggyDuration {
     duration: Duration,
 }
@@ -3,6 +3,9 @@ use async_trait::async_trait;
 use iggy::client::Client;
 use iggy::http::client::HttpClient;
 use iggy::http::config::HttpClientConfig;
+use iggy::models::identity_info::IdentityInfo;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -24,3 +27,20 @@ impl ClientFactory for HttpClientFactory {
 
 unsafe impl Send for HttpClientFactory {}
 unsafe impl Sync for HttpClientFactory {}
+
+/// Logs in as the root user and returns the access token, which is used to call the HTTP API endpoints not supported by `HttpClient`.
+pub async fn get_root_access_token(server_addr: &str) -> String {
+    let identity = reqwest::Client::new()
+        .post(format!("http://{server_addr}/users/login"))
+        .json(&json!({
+            "username": DEFAULT_ROOT_USERNAME,
+            "password": DEFAULT_ROOT_PASSWORD,
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json::<IdentityInfo>()
+        .await
+        .unwrap();
+    identity.tokens.unwrap().access_token.token
+}
@@ -233,6 +233,14 @@ impl TestServer {
         self.child_handle.as_ref().unwrap().id()
     }
 
+    /// Sends SIGTERM to the server without waiting for its exit, so that the graceful shutdown can be observed.
+    #[cfg(unix)]
+    pub fn request_shutdown(&self) {
+        unsafe {
+            libc::kill(self.pid() as libc::pid_t, libc::SIGTERM);
+        }
+    }
+
     fn cleanup(&self) {
         if !self.cleanup {
             return;
@@ -0,0 +1,239 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::identifier::Identifier;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::users::get_users::GetUsers;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const DOCUMENT: &str = r#"
+[[streams]]
+id = 2
+name = "orders"
+
+[[streams.topics]]
+id = 1
+name = "events"
+partitions_count = 3
+message_expiry = "1h"
+
+[[streams.topics.consumer_groups]]
+id = 1
+name = "workers"
+
+[[streams]]
+id = 3
+name = "payments"
+
+[[users]]
+username = "app"
+password = "secret"
+"#;
+
+struct TestApplyCmd {
+    document_path: String,
+    dry_run: bool,
+}
+
+impl TestApplyCmd {
+    fn new(dry_run: bool) -> Self {
+        Self {
+            document_path: std::env::temp_dir()
+                .join(format!("apply_{}.toml", Uuid::new_v4().to_u128_le()))
+                .to_string_lossy()
+                .to_string(),
+            dry_run,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestApplyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        std::fs::write(&self.document_path, DOCUMENT).unwrap();
+        for (stream_id, name) in [(1, "legacy"), (2, "orders")] {
+            let stream = client
+                .create_stream(&CreateStream {
+                    stream_id,
+                    name: String::from(name),
+                })
+                .await;
+            assert!(stream.is_ok());
+        }
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(2).unwrap(),
+                topic_id: 1,
+                partitions_count: 1,
+                message_expiry: None,
+                name: String::from("events"),
+                config: HashMap::new(),
+            })
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("apply")
+            .arg(&self.document_path)
+            .arg("--delete")
+            .with_env_credentials();
+        match self.dry_run {
+            true => command.arg("--dry-run"),
+            false => command,
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.success().stdout(starts_with(format!(
+            "Executing apply document from file: {}",
+            self.document_path
+        )));
+        match self.dry_run {
+            true => command_state
+                .stdout(contains("Dry run, 6 change(s) planned"))
+                .stdout(contains("Planned: delete stream with ID: 1"))
+                .stdout(contains(
+                    "Planned: create 2 partition(s) for topic with ID: 1 in stream with ID: 2",
+                )),
+            false => command_state
+                .stdout(contains("Applied: delete stream with ID: 1"))
+                .stdout(contains("Applied: create user with username: app"))
+                .stdout(contains("Applied 6 change(s)")),
+        };
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        std::fs::remove_file(&self.document_path).unwrap();
+        let streams = client.get_streams(&GetStreams {}).await.unwrap();
+        let users = client.get_users(&GetUsers {}).await.unwrap();
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: Identifier::numeric(2).unwrap(),
+                topic_id: Identifier::numeric(1).unwrap(),
+            })
+            .await
+            .unwrap();
+        let consumer_groups = client
+            .get_consumer_groups(&GetConsumerGroups {
+                stream_id: Identifier::numeric(2).unwrap(),
+                topic_id: Identifier::numeric(1).unwrap(),
+            })
+            .await
+            .unwrap();
+
+        if self.dry_run {
+            assert_eq!(streams.len(), 2);
+            assert_eq!(users.len(), 1);
+            assert_eq!(topic.partitions_count, 1);
+            assert_eq!(topic.message_expiry, None);
+            assert!(consumer_groups.is_empty());
+            return;
+        }
+
+        let mut stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+        stream_ids.sort();
+        assert_eq!(stream_ids, vec![2, 3]);
+        assert_eq!(users.len(), 2);
+        assert!(users.iter().any(|user| user.username == "app"));
+        assert_eq!(topic.partitions_count, 3);
+        assert_eq!(topic.message_expiry, Some(3600));
+        assert_eq!(consumer_groups.len(), 1);
+        assert_eq!(consumer_groups[0].name, "workers");
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestApplyCmd::new(false)).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_not_change_server_state_in_dry_run_mode() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestApplyCmd::new(true)).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "--help"],
+            format!(
+                r#"apply declarative document with desired server state
+
+Compare streams, topics, partitions, consumer groups, users and personal access tokens described in TOML, YAML or JSON document with the current server state and create, update or (optionally) delete resources to match it.
+
+{USAGE_PREFIX} apply [OPTIONS] <FILE>
+
+Arguments:
+  <FILE>
+          Path to the document describing desired server state
+{CLAP_INDENT}
+          Document format is detected using file extension,
+          supported formats are TOML (.toml), YAML (.yaml, .yml) and JSON (.json).
+
+Options:
+      --dry-run
+          Only print changes which would be made, without applying them
+
+      --delete
+          Delete resources which exist on the server but are missing in the document
+{CLAP_INDENT}
+          Root user and currently logged in user are never deleted.
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "-h"],
+            format!(
+                r#"apply declarative document with desired server state
+
+{USAGE_PREFIX} apply [OPTIONS] <FILE>
+
+Arguments:
+  <FILE>  Path to the document describing desired server state
+
+Options:
+      --dry-run  Only print changes which would be made, without applying them
+      --delete   Delete resources which exist on the server but are missing in the document
+  -h, --help     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -13,6 +13,7 @@ use iggy::topics::delete_topic::DeleteTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::diff;
 use serial_test::parallel;
+use std::collections::HashMap;
 
 struct TestConsumerGroupDeleteCmd {
     stream_id: u32,
@@ -90,6 +91,7 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 partitions_count: 0,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
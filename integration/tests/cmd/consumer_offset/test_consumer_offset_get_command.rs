@@ -0,0 +1,292 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestConsumerOffsetGetCmd {
+    consumer_id: u32,
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    messages_count: u32,
+    offset: u64,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestConsumerOffsetGetCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        consumer_id: u32,
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        messages_count: u32,
+        offset: u64,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            consumer_id,
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            messages_count,
+            offset,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn stream_arg(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn topic_arg(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            format!("{}", self.consumer_id),
+            self.stream_arg(),
+            self.topic_arg(),
+            format!("{}", self.partition_id),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                config: HashMap::new(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = (1..=self.messages_count)
+            .map(|id| Message::new(None, Bytes::from(format!("Test message {id}")), None))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(self.partition_id),
+                acknowledgement: Acknowledgement::default(),
+                producer: None,
+                messages,
+                transaction_id: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+
+        let offset = client
+            .store_consumer_offset(&StoreConsumerOffset {
+                consumer: Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partition_id: Some(self.partition_id),
+                offset: self.offset,
+                transaction_id: None,
+            })
+            .await;
+        assert!(offset.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-offset")
+            .arg("get")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing get consumer offset for consumer with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.consumer_id,
+            self.partition_id,
+            self.topic_arg(),
+            self.stream_arg()
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!("Consumer ID    | {}", self.consumer_id)))
+            .stdout(contains(format!("Partition ID   | {}", self.partition_id)))
+            .stdout(contains(format!(
+                "Current offset | {}",
+                self.messages_count - 1
+            )))
+            .stdout(contains(format!("Stored offset  | {}", self.offset)));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (TestStreamId::Numeric, TestTopicId::Numeric),
+        (TestStreamId::Numeric, TestTopicId::Named),
+        (TestStreamId::Named, TestTopicId::Numeric),
+        (TestStreamId::Named, TestTopicId::Named),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestConsumerOffsetGetCmd::new(
+                1,
+                2,
+                String::from("stream"),
+                3,
+                String::from("topic"),
+                1,
+                10,
+                5,
+                using_stream_id,
+                using_topic_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-offset", "get", "--help"],
+            format!(
+                r#"Retrieve the offset of a consumer for a given partition from the server
+
+Consumer ID can be specified as a consumer name or ID
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy consumer-offset get 1 3 5 1
+ iggy consumer-offset get consumer stream 5 1
+ iggy consumer-offset get 1 3 topic 1
+ iggy consumer-offset get consumer stream topic 1
+
+{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <CONSUMER_ID>
+          Regular consumer for which the offset is retrieved
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+
+  <STREAM_ID>
+          Stream ID for which consumer offset is retrieved
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID for which consumer offset is retrieved
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID for which consumer offset is retrieved
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-offset", "get", "-h"],
+            format!(
+                r#"Retrieve the offset of a consumer for a given partition from the server
+
+{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <CONSUMER_ID>   Regular consumer for which the offset is retrieved
+  <STREAM_ID>     Stream ID for which consumer offset is retrieved
+  <TOPIC_ID>      Topic ID for which consumer offset is retrieved
+  <PARTITION_ID>  Partition ID for which consumer offset is retrieved
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,28 @@
+use crate::cmd::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-offset", "help"],
+            format!(
+                r#"consumer offset operations
+
+{USAGE_PREFIX} consumer-offset <COMMAND>
+
+Commands:
+  get   Retrieve the offset of a consumer for a given partition from the server [aliases: g]
+  set   Set the offset of a consumer for a given partition on the server [aliases: s]
+  help  Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help  Print help
+"#,
+            ),
+        ))
+        .await;
+}
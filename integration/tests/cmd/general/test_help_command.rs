@@ -15,17 +15,23 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} [OPTIONS] [COMMAND]
 
 Commands:
-  stream          stream operations [aliases: s]
-  topic           topic operations [aliases: t]
-  partition       partition operations [aliases: p]
-  ping            ping iggy server
-  me              get current client info
-  stats           get iggy server statistics
-  pat             personal access token operations
-  user            user operations [aliases: u]
-  client          client operations [aliases: c]
-  consumer-group  consumer group operations [aliases: g]
-  help            Print this message or the help of the given subcommand(s)
+  stream           stream operations [aliases: s]
+  topic            topic operations [aliases: t]
+  partition        partition operations [aliases: p]
+  ping             ping iggy server
+  me               get current client info
+  stats            get iggy server statistics
+  backup           create iggy server backup
+  log-level        set iggy server log level
+  reload-config    reload iggy server configuration
+  apply            apply declarative document with desired server state
+  pat              personal access token operations
+  user             user operations [aliases: u]
+  client           client operations [aliases: c]
+  consumer-group   consumer group operations [aliases: g]
+  consumer-offset  consumer offset operations [aliases: o]
+  message          message operations [aliases: m]
+  help             Print this message or the help of the given subcommand(s)
 
 Options:
       --transport <TRANSPORT>
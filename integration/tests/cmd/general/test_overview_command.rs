@@ -26,17 +26,23 @@ Iggy is the persistent message streaming platform written in Rust, supporting QU
 Usage: iggy [OPTIONS] [COMMAND]
 
 Commands:
-  stream          stream operations [aliases: s]
-  topic           topic operations [aliases: t]
-  partition       partition operations [aliases: p]
-  ping            ping iggy server
-  me              get current client info
-  stats           get iggy server statistics
-  pat             personal access token operations
-  user            user operations [aliases: u]
-  client          client operations [aliases: c]
-  consumer-group  consumer group operations [aliases: g]
-  help            Print this message or the help of the given subcommand(s)
+  stream           stream operations [aliases: s]
+  topic            topic operations [aliases: t]
+  partition        partition operations [aliases: p]
+  ping             ping iggy server
+  me               get current client info
+  stats            get iggy server statistics
+  backup           create iggy server backup
+  log-level        set iggy server log level
+  reload-config    reload iggy server configuration
+  apply            apply declarative document with desired server state
+  pat              personal access token operations
+  user             user operations [aliases: u]
+  client           client operations [aliases: c]
+  consumer-group   consumer group operations [aliases: g]
+  consumer-offset  consumer offset operations [aliases: o]
+  message          message operations [aliases: m]
+  help             Print this message or the help of the given subcommand(s)
 
 
 Run 'iggy --help' for full help message.
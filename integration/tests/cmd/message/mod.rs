@@ -0,0 +1,3 @@
+mod test_message_help_command;
+mod test_message_poll_command;
+mod test_message_send_command;
@@ -0,0 +1,363 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy)]
+enum TestOutput {
+    Raw,
+    Hex,
+}
+
+impl TestOutput {
+    fn name(&self) -> &'static str {
+        match self {
+            TestOutput::Raw => "raw",
+            TestOutput::Hex => "hex",
+        }
+    }
+
+    fn format(&self, message: &str) -> String {
+        match self {
+            TestOutput::Raw => message.to_string(),
+            TestOutput::Hex => message
+                .as_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+        }
+    }
+}
+
+struct TestMessagePollCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    messages: Vec<String>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_output: TestOutput,
+}
+
+impl TestMessagePollCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        messages: Vec<String>,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        using_output: TestOutput,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            messages,
+            using_stream_id,
+            using_topic_id,
+            using_output,
+        }
+    }
+
+    fn stream_arg(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn topic_arg(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            "--offset".into(),
+            "0".into(),
+            "--message-count".into(),
+            format!("{}", self.messages.len()),
+            "--output".into(),
+            self.using_output.name().into(),
+            self.stream_arg(),
+            self.topic_arg(),
+            format!("{}", self.partition_id),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessagePollCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                config: HashMap::new(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = self
+            .messages
+            .iter()
+            .map(|message| Message::new(None, Bytes::from(message.clone()), None))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(self.partition_id),
+                acknowledgement: Acknowledgement::default(),
+                producer: None,
+                messages,
+                transaction_id: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("poll")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing poll {} messages using offset strategy with value: 0 by consumer with ID: 1 from partition with ID: {} for topic with ID: {} and stream with ID: {} in {} mode",
+            self.messages.len(),
+            self.partition_id,
+            self.topic_arg(),
+            self.stream_arg(),
+            self.using_output.name()
+        );
+
+        let mut command_state = command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!(
+                "Polled {} messages from partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                self.messages.len(),
+                self.partition_id,
+                self.topic_arg(),
+                self.stream_arg()
+            )));
+
+        for message in &self.messages {
+            command_state = command_state.stdout(contains(self.using_output.format(message)));
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (TestStreamId::Numeric, TestTopicId::Numeric, TestOutput::Raw),
+        (TestStreamId::Numeric, TestTopicId::Named, TestOutput::Hex),
+        (TestStreamId::Named, TestTopicId::Numeric, TestOutput::Hex),
+        (TestStreamId::Named, TestTopicId::Named, TestOutput::Raw),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, using_output) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessagePollCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                1,
+                vec![
+                    String::from("first message"),
+                    String::from("second message"),
+                    String::from("third message"),
+                ],
+                using_stream_id,
+                using_topic_id,
+                using_output,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "poll", "--help"],
+            format!(
+                r#"Poll messages from given partition ID of given topic ID and given stream ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy message poll 1 2 1
+ iggy message poll --offset 100 --message-count 20 stream topic 1
+ iggy message poll --next --auto-commit --consumer reader stream topic 2
+ iggy message poll --last --output json 1 topic 1
+ iggy message poll --follow --output hex stream 2 1
+
+{USAGE_PREFIX} message poll [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID from which messages are polled
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID from which messages are polled
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID from which messages are polled
+
+Options:
+  -m, --message-count <MESSAGE_COUNT>
+          Number of messages to poll
+{CLAP_INDENT}
+          [default: 10]
+
+  -o, --offset <OFFSET>
+          Poll messages starting from given offset
+
+  -t, --timestamp <TIMESTAMP>
+          Poll messages starting from given timestamp (in microseconds)
+
+  -f, --first
+          Poll messages starting from the first message in the partition (default)
+
+  -l, --last
+          Poll the last messages in the partition
+
+  -n, --next
+          Poll messages starting from the next one after the offset stored by the consumer
+
+  -c, --consumer <CONSUMER>
+          Consumer ID used for polling and storing the offset
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+{CLAP_INDENT}
+          [default: 1]
+
+  -a, --auto-commit
+          Store the offset of the last polled message for the consumer
+
+  -F, --follow
+          Keep polling the new messages until interrupted (Ctrl+C)
+
+  -O, --output <OUTPUT>
+          Output format of the polled messages
+{CLAP_INDENT}
+          [default: raw]
+
+          Possible values:
+          - raw:  Message payload as text
+          - json: Whole message (with offset, timestamp, ID, headers and base64 payload) as JSON
+          - hex:  Message payload as hexadecimal string
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "poll", "-h"],
+            format!(
+                r#"Poll messages from given partition ID of given topic ID and given stream ID
+
+{USAGE_PREFIX} message poll [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <STREAM_ID>     Stream ID from which messages are polled
+  <TOPIC_ID>      Topic ID from which messages are polled
+  <PARTITION_ID>  Partition ID from which messages are polled
+
+Options:
+  -m, --message-count <MESSAGE_COUNT>  Number of messages to poll [default: 10]
+  -o, --offset <OFFSET>                Poll messages starting from given offset
+  -t, --timestamp <TIMESTAMP>          Poll messages starting from given timestamp (in microseconds)
+  -f, --first                          Poll messages starting from the first message in the partition (default)
+  -l, --last                           Poll the last messages in the partition
+  -n, --next                           Poll messages starting from the next one after the offset stored by the consumer
+  -c, --consumer <CONSUMER>            Consumer ID used for polling and storing the offset [default: 1]
+  -a, --auto-commit                    Store the offset of the last polled message for the consumer
+  -F, --follow                         Keep polling the new messages until interrupted (Ctrl+C)
+  -O, --output <OUTPUT>                Output format of the polled messages [default: raw] [possible values: raw, json, hex]
+  -h, --help                           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,346 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TestInput {
+    Arguments,
+    Stdin,
+}
+
+struct TestMessageSendCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    messages: Vec<String>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_input: TestInput,
+}
+
+impl TestMessageSendCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        messages: Vec<String>,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        using_input: TestInput,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            messages,
+            using_stream_id,
+            using_topic_id,
+            using_input,
+        }
+    }
+
+    fn stream_arg(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn topic_arg(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            "--partition-id".into(),
+            format!("{}", self.partition_id),
+            self.stream_arg(),
+            self.topic_arg(),
+        ];
+
+        if self.using_input == TestInput::Arguments {
+            command.extend(self.messages.clone());
+        }
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageSendCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                config: HashMap::new(),
+            })
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("send")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn provide_stdin_input(&self) -> Option<Vec<String>> {
+        match self.using_input {
+            TestInput::Arguments => None,
+            TestInput::Stdin => Some(self.messages.clone()),
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing send messages to topic with ID: {} and stream with ID: {}",
+            self.topic_arg(),
+            self.stream_arg()
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!(
+                "Sent {} messages to topic with ID: {} and stream with ID: {}",
+                self.messages.len(),
+                self.topic_arg(),
+                self.stream_arg()
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let polled_messages = client
+            .poll_messages(&PollMessages {
+                consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partition_id: Some(self.partition_id),
+                strategy: PollingStrategy::offset(0),
+                count: self.messages.len() as u32 + 1,
+                auto_commit: false,
+                isolation: IsolationLevel::ReadUncommitted,
+            })
+            .await;
+        assert!(polled_messages.is_ok());
+        let polled_messages = polled_messages.unwrap();
+        assert_eq!(polled_messages.messages.len(), self.messages.len());
+        for (polled_message, message) in polled_messages.messages.iter().zip(&self.messages) {
+            assert_eq!(polled_message.payload, message.as_bytes());
+        }
+
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestInput::Arguments,
+        ),
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            TestInput::Arguments,
+        ),
+        (TestStreamId::Named, TestTopicId::Numeric, TestInput::Stdin),
+        (TestStreamId::Named, TestTopicId::Named, TestInput::Stdin),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, using_input) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageSendCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                1,
+                vec![
+                    String::from("first message"),
+                    String::from("second message"),
+                    String::from("third message"),
+                ],
+                using_stream_id,
+                using_topic_id,
+                using_input,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "send", "--help"],
+            format!(
+                r#"Send messages to given topic ID and given stream ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Messages can be provided as command line arguments (each argument is
+a separate message), as an input file (whole file is a single message)
+or read from standard input (each line is a separate message).
+
+Examples
+ iggy message send 1 2 message
+ iggy message send stream topic "hello world" "second message"
+ iggy message send --partition-id 3 1 2 message
+ iggy message send --message-key key --header source:string:cli 1 topic message
+ iggy message send --input-file payload.bin stream 2
+ echo "message" | iggy message send --acknowledgement persisted stream topic
+
+{USAGE_PREFIX} message send [OPTIONS] <STREAM_ID> <TOPIC_ID> [MESSAGES]...
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to send messages to
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to send messages to
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  [MESSAGES]...
+          Messages to be sent
+{CLAP_INDENT}
+          If neither messages nor input file are provided,
+          messages are read from the standard input (one per line)
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          ID of the partition to which messages are sent
+{CLAP_INDENT}
+          If neither partition ID nor message key are provided,
+          messages are balanced between the partitions by the server
+
+  -m, --message-key <MESSAGE_KEY>
+          Messages key used to calculate the partition to which messages are sent
+
+  -H, --header <HEADERS>
+          Message header in key:kind:value format (can be used multiple times)
+{CLAP_INDENT}
+          Kind is one of: raw, string, bool, int8, int16, int32, int64, int128,
+          uint8, uint16, uint32, uint64, uint128, float32 and float64.
+          If kind is omitted (key:value), string is used.
+
+  -i, --input-file <INPUT_FILE>
+          Input file with the payload of a single message
+
+  -a, --acknowledgement <ACKNOWLEDGEMENT>
+          When the server acknowledges the messages
+{CLAP_INDENT}
+          [default: memory]
+
+          Possible values:
+          - none:      Acknowledged as soon as received by the server
+          - memory:    Acknowledged once appended to the partition buffer
+          - persisted: Acknowledged once saved on disk
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "send", "-h"],
+            format!(
+                r#"Send messages to given topic ID and given stream ID
+
+{USAGE_PREFIX} message send [OPTIONS] <STREAM_ID> <TOPIC_ID> [MESSAGES]...
+
+Arguments:
+  <STREAM_ID>    Stream ID to send messages to
+  <TOPIC_ID>     Topic ID to send messages to
+  [MESSAGES]...  Messages to be sent
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          ID of the partition to which messages are sent
+  -m, --message-key <MESSAGE_KEY>
+          Messages key used to calculate the partition to which messages are sent
+  -H, --header <HEADERS>
+          Message header in key:kind:value format (can be used multiple times)
+  -i, --input-file <INPUT_FILE>
+          Input file with the payload of a single message
+  -a, --acknowledgement <ACKNOWLEDGEMENT>
+          When the server acknowledges the messages [default: memory] [possible values: none, memory, persisted]
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,7 +1,10 @@
+mod apply;
 mod client;
 mod common;
 mod consumer_group;
+mod consumer_offset;
 mod general;
+mod message;
 mod partition;
 mod personal_access_token;
 mod stream;
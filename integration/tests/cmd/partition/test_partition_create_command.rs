@@ -12,6 +12,7 @@ use iggy::topics::get_topic::GetTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::diff;
 use serial_test::parallel;
+use std::collections::HashMap;
 
 struct TestPartitionCreateCmd {
     stream_id: u32,
@@ -83,6 +84,7 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
                 partitions_count: self.partitions_count,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
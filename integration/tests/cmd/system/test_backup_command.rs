@@ -0,0 +1,95 @@
+use crate::cmd::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::get_streams::GetStreams;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestBackupCmd {}
+
+#[async_trait]
+impl IggyCmdTestCase for TestBackupCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: 1,
+                name: String::from("backup"),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new().arg("backup").with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with("Executing backup command\n"))
+            .stdout(contains("Backup: backup_"))
+            .stdout(contains("created at path:"));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let streams = client.get_streams(&GetStreams {}).await;
+        assert!(streams.is_ok());
+        assert_eq!(streams.unwrap().len(), 1);
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestBackupCmd {}).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "--help"],
+            format!(
+                r#"create iggy server backup
+
+Create a consistent, point-in-time backup of the whole Iggy server, including users, personal access tokens, consumer offsets, streams, topics, partitions and all the segments. Backup is created in the directory configured on the server and can be restored by starting the server with --restore <path> option.
+
+{USAGE_PREFIX} backup
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "-h"],
+            format!(
+                r#"create iggy server backup
+
+{USAGE_PREFIX} backup
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
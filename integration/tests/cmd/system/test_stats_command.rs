@@ -6,6 +6,7 @@ use iggy::topics::create_topic::CreateTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 struct TestStatsCmd {}
 
@@ -28,6 +29,7 @@ impl IggyCmdTestCase for TestStatsCmd {
                 partitions_count: 5,
                 message_expiry: None,
                 name: String::from("topic"),
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
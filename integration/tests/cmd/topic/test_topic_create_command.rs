@@ -206,8 +206,9 @@ Examples
  iggy topic create 1 1 2 sensor1 15days
  iggy topic create prod 2 2 sensor2
  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+ iggy topic create prod 4 2 orders -c segment.size=256MB -c partition.enforce_fsync=true
 
-{USAGE_PREFIX} topic create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>
@@ -228,6 +229,15 @@ Arguments:
           Message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter disables message expiry functionality in topic)
 
 Options:
+  -c, --config <CONFIG>
+          Topic config override in key=value format (can be used multiple times)
+{CLAP_INDENT}
+          Key is one of: segment.size, partition.enforce_fsync,
+          partition.validate_checksum, partition.messages_required_to_save,
+          message_deduplication.enabled, message_deduplication.max_entries,
+          message_deduplication.expiry, cache.enabled, topic.partitioning,
+          topic.sticky_batch_interval and topic.key_ranges.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -248,7 +258,7 @@ pub async fn should_short_help_match() {
                 r#"Create topic with given ID, name, number of partitions
 and expiry time for given stream ID
 
-{USAGE_PREFIX} topic create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>          Stream ID to create topic
@@ -258,7 +268,8 @@ Arguments:
   [MESSAGE_EXPIRY]...  Message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter disables message expiry functionality in topic)
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -c, --config <CONFIG>  Topic config override in key=value format (can be used multiple times)
+  -h, --help             Print help (see more with '--help')
 "#,
             ),
         ))
@@ -10,6 +10,7 @@ use iggy::topics::get_topics::GetTopics;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::diff;
 use serial_test::parallel;
+use std::collections::HashMap;
 
 struct TestTopicDeleteCmd {
     stream_id: u32,
@@ -72,6 +73,7 @@ impl IggyCmdTestCase for TestTopicDeleteCmd {
                 partitions_count: 1,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
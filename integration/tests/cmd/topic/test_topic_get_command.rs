@@ -11,6 +11,7 @@ use iggy::topics::delete_topic::DeleteTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 struct TestTopicGetCmd {
     stream_id: u32,
@@ -73,6 +74,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 partitions_count: 1,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
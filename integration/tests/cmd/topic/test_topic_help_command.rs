@@ -15,13 +15,16 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} topic <COMMAND>
 
 Commands:
-  create  Create topic with given ID, name, number of partitions
-              and expiry time for given stream ID [aliases: c]
-  delete  Delete topic with given ID in given stream ID [aliases: d]
-  update  Update topic name an message expiry time for given topic ID in given stream ID [aliases: u]
-  get     Get topic detail for given topic ID and stream ID [aliases: g]
-  list    List all topics in given stream ID [aliases: l]
-  help    Print this message or the help of the given subcommand(s)
+  create       Create topic with given ID, name, number of partitions
+                   and expiry time for given stream ID [aliases: c]
+  delete       Delete topic with given ID in given stream ID [aliases: d]
+  restore      Restore soft-deleted topic with given ID in given stream ID [aliases: r]
+  update       Update topic name an message expiry time for given topic ID in given stream ID [aliases: u]
+  repartition  Change number of partitions for given topic ID in given stream ID,
+                   re-appending its messages to the new partitions by their key [aliases: rp]
+  get          Get topic detail for given topic ID and stream ID [aliases: g]
+  list         List all topics in given stream ID [aliases: l]
+  help         Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -14,6 +14,7 @@ use iggy::topics::get_topic::GetTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::diff;
 use serial_test::parallel;
+use std::collections::HashMap;
 use std::time::Duration;
 
 struct TestTopicUpdateCmd {
@@ -103,6 +104,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 partitions_count: 1,
                 name: self.topic_name.clone(),
                 message_expiry,
+                config: HashMap::new(),
             })
             .await;
         assert!(topic.is_ok());
@@ -304,8 +306,10 @@ Examples
  iggy update test debugs ready 15days
  iggy update 1 1 new-name
  iggy update 1 2 new-name 1day 1hour 1min 1sec
+ iggy update prod orders orders -c cache.enabled=false
+ iggy update prod orders orders --reset-config
 
-{USAGE_PREFIX} topic update <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>
@@ -325,6 +329,20 @@ Arguments:
           New message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter causes removal of expiry parameter in topic)
 
 Options:
+  -c, --config <CONFIG>
+          Topic config override in key=value format (can be used multiple times)
+{CLAP_INDENT}
+          Replaces all the current config overrides of the topic,
+          which are kept unchanged if the option is not used.
+          Key is one of: segment.size, partition.enforce_fsync,
+          partition.validate_checksum, partition.messages_required_to_save,
+          message_deduplication.enabled, message_deduplication.max_entries,
+          message_deduplication.expiry, cache.enabled, topic.partitioning,
+          topic.sticky_batch_interval and topic.key_ranges.
+
+      --reset-config
+          Remove all the config overrides of the topic
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -344,7 +362,7 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Update topic name an message expiry time for given topic ID in given stream ID
 
-{USAGE_PREFIX} topic update <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>          Stream ID to update topic
@@ -353,7 +371,9 @@ Arguments:
   [MESSAGE_EXPIRY]...  New message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter causes removal of expiry parameter in topic)
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -c, --config <CONFIG>  Topic config override in key=value format (can be used multiple times)
+      --reset-config     Remove all the config overrides of the topic
+  -h, --help             Print help (see more with '--help')
 "#,
             ),
         ))
@@ -1,6 +1,32 @@
-use crate::server::scenarios::{message_headers_scenario, system_scenario, user_scenario};
+use crate::server::scenarios::{
+    consumer_group_members_scenario, delayed_messages_scenario, idempotent_producer_scenario,
+    message_headers_scenario, soft_delete_scenario, stream_messages_scenario, system_scenario,
+    transaction_scenario, unacknowledged_messages_scenario, user_scenario,
+};
 use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
+
+const OPENAPI_PATHS: &[&str] = &[
+    "/ping",
+    "/stats",
+    "/log-level",
+    "/config/reload",
+    "/clients",
+    "/users",
+    "/users/login",
+    "/personal-access-tokens",
+    "/streams",
+    "/streams/{stream_id}",
+    "/streams/{stream_id}/topics",
+    "/streams/{stream_id}/topics/{topic_id}",
+    "/streams/{stream_id}/topics/{topic_id}/partitions",
+    "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    "/streams/{stream_id}/topics/{topic_id}/messages",
+    "/producers",
+    "/transactions",
+];
 
 #[tokio::test]
 #[parallel]
@@ -31,3 +57,132 @@ async fn message_headers_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     message_headers_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn idempotent_producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    idempotent_producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn transaction_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    transaction_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn unacknowledged_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    unacknowledged_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delayed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    delayed_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn stream_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    stream_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_members_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    consumer_group_members_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn openapi_specification_should_describe_http_api() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let response = reqwest::get(format!("http://{server_addr}/openapi.json"))
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let specification = response.json::<serde_json::Value>().await.unwrap();
+    assert!(specification["openapi"].as_str().unwrap().starts_with("3."));
+    let paths = specification["paths"].as_object().unwrap();
+    for path in OPENAPI_PATHS {
+        assert!(paths.contains_key(*path), "Missing OpenAPI path: {path}");
+    }
+
+    let schemas = specification["components"]["schemas"].as_object().unwrap();
+    for schema in [
+        "StreamDetails",
+        "SendMessages",
+        "SendMessage",
+        "PolledMessages",
+        "IdentityInfo",
+    ] {
+        assert!(
+            schemas.contains_key(schema),
+            "Missing OpenAPI schema: {schema}"
+        );
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn openapi_specification_should_be_served_without_authentication_only_from_configured_endpoint(
+) {
+    let mut test_server = TestServer::new(
+        Some(HashMap::from([(
+            "IGGY_HTTP_OPENAPI_ENDPOINT".to_string(),
+            "/docs/openapi.json".to_string(),
+        )])),
+        true,
+        None,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let response = reqwest::get(format!("http://{server_addr}/docs/openapi.json"))
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let response = reqwest::get(format!("http://{server_addr}/openapi.json"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+#[parallel]
+async fn soft_delete_scenario_should_be_valid() {
+    let mut test_server =
+        TestServer::new(Some(soft_delete_scenario::soft_delete_envs()), true, None);
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    soft_delete_scenario::run(&client_factory).await;
+}
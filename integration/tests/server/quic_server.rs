@@ -1,7 +1,8 @@
 use crate::server::scenarios::{
     consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario, user_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, delayed_messages_scenario,
+    graceful_shutdown_scenario, idempotent_producer_scenario, message_headers_scenario,
+    system_scenario, transaction_scenario, user_scenario,
 };
 use integration::{quic_client::QuicClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -36,6 +37,36 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn idempotent_producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    idempotent_producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn transaction_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    transaction_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delayed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    delayed_messages_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_join_scenario_should_be_valid() {
@@ -65,3 +96,14 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     let client_factory = QuicClientFactory { server_addr };
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
+
+#[cfg(unix)]
+#[tokio::test]
+#[parallel]
+async fn graceful_shutdown_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    graceful_shutdown_scenario::run(&client_factory, &test_server).await;
+}
@@ -12,6 +12,7 @@ use iggy::topics::create_topic::CreateTopic;
 use integration::test_server::{
     assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
 };
+use std::collections::HashMap;
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
 const STREAM_NAME: &str = "test-stream";
@@ -46,6 +47,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        config: HashMap::new(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -0,0 +1,186 @@
+use bytes::Bytes;
+use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::messages::PolledMessages;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::http_client::{get_root_access_token, HttpClientFactory};
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const CONSUMER_GROUP_NAME: &str = "test-consumer-group";
+const PARTITIONS_COUNT: u32 = 3;
+const MESSAGES_PER_PARTITION: u32 = 3;
+
+pub async fn run(client_factory: &HttpClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+    let http = reqwest::Client::new();
+    let token = get_root_access_token(&client_factory.server_addr).await;
+    let topic_url = format!(
+        "http://{}/streams/{STREAM_ID}/topics/{TOPIC_ID}",
+        client_factory.server_addr
+    );
+    let members_url = format!("{topic_url}/consumer-groups/{CONSUMER_GROUP_ID}/members");
+
+    // 1. Join the consumer group and ensure that the member has all the partitions assigned
+    let response = http
+        .post(&members_url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let lease = response.json::<Value>().await.unwrap();
+    let member_id = lease["member_id"].as_u64().unwrap() as u32;
+
+    let consumer_group = client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(consumer_group.members_count, 1);
+    assert_eq!(consumer_group.members[0].id, member_id);
+    assert_eq!(consumer_group.members[0].partitions_count, PARTITIONS_COUNT);
+
+    // 2. Poll the messages as the member, which gets the next partition with each poll
+    let mut polled_messages_count = 0;
+    for _ in 0..PARTITIONS_COUNT {
+        let response = http
+            .get(format!("{topic_url}/messages"))
+            .query(&[
+                ("id", CONSUMER_GROUP_ID.to_string()),
+                ("member_id", member_id.to_string()),
+                ("kind", "next".to_string()),
+                ("count", (2 * MESSAGES_PER_PARTITION).to_string()),
+                ("auto_commit", "true".to_string()),
+            ])
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let polled_messages = response.json::<PolledMessages>().await.unwrap();
+        assert_eq!(
+            polled_messages.messages.len() as u32,
+            MESSAGES_PER_PARTITION
+        );
+        polled_messages_count += polled_messages.messages.len() as u32;
+    }
+    assert_eq!(
+        polled_messages_count,
+        PARTITIONS_COUNT * MESSAGES_PER_PARTITION
+    );
+
+    // 3. Renew the member lease
+    let response = http
+        .put(format!("{members_url}/{member_id}"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 4. Leave the consumer group and ensure that the member can't poll the messages anymore
+    let response = http
+        .delete(format!("{members_url}/{member_id}"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = http
+        .get(format!("{topic_url}/messages"))
+        .query(&[
+            ("id", CONSUMER_GROUP_ID.to_string()),
+            ("member_id", member_id.to_string()),
+        ])
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let consumer_group = client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(consumer_group.members_count, 0);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the consumer group
+    let create_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        name: CONSUMER_GROUP_NAME.to_string(),
+    };
+    client.create_consumer_group(&create_group).await.unwrap();
+
+    // 4. Send the messages to each partition
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let messages = (0..MESSAGES_PER_PARTITION)
+            .map(|index| Message::new(None, Bytes::from(format!("message {index}")), None))
+            .collect();
+        let mut send_messages = SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(partition_id),
+            messages,
+            ..Default::default()
+        };
+        client.send_messages(&mut send_messages).await.unwrap();
+    }
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
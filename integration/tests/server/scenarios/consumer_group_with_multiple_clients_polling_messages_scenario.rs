@@ -5,8 +5,8 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
@@ -15,6 +15,7 @@ use iggy::topics::create_topic::CreateTopic;
 use integration::test_server::{
     assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
 };
+use std::collections::HashMap;
 use std::str::{from_utf8, FromStr};
 
 const STREAM_ID: u32 = 1;
@@ -70,6 +71,7 @@ async fn init_system(
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        config: HashMap::new(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -137,7 +139,10 @@ async fn execute_using_messages_key_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
             messages,
+            transaction_id: None,
         };
         system_client
             .send_messages(&mut send_messages)
@@ -163,6 +168,7 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let mut total_read_messages_count = 0;
@@ -198,7 +204,10 @@ async fn execute_using_none_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
             messages,
+            transaction_id: None,
         };
         system_client
             .send_messages(&mut send_messages)
@@ -246,6 +255,7 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     for i in 1..=MESSAGES_COUNT {
@@ -5,13 +5,14 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
 use iggy::topics::create_topic::CreateTopic;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
 use std::str::{from_utf8, FromStr};
 
 const STREAM_ID: u32 = 1;
@@ -57,6 +58,7 @@ async fn init_system(client: &IggyClient) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        config: HashMap::new(),
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -108,7 +110,10 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
             messages,
+            transaction_id: None,
         };
         client.send_messages(&mut send_messages).await.unwrap();
     }
@@ -122,6 +127,7 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let mut total_read_messages_count = 0;
@@ -152,7 +158,10 @@ async fn execute_using_none_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            acknowledgement: Acknowledgement::default(),
+            producer: None,
             messages,
+            transaction_id: None,
         };
         client.send_messages(&mut send_messages).await.unwrap();
     }
@@ -166,6 +175,7 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let mut partition_id = 1;
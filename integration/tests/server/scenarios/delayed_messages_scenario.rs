@@ -0,0 +1,119 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::duration::IggyDuration;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tokio::time::{sleep, Duration};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the batch, in which every other message is delayed
+    let delay = IggyDuration::from_str("2s").unwrap();
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let message = Message::new(None, Bytes::from(format!("message {}", offset)), None);
+        match offset % 2 {
+            0 => messages.push(message),
+            _ => messages.push(message.with_delay(delay)),
+        }
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        ..Default::default()
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Only the messages without the delay should be visible right away
+    let polled_messages = poll_messages(&client).await;
+    assert_eq!(polled_messages.len() as u32, MESSAGES_COUNT / 2);
+    for (index, payload) in polled_messages.iter().enumerate() {
+        assert_eq!(payload, &format!("message {}", index * 2));
+    }
+
+    // 3. The delayed messages should be appended once they are due
+    sleep(Duration::from_secs(4)).await;
+    let polled_messages = poll_messages(&client).await;
+    assert_eq!(polled_messages.len() as u32, MESSAGES_COUNT);
+    for (index, payload) in polled_messages
+        .iter()
+        .skip(MESSAGES_COUNT as usize / 2)
+        .enumerate()
+    {
+        assert_eq!(payload, &format!("message {}", index * 2 + 1));
+    }
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn poll_messages(client: &IggyClient) -> Vec<String> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 2 * MESSAGES_COUNT,
+        auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    polled_messages
+        .messages
+        .iter()
+        .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+        .collect()
+}
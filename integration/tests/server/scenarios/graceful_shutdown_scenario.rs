@@ -0,0 +1,33 @@
+use iggy::client::SystemClient;
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::error::Error;
+use iggy::system::ping::Ping;
+use integration::test_server::{ClientFactory, TestServer};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const ATTEMPTS: u32 = 50;
+
+pub async fn run(client_factory: &dyn ClientFactory, test_server: &TestServer) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Ping server
+    client.ping(&Ping {}).await.unwrap();
+
+    // 2. Shutdown the server and ensure that the connected client is notified
+    test_server.request_shutdown();
+    let mut result = client.ping(&Ping {}).await;
+    for _ in 0..ATTEMPTS {
+        if result.is_err() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+        result = client.ping(&Ping {}).await;
+    }
+    assert!(matches!(result, Err(Error::ServerShuttingDown)));
+
+    // 3. Ensure that the client has been disconnected
+    let result = client.ping(&Ping {}).await;
+    assert!(matches!(result, Err(Error::NotConnected)));
+}
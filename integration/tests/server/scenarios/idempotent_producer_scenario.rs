@@ -0,0 +1,252 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{
+    IdempotentProducerConfig, IggyClient, IggyClientConfig, SendMessagesConfig,
+};
+use iggy::consumer::Consumer;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::register_producer::RegisterProducer;
+use iggy::messages::send_messages::{
+    Acknowledgement, Message, Partitioning, ProducerSequence, SendMessages,
+};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use tokio::time::{sleep, Duration};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+const SEND_INTERVAL_MS: u64 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Register the new producer
+    let producer = client
+        .register_producer(&RegisterProducer { producer_id: 0 })
+        .await
+        .unwrap();
+    assert!(producer.producer_id > 0);
+    assert_eq!(producer.epoch, 1);
+
+    // 2. Send the batch and retry it with the same sequence number, which should be ignored
+    let producer_sequence = ProducerSequence {
+        producer_id: producer.producer_id,
+        epoch: producer.epoch,
+        sequence: 1,
+    };
+    let mut send_messages = create_send_messages(Some(producer_sequence));
+    client.send_messages(&mut send_messages).await.unwrap();
+    let mut send_messages = create_send_messages(Some(producer_sequence));
+    client.send_messages(&mut send_messages).await.unwrap();
+    assert_messages_count(&client, MESSAGES_COUNT).await;
+
+    // 3. Send the next batch with the incremented sequence number
+    let mut send_messages = create_send_messages(Some(ProducerSequence {
+        sequence: 2,
+        ..producer_sequence
+    }));
+    client.send_messages(&mut send_messages).await.unwrap();
+    assert_messages_count(&client, 2 * MESSAGES_COUNT).await;
+
+    // 4. The batches with a gap after the last sequence number, or older than the last one, should be rejected
+    let mut send_messages = create_send_messages(Some(ProducerSequence {
+        sequence: 4,
+        ..producer_sequence
+    }));
+    let error = client.send_messages(&mut send_messages).await.unwrap_err();
+    assert_eq!(
+        error.as_server_code(),
+        Error::ProducerSequenceGap(0, 0, 0).as_code()
+    );
+    let mut send_messages = create_send_messages(Some(producer_sequence));
+    let error = client.send_messages(&mut send_messages).await.unwrap_err();
+    assert_eq!(
+        error.as_server_code(),
+        Error::ProducerSequenceOutOfOrder(0, 0, 0).as_code()
+    );
+    assert_messages_count(&client, 2 * MESSAGES_COUNT).await;
+
+    // 5. Register the same producer again, which should fence its previous epoch
+    let registered_producer = client
+        .register_producer(&RegisterProducer {
+            producer_id: producer.producer_id,
+        })
+        .await
+        .unwrap();
+    assert_eq!(registered_producer.producer_id, producer.producer_id);
+    assert_eq!(registered_producer.epoch, 2);
+    let mut send_messages = create_send_messages(Some(ProducerSequence {
+        sequence: 3,
+        ..producer_sequence
+    }));
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+    assert_messages_count(&client, 2 * MESSAGES_COUNT).await;
+
+    // 6. Send the batches using the client with the idempotent producer enabled, which assigns the sequence numbers on its own
+    let idempotent_client = client_factory.create_client().await;
+    let idempotent_client = IggyClient::create(
+        idempotent_client,
+        IggyClientConfig {
+            idempotent_producer: IdempotentProducerConfig {
+                enabled: true,
+                producer_id: 0,
+            },
+            ..Default::default()
+        },
+        None,
+        None,
+        None,
+    );
+    login_root(&idempotent_client).await;
+    let mut send_messages = create_send_messages(None);
+    idempotent_client
+        .send_messages(&mut send_messages)
+        .await
+        .unwrap();
+    assert!(send_messages.producer.is_none());
+    let mut send_messages = create_send_messages(None);
+    idempotent_client
+        .send_messages(&mut send_messages)
+        .await
+        .unwrap();
+    assert_messages_count(&client, 4 * MESSAGES_COUNT).await;
+
+    // 7. Send the batches in the background using the same producer, which should stop sending once it's fenced by a newer epoch
+    let background_client = client_factory.create_client().await;
+    let background_client = IggyClient::create(
+        background_client,
+        IggyClientConfig {
+            send_messages: SendMessagesConfig {
+                enabled: true,
+                interval: SEND_INTERVAL_MS,
+                max_messages: MESSAGES_COUNT,
+            },
+            idempotent_producer: IdempotentProducerConfig {
+                enabled: true,
+                producer_id: producer.producer_id,
+            },
+            ..Default::default()
+        },
+        None,
+        None,
+        None,
+    );
+    login_root(&background_client).await;
+    let mut send_messages = create_send_messages(None);
+    background_client
+        .send_messages(&mut send_messages)
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(20 * SEND_INTERVAL_MS)).await;
+    assert_messages_count(&client, 5 * MESSAGES_COUNT).await;
+
+    client
+        .register_producer(&RegisterProducer {
+            producer_id: producer.producer_id,
+        })
+        .await
+        .unwrap();
+    let mut send_messages = create_send_messages(None);
+    background_client
+        .send_messages(&mut send_messages)
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(20 * SEND_INTERVAL_MS)).await;
+    let mut send_messages = create_send_messages(None);
+    let error = background_client
+        .send_messages(&mut send_messages)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_server_code(),
+        Error::ProducerFenced(0, 0).as_code()
+    );
+    assert_messages_count(&client, 5 * MESSAGES_COUNT).await;
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn assert_messages_count(client: &IggyClient, expected_messages_count: u32) {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 10 * MESSAGES_COUNT,
+        auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        expected_messages_count
+    );
+}
+
+fn create_send_messages(producer: Option<ProducerSequence>) -> SendMessages {
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+            deliver_at: None,
+        });
+    }
+
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        acknowledgement: Acknowledgement::default(),
+        producer,
+        messages,
+        transaction_id: None,
+    }
+}
@@ -3,8 +3,8 @@ use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
@@ -39,6 +39,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             length: payload.len() as u32,
             payload,
             headers: Some(headers),
+            deliver_at: None,
         });
     }
 
@@ -46,7 +47,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        acknowledgement: Acknowledgement::default(),
+        producer: None,
         messages,
+        transaction_id: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -59,6 +63,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -109,6 +114,7 @@ async fn init_system(client: &IggyClient) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        config: HashMap::new(),
     };
     client.create_topic(&create_topic).await.unwrap();
 }
@@ -1,6 +1,14 @@
 pub mod consumer_group_join_scenario;
+pub mod consumer_group_members_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod delayed_messages_scenario;
+pub mod graceful_shutdown_scenario;
+pub mod idempotent_producer_scenario;
 pub mod message_headers_scenario;
+pub mod soft_delete_scenario;
+pub mod stream_messages_scenario;
 pub mod system_scenario;
+pub mod transaction_scenario;
+pub mod unacknowledged_messages_scenario;
 pub mod user_scenario;
@@ -0,0 +1,220 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::stream::StreamDetails;
+use iggy::models::topic::TopicDetails;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::restore_stream::RestoreStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::restore_topic::RestoreTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use tokio::time::{sleep, Duration};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const RENAMED_STREAM_NAME: &str = "renamed-stream";
+const RENAMED_TOPIC_NAME: &str = "renamed-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+/// The server must be started with these overrides, so that the soft-deleted resources are purged during the scenario.
+pub fn soft_delete_envs() -> HashMap<String, String> {
+    HashMap::from([
+        (
+            "IGGY_SYSTEM_SOFT_DELETE_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_SOFT_DELETE_GRACE_PERIOD".to_string(),
+            "2s".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_SOFT_DELETE_CLEANER_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_SOFT_DELETE_CLEANER_INTERVAL".to_string(),
+            "1s".to_string(),
+        ),
+    ])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+    send_messages(&client).await;
+
+    // 1. The deleted topic should be hidden, until it's restored along with its messages
+    let delete_topic = DeleteTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+    };
+    client.delete_topic(&delete_topic).await.unwrap();
+    assert!(get_topic(&client, Identifier::numeric(TOPIC_ID).unwrap())
+        .await
+        .is_err());
+
+    let restore_topic = RestoreTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+    };
+    client.restore_topic(&restore_topic).await.unwrap();
+    assert!(get_topic(&client, Identifier::numeric(TOPIC_ID).unwrap())
+        .await
+        .is_ok());
+    assert_eq!(poll_messages(&client).await.len() as u32, MESSAGES_COUNT);
+
+    // 2. The deleted stream should be hidden, until it's restored along with its topics and messages
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+    assert!(get_stream(&client, Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .is_err());
+
+    let restore_stream = RestoreStream {
+        stream_id: Identifier::named(STREAM_NAME).unwrap(),
+    };
+    client.restore_stream(&restore_stream).await.unwrap();
+    let stream = get_stream(&client, Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.topics_count, 1);
+    assert_eq!(poll_messages(&client).await.len() as u32, MESSAGES_COUNT);
+
+    // 3. The renamed stream and topic should be still available by their previous names
+    let update_stream = UpdateStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        name: RENAMED_STREAM_NAME.to_string(),
+    };
+    client.update_stream(&update_stream).await.unwrap();
+    let update_topic = UpdateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        name: RENAMED_TOPIC_NAME.to_string(),
+        ..Default::default()
+    };
+    client.update_topic(&update_topic).await.unwrap();
+
+    let stream = get_stream(&client, Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.id, STREAM_ID);
+    assert_eq!(stream.name, RENAMED_STREAM_NAME);
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.id, TOPIC_ID);
+    assert_eq!(topic.name, RENAMED_TOPIC_NAME);
+
+    // 4. The soft-deleted stream should keep its ID reserved until it's purged by the cleaner after the grace period
+    client.delete_stream(&delete_stream).await.unwrap();
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    assert!(client.create_stream(&create_stream).await.is_err());
+
+    sleep(Duration::from_secs(5)).await;
+    let restore_stream = RestoreStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    assert!(client.restore_stream(&restore_stream).await.is_err());
+    client.create_stream(&create_stream).await.unwrap();
+    let stream = get_stream(&client, Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.topics_count, 0);
+
+    client.delete_stream(&delete_stream).await.unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient) {
+    let messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::new(None, Bytes::from(format!("message {}", offset)), None))
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        ..Default::default()
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn get_stream(client: &IggyClient, stream_id: Identifier) -> Result<StreamDetails, Error> {
+    client.get_stream(&GetStream { stream_id }).await
+}
+
+async fn get_topic(client: &IggyClient, topic_id: Identifier) -> Result<TopicDetails, Error> {
+    client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id,
+        })
+        .await
+}
+
+async fn poll_messages(client: &IggyClient) -> Vec<String> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 2 * MESSAGES_COUNT,
+        auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    polled_messages
+        .messages
+        .iter()
+        .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+        .collect()
+}
@@ -0,0 +1,173 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::http_client::{get_root_access_token, HttpClientFactory};
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use reqwest::{Response, StatusCode};
+use std::collections::HashMap;
+use tokio::time::{timeout, Duration};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u64 = 5;
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub async fn run(client_factory: &HttpClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+    let http = reqwest::Client::new();
+    let url = format!(
+        "http://{}/streams/{STREAM_ID}/topics/{TOPIC_ID}/messages/stream",
+        client_factory.server_addr
+    );
+    let token = get_root_access_token(&client_factory.server_addr).await;
+
+    // 1. Streaming the messages without the partition ID should be rejected
+    let response = http
+        .get(&url)
+        .query(&[("kind", "offset"), ("value", "0")])
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // 2. Stream the messages which are already in the partition
+    send_messages(&client, 0, MESSAGES_COUNT).await;
+    let mut events = EventsReader::new(
+        http.get(&url)
+            .query(&[("partition_id", "1"), ("kind", "offset"), ("value", "0")])
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap(),
+    );
+    for offset in 0..MESSAGES_COUNT {
+        assert_eq!(events.next_message_offset().await, offset);
+    }
+
+    // 3. The messages appended afterwards should be streamed right away
+    send_messages(&client, MESSAGES_COUNT, MESSAGES_COUNT).await;
+    for offset in MESSAGES_COUNT..2 * MESSAGES_COUNT {
+        assert_eq!(events.next_message_offset().await, offset);
+    }
+    drop(events);
+
+    // 4. The stream should resume right after the last event ID, regardless of the polling strategy
+    let mut events = EventsReader::new(
+        http.get(&url)
+            .query(&[("partition_id", "1"), ("kind", "offset"), ("value", "0")])
+            .header("Last-Event-ID", (MESSAGES_COUNT + 1).to_string())
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap(),
+    );
+    for offset in MESSAGES_COUNT + 2..2 * MESSAGES_COUNT {
+        assert_eq!(events.next_message_offset().await, offset);
+    }
+    drop(events);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+struct EventsReader {
+    response: Response,
+    buffer: String,
+}
+
+impl EventsReader {
+    fn new(response: Response) -> Self {
+        assert_eq!(response.status(), StatusCode::OK);
+        Self {
+            response,
+            buffer: String::new(),
+        }
+    }
+
+    async fn next_message_offset(&mut self) -> u64 {
+        loop {
+            if let Some(position) = self.buffer.find("\n\n") {
+                let event = self.buffer[..position].to_string();
+                self.buffer.drain(..position + 2);
+                let mut kind = None;
+                let mut id = None;
+                for line in event.lines() {
+                    if let Some(value) = line.strip_prefix("event:") {
+                        kind = Some(value.trim().to_string());
+                    } else if let Some(value) = line.strip_prefix("id:") {
+                        id = Some(value.trim().parse::<u64>().unwrap());
+                    }
+                }
+
+                match kind.as_deref() {
+                    Some("message") => return id.unwrap(),
+                    Some(kind) => panic!("Unexpected event: {kind}, {event}"),
+                    // The keep-alive comments don't have the event kind.
+                    None => continue,
+                }
+            }
+
+            let chunk = timeout(EVENT_TIMEOUT, self.response.chunk())
+                .await
+                .expect("No event received in time")
+                .unwrap()
+                .expect("Stream closed");
+            self.buffer.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    }
+}
+
+async fn send_messages(client: &IggyClient, start: u64, count: u64) {
+    let messages = (start..start + count)
+        .map(|index| Message::new(None, Bytes::from(format!("message {index}")), None))
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        ..Default::default()
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
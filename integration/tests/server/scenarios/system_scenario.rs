@@ -15,8 +15,8 @@ use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::streams::create_stream::CreateStream;
@@ -29,6 +29,8 @@ use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::reload_config::ReloadConfig;
+use iggy::system::set_log_level::{LogLevel, SetLogLevel};
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
@@ -38,6 +40,7 @@ use iggy::topics::update_topic::UpdateTopic;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
 use integration::test_server::{assert_clean_system, ClientFactory};
+use std::collections::HashMap;
 
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
@@ -131,6 +134,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        config: HashMap::new(),
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -221,7 +225,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        acknowledgement: Acknowledgement::default(),
+        producer: None,
         messages,
+        transaction_id: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -237,6 +244,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -263,6 +271,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            isolation: IsolationLevel::ReadUncommitted,
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -307,6 +316,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
@@ -340,6 +350,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partition_id: Some(PARTITION_ID),
             offset: stored_offset,
+            transaction_id: None,
         })
         .await
         .unwrap();
@@ -374,6 +385,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        isolation: IsolationLevel::ReadUncommitted,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -588,6 +600,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             name: updated_topic_name.clone(),
             message_expiry: Some(updated_message_expiry),
+            config: None,
         })
         .await
         .unwrap();
@@ -642,7 +655,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        acknowledgement: Acknowledgement::Persisted,
+        producer: None,
         messages,
+        transaction_id: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -688,6 +704,18 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert!(clients.len() <= 1);
 
+    // 43. Change the log level at runtime and restore the default one
+    client
+        .set_log_level(&SetLogLevel {
+            level: LogLevel::Debug,
+        })
+        .await
+        .unwrap();
+    client.set_log_level(&SetLogLevel::default()).await.unwrap();
+
+    // 44. Reload the server config
+    client.reload_config(&ReloadConfig {}).await.unwrap();
+
     assert_clean_system(&client).await;
 }
 
@@ -708,6 +736,7 @@ fn create_messages() -> Vec<Message> {
             length: payload.len() as u32,
             payload,
             headers: None,
+            deliver_at: None,
         });
     }
     messages
@@ -0,0 +1,195 @@
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerOffsetClient, MessageClient, StreamClient, TopicClient, TransactionClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages within the open transaction, which should be hidden from the read-committed consumer
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    let mut send_messages = create_send_messages(Some(transaction.transaction_id));
+    client.send_messages(&mut send_messages).await.unwrap();
+    assert_messages_count(&client, IsolationLevel::ReadUncommitted, MESSAGES_COUNT).await;
+    assert_messages_count(&client, IsolationLevel::ReadCommitted, 0).await;
+
+    // 2. Commit the transaction, which should make the messages visible
+    client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.transaction_id,
+        })
+        .await
+        .unwrap();
+    assert_messages_count(&client, IsolationLevel::ReadCommitted, MESSAGES_COUNT).await;
+
+    // 3. Abort the next transaction, which should hide its messages forever
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    let mut send_messages = create_send_messages(Some(transaction.transaction_id));
+    client.send_messages(&mut send_messages).await.unwrap();
+    client
+        .abort_transaction(&AbortTransaction {
+            transaction_id: transaction.transaction_id,
+        })
+        .await
+        .unwrap();
+    let mut send_messages = create_send_messages(None);
+    client.send_messages(&mut send_messages).await.unwrap();
+    assert_messages_count(&client, IsolationLevel::ReadUncommitted, 3 * MESSAGES_COUNT).await;
+    assert_messages_count(&client, IsolationLevel::ReadCommitted, 2 * MESSAGES_COUNT).await;
+
+    // 4. Store the consumer offset within the transaction, which should be applied only once it's committed
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    client
+        .store_consumer_offset(&StoreConsumerOffset {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            offset: 5,
+            transaction_id: Some(transaction.transaction_id),
+        })
+        .await
+        .unwrap();
+    assert_stored_offset(&client, 0).await;
+    client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.transaction_id,
+        })
+        .await
+        .unwrap();
+    assert_stored_offset(&client, 5).await;
+
+    // 5. The completed transaction can't be completed again
+    assert!(client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.transaction_id,
+        })
+        .await
+        .is_err());
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn assert_messages_count(
+    client: &IggyClient,
+    isolation: IsolationLevel,
+    expected_messages_count: u32,
+) {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 10 * MESSAGES_COUNT,
+        auto_commit: false,
+        isolation,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        expected_messages_count
+    );
+}
+
+async fn assert_stored_offset(client: &IggyClient, expected_offset: u64) {
+    let offset = client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(offset.stored_offset, expected_offset);
+}
+
+fn create_send_messages(transaction_id: Option<u64>) -> SendMessages {
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+            deliver_at: None,
+        });
+    }
+
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        acknowledgement: Acknowledgement::default(),
+        producer: None,
+        messages,
+        transaction_id,
+    }
+}
@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use tokio::time::{sleep, Duration};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const BATCHES_COUNT: u32 = 100;
+const PARTITION_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the batches without acknowledgement, one message each
+    for index in 0..BATCHES_COUNT {
+        let mut send_messages = SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(PARTITION_ID),
+            acknowledgement: Acknowledgement::None,
+            messages: vec![Message::new(
+                None,
+                Bytes::from(format!("message {}", index)),
+                None,
+            )],
+            ..Default::default()
+        };
+        client.send_messages(&mut send_messages).await.unwrap();
+    }
+
+    // 2. All the messages should be eventually appended in the same order as they were sent
+    let mut polled_messages = Vec::new();
+    for _ in 0..50 {
+        polled_messages = poll_messages(&client).await;
+        if polled_messages.len() as u32 == BATCHES_COUNT {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    assert_eq!(polled_messages.len() as u32, BATCHES_COUNT);
+    for (index, payload) in polled_messages.iter().enumerate() {
+        assert_eq!(payload, &format!("message {}", index));
+    }
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        config: HashMap::new(),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn poll_messages(client: &IggyClient) -> Vec<String> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 2 * BATCHES_COUNT,
+        auto_commit: false,
+        isolation: IsolationLevel::ReadUncommitted,
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    polled_messages
+        .messages
+        .iter()
+        .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+        .collect()
+}
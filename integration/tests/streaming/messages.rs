@@ -80,7 +80,10 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
 
     setup.create_partitions_directory(stream_id, topic_id).await;
     partition.persist().await.unwrap();
-    partition.append_messages(messages).await.unwrap();
+    partition
+        .append_messages(messages, None, None)
+        .await
+        .unwrap();
     assert_eq!(partition.unsaved_messages_count, 0);
 
     let mut loaded_partition = Partition::create(
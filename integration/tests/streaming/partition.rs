@@ -1,5 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use iggy::messages::send_messages::ProducerSequence;
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
 use tokio::fs;
@@ -134,7 +135,10 @@ async fn should_purge_existing_partition_on_disk() {
         assert_persisted_partition(&partition.path, with_segment).await;
         let messages = create_messages();
         let messages_count = messages.len();
-        partition.append_messages(messages).await.unwrap();
+        partition
+            .append_messages(messages, None, None)
+            .await
+            .unwrap();
         let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
         assert_eq!(loaded_messages.len(), messages_count);
         partition.purge().await.unwrap();
@@ -146,6 +150,66 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_ignore_already_appended_producer_batch_after_loading_partition_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 1;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    let producer = ProducerSequence {
+        producer_id: 1,
+        epoch: 1,
+        sequence: 1,
+    };
+    let messages = create_messages();
+    let messages_count = messages.len();
+    partition
+        .append_messages(messages, Some(&producer), None)
+        .await
+        .unwrap();
+    partition.persist_unsaved_messages().await.unwrap();
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    loaded_partition
+        .append_messages(create_messages(), Some(&producer), None)
+        .await
+        .unwrap();
+
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count);
+    let fenced_producer = ProducerSequence {
+        epoch: 0,
+        ..producer
+    };
+    assert!(loaded_partition
+        .append_messages(create_messages(), Some(&fenced_producer), None)
+        .await
+        .is_err());
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
@@ -1,6 +1,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
+use iggy::utils::crypto::{Aes256GcmEncryptor, Aes256GcmKeyring, Encryptor};
 use iggy::utils::{checksum, timestamp::TimeStamp};
 use server::streaming::segments::segment;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
@@ -253,6 +254,221 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     assert!(!is_expired);
 }
 
+#[tokio::test]
+async fn closed_segment_should_be_reencrypted_with_active_key() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let old_keyring = Aes256GcmKeyring::new(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+    let mut new_keyring = Aes256GcmKeyring::new(2, Aes256GcmEncryptor::new(&[2; 32]).unwrap());
+    new_keyring
+        .add_decryption_key(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap())
+        .unwrap();
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let payload = old_keyring.encrypt(format!("test-{i}").as_bytes()).unwrap();
+        let message = create_message(i, "", TimeStamp::now().to_micros());
+        let message = Message::create(
+            message.offset,
+            message.state,
+            message.timestamp,
+            message.id,
+            Bytes::from(payload.clone()),
+            checksum::calculate(&payload),
+            None,
+        );
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+    segment.is_closed = true;
+
+    let reencrypted_messages_count = segment.reencrypt_messages(&new_keyring).await.unwrap();
+    assert_eq!(reencrypted_messages_count, messages_count as u32);
+    let reencrypted_messages_count = segment.reencrypt_messages(&new_keyring).await.unwrap();
+    assert_eq!(reencrypted_messages_count, 0);
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    assert_eq!(
+        loaded_segment.current_size_bytes,
+        segment.current_size_bytes
+    );
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, i as u64);
+        assert_eq!(message.checksum, checksum::calculate(&message.payload));
+        assert!(!new_keyring.requires_reencryption(&message.payload));
+        assert!(old_keyring.decrypt(&message.payload).is_err());
+        let payload = new_keyring.decrypt(&message.payload).unwrap();
+        assert_eq!(payload, format!("test-{i}").as_bytes());
+    }
+}
+
+#[tokio::test]
+async fn closed_segment_with_unencrypted_messages_should_be_skipped_by_reencryption() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let keyring = Aes256GcmKeyring::new(1, Aes256GcmEncryptor::new(&[1; 32]).unwrap());
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, &format!("test-{i}"), TimeStamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+    segment.is_closed = true;
+    let size_bytes = segment.current_size_bytes;
+
+    let reencrypted_messages_count = segment.reencrypt_messages(&keyring).await.unwrap();
+    assert_eq!(reencrypted_messages_count, 0);
+    let reencrypted_messages = segment
+        .prepare_reencrypted_messages(&keyring)
+        .await
+        .unwrap();
+    assert!(reencrypted_messages.is_none());
+    assert_eq!(segment.current_size_bytes, size_bytes);
+
+    let messages = segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.payload, format!("test-{i}").as_bytes());
+    }
+}
+
+#[tokio::test]
+async fn interrupted_messages_replacement_should_be_recovered_when_loading_segment() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, &format!("test-{i}"), TimeStamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+
+    let log_replacement_path = format!("{}.tmp", segment.log_path);
+    let index_replacement_path = format!("{}.tmp", segment.index_path);
+
+    // The log wasn't replaced yet, so the replacement is rolled back.
+    fs::write(&log_replacement_path, b"partial").await.unwrap();
+    fs::write(&index_replacement_path, b"partial")
+        .await
+        .unwrap();
+    assert_loaded_segment_messages(&setup, &segment, messages_count).await;
+    assert!(fs::metadata(&log_replacement_path).await.is_err());
+    assert!(fs::metadata(&index_replacement_path).await.is_err());
+
+    // The log was already replaced, so the replacement is completed by renaming the index.
+    fs::copy(&segment.index_path, &index_replacement_path)
+        .await
+        .unwrap();
+    fs::write(&segment.index_path, b"").await.unwrap();
+    assert_loaded_segment_messages(&setup, &segment, messages_count).await;
+    assert!(fs::metadata(&index_replacement_path).await.is_err());
+}
+
+async fn assert_loaded_segment_messages(
+    setup: &TestSetup,
+    segment: &segment::Segment,
+    messages_count: u64,
+) {
+    let mut loaded_segment = segment::Segment::create(
+        segment.stream_id,
+        segment.topic_id,
+        segment.partition_id,
+        segment.start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, i as u64);
+        assert_eq!(message.payload, format!("test-{i}").as_bytes());
+    }
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
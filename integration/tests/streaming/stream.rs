@@ -1,10 +1,11 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Partitioning};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
+use std::collections::HashMap;
 use tokio::fs;
 
 #[tokio::test]
@@ -76,6 +77,36 @@ async fn should_delete_existing_stream_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_save_load_and_delete_stream_data_key() {
+    let setup = TestSetup::init().await;
+    setup.create_streams_directory().await;
+    let stream_id = 1;
+    let stream = Stream::create(
+        stream_id,
+        "test",
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    stream.persist().await.unwrap();
+    let wrapped_data_key = vec![1, 2, 3, 4];
+
+    setup
+        .storage
+        .stream
+        .save_data_key(&stream, &wrapped_data_key)
+        .await
+        .unwrap();
+
+    let loaded_data_key = setup.storage.stream.load_data_key(&stream).await.unwrap();
+    assert_eq!(loaded_data_key, Some(wrapped_data_key));
+
+    stream.delete().await.unwrap();
+
+    let loaded_data_key = setup.storage.stream.load_data_key(&stream).await.unwrap();
+    assert!(loaded_data_key.is_none());
+}
+
 #[tokio::test]
 async fn should_purge_existing_stream_on_disk() {
     let setup = TestSetup::init().await;
@@ -94,7 +125,7 @@ async fn should_purge_existing_stream_on_disk() {
 
         let topic_id = 1;
         stream
-            .create_topic(topic_id, "test", 1, None)
+            .create_topic(topic_id, "test", 1, None, HashMap::new())
             .await
             .unwrap();
 
@@ -104,7 +135,13 @@ async fn should_purge_existing_stream_on_disk() {
             .get_topic(&Identifier::numeric(topic_id).unwrap())
             .unwrap();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(
+                &Partitioning::partition_id(1),
+                messages,
+                Acknowledgement::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -113,6 +150,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -126,6 +164,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -1,9 +1,15 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::identifier::Identifier;
+use iggy::messages::send_messages;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning, ProducerSequence};
 use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
+use std::collections::HashMap;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
@@ -82,6 +88,172 @@ async fn should_delete_persisted_stream() {
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_create_backup_and_restore_it_in_another_directory() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let messages_count = 10;
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system.create_stream(&session, 1, "test").await.unwrap();
+    system
+        .create_topic(&session, &stream_id, 1, "test", 1, None, HashMap::new())
+        .await
+        .unwrap();
+    let messages = (0..messages_count)
+        .map(|id| send_messages::Message::from_str(&format!("message-{id}")).unwrap())
+        .collect::<Vec<_>>();
+    system
+        .append_messages(
+            &session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &messages,
+            Acknowledgement::default(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let backup = system.create_backup(&session).await.unwrap();
+
+    assert!(backup.name.starts_with("backup_"));
+    assert_eq!(backup.files_count, 3);
+    assert!(backup.size_bytes > 0);
+
+    let restored_config = Arc::new(SystemConfig {
+        path: format!("{}_restored", setup.config.get_system_path()),
+        ..Default::default()
+    });
+    System::restore_backup(&restored_config, &backup.path)
+        .await
+        .unwrap();
+    let mut restored_system = System::new(
+        restored_config.clone(),
+        None,
+        PersonalAccessTokenConfig::default(),
+    );
+    restored_system.init().await.unwrap();
+
+    let stream = restored_system.get_stream(&stream_id).unwrap();
+    let topic = stream.get_topic(&topic_id).unwrap();
+    let partition = topic.get_partition(1).unwrap();
+    let partition = partition.read().await;
+    assert_eq!(partition.current_offset, messages_count - 1);
+    drop(partition);
+    drop(restored_system);
+    fs::remove_dir_all(restored_config.get_system_path())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_ignore_retried_producer_batch_after_restoring_backup() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let messages_count = 10;
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system.create_stream(&session, 1, "test").await.unwrap();
+    system
+        .create_topic(&session, &stream_id, 1, "test", 1, None, HashMap::new())
+        .await
+        .unwrap();
+    let producer = system.register_producer(&session, 0).await.unwrap();
+    let producer = ProducerSequence {
+        producer_id: producer.producer_id,
+        epoch: producer.epoch,
+        sequence: 1,
+    };
+    let messages = (0..messages_count)
+        .map(|id| send_messages::Message::from_str(&format!("message-{id}")).unwrap())
+        .collect::<Vec<_>>();
+    system
+        .append_messages(
+            &session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &messages,
+            Acknowledgement::default(),
+            Some(producer),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let backup = system.create_backup(&session).await.unwrap();
+
+    let restored_config = Arc::new(SystemConfig {
+        path: format!("{}_restored", setup.config.get_system_path()),
+        ..Default::default()
+    });
+    System::restore_backup(&restored_config, &backup.path)
+        .await
+        .unwrap();
+    let mut restored_system = System::new(
+        restored_config.clone(),
+        None,
+        PersonalAccessTokenConfig::default(),
+    );
+    restored_system.init().await.unwrap();
+    restored_system
+        .append_messages(
+            &session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &messages,
+            Acknowledgement::default(),
+            Some(producer),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let stream = restored_system.get_stream(&stream_id).unwrap();
+    let topic = stream.get_topic(&topic_id).unwrap();
+    let partition = topic.get_partition(1).unwrap();
+    let partition = partition.read().await;
+    assert_eq!(partition.current_offset, messages_count - 1);
+    drop(partition);
+    drop(restored_system);
+    fs::remove_dir_all(restored_config.get_system_path())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_not_restore_backup_over_existing_data() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    let backup = system.create_backup(&session).await.unwrap();
+
+    let result = System::restore_backup(&setup.config, &backup.path).await;
+
+    assert!(result.is_err());
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
@@ -1,7 +1,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
-use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::send_messages::{Acknowledgement, Partitioning};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
@@ -143,7 +143,13 @@ async fn should_purge_existing_topic_on_disk() {
         let messages = create_messages();
         let messages_count = messages.len();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(
+                &Partitioning::partition_id(1),
+                messages,
+                Acknowledgement::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -152,6 +158,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -164,6 +171,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
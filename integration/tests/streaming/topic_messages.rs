@@ -1,16 +1,22 @@
 use crate::streaming::common::test_setup::TestSetup;
 use byte_unit::Byte;
-use iggy::messages::poll_messages::PollingStrategy;
+use bytes::Bytes;
+use iggy::error::Error;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::Message;
+use iggy::topics::config;
 use server::configs::resource_quota::MemoryResourceQuota;
 use server::configs::system::{CacheConfig, SystemConfig};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use server::streaming::utils::hash;
 use std::collections::HashMap;
+use std::path::Path;
 use std::str::{from_utf8, FromStr};
+use tokio::fs;
 
 #[tokio::test]
 async fn given_disabled_cache_all_messages_should_be_polled() {
@@ -69,7 +75,13 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         sent_messages.push(get_message(from_utf8(&message.payload).unwrap()))
     }
     topic
-        .append_messages(&partitioning, messages)
+        .append_messages(
+            &partitioning,
+            messages,
+            Acknowledgement::default(),
+            None,
+            None,
+        )
         .await
         .unwrap();
 
@@ -80,6 +92,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            IsolationLevel::ReadUncommitted,
         )
         .await
         .unwrap();
@@ -109,7 +122,13 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -129,7 +148,13 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -153,7 +178,13 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
         let payload = get_payload(entity_id);
         let partitioning = Partitioning::messages_key_u32(entity_id);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -178,6 +209,268 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
     }
 }
 
+#[tokio::test]
+async fn given_key_range_key_messages_should_be_appended_to_the_partition_of_its_range() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 3;
+    let mut topic = init_topic(&setup, partitions_count).await;
+    topic
+        .update_config_overrides(HashMap::from([(
+            config::TOPIC_KEY_RANGES.to_string(),
+            "g;n".to_string(),
+        )]))
+        .await
+        .unwrap();
+    let keys = [
+        "apple", "banana", "grape", "kiwi", "mango", "orange", "peach",
+    ];
+    for (index, key) in keys.iter().enumerate() {
+        let partitioning = Partitioning::key_range_str(key).unwrap();
+        topic
+            .append_messages(
+                &partitioning,
+                vec![get_message(&get_payload(index as u32))],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    assert_messages(&topic, 1, 2).await;
+    assert_messages(&topic, 2, 3).await;
+    assert_messages(&topic, 3, 2).await;
+}
+
+#[tokio::test]
+async fn given_persisted_acknowledgement_messages_should_be_saved_on_disk_before_returning() {
+    let setup = TestSetup::init().await;
+    let partition_id = 1;
+    let messages_count = 10;
+    let topic = init_topic(&setup, 1).await;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let messages = (1..=messages_count)
+        .map(|i| get_message(&get_payload(i)))
+        .collect::<Vec<_>>();
+    assert!(messages_count < setup.config.partition.messages_required_to_save);
+
+    topic
+        .append_messages(
+            &partitioning,
+            messages,
+            Acknowledgement::Persisted,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let partition = topic.get_partition(partition_id).unwrap();
+    let partition = partition.read().await;
+    let segment = partition.get_segments().last().unwrap();
+    let log_file_size = tokio::fs::metadata(&segment.log_path).await.unwrap().len();
+    assert_eq!(log_file_size, segment.current_size_bytes as u64);
+    assert!(log_file_size > 0);
+    assert_messages(&topic, partition_id, messages_count).await;
+}
+
+#[tokio::test]
+async fn given_key_header_repartitioned_topic_should_keep_messages_of_the_same_key_in_order() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 3;
+    let new_partitions_count = 2;
+    let keys_count = 10;
+    let messages_per_key_count = 5;
+    let key_header = HeaderKey::new("key").unwrap();
+    let mut topic = init_topic(&setup, partitions_count).await;
+    for i in 0..keys_count * messages_per_key_count {
+        let key = i % keys_count + 1;
+        let partitioning = Partitioning::messages_key_u32(key);
+        let headers = HashMap::from([(key_header.clone(), HeaderValue::from_uint32(key).unwrap())]);
+        let message = send_messages::Message::new(None, Bytes::from(get_payload(i)), Some(headers));
+        topic
+            .append_messages(
+                &partitioning,
+                vec![Message::from_message(&message)],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    let layout = topic
+        .prepare_layout(new_partitions_count, key_header.clone())
+        .await
+        .unwrap();
+    let replaced_partitions = topic.complete_layout(layout).await.unwrap();
+
+    assert_eq!(replaced_partitions.old_partitions_count, partitions_count);
+    assert_eq!(
+        replaced_partitions.new_partitions_count,
+        new_partitions_count
+    );
+    assert_eq!(
+        replaced_partitions.new_messages_count,
+        (keys_count * messages_per_key_count) as u64
+    );
+    assert_eq!(topic.get_partitions_count(), new_partitions_count);
+    let mut polled_messages_count = 0;
+    for partition_id in 1..=new_partitions_count {
+        let consumer = PollingConsumer::Consumer(0, partition_id);
+        let polled_messages = topic
+            .get_messages(
+                consumer,
+                partition_id,
+                PollingStrategy::offset(0),
+                1000,
+                IsolationLevel::ReadUncommitted,
+            )
+            .await
+            .unwrap();
+        let mut last_ids = HashMap::new();
+        for message in &polled_messages.messages {
+            let key = message.headers.as_ref().unwrap()[&key_header]
+                .as_uint32()
+                .unwrap();
+            let hash = hash::calculate_32(&key.to_le_bytes());
+            let mut expected_partition_id = hash % new_partitions_count;
+            if expected_partition_id == 0 {
+                expected_partition_id = new_partitions_count;
+            }
+            assert_eq!(partition_id, expected_partition_id);
+
+            let id = from_utf8(&message.payload)
+                .unwrap()
+                .trim_start_matches("message-")
+                .parse::<u32>()
+                .unwrap();
+            if let Some(last_id) = last_ids.insert(key, id) {
+                assert!(id > last_id);
+            }
+        }
+        polled_messages_count += polled_messages.messages.len() as u32;
+    }
+    assert_eq!(polled_messages_count, keys_count * messages_per_key_count);
+}
+
+#[tokio::test]
+async fn given_message_without_key_header_topic_repartition_should_fail() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 3;
+    let messages_count = 10;
+    let key_header = HeaderKey::new("key").unwrap();
+    let topic = init_topic(&setup, partitions_count).await;
+    for i in 0..messages_count {
+        let headers = match i {
+            0 => None,
+            _ => Some(HashMap::from([(
+                key_header.clone(),
+                HeaderValue::from_uint32(i).unwrap(),
+            )])),
+        };
+        let message = send_messages::Message::new(None, Bytes::from(get_payload(i)), headers);
+        topic
+            .append_messages(
+                &Partitioning::partition_id(1),
+                vec![Message::from_message(&message)],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    let result = topic.prepare_layout(2, key_header).await;
+
+    assert!(matches!(result, Err(Error::MessageKeyHeaderNotFound(..))));
+    assert_eq!(topic.get_partitions_count(), partitions_count);
+    assert_messages(&topic, 1, messages_count).await;
+    let mut dir_entries = fs::read_dir(&topic.path).await.unwrap();
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap() {
+        assert_eq!(dir_entry.path(), Path::new(&topic.partitions_path));
+    }
+}
+
+#[tokio::test]
+async fn given_key_ranges_topic_repartition_should_fail() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 3;
+    let mut topic = init_topic(&setup, partitions_count).await;
+    topic
+        .update_config_overrides(HashMap::from([(
+            config::TOPIC_KEY_RANGES.to_string(),
+            "g;n".to_string(),
+        )]))
+        .await
+        .unwrap();
+
+    let result = topic
+        .prepare_layout(partitions_count, HeaderKey::new("key").unwrap())
+        .await;
+
+    assert!(matches!(result, Err(Error::TopicHasKeyRanges(..))));
+    assert_eq!(topic.get_partitions_count(), partitions_count);
+}
+
+#[tokio::test]
+async fn interrupted_topic_repartition_should_be_rolled_back_when_loading_topic() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 3;
+    let messages_count = 10;
+    let topic = init_topic(&setup, partitions_count).await;
+    for i in 0..messages_count {
+        topic
+            .append_messages(
+                &Partitioning::partition_id(1),
+                vec![get_message(&get_payload(i))],
+                Acknowledgement::default(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    topic
+        .get_partition(1)
+        .unwrap()
+        .write()
+        .await
+        .persist_unsaved_messages()
+        .await
+        .unwrap();
+
+    // The server has stopped after moving the current partitions aside and the staged ones into their place.
+    let replaced_path = format!("{}/replaced_1", topic.path);
+    fs::rename(&topic.partitions_path, &replaced_path)
+        .await
+        .unwrap();
+    fs::create_dir_all(format!("{}/1", topic.partitions_path))
+        .await
+        .unwrap();
+    fs::create_dir_all(format!("{}/repartition_1/1", topic.path))
+        .await
+        .unwrap();
+
+    let mut loaded_topic = Topic::empty(
+        topic.stream_id,
+        topic.topic_id,
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(loaded_topic.get_partitions_count(), partitions_count);
+    assert_messages(&loaded_topic, 1, messages_count).await;
+    let mut dir_entries = fs::read_dir(&topic.path).await.unwrap();
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap() {
+        assert_eq!(dir_entry.path(), Path::new(&topic.partitions_path));
+    }
+}
+
 fn get_payload(id: u32) -> String {
     format!("message-{}", id)
 }
@@ -185,7 +478,13 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            IsolationLevel::ReadUncommitted,
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
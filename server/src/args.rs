@@ -3,6 +3,12 @@ use clap::Parser;
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    /// Configuration provider: `file` (IGGY_CONFIG_PATH), `directory` of the merged fragments (IGGY_CONFIG_PATH)
+    /// or `http` (IGGY_CONFIG_URL).
     #[arg(short, long, default_value = "file")]
     pub config_provider: String,
+
+    /// Path to the backup directory to restore the server data from, before starting the server.
+    #[arg(long)]
+    pub restore: Option<String>,
 }
@@ -12,6 +12,7 @@ use crate::binary::handlers::personal_access_tokens::{
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
+use crate::binary::handlers::transactions::*;
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
     get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
@@ -22,7 +23,9 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::command::Command;
 use iggy::error::Error;
-use tracing::debug;
+use std::fmt::Display;
+use tracing::field::{display, Empty};
+use tracing::{debug, info_span, Instrument, Span};
 
 pub async fn handle(
     command: &Command,
@@ -30,16 +33,77 @@ pub async fn handle(
     session: &mut Session,
     system: SharedSystem,
 ) -> Result<(), Error> {
-    let result = try_handle(command, sender, session, &system).await;
-    if result.is_ok() {
-        debug!("Command was handled successfully, session: {session}.",);
-        return Ok(());
+    let span = create_span(command, session);
+    async {
+        let result = try_handle(command, sender, session, &system).await;
+        // The user might have been logged in or out by the command.
+        Span::current().record("user_id", session.user_id);
+        if result.is_ok() {
+            debug!("Command was handled successfully, session: {session}.",);
+            return Ok(());
+        }
+
+        let error = result.err().unwrap();
+        debug!("Command was not handled successfully, session: {session}, error: {error}.",);
+        sender.send_error_response(error).await?;
+        Ok(())
     }
+    .instrument(span)
+    .await
+}
 
-    let error = result.err().unwrap();
-    debug!("Command was not handled successfully, session: {session}, error: {error}.",);
-    sender.send_error_response(error).await?;
-    Ok(())
+/// Creates the span including the client, the user and (if available) the stream and topic of the command,
+/// so that all the events logged while handling the command can be correlated.
+fn create_span(command: &Command, session: &Session) -> Span {
+    let span = info_span!(
+        "command",
+        client_id = session.client_id,
+        user_id = session.user_id,
+        stream_id = Empty,
+        topic_id = Empty
+    );
+    let (stream_id, topic_id): (Option<&dyn Display>, Option<&dyn Display>) = match command {
+        Command::SendMessages(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::PollMessages(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::GetConsumerOffset(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::StoreConsumerOffset(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        Command::GetStream(command) => (Some(&command.stream_id), None),
+        Command::CreateStream(command) => (Some(&command.stream_id), None),
+        Command::DeleteStream(command) => (Some(&command.stream_id), None),
+        Command::UpdateStream(command) => (Some(&command.stream_id), None),
+        Command::PurgeStream(command) => (Some(&command.stream_id), None),
+        Command::RestoreStream(command) => (Some(&command.stream_id), None),
+        Command::GetTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::GetTopics(command) => (Some(&command.stream_id), None),
+        Command::CreateTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::DeleteTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::UpdateTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::PurgeTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::RestoreTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::RepartitionTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::CreatePartitions(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::DeletePartitions(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::GetConsumerGroup(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::GetConsumerGroups(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::CreateConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        Command::DeleteConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        Command::JoinConsumerGroup(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        Command::LeaveConsumerGroup(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        _ => (None, None),
+    };
+    if let Some(stream_id) = stream_id {
+        span.record("stream_id", display(stream_id));
+    }
+    if let Some(topic_id) = topic_id {
+        span.record("topic_id", display(topic_id));
+    }
+    span
 }
 
 async fn try_handle(
@@ -54,6 +118,15 @@ async fn try_handle(
         Command::GetStats(command) => {
             get_stats_handler::handle(command, sender, session, system).await
         }
+        Command::CreateBackup(command) => {
+            create_backup_handler::handle(command, sender, session, system).await
+        }
+        Command::SetLogLevel(command) => {
+            set_log_level_handler::handle(command, sender, session, system).await
+        }
+        Command::ReloadConfig(command) => {
+            reload_config_handler::handle(command, sender, session, system).await
+        }
         Command::GetMe(command) => get_me_handler::handle(command, sender, session, system).await,
         Command::GetClient(command) => {
             get_client_handler::handle(command, sender, session, system).await
@@ -106,6 +179,18 @@ async fn try_handle(
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        Command::RegisterProducer(command) => {
+            register_producer_handler::handle(command, sender, session, system).await
+        }
+        Command::BeginTransaction(command) => {
+            begin_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::CommitTransaction(command) => {
+            commit_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::AbortTransaction(command) => {
+            abort_transaction_handler::handle(command, sender, session, system).await
+        }
         Command::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -130,6 +215,9 @@ async fn try_handle(
         Command::PurgeStream(command) => {
             purge_stream_handler::handle(command, sender, session, system).await
         }
+        Command::RestoreStream(command) => {
+            restore_stream_handler::handle(command, sender, session, system).await
+        }
         Command::GetTopic(command) => {
             get_topic_handler::handle(command, sender, session, system).await
         }
@@ -148,6 +236,12 @@ async fn try_handle(
         Command::PurgeTopic(command) => {
             purge_topic_handler::handle(command, sender, session, system).await
         }
+        Command::RestoreTopic(command) => {
+            restore_topic_handler::handle(command, sender, session, system).await
+        }
+        Command::RepartitionTopic(command) => {
+            repartition_topic_handler::handle(command, sender, session, system).await
+        }
         Command::CreatePartitions(command) => {
             create_partitions_handler::handle(command, sender, session, system).await
         }
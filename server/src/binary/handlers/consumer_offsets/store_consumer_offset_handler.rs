@@ -24,6 +24,7 @@ pub async fn handle(
             &command.stream_id,
             &command.topic_id,
             command.offset,
+            command.transaction_id,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -25,7 +25,12 @@ pub async fn handle(
             consumer,
             &command.stream_id,
             &command.topic_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
+            PollingArgs::new(
+                command.strategy,
+                command.count,
+                command.auto_commit,
+                command.isolation,
+            ),
         )
         .await?;
     let messages = mapper::map_polled_messages(&messages);
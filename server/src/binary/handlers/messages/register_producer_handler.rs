@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::register_producer::RegisterProducer;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RegisterProducer,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    let producer = system
+        .register_producer(session, command.producer_id)
+        .await?;
+    let bytes = mapper::map_producer(&producer);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -3,8 +3,8 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::Error;
-use iggy::messages::send_messages::SendMessages;
-use tracing::debug;
+use iggy::messages::send_messages::{Acknowledgement, SendMessages};
+use tracing::{debug, error};
 
 pub async fn handle(
     command: &SendMessages,
@@ -13,6 +13,25 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
+    if command.acknowledgement == Acknowledgement::None {
+        sender.send_empty_ok_response().await?;
+        // The response has been already sent, so the error can't be returned to the client anymore.
+        if let Err(error) = append_messages(command, session, system).await {
+            error!("Failed to append messages without acknowledgement, session: {session}. Error: {error}");
+        }
+        return Ok(());
+    }
+
+    append_messages(command, session, system).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
+
+async fn append_messages(
+    command: &SendMessages,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
     let system = system.read();
     system
         .append_messages(
@@ -21,8 +40,9 @@ pub async fn handle(
             &command.topic_id,
             &command.partitioning,
             &command.messages,
+            command.acknowledgement,
+            command.producer,
+            command.transaction_id,
         )
-        .await?;
-    sender.send_empty_ok_response().await?;
-    Ok(())
+        .await
 }
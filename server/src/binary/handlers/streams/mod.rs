@@ -3,4 +3,5 @@ pub mod delete_stream_handler;
 pub mod get_stream_handler;
 pub mod get_streams_handler;
 pub mod purge_stream_handler;
+pub mod restore_stream_handler;
 pub mod update_stream_handler;
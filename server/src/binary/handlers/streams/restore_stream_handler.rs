@@ -0,0 +1,20 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::streams::restore_stream::RestoreStream;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RestoreStream,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system.restore_stream(session, &command.stream_id).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::Error;
+use iggy::system::create_backup::CreateBackup;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateBackup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let backup = system.create_backup(session).await?;
+    let bytes = mapper::map_backup_info(&backup);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -1,5 +1,8 @@
+pub mod create_backup_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
 pub mod get_me_handler;
 pub mod get_stats_handler;
 pub mod ping_handler;
+pub mod reload_config_handler;
+pub mod set_log_level_handler;
@@ -0,0 +1,18 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::Error;
+use iggy::system::reload_config::ReloadConfig;
+use tracing::debug;
+
+pub async fn handle(
+    command: &ReloadConfig,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    system.reload_config(session).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::Error;
+use iggy::system::set_log_level::SetLogLevel;
+use tracing::debug;
+
+pub async fn handle(
+    command: &SetLogLevel,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system.set_log_level(session, command.level)?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -22,6 +22,7 @@ pub async fn handle(
             &command.name,
             command.partitions_count,
             command.message_expiry,
+            command.config.clone(),
         )
         .await?;
     sender.send_empty_ok_response().await?;
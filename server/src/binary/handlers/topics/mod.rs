@@ -3,4 +3,6 @@ pub mod delete_topic_handler;
 pub mod get_topic_handler;
 pub mod get_topics_handler;
 pub mod purge_topic_handler;
+pub mod repartition_topic_handler;
+pub mod restore_topic_handler;
 pub mod update_topic_handler;
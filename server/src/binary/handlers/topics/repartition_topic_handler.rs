@@ -0,0 +1,33 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::topics::repartition_topic::RepartitionTopic;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RepartitionTopic,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    // The messages are copied under the read lock, and only the switchover to the new partitions requires the write lock.
+    let layout = {
+        let system = system.read();
+        system
+            .prepare_topic_repartition(
+                session,
+                &command.stream_id,
+                &command.topic_id,
+                command.partitions_count,
+                command.key_header.clone(),
+            )
+            .await?
+    };
+    let mut system = system.write();
+    system.complete_topic_repartition(session, layout).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
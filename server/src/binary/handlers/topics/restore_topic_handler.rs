@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::topics::restore_topic::RestoreTopic;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RestoreTopic,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .restore_topic(session, &command.stream_id, &command.topic_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
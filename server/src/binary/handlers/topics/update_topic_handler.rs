@@ -21,6 +21,7 @@ pub async fn handle(
             &command.topic_id,
             &command.name,
             command.message_expiry,
+            command.config.clone(),
         )
         .await?;
     sender.send_empty_ok_response().await?;
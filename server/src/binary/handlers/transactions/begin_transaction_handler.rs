@@ -0,0 +1,22 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use tracing::debug;
+
+pub async fn handle(
+    command: &BeginTransaction,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let transaction_id = system.begin_transaction(session).await?;
+    let bytes = mapper::map_transaction(transaction_id);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
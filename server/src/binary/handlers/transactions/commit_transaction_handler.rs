@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::transactions::commit_transaction::CommitTransaction;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CommitTransaction,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system
+        .commit_transaction(session, command.transaction_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -2,15 +2,18 @@ use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::producers::producer::Producer;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::BufMut;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::backup_info::BackupInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use iggy::models::stats::Stats;
 use iggy::models::user_info::UserId;
+use iggy::topics::config;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -44,6 +47,31 @@ pub fn map_stats(stats: &Stats) -> Vec<u8> {
     bytes
 }
 
+pub fn map_backup_info(backup: &BackupInfo) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(25 + backup.name.len() + backup.path.len());
+    bytes.put_u64_le(backup.created_at);
+    bytes.put_u64_le(backup.size_bytes);
+    bytes.put_u32_le(backup.files_count);
+    bytes.put_u8(backup.name.len() as u8);
+    bytes.extend(backup.name.as_bytes());
+    bytes.put_u32_le(backup.path.len() as u32);
+    bytes.extend(backup.path.as_bytes());
+    bytes
+}
+
+pub fn map_producer(producer: &Producer) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(12);
+    bytes.put_u64_le(producer.producer_id);
+    bytes.put_u32_le(producer.epoch);
+    bytes
+}
+
+pub fn map_transaction(transaction_id: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.put_u64_le(transaction_id);
+    bytes
+}
+
 pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(20);
     bytes.put_u32_le(offset.partition_id);
@@ -167,6 +195,11 @@ pub async fn map_topic(topic: &Topic) -> Vec<u8> {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
     }
+    // The clients not aware of the config overrides read the partitions until the end of the payload,
+    // so the overrides are appended only if there are any, and such clients can't read the topics having them.
+    if !topic.config_overrides.is_empty() {
+        config::extend_bytes(&topic.config_overrides, &mut bytes);
+    }
     bytes
 }
 
@@ -263,3 +296,57 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut Vec<u8>)
     bytes.extend(personal_access_token.name.as_bytes());
     bytes.put_u64_le(personal_access_token.expiry.unwrap_or(0));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::collections::HashMap;
+
+    const PARTITION_SIZE_BYTES: usize = 40;
+
+    #[tokio::test]
+    async fn topic_without_config_overrides_should_be_readable_by_previous_clients() {
+        let topic = init_topic(3);
+
+        let bytes = map_topic(&topic).await;
+
+        // The previous clients read the partitions until the end of the payload.
+        let mut position = get_topic_size_bytes(&topic);
+        let mut partition_ids = Vec::new();
+        while position < bytes.len() {
+            assert!(position + PARTITION_SIZE_BYTES <= bytes.len());
+            partition_ids.push(u32::from_le_bytes(
+                bytes[position..position + 4].try_into().unwrap(),
+            ));
+            position += PARTITION_SIZE_BYTES;
+        }
+        partition_ids.sort();
+        assert_eq!(partition_ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn topic_config_overrides_should_be_appended_after_partitions() {
+        let mut topic = init_topic(3);
+        let overrides = HashMap::from([(config::CACHE_ENABLED.to_string(), "false".to_string())]);
+        topic.set_config_overrides(overrides.clone()).unwrap();
+
+        let bytes = map_topic(&topic).await;
+
+        let position = get_topic_size_bytes(&topic) + 3 * PARTITION_SIZE_BYTES;
+        let (config, read_bytes) = config::from_bytes(&bytes[position..]).unwrap();
+        assert_eq!(config, overrides);
+        assert_eq!(position + read_bytes, bytes.len());
+    }
+
+    fn get_topic_size_bytes(topic: &Topic) -> usize {
+        4 + 8 + 4 + 4 + 8 + 8 + 1 + topic.name.len()
+    }
+
+    fn init_topic(partitions_count: u32) -> Topic {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        Topic::create(1, 2, "test", partitions_count, config, storage, None).unwrap()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::SoftDeleteConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct DeletedResourcesCleaner {
+    enabled: bool,
+    interval: IggyDuration,
+    grace_period: IggyDuration,
+    sender: Sender<CleanDeletedResourcesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanDeletedResourcesCommand {
+    grace_period_secs: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanDeletedResourcesExecutor;
+
+impl DeletedResourcesCleaner {
+    pub fn new(config: &SoftDeleteConfig, sender: Sender<CleanDeletedResourcesCommand>) -> Self {
+        Self {
+            enabled: config.cleaner.enabled,
+            interval: config.cleaner.interval,
+            grace_period: config.grace_period,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Soft delete cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let grace_period = self.grace_period;
+        let grace_period_secs = grace_period.as_secs();
+        let sender = self.sender.clone();
+        info!(
+            "Soft delete cleaner is enabled, streams and topics soft-deleted for longer than: {} will be permanently deleted every: {:?}.",
+            grace_period, interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CleanDeletedResourcesCommand { grace_period_secs })
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send CleanDeletedResourcesCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CleanDeletedResourcesCommand> for CleanDeletedResourcesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, command: CleanDeletedResourcesCommand) {
+        let mut system = system.write();
+        match system
+            .delete_expired_soft_deleted_resources(command.grace_period_secs)
+            .await
+        {
+            Ok(deleted_resources)
+                if deleted_resources.streams_count == 0 && deleted_resources.topics_count == 0 =>
+            {
+                debug!("No expired soft-deleted streams or topics to delete.")
+            }
+            Ok(deleted_resources) => info!(
+                "Permanently deleted {} soft-deleted stream(s) and {} soft-deleted topic(s).",
+                deleted_resources.streams_count, deleted_resources.topics_count
+            ),
+            Err(error) => error!(
+                "Failed to delete expired soft-deleted streams and topics. Error: {}",
+                error
+            ),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanDeletedResourcesCommand>,
+    ) {
+        let deleted_resources_cleaner =
+            DeletedResourcesCleaner::new(&config.system.soft_delete, sender);
+        deleted_resources_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanDeletedResourcesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Soft delete cleaner receiver stopped.");
+        });
+    }
+}
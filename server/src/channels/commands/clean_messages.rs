@@ -1,3 +1,4 @@
+use crate::configs::reload::{self, ReloadableConfig};
 use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::topics::topic::Topic;
 use crate::{channels::server_command::ServerCommand, configs::server::MessageCleanerConfig};
@@ -6,7 +7,7 @@ use flume::Sender;
 use iggy::error::Error;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::TimeStamp;
-use tokio::time;
+use tokio::sync::watch;
 use tracing::{error, info};
 
 struct DeletedSegments {
@@ -18,6 +19,7 @@ pub struct MessagesCleaner {
     enabled: bool,
     interval: IggyDuration,
     sender: Sender<CleanMessagesCommand>,
+    config_changes: Option<watch::Receiver<ReloadableConfig>>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -27,34 +29,66 @@ pub struct CleanMessagesCommand;
 pub struct CleanMessagesExecutor;
 
 impl MessagesCleaner {
-    pub fn new(config: &MessageCleanerConfig, sender: Sender<CleanMessagesCommand>) -> Self {
+    pub fn new(
+        config: &MessageCleanerConfig,
+        sender: Sender<CleanMessagesCommand>,
+        config_changes: Option<watch::Receiver<ReloadableConfig>>,
+    ) -> Self {
         Self {
             enabled: config.enabled,
             interval: config.interval,
             sender,
+            config_changes,
         }
     }
 
-    pub fn start(&self) {
-        if !self.enabled {
-            info!("Message cleaner is disabled.");
+    pub fn start(self) {
+        let mut enabled = self.enabled;
+        let mut interval = self.interval;
+        let mut config_changes = self.config_changes;
+        let sender = self.sender;
+        if enabled && interval.is_zero() {
+            error!("Message cleaner interval cannot be zero, it must be greater than 0.");
             return;
         }
 
-        let interval = self.interval;
-        let sender = self.sender.clone();
-        info!(
-            "Message cleaner is enabled, expired messages will be deleted every: {:?}.",
-            interval
-        );
+        if enabled {
+            info!(
+                "Message cleaner is enabled, expired messages will be deleted every: {:?}.",
+                interval
+            );
+        } else {
+            info!("Message cleaner is disabled.");
+            if config_changes.is_none() {
+                return;
+            }
+        }
 
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = reload::interval_timer(enabled, interval);
             loop {
-                interval_timer.tick().await;
-                sender.send(CleanMessagesCommand).unwrap_or_else(|err| {
-                    error!("Failed to send CleanMessagesCommand. Error: {}", err);
-                });
+                tokio::select! {
+                    _ = reload::tick(&mut interval_timer) => {
+                        sender.send(CleanMessagesCommand).unwrap_or_else(|err| {
+                            error!("Failed to send CleanMessagesCommand. Error: {}", err);
+                        });
+                    }
+                    Some(config) = reload::changed(&mut config_changes) => {
+                        let config = config.message_cleaner;
+                        if config.enabled == enabled && config.interval == interval {
+                            continue;
+                        }
+
+                        enabled = config.enabled;
+                        interval = config.interval;
+                        interval_timer = reload::interval_timer(enabled, interval);
+                        info!(
+                            "Message cleaner config was reloaded, enabled: {}, interval: {:?}.",
+                            enabled, interval
+                        );
+                    }
+                    else => break,
+                }
             }
         });
     }
@@ -94,11 +128,13 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<CleanMessagesCommand>,
     ) {
-        let messages_cleaner = MessagesCleaner::new(&config.message_cleaner, sender);
+        let config_changes = system.read().subscribe_to_config_changes();
+        let messages_cleaner =
+            MessagesCleaner::new(&config.message_cleaner, sender, config_changes);
         messages_cleaner.start();
     }
 
@@ -0,0 +1,103 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::TransactionConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct TransactionCleaner {
+    enabled: bool,
+    interval: IggyDuration,
+    timeout: IggyDuration,
+    sender: Sender<CleanTransactionsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanTransactionsCommand {
+    timeout_secs: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanTransactionsExecutor;
+
+impl TransactionCleaner {
+    pub fn new(config: &TransactionConfig, sender: Sender<CleanTransactionsCommand>) -> Self {
+        Self {
+            enabled: config.cleaner.enabled,
+            interval: config.cleaner.interval,
+            timeout: config.timeout,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Transaction cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let timeout_secs = self.timeout.as_secs();
+        let sender = self.sender.clone();
+        info!(
+            "Transaction cleaner is enabled, transactions open for longer than: {} will be aborted every: {:?}.",
+            self.timeout, interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CleanTransactionsCommand { timeout_secs })
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send CleanTransactionsCommand. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CleanTransactionsCommand> for CleanTransactionsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, command: CleanTransactionsCommand) {
+        let system = system.read();
+        match system
+            .abort_expired_transactions(command.timeout_secs)
+            .await
+        {
+            Ok(0) => debug!("No expired transactions to abort."),
+            Ok(aborted_transactions) => {
+                info!("Aborted {aborted_transactions} expired transaction(s).")
+            }
+            Err(error) => error!("Failed to abort expired transactions. Error: {}", error),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanTransactionsCommand>,
+    ) {
+        let transaction_cleaner = TransactionCleaner::new(&config.transaction, sender);
+        transaction_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanTransactionsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Transaction cleaner receiver stopped.");
+        });
+    }
+}
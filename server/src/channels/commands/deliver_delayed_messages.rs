@@ -0,0 +1,104 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::MessageSchedulerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, trace};
+
+pub struct MessageScheduler {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<DeliverDelayedMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DeliverDelayedMessagesCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct DeliverDelayedMessagesExecutor;
+
+impl MessageScheduler {
+    pub fn new(
+        config: &MessageSchedulerConfig,
+        sender: Sender<DeliverDelayedMessagesCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Message scheduler is disabled, delayed messages will not be delivered.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Message scheduler is enabled, delayed messages will be delivered every: {interval}."
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(DeliverDelayedMessagesCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send DeliverDelayedMessagesCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<DeliverDelayedMessagesCommand> for DeliverDelayedMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: DeliverDelayedMessagesCommand) {
+        // The scheduler runs frequently, so rather than blocking the runtime thread while the system is being modified,
+        // the delivery is postponed until the next tick.
+        let Some(system) = system.try_read() else {
+            return;
+        };
+        match system.deliver_delayed_messages().await {
+            Ok(0) => {}
+            Ok(delivered_messages) => {
+                trace!("Delivered {delivered_messages} delayed message(s).")
+            }
+            Err(error) => error!("Failed to deliver delayed messages. Error: {}", error),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<DeliverDelayedMessagesCommand>,
+    ) {
+        let message_scheduler = MessageScheduler::new(&config.message_scheduler, sender);
+        message_scheduler.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<DeliverDelayedMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Message scheduler receiver stopped.");
+        });
+    }
+}
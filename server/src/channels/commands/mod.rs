@@ -1,3 +1,7 @@
+pub mod clean_deleted_resources;
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod clean_transactions;
+pub mod deliver_delayed_messages;
+pub mod reencrypt_messages;
 pub mod save_messages;
@@ -0,0 +1,174 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::MessageReencryptorConfig;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{error, info};
+
+pub struct MessageReencryptor {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ReencryptMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReencryptMessagesCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct ReencryptMessagesExecutor;
+
+impl MessageReencryptor {
+    pub fn new(
+        config: &MessageReencryptorConfig,
+        sender: Sender<ReencryptMessagesCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Message reencryptor is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Message reencryptor is enabled, closed segments will be re-encrypted with the active key every: {interval}."
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(ReencryptMessagesCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send ReencryptMessagesCommand. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<ReencryptMessagesCommand> for ReencryptMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: ReencryptMessagesCommand) {
+        let partitions = {
+            let system = system.read();
+            if system.encryptor.is_none() {
+                info!("Encryption is disabled, there are no messages to re-encrypt.");
+                return;
+            }
+
+            let mut partitions = Vec::new();
+            for stream in system.get_streams() {
+                if system.get_encryptor(stream).is_none() {
+                    continue;
+                }
+                for topic in stream.get_topics() {
+                    for partition in topic.get_partitions() {
+                        partitions.push((stream.stream_id, topic.topic_id, partition));
+                    }
+                }
+            }
+            partitions
+        };
+
+        // Each segment is re-encrypted separately, so the locks are held only for a single segment at a time.
+        for (stream_id, topic_id, partition) in partitions {
+            let (partition_id, start_offsets) = {
+                let partition = partition.read().await;
+                (
+                    partition.partition_id,
+                    partition.get_segments_to_reencrypt(),
+                )
+            };
+            let mut reencrypted_messages_count = 0;
+            for start_offset in start_offsets {
+                match reencrypt_segment(system, stream_id, &partition, start_offset).await {
+                    Ok(count) => reencrypted_messages_count += count as u64,
+                    Err(error) => {
+                        error!(
+                            "Failed to re-encrypt messages for stream ID: {}, topic ID: {}, partition ID: {}, segment start offset: {}. Error: {}",
+                            stream_id, topic_id, partition_id, start_offset, error
+                        );
+                    }
+                }
+            }
+
+            if reencrypted_messages_count > 0 {
+                info!(
+                    "Re-encrypted {} messages for stream ID: {}, topic ID: {}, partition ID: {}",
+                    reencrypted_messages_count, stream_id, topic_id, partition_id
+                );
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<ReencryptMessagesCommand>,
+    ) {
+        let message_reencryptor = MessageReencryptor::new(&config.message_reencryptor, sender);
+        message_reencryptor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<ReencryptMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Message reencryptor receiver stopped.");
+        });
+    }
+}
+
+async fn reencrypt_segment(
+    system: &SharedSystem,
+    stream_id: u32,
+    partition: &RwLock<Partition>,
+    start_offset: u64,
+) -> Result<u32, Error> {
+    let reencrypted_messages = {
+        let system = system.read();
+        let Ok(stream) = system.get_stream(&Identifier::numeric(stream_id)?) else {
+            return Ok(0);
+        };
+        let Some(encryptor) = system.get_encryptor(stream) else {
+            return Ok(0);
+        };
+        let partition = partition.read().await;
+        partition
+            .prepare_reencrypted_segment(start_offset, encryptor)
+            .await?
+    };
+
+    let Some(reencrypted_messages) = reencrypted_messages else {
+        return Ok(0);
+    };
+
+    partition
+        .write()
+        .await
+        .replace_reencrypted_segment(start_offset, reencrypted_messages)
+        .await
+}
@@ -1,4 +1,5 @@
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::{self, ReloadableConfig};
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
 use crate::streaming::persistence::persister::*;
@@ -8,13 +9,15 @@ use async_trait::async_trait;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
 use std::sync::Arc;
-use tokio::time;
+use tokio::sync::watch;
 use tracing::{error, info, warn};
 
 pub struct MessagesSaver {
+    enabled: bool,
     enforce_fsync: bool,
     interval: IggyDuration,
     sender: Sender<SaveMessagesCommand>,
+    config_changes: Option<watch::Receiver<ReloadableConfig>>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -26,36 +29,70 @@ pub struct SaveMessagesCommand {
 pub struct SaveMessagesExecutor;
 
 impl MessagesSaver {
-    pub fn new(config: &MessageSaverConfig, sender: Sender<SaveMessagesCommand>) -> Self {
+    pub fn new(
+        config: &MessageSaverConfig,
+        sender: Sender<SaveMessagesCommand>,
+        config_changes: Option<watch::Receiver<ReloadableConfig>>,
+    ) -> Self {
         Self {
+            enabled: config.enabled,
             enforce_fsync: config.enforce_fsync,
             interval: config.interval,
             sender,
+            config_changes,
         }
     }
 
-    pub fn start(&self) {
-        if !self.enforce_fsync {
-            info!("Message saver is disabled.");
+    pub fn start(self) {
+        let mut enabled = self.enabled;
+        let mut enforce_fsync = self.enforce_fsync;
+        let mut interval = self.interval;
+        let mut config_changes = self.config_changes;
+        let sender = self.sender;
+        if enabled && interval.is_zero() {
+            error!("Message saver interval cannot be zero, it must be greater than 0.");
             return;
         }
 
-        let enforce_fsync = self.enforce_fsync;
-        let interval = self.interval;
-        let sender = self.sender.clone();
-        info!(
-            "Message saver is enabled, buffered messages will be automatically saved every: {:?}, enforce fsync: {:?}.",
-            interval, enforce_fsync
-        );
+        if enabled {
+            info!(
+                "Message saver is enabled, buffered messages will be automatically saved every: {:?}, enforce fsync: {:?}.",
+                interval, enforce_fsync
+            );
+        } else {
+            info!("Message saver is disabled.");
+            if config_changes.is_none() {
+                return;
+            }
+        }
 
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = reload::interval_timer(enabled, interval);
             loop {
-                interval_timer.tick().await;
-                let command = SaveMessagesCommand { enforce_fsync };
-                sender.send(command).unwrap_or_else(|error| {
-                    error!("Failed to send SaveMessagesCommand. Error: {}", error);
-                });
+                tokio::select! {
+                    _ = reload::tick(&mut interval_timer) => {
+                        let command = SaveMessagesCommand { enforce_fsync };
+                        sender.send(command).unwrap_or_else(|error| {
+                            error!("Failed to send SaveMessagesCommand. Error: {}", error);
+                        });
+                    }
+                    Some(config) = reload::changed(&mut config_changes) => {
+                        let config = config.message_saver;
+                        enforce_fsync = config.enforce_fsync;
+                        if config.enabled == enabled && config.interval == interval {
+                            continue;
+                        }
+
+                        enabled = config.enabled;
+                        interval = config.interval;
+                        interval_timer = reload::interval_timer(enabled, interval);
+                        info!(
+                            "Message saver config was reloaded, enabled: {}, interval: {:?}, enforce fsync: {:?}.",
+                            enabled, interval, enforce_fsync
+                        );
+                    }
+                    else => break,
+                }
             }
         });
     }
@@ -83,11 +120,12 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<SaveMessagesCommand>,
     ) {
-        let messages_saver = MessagesSaver::new(&config.message_saver, sender);
+        let config_changes = system.read().subscribe_to_config_changes();
+        let messages_saver = MessagesSaver::new(&config.message_saver, sender, config_changes);
         messages_saver.start();
     }
 
@@ -6,15 +6,20 @@ use figment::{
     value::{Dict, Map as FigmentMap, Tag, Value as FigmentValue},
     Error, Figment, Metadata, Profile, Provider,
 };
+use std::time::Duration;
 use std::{env, path::Path};
 use toml::{map::Map, Value as TomlValue};
 use tracing::info;
 
 const DEFAULT_CONFIG_PROVIDER: &str = "file";
+const DIRECTORY_CONFIG_PROVIDER: &str = "directory";
+const HTTP_CONFIG_PROVIDER: &str = "http";
 const DEFAULT_CONFIG_PATH: &str = "configs/server.toml";
+const DEFAULT_CONFIG_DIRECTORY_PATH: &str = "configs/server.d";
+const HTTP_CONFIG_PROVIDER_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[async_trait]
-pub trait ConfigProvider {
+pub trait ConfigProvider: Send + Sync {
     async fn load_config(&self) -> Result<ServerConfig, ServerError>;
 }
 
@@ -23,6 +28,23 @@ pub struct FileConfigProvider {
     path: String,
 }
 
+/// Loads the configuration from all the TOML and JSON fragments found in the directory.
+/// The fragments are merged in the order of their file names, so the later ones override the earlier ones,
+/// e.g. `00-base.toml` can be combined with `10-tcp.toml` overriding only the TCP section.
+#[derive(Debug)]
+pub struct DirectoryConfigProvider {
+    path: String,
+}
+
+/// Loads the configuration from the remote HTTP(S) endpoint, e.g. the configuration service.
+/// The format is determined by the `Content-Type` header of the response or by the URL extension,
+/// TOML is used by default. The request fails if the response isn't received within the timeout.
+#[derive(Debug)]
+pub struct HttpConfigProvider {
+    url: String,
+    timeout: Duration,
+}
+
 pub struct CustomEnvProvider {
     prefix: String,
 }
@@ -33,6 +55,21 @@ impl FileConfigProvider {
     }
 }
 
+impl DirectoryConfigProvider {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+impl HttpConfigProvider {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            timeout: HTTP_CONFIG_PROVIDER_TIMEOUT,
+        }
+    }
+}
+
 impl CustomEnvProvider {
     pub fn new(prefix: &str) -> Self {
         Self {
@@ -60,6 +97,9 @@ impl CustomEnvProvider {
         }
     }
 
+    /// Inserts the value under the path matching the keys (the parts of the environment variable name).
+    /// As the names of the sections might contain underscores too (e.g. `soft_delete`),
+    /// the consecutive keys are joined until they match the existing section or value.
     fn insert_overridden_values_from_env(
         source: &Dict,
         target: &mut Dict,
@@ -70,36 +110,30 @@ impl CustomEnvProvider {
             return;
         }
 
-        let mut current_source = source;
-        let mut current_target = target;
-
-        for i in 0..keys.len() {
-            let combined_keys = keys[i..].join("_");
+        let combined_keys = keys.join("_");
+        if source.contains_key(&combined_keys) {
+            target.insert(combined_keys, value);
+            return;
+        }
 
-            if current_source.contains_key(&combined_keys) {
-                current_target.insert(combined_keys, value.clone());
-                return;
-            }
+        for i in 1..keys.len() {
+            let key = keys[..i].join("_");
+            let Some(FigmentValue::Dict(_, inner_source_dict)) = source.get(&key) else {
+                continue;
+            };
 
-            let key = &keys[i];
-            match current_source.get(key) {
-                Some(FigmentValue::Dict(_, inner_source_dict)) => {
-                    if !current_target.contains_key(key) {
-                        current_target
-                            .insert(key.clone(), FigmentValue::Dict(Tag::Default, Dict::new()));
-                    }
-
-                    if let Some(FigmentValue::Dict(_, ref mut actual_inner_target_dict)) =
-                        current_target.get_mut(key)
-                    {
-                        current_source = inner_source_dict;
-                        current_target = actual_inner_target_dict;
-                    } else {
-                        return;
-                    }
-                }
-                _ => return,
+            let inner_target = target
+                .entry(key)
+                .or_insert_with(|| FigmentValue::Dict(Tag::Default, Dict::new()));
+            if let FigmentValue::Dict(_, inner_target_dict) = inner_target {
+                Self::insert_overridden_values_from_env(
+                    inner_source_dict,
+                    inner_target_dict,
+                    keys[i..].to_vec(),
+                    value,
+                );
             }
+            return;
         }
     }
 
@@ -191,6 +225,20 @@ pub fn resolve(config_provider_type: &str) -> Result<Box<dyn ConfigProvider>, Se
                 env::var("IGGY_CONFIG_PATH").unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string());
             Ok(Box::new(FileConfigProvider::new(path)))
         }
+        DIRECTORY_CONFIG_PROVIDER => {
+            let path = env::var("IGGY_CONFIG_PATH")
+                .unwrap_or_else(|_| DEFAULT_CONFIG_DIRECTORY_PATH.to_string());
+            Ok(Box::new(DirectoryConfigProvider::new(path)))
+        }
+        HTTP_CONFIG_PROVIDER => {
+            let url = env::var("IGGY_CONFIG_URL").map_err(|_| {
+                ServerError::CannotLoadConfiguration(
+                    "IGGY_CONFIG_URL environment variable is required for the HTTP configuration provider."
+                        .to_string(),
+                )
+            })?;
+            Ok(Box::new(HttpConfigProvider::new(url)))
+        }
         _ => Err(ServerError::InvalidConfigurationProvider(
             config_provider_type.to_string(),
         )),
@@ -246,20 +294,251 @@ impl ConfigProvider for FileConfigProvider {
             }
         };
 
-        let custom_env_provider = CustomEnvProvider::new("IGGY_");
-        let config_result: Result<ServerConfig, figment::Error> =
-            config_builder.merge(custom_env_provider).extract();
+        let config = extract_config(config_builder)?;
+        info!("Config loaded from path: '{}'", self.path);
+        info!("Using Config: {}", config);
+        Ok(config)
+    }
+}
 
-        match config_result {
-            Ok(config) => {
-                info!("Config loaded from path: '{}'", self.path);
-                info!("Using Config: {}", config);
-                Ok(config)
-            }
-            Err(figment_error) => Err(ServerError::CannotLoadConfiguration(format!(
-                "Failed to load configuration: {}",
-                figment_error
-            ))),
+#[async_trait]
+impl ConfigProvider for DirectoryConfigProvider {
+    async fn load_config(&self) -> Result<ServerConfig, ServerError> {
+        info!(
+            "Loading config fragments from directory: '{}'...",
+            self.path
+        );
+        let dir_entries = std::fs::read_dir(&self.path).map_err(|error| {
+            ServerError::CannotLoadConfiguration(format!(
+                "Cannot read configuration directory: '{}', error: {}",
+                self.path, error
+            ))
+        })?;
+
+        let mut fragments = dir_entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && matches!(
+                        path.extension().and_then(|extension| extension.to_str()),
+                        Some("json" | "toml")
+                    )
+            })
+            .collect::<Vec<_>>();
+        if fragments.is_empty() {
+            return Err(ServerError::CannotLoadConfiguration(format!(
+                "Cannot find any .json or .toml configuration files in directory: '{}'.",
+                self.path
+            )));
+        }
+
+        fragments.sort();
+        let mut config_builder = Figment::new();
+        for fragment in &fragments {
+            info!("Merging config fragment: '{}'...", fragment.display());
+            config_builder = match fragment
+                .extension()
+                .and_then(|extension| extension.to_str())
+            {
+                Some("json") => config_builder.merge(Json::file(fragment)),
+                _ => config_builder.merge(Toml::file(fragment)),
+            };
+        }
+
+        let config = extract_config(config_builder)?;
+        info!(
+            "Config loaded from {} fragment(s) in directory: '{}'",
+            fragments.len(),
+            self.path
+        );
+        info!("Using Config: {}", config);
+        Ok(config)
+    }
+}
+
+#[async_trait]
+impl ConfigProvider for HttpConfigProvider {
+    async fn load_config(&self) -> Result<ServerConfig, ServerError> {
+        info!("Loading config from URL: '{}'...", self.url);
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|error| {
+                ServerError::CannotLoadConfiguration(format!(
+                    "Cannot create HTTP client for URL: '{}', error: {}",
+                    self.url, error
+                ))
+            })?;
+        let response = client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| {
+                ServerError::CannotLoadConfiguration(format!(
+                    "Cannot fetch configuration from URL: '{}', error: {}",
+                    self.url, error
+                ))
+            })?;
+
+        let is_json = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map_or(self.url.ends_with(".json"), |content_type| {
+                content_type.contains("json")
+            });
+        let content = response.text().await.map_err(|error| {
+            ServerError::CannotLoadConfiguration(format!(
+                "Cannot read configuration from URL: '{}', error: {}",
+                self.url, error
+            ))
+        })?;
+
+        let config_builder = match is_json {
+            true => Figment::new().merge(Json::string(&content)),
+            false => Figment::new().merge(Toml::string(&content)),
+        };
+        let config = extract_config(config_builder)?;
+        info!("Config loaded from URL: '{}'", self.url);
+        info!("Using Config: {}", config);
+        Ok(config)
+    }
+}
+
+/// Applies the overrides from the environment variables and extracts the configuration.
+fn extract_config(config_builder: Figment) -> Result<ServerConfig, ServerError> {
+    let custom_env_provider = CustomEnvProvider::new("IGGY_");
+    let config_result: Result<ServerConfig, figment::Error> =
+        config_builder.merge(custom_env_provider).extract();
+
+    config_result.map_err(|figment_error| {
+        ServerError::CannotLoadConfiguration(format!(
+            "Failed to load configuration: {}",
+            figment_error
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use ulid::Ulid;
+
+    const BASE_CONFIG: &str = include_str!("../../../configs/server.toml");
+
+    #[tokio::test]
+    async fn directory_config_provider_should_merge_fragments_in_order_of_file_names() {
+        let path = create_config_directory();
+        std::fs::write(format!("{path}/00-base.toml"), BASE_CONFIG).unwrap();
+        std::fs::write(
+            format!("{path}/10-tcp.toml"),
+            "[tcp]\naddress = \"127.0.0.1:9001\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            format!("{path}/20-tcp.json"),
+            r#"{ "tcp": { "address": "127.0.0.1:9002" }, "http": { "address": "127.0.0.1:9003" } }"#,
+        )
+        .unwrap();
+        std::fs::write(format!("{path}/30-quic.txt"), "[quic]\nenabled = false\n").unwrap();
+
+        let config = DirectoryConfigProvider::new(path.clone())
+            .load_config()
+            .await;
+        std::fs::remove_dir_all(&path).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.tcp.address, "127.0.0.1:9002");
+        assert_eq!(config.http.address, "127.0.0.1:9003");
+        assert!(config.quic.enabled);
+    }
+
+    #[tokio::test]
+    async fn directory_config_provider_should_fail_without_any_fragments() {
+        let path = create_config_directory();
+        std::fs::write(format!("{path}/server.txt"), BASE_CONFIG).unwrap();
+
+        let config = DirectoryConfigProvider::new(path.clone())
+            .load_config()
+            .await;
+        std::fs::remove_dir_all(&path).unwrap();
+
+        assert!(matches!(
+            config,
+            Err(ServerError::CannotLoadConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn http_config_provider_should_load_config_in_format_of_content_type() {
+        let json_config = BASE_CONFIG
+            .parse::<TomlValue>()
+            .map(|config| serde_json::to_string(&config).unwrap())
+            .unwrap();
+        for (content_type, content) in [
+            ("application/toml", BASE_CONFIG.to_string()),
+            ("application/json", json_config),
+        ] {
+            let url = serve_config_once(content_type, content).await;
+
+            let config = HttpConfigProvider::new(url).load_config().await.unwrap();
+
+            assert_eq!(config.tcp.address, "0.0.0.0:8090");
         }
     }
+
+    #[tokio::test]
+    async fn http_config_provider_should_fail_when_response_is_not_received_within_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+        let provider = HttpConfigProvider {
+            url: format!("http://{address}/server.toml"),
+            timeout: Duration::from_millis(100),
+        };
+
+        let config = provider.load_config().await;
+
+        assert!(matches!(
+            config,
+            Err(ServerError::CannotLoadConfiguration(_))
+        ));
+    }
+
+    fn create_config_directory() -> String {
+        let path = std::env::temp_dir().join(format!("iggy-config-{}", Ulid::new()));
+        std::fs::create_dir_all(&path).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    async fn serve_config_once(content_type: &'static str, content: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let read_bytes = stream.read(&mut buffer).await.unwrap();
+                if read_bytes == 0 {
+                    return;
+                }
+                request.extend_from_slice(&buffer[..read_bytes]);
+            }
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content_type,
+                content.len(),
+                content
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+        format!("http://{address}/config")
+    }
 }
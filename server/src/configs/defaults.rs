@@ -1,17 +1,21 @@
 use crate::configs::http::{
-    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+    HttpConfig, HttpConsumerGroupsConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig,
+    HttpOpenApiConfig, HttpTlsConfig,
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    MessageCleanerConfig, MessageReencryptorConfig, MessageSaverConfig, MessageSchedulerConfig,
+    PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig, ShutdownConfig,
+    TransactionCleanerConfig, TransactionConfig,
 };
 use crate::configs::system::{
-    CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RetentionPolicyConfig, RuntimeConfig,
-    SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+    BackupConfig, CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LogFormat,
+    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, PersisterKind,
+    RetentionPolicyConfig, RuntimeConfig, SegmentConfig, SoftDeleteCleanerConfig, SoftDeleteConfig,
+    StreamConfig, SystemConfig, TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use iggy::topics::partitioning_strategy::PartitioningStrategy;
 use std::sync::Arc;
 
 impl Default for ServerConfig {
@@ -19,7 +23,11 @@ impl Default for ServerConfig {
         ServerConfig {
             message_cleaner: MessageCleanerConfig::default(),
             message_saver: MessageSaverConfig::default(),
+            message_scheduler: MessageSchedulerConfig::default(),
+            message_reencryptor: MessageReencryptorConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
+            transaction: TransactionConfig::default(),
+            shutdown: ShutdownConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
@@ -73,11 +81,21 @@ impl Default for HttpConfig {
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
+            openapi: HttpOpenApiConfig::default(),
+            consumer_groups: HttpConsumerGroupsConfig::default(),
             tls: HttpTlsConfig::default(),
         }
     }
 }
 
+impl Default for HttpConsumerGroupsConfig {
+    fn default() -> HttpConsumerGroupsConfig {
+        HttpConsumerGroupsConfig {
+            member_expiry: "30s".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for HttpJwtConfig {
     fn default() -> HttpJwtConfig {
         HttpJwtConfig {
@@ -106,6 +124,32 @@ impl Default for MessageCleanerConfig {
     }
 }
 
+impl Default for MessageSchedulerConfig {
+    fn default() -> MessageSchedulerConfig {
+        MessageSchedulerConfig {
+            enabled: true,
+            interval: "100ms".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> ShutdownConfig {
+        ShutdownConfig {
+            drain_timeout: "10s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for MessageReencryptorConfig {
+    fn default() -> MessageReencryptorConfig {
+        MessageReencryptorConfig {
+            enabled: false,
+            interval: "1h".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for MessageSaverConfig {
     fn default() -> MessageSaverConfig {
         MessageSaverConfig {
@@ -134,18 +178,38 @@ impl Default for PersonalAccessTokenCleanerConfig {
     }
 }
 
+impl Default for TransactionConfig {
+    fn default() -> TransactionConfig {
+        TransactionConfig {
+            timeout: "1m".parse().unwrap(),
+            cleaner: TransactionCleanerConfig::default(),
+        }
+    }
+}
+
+impl Default for TransactionCleanerConfig {
+    fn default() -> TransactionCleanerConfig {
+        TransactionCleanerConfig {
+            enabled: true,
+            interval: "10s".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
             path: "local_data".to_string(),
             database: DatabaseConfig::default(),
             runtime: RuntimeConfig::default(),
+            backup: BackupConfig::default(),
             logging: LoggingConfig::default(),
             cache: CacheConfig::default(),
             retention_policy: RetentionPolicyConfig::default(),
             stream: StreamConfig::default(),
             encryption: EncryptionConfig::default(),
             topic: TopicConfig::default(),
+            soft_delete: SoftDeleteConfig::default(),
             partition: PartitionConfig::default(),
             segment: SegmentConfig::default(),
             compression: CompressionConfig::default(),
@@ -170,6 +234,14 @@ impl Default for RuntimeConfig {
     }
 }
 
+impl Default for BackupConfig {
+    fn default() -> BackupConfig {
+        BackupConfig {
+            path: "backups".to_string(),
+        }
+    }
+}
+
 impl Default for CompressionConfig {
     fn default() -> Self {
         CompressionConfig {
@@ -184,8 +256,12 @@ impl Default for LoggingConfig {
         LoggingConfig {
             path: "logs".to_string(),
             level: "info".to_string(),
+            format: LogFormat::default(),
             max_size: "200 MB".parse().unwrap(),
+            rotation_interval: "1 hour".parse().unwrap(),
             retention: "7 days".parse().unwrap(),
+            max_total_size: "2 GB".parse().unwrap(),
+            compress_rotated: true,
         }
     }
 }
@@ -220,6 +296,27 @@ impl Default for TopicConfig {
     fn default() -> TopicConfig {
         TopicConfig {
             path: "topics".to_string(),
+            partitioning: PartitioningStrategy::default(),
+            sticky_batch_interval: "100ms".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for SoftDeleteConfig {
+    fn default() -> SoftDeleteConfig {
+        SoftDeleteConfig {
+            enabled: false,
+            grace_period: "24h".parse().unwrap(),
+            cleaner: SoftDeleteCleanerConfig::default(),
+        }
+    }
+}
+
+impl Default for SoftDeleteCleanerConfig {
+    fn default() -> SoftDeleteCleanerConfig {
+        SoftDeleteCleanerConfig {
+            enabled: true,
+            interval: "1m".parse().unwrap(),
         }
     }
 }
@@ -231,6 +328,10 @@ impl Default for PartitionConfig {
             messages_required_to_save: 1000,
             enforce_fsync: false,
             validate_checksum: false,
+            persister: PersisterKind::default(),
+            group_commit: false,
+            group_commit_interval: "5ms".parse().unwrap(),
+            group_commit_max_size: "4 MiB".parse().unwrap(),
         }
     }
 }
@@ -1,13 +1,16 @@
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
-    http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    http::{
+        HttpConfig, HttpConsumerGroupsConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig,
+        HttpOpenApiConfig, HttpTlsConfig,
+    },
     resource_quota::MemoryResourceQuota,
     server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
     system::{
-        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-        PartitionConfig, RetentionPolicyConfig, SegmentConfig, StreamConfig, SystemConfig,
-        TopicConfig,
+        BackupConfig, CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LogFormat,
+        LoggingConfig, PartitionConfig, PersisterKind, RetentionPolicyConfig, SegmentConfig,
+        SoftDeleteConfig, StreamConfig, SystemConfig, TopicConfig,
     },
     tcp::{TcpConfig, TcpTlsConfig},
 };
@@ -17,8 +20,15 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, cors: {}, jwt: {}, metrics: {}, openapi: {}, consumer_groups: {}, tls: {} }}",
+            self.enabled,
+            self.address,
+            self.cors,
+            self.jwt,
+            self.metrics,
+            self.openapi,
+            self.consumer_groups,
+            self.tls
         )
     }
 }
@@ -53,6 +63,22 @@ impl Display for HttpMetricsConfig {
     }
 }
 
+impl Display for HttpOpenApiConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, endpoint: {} }}",
+            self.enabled, self.endpoint
+        )
+    }
+}
+
+impl Display for HttpConsumerGroupsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ member_expiry: {} }}", self.member_expiry)
+    }
+}
+
 impl Display for HttpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -147,6 +173,12 @@ impl Display for DatabaseConfig {
     }
 }
 
+impl Display for BackupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ path: {} }}", self.path)
+    }
+}
+
 impl Display for CacheConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {}, size: {} }}", self.enabled, self.size)
@@ -165,7 +197,18 @@ impl Display for RetentionPolicyConfig {
 
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {} }}", self.enabled)
+        write!(
+            f,
+            "{{ enabled: {}, key_id: {}, decryption_key_ids: {:?}, per_stream_keys: {}, master_key_path: {} }}",
+            self.enabled,
+            self.key_id,
+            self.decryption_keys
+                .iter()
+                .map(|key| key.id)
+                .collect::<Vec<_>>(),
+            self.per_stream_keys,
+            self.master_key_path
+        )
     }
 }
 
@@ -177,7 +220,21 @@ impl Display for StreamConfig {
 
 impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ path: {} }}", self.path)
+        write!(
+            f,
+            "{{ path: {}, partitioning: {}, sticky_batch_interval: {} }}",
+            self.path, self.partitioning, self.sticky_batch_interval
+        )
+    }
+}
+
+impl Display for SoftDeleteConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, grace_period: {}, cleaner: {{ enabled: {}, interval: {} }} }}",
+            self.enabled, self.grace_period, self.cleaner.enabled, self.cleaner.interval
+        )
     }
 }
 
@@ -185,15 +242,37 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, persister: {}, group_commit: {}, group_commit_interval: {}, group_commit_max_size: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.persister,
+          self.group_commit,
+          self.group_commit_interval,
+          self.group_commit_max_size
       )
     }
 }
 
+impl Display for PersisterKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersisterKind::File => write!(f, "file"),
+            PersisterKind::IoUring => write!(f, "io_uring"),
+        }
+    }
+}
+
+impl Display for LogFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogFormat::Text => write!(f, "text"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
 impl Display for MessageDeduplicationConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -218,8 +297,15 @@ impl Display for LoggingConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, level: {}, max_size: {}, retention: {} }}",
-            self.path, self.level, self.max_size, self.retention
+            "{{ path: {}, level: {}, format: {}, max_size: {}, rotation_interval: {}, retention: {}, max_total_size: {}, compress_rotated: {} }}",
+            self.path,
+            self.level,
+            self.format,
+            self.max_size,
+            self.rotation_interval,
+            self.retention,
+            self.max_total_size,
+            self.compress_rotated
         )
     }
 }
@@ -248,13 +334,15 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, database: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {} }}",
+          "{{ path: {}, database: {}, backup: {}, logging: {}, cache: {}, stream: {}, topic: {}, soft_delete: {}, partition: {}, segment: {}, encryption: {} }}",
           self.path,
           self.database,
+          self.backup,
           self.logging,
           self.cache,
           self.stream,
           self.topic,
+          self.soft_delete,
           self.partition,
           self.segment,
           self.encryption
@@ -12,6 +12,8 @@ pub struct HttpConfig {
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
+    pub openapi: HttpOpenApiConfig,
+    pub consumer_groups: HttpConsumerGroupsConfig,
     pub tls: HttpTlsConfig,
 }
 
@@ -53,6 +55,19 @@ pub struct HttpMetricsConfig {
     pub endpoint: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct HttpOpenApiConfig {
+    pub enabled: bool,
+    pub endpoint: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpConsumerGroupsConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub member_expiry: IggyDuration,
+}
+
 #[derive(Debug)]
 pub enum JwtSecret {
     Default(String),
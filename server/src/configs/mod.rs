@@ -8,5 +8,6 @@ pub mod tcp;
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
+pub mod reload;
 pub mod resource_quota;
 pub mod validators;
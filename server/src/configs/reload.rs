@@ -0,0 +1,267 @@
+use crate::configs::config_provider::ConfigProvider;
+use crate::configs::http::HttpCorsConfig;
+use crate::configs::server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig};
+use crate::configs::system::{CacheConfig, RetentionPolicyConfig};
+use crate::server_error::ServerError;
+use iggy::utils::duration::IggyDuration;
+use std::fmt::{Debug, Formatter};
+use tokio::sync::{watch, Mutex};
+use tokio::time::{self, Interval};
+use toml::Value as TomlValue;
+use tracing::{info, warn};
+
+// The paths of the config sections (or single values) which can be changed without the server restart.
+// The cache can't be enabled or disabled at runtime, only its size can be changed.
+const RELOADABLE_PATHS: &[&str] = &[
+    "message_cleaner",
+    "message_saver",
+    "system.retention_policy",
+    "system.cache.size",
+    "system.logging.level",
+    "http.cors",
+];
+
+/// The part of the server configuration which is applied at runtime when the configuration is reloaded.
+#[derive(Debug, Clone)]
+pub struct ReloadableConfig {
+    pub message_cleaner: MessageCleanerConfig,
+    pub message_saver: MessageSaverConfig,
+    pub retention_policy: RetentionPolicyConfig,
+    pub cache: CacheConfig,
+    pub log_level: String,
+    pub cors: HttpCorsConfig,
+}
+
+impl From<&ServerConfig> for ReloadableConfig {
+    fn from(config: &ServerConfig) -> Self {
+        Self {
+            message_cleaner: config.message_cleaner.clone(),
+            message_saver: config.message_saver.clone(),
+            retention_policy: config.system.retention_policy,
+            cache: config.system.cache.clone(),
+            log_level: config.system.logging.level.clone(),
+            cors: config.http.cors.clone(),
+        }
+    }
+}
+
+/// Loads the configuration again using the same provider as during the startup and publishes its reloadable part
+/// to the subscribers (e.g. background jobs or HTTP server), which apply the changes on their own.
+/// The last loaded configuration is kept, so the next reload reports only the changes made since then.
+pub struct ConfigReloader {
+    provider: Box<dyn ConfigProvider>,
+    config: Mutex<ServerConfig>,
+    sender: watch::Sender<ReloadableConfig>,
+}
+
+impl Debug for ConfigReloader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigReloader")
+            .field("current", &*self.sender.borrow())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConfigReloader {
+    pub fn new(provider: Box<dyn ConfigProvider>, config: &ServerConfig) -> Self {
+        let (sender, _) = watch::channel(ReloadableConfig::from(config));
+        Self {
+            provider,
+            config: Mutex::new(config.clone()),
+            sender,
+        }
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<ReloadableConfig> {
+        self.sender.subscribe()
+    }
+
+    pub fn current(&self) -> ReloadableConfig {
+        self.sender.borrow().clone()
+    }
+
+    /// Reloads the configuration and returns the previous and the new reloadable config.
+    /// The changes made to the other sections are ignored until the server restart.
+    /// The concurrent reloads are serialized, so each of them is compared with the previously applied config.
+    pub async fn reload(&self) -> Result<(ReloadableConfig, ReloadableConfig), ServerError> {
+        let mut current_config = self.config.lock().await;
+        info!("Reloading config...");
+        let config = ServerConfig::load(self.provider.as_ref()).await?;
+        for path in get_non_reloadable_changes(&current_config, &config) {
+            warn!("Config value: '{path}' was changed, but it will be applied only after the server restart.");
+        }
+
+        let reloadable_config = ReloadableConfig::from(&config);
+        *current_config = config;
+        let previous_config = self.sender.send_replace(reloadable_config.clone());
+        info!("Config reloaded.");
+        Ok((previous_config, reloadable_config))
+    }
+}
+
+/// Waits for the next config change, if the receiver is available, otherwise never completes.
+/// Returns `None` when the reloader has been dropped, so that the receiver won't be polled again.
+pub async fn changed(
+    receiver: &mut Option<watch::Receiver<ReloadableConfig>>,
+) -> Option<ReloadableConfig> {
+    let Some(config_changes) = receiver.as_mut() else {
+        return std::future::pending().await;
+    };
+
+    if config_changes.changed().await.is_err() {
+        *receiver = None;
+        return None;
+    }
+
+    let config = config_changes.borrow_and_update().clone();
+    Some(config)
+}
+
+/// Creates the timer of the background job only if it's enabled, as the interval of the disabled one might be zero.
+pub fn interval_timer(enabled: bool, interval: IggyDuration) -> Option<Interval> {
+    match enabled && !interval.get_duration().is_zero() {
+        true => Some(time::interval(interval.get_duration())),
+        false => None,
+    }
+}
+
+/// Waits for the next tick of the timer, if it's available, otherwise never completes.
+pub async fn tick(timer: &mut Option<Interval>) {
+    match timer.as_mut() {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+fn get_non_reloadable_changes(current: &ServerConfig, reloaded: &ServerConfig) -> Vec<String> {
+    let (Ok(current), Ok(reloaded)) = (TomlValue::try_from(current), TomlValue::try_from(reloaded))
+    else {
+        return vec![];
+    };
+
+    let mut changes = Vec::new();
+    collect_changes("", &current, &reloaded, &mut changes);
+    changes
+        .into_iter()
+        .filter(|path| {
+            !RELOADABLE_PATHS.iter().any(|reloadable_path| {
+                path == reloadable_path || path.starts_with(&format!("{reloadable_path}."))
+            })
+        })
+        .collect()
+}
+
+fn collect_changes(
+    path: &str,
+    current: &TomlValue,
+    reloaded: &TomlValue,
+    changes: &mut Vec<String>,
+) {
+    match (current, reloaded) {
+        (TomlValue::Table(current), TomlValue::Table(reloaded)) => {
+            for (key, value) in current {
+                let key_path = match path.is_empty() {
+                    true => key.to_string(),
+                    false => format!("{path}.{key}"),
+                };
+                match reloaded.get(key) {
+                    Some(reloaded_value) => {
+                        collect_changes(&key_path, value, reloaded_value, changes)
+                    }
+                    None => changes.push(key_path),
+                }
+            }
+        }
+        _ => {
+            if current != reloaded {
+                changes.push(path.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use async_trait::async_trait;
+    use iggy::utils::duration::IggyDuration;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    struct StaticConfigProvider {
+        config: std::sync::Mutex<ServerConfig>,
+    }
+
+    #[async_trait]
+    impl ConfigProvider for StaticConfigProvider {
+        async fn load_config(&self) -> Result<ServerConfig, ServerError> {
+            Ok(self.config.lock().unwrap().clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn reloaded_config_should_be_compared_with_previously_applied_one() {
+        let config = ServerConfig::default();
+        let mut reloaded = config.clone();
+        reloaded.tcp.address = "127.0.0.1:9090".to_string();
+        reloaded.message_cleaner.interval = IggyDuration::from_str("5m").unwrap();
+        let reloader = ConfigReloader::new(
+            Box::new(StaticConfigProvider {
+                config: std::sync::Mutex::new(reloaded),
+            }),
+            &config,
+        );
+
+        let (previous_config, current_config) = reloader.reload().await.unwrap();
+
+        assert_eq!(
+            previous_config.message_cleaner.interval,
+            config.message_cleaner.interval
+        );
+        assert_eq!(
+            current_config.message_cleaner.interval,
+            IggyDuration::from_str("5m").unwrap()
+        );
+        let applied_config = reloader.config.lock().await;
+        assert_eq!(applied_config.tcp.address, "127.0.0.1:9090");
+    }
+
+    #[test]
+    fn changes_of_reloadable_sections_should_not_be_reported() {
+        let current = ServerConfig::default();
+        let mut reloaded = current.clone();
+        reloaded.message_cleaner.interval = IggyDuration::from_str("5m").unwrap();
+        reloaded.http.cors.allowed_origins = vec!["https://iggy.rs".to_string()];
+        let mut system = SystemConfig::default();
+        system.logging.level = "debug".to_string();
+        system.retention_policy.message_expiry = IggyDuration::from_str("1h").unwrap();
+        reloaded.system = Arc::new(system);
+
+        let changes = get_non_reloadable_changes(&current, &reloaded);
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn changes_of_non_reloadable_sections_should_be_reported() {
+        let current = ServerConfig::default();
+        let mut reloaded = current.clone();
+        reloaded.tcp.address = "127.0.0.1:9090".to_string();
+        let mut system = SystemConfig::default();
+        system.cache.enabled = !system.cache.enabled;
+        reloaded.system = Arc::new(system);
+
+        let changes = get_non_reloadable_changes(&current, &reloaded);
+
+        assert_eq!(
+            changes,
+            vec![
+                "system.cache.enabled".to_string(),
+                "tcp.address".to_string()
+            ]
+        );
+    }
+}
@@ -15,7 +15,11 @@ use std::sync::Arc;
 pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
     pub message_saver: MessageSaverConfig,
+    pub message_scheduler: MessageSchedulerConfig,
+    pub message_reencryptor: MessageReencryptorConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub transaction: TransactionConfig,
+    pub shutdown: ShutdownConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
@@ -39,6 +43,22 @@ pub struct MessageSaverConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MessageSchedulerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MessageReencryptorConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
 pub struct PersonalAccessTokenConfig {
     pub max_tokens_per_user: u32,
@@ -53,6 +73,29 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct TransactionConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub cleaner: TransactionCleanerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct TransactionCleanerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct ShutdownConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub drain_timeout: IggyDuration,
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
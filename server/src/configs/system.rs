@@ -1,22 +1,30 @@
 use crate::configs::resource_quota::MemoryResourceQuota;
+use crate::streaming::segments::segment;
 use byte_unit::Byte;
+use iggy::error::Error;
+use iggy::topics::config;
+use iggy::topics::partitioning_strategy::PartitioningStrategy;
 use iggy::{
     compression::compression_algorithm::CompressionAlgorithm, utils::duration::IggyDuration,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SystemConfig {
     pub path: String,
     pub database: DatabaseConfig,
     pub runtime: RuntimeConfig,
+    pub backup: BackupConfig,
     pub logging: LoggingConfig,
     pub cache: CacheConfig,
     pub retention_policy: RetentionPolicyConfig,
     pub stream: StreamConfig,
     pub topic: TopicConfig,
+    pub soft_delete: SoftDeleteConfig,
     pub partition: PartitionConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
@@ -24,33 +32,43 @@ pub struct SystemConfig {
     pub message_deduplication: MessageDeduplicationConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DatabaseConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RuntimeConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BackupConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
+    pub format: LogFormat,
     pub max_size: Byte,
     #[serde_as(as = "DisplayFromStr")]
+    pub rotation_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
     pub retention: IggyDuration,
+    pub max_total_size: Byte,
+    pub compress_rotated: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
@@ -64,32 +82,85 @@ pub struct RetentionPolicyConfig {
     pub max_topic_size: Byte,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
+    pub key_id: u32,
+    pub decryption_keys: Vec<EncryptionKeyConfig>,
+    pub per_stream_keys: bool,
+    pub master_key_path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EncryptionKeyConfig {
+    pub id: u32,
+    pub key: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StreamConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TopicConfig {
     pub path: String,
+    pub partitioning: PartitioningStrategy,
+    #[serde_as(as = "DisplayFromStr")]
+    pub sticky_batch_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SoftDeleteConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub grace_period: IggyDuration,
+    pub cleaner: SoftDeleteCleanerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SoftDeleteCleanerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub persister: PersisterKind,
+    pub group_commit: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub group_commit_interval: IggyDuration,
+    pub group_commit_max_size: Byte,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PersisterKind {
+    #[default]
+    File,
+    IoUring,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct MessageDeduplicationConfig {
     pub enabled: bool,
     pub max_entries: u64,
@@ -97,7 +168,7 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SegmentConfig {
     pub size: Byte,
     pub cache_indexes: bool,
@@ -105,6 +176,64 @@ pub struct SegmentConfig {
 }
 
 impl SystemConfig {
+    /// Returns the copy of the config with the per-topic overrides applied on top of it.
+    /// The cache can't be enabled for the topic if it's disabled for the whole server.
+    pub fn with_topic_overrides(
+        &self,
+        overrides: &HashMap<String, String>,
+    ) -> Result<SystemConfig, Error> {
+        let mut topic_config = self.clone();
+        for (key, value) in overrides {
+            match key.as_str() {
+                config::SEGMENT_SIZE => {
+                    let size = parse_override::<Byte>(key, value)?;
+                    if size.as_u64() == 0 || size.as_u64() > segment::MAX_SIZE_BYTES as u64 {
+                        return Err(Error::InvalidTopicConfig(key.to_string()));
+                    }
+                    topic_config.segment.size = size;
+                }
+                config::PARTITION_ENFORCE_FSYNC => {
+                    topic_config.partition.enforce_fsync = parse_override(key, value)?;
+                }
+                config::PARTITION_VALIDATE_CHECKSUM => {
+                    topic_config.partition.validate_checksum = parse_override(key, value)?;
+                }
+                config::PARTITION_MESSAGES_REQUIRED_TO_SAVE => {
+                    topic_config.partition.messages_required_to_save = parse_override(key, value)?;
+                }
+                config::MESSAGE_DEDUPLICATION_ENABLED => {
+                    topic_config.message_deduplication.enabled = parse_override(key, value)?;
+                }
+                config::MESSAGE_DEDUPLICATION_MAX_ENTRIES => {
+                    topic_config.message_deduplication.max_entries = parse_override(key, value)?;
+                }
+                config::MESSAGE_DEDUPLICATION_EXPIRY => {
+                    topic_config.message_deduplication.expiry = parse_override(key, value)?;
+                }
+                config::CACHE_ENABLED => {
+                    let enabled = parse_override(key, value)?;
+                    if enabled && !self.cache.enabled {
+                        return Err(Error::InvalidTopicConfig(key.to_string()));
+                    }
+                    topic_config.cache.enabled = enabled;
+                }
+                config::TOPIC_PARTITIONING => {
+                    topic_config.topic.partitioning = parse_override(key, value)?;
+                }
+                config::TOPIC_STICKY_BATCH_INTERVAL => {
+                    topic_config.topic.sticky_batch_interval = parse_override(key, value)?;
+                }
+                // The key ranges only apply to the topic they're set for, so they're kept by the topic itself.
+                config::TOPIC_KEY_RANGES => {
+                    config::parse_key_ranges(value)?;
+                }
+                _ => return Err(Error::InvalidTopicConfig(key.to_string())),
+            }
+        }
+
+        Ok(topic_config)
+    }
+
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
     }
@@ -117,6 +246,14 @@ impl SystemConfig {
         format!("{}/{}", self.get_system_path(), self.runtime.path)
     }
 
+    pub fn get_backups_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.backup.path)
+    }
+
+    pub fn get_backup_path(&self, name: &str) -> String {
+        format!("{}/{}", self.get_backups_path(), name)
+    }
+
     pub fn get_streams_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.stream.path)
     }
@@ -163,3 +300,9 @@ impl SystemConfig {
         )
     }
 }
+
+fn parse_override<T: FromStr>(key: &str, value: &str) -> Result<T, Error> {
+    value
+        .parse::<T>()
+        .map_err(|_| Error::InvalidTopicConfig(key.to_string()))
+}
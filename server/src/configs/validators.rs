@@ -1,13 +1,20 @@
 extern crate sysinfo;
 
-use super::server::{MessageCleanerConfig, MessageSaverConfig};
+use super::server::{
+    MessageCleanerConfig, MessageReencryptorConfig, MessageSaverConfig, MessageSchedulerConfig,
+};
 use super::system::CompressionConfig;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, RetentionPolicyConfig, SegmentConfig};
+use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig, TransactionConfig};
+use crate::configs::system::{
+    CacheConfig, EncryptionConfig, LoggingConfig, PartitionConfig, PersisterKind,
+    RetentionPolicyConfig, SegmentConfig, SoftDeleteConfig,
+};
 use crate::server_error::ServerError;
+use crate::streaming::encryption::master_key_provider::LocalMasterKeyProvider;
 use crate::streaming::segments::segment;
 use byte_unit::{Byte, UnitType};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::crypto::Aes256GcmKeyring;
 use iggy::validatable::Validatable;
 use sysinfo::System;
 use tracing::{error, info, warn};
@@ -18,7 +25,14 @@ impl Validatable<ServerError> for ServerConfig {
         self.system.cache.validate()?;
         self.system.retention_policy.validate()?;
         self.system.compression.validate()?;
+        self.system.partition.validate()?;
+        self.system.logging.validate()?;
+        self.system.encryption.validate()?;
+        self.system.soft_delete.validate()?;
+        self.message_scheduler.validate()?;
+        self.message_reencryptor.validate()?;
         self.personal_access_token.validate()?;
+        self.transaction.validate()?;
 
         Ok(())
     }
@@ -39,6 +53,21 @@ impl Validatable<ServerError> for CompressionConfig {
     }
 }
 
+impl Validatable<ServerError> for PartitionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.persister == PersisterKind::IoUring
+            && !cfg!(all(target_os = "linux", feature = "io-uring"))
+        {
+            return Err(ServerError::PartitionConfigValidationFailure(
+                "io_uring persister requires Linux and the server built with `io-uring` feature."
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for CacheConfig {
     fn validate(&self) -> Result<(), ServerError> {
         let limit_bytes = self.size.clone().into();
@@ -104,6 +133,26 @@ impl Validatable<ServerError> for SegmentConfig {
     }
 }
 
+impl Validatable<ServerError> for LoggingConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.max_size.as_u64() == 0 {
+            error!("Logging configuration -> max_size must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        let max_total_size = self.max_total_size.as_u64();
+        if max_total_size > 0 && max_total_size < self.max_size.as_u64() {
+            error!(
+                "Logging configuration -> max_total_size: {} cannot be lower than max_size: {}.",
+                self.max_total_size, self.max_size
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for MessageSaverConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.enabled && self.interval.is_zero() {
@@ -126,6 +175,90 @@ impl Validatable<ServerError> for MessageCleanerConfig {
     }
 }
 
+impl Validatable<ServerError> for MessageSchedulerConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        // The sub-second interval is allowed, as it determines the delivery precision.
+        if self.enabled && self.interval.get_duration().is_zero() {
+            error!("Message scheduler interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for MessageReencryptorConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval.is_zero() {
+            error!("Message reencryptor interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for EncryptionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let decryption_keys = self
+            .decryption_keys
+            .iter()
+            .map(|key| (key.id, key.key.as_str()))
+            .collect::<Vec<_>>();
+        if let Err(error) =
+            Aes256GcmKeyring::from_base64_keys(self.key_id, &self.key, &decryption_keys)
+        {
+            error!("Encryption configuration is invalid. Error: {error}");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !self.master_key_path.is_empty() {
+            if let Err(error) = LocalMasterKeyProvider::from_file(&self.master_key_path) {
+                error!("Encryption master key is invalid. Error: {error}");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TransactionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.timeout.is_zero() {
+            error!("Transaction timeout cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.cleaner.enabled && self.cleaner.interval.is_zero() {
+            error!("Transaction cleaner interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for SoftDeleteConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.grace_period.is_zero() {
+            error!("Soft delete grace period cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.cleaner.enabled && self.cleaner.interval.is_zero() {
+            error!("Soft delete cleaner interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
@@ -0,0 +1,241 @@
+use crate::configs::http::HttpConsumerGroupsConfig;
+use crate::http::shared::AppState;
+use crate::streaming::utils::hash;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::user_info::UserId;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::TimeStamp;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info};
+use ulid::Ulid;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConsumerGroupMemberLease {
+    pub member_id: u32,
+    pub expiry: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MemberLease {
+    pub member_id: u32,
+    pub user_id: UserId,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub consumer_group_id: u32,
+    pub expiry: u64,
+}
+
+pub struct ConsumerGroupLeases {
+    member_expiry: IggyDuration,
+    leases: RwLock<HashMap<u32, MemberLease>>,
+}
+
+impl ConsumerGroupLeases {
+    pub fn from_config(config: &HttpConsumerGroupsConfig) -> Self {
+        Self {
+            member_expiry: config.member_expiry,
+            leases: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Creates the lease for the new member, which ID is neither used by the other members nor by the connected TCP and QUIC clients,
+    /// as all of them share the same consumer group member IDs.
+    pub async fn create(
+        &self,
+        user_id: UserId,
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+        is_client_id: impl Fn(u32) -> bool,
+    ) -> MemberLease {
+        let mut leases = self.leases.write().await;
+        let mut member_id = Self::generate_member_id();
+        while leases.contains_key(&member_id) || is_client_id(member_id) {
+            member_id = Self::generate_member_id();
+        }
+
+        let lease = MemberLease {
+            member_id,
+            user_id,
+            stream_id,
+            topic_id,
+            consumer_group_id,
+            expiry: self.calculate_expiry(),
+        };
+        leases.insert(member_id, lease);
+        debug!(
+            "Created lease for member with ID: {member_id} in consumer group with ID: {consumer_group_id}."
+        );
+        lease
+    }
+
+    pub async fn renew(&self, member_id: u32, user_id: UserId) -> Result<MemberLease, Error> {
+        let now = TimeStamp::now().to_secs();
+        let mut leases = self.leases.write().await;
+        let lease = leases
+            .get_mut(&member_id)
+            .filter(|lease| lease.user_id == user_id && lease.expiry > now);
+        let Some(lease) = lease else {
+            return Err(Error::ResourceNotFound(format!(
+                "consumer group member: {member_id}"
+            )));
+        };
+
+        lease.expiry = now + self.member_expiry.as_secs() as u64;
+        Ok(*lease)
+    }
+
+    pub async fn remove(&self, member_id: u32, user_id: UserId) -> Result<MemberLease, Error> {
+        let mut leases = self.leases.write().await;
+        if leases
+            .get(&member_id)
+            .is_none_or(|lease| lease.user_id != user_id)
+        {
+            return Err(Error::ResourceNotFound(format!(
+                "consumer group member: {member_id}"
+            )));
+        }
+
+        Ok(leases.remove(&member_id).unwrap())
+    }
+
+    pub async fn remove_expired(&self, now: u64) -> Vec<MemberLease> {
+        let mut leases = self.leases.write().await;
+        let expired_members = leases
+            .values()
+            .filter(|lease| lease.expiry <= now)
+            .map(|lease| lease.member_id)
+            .collect::<Vec<_>>();
+        expired_members
+            .iter()
+            .filter_map(|member_id| leases.remove(member_id))
+            .collect()
+    }
+
+    fn calculate_expiry(&self) -> u64 {
+        TimeStamp::now().to_secs() + self.member_expiry.as_secs() as u64
+    }
+
+    fn generate_member_id() -> u32 {
+        hash::calculate_32(Ulid::new().to_string().as_bytes())
+    }
+}
+
+impl From<MemberLease> for ConsumerGroupMemberLease {
+    fn from(lease: MemberLease) -> Self {
+        Self {
+            member_id: lease.member_id,
+            expiry: lease.expiry,
+        }
+    }
+}
+
+pub fn start_expired_leases_cleaner(app_state: Arc<AppState>, interval: IggyDuration) {
+    tokio::spawn(async move {
+        let mut interval_timer = tokio::time::interval(interval.get_duration());
+        loop {
+            interval_timer.tick().await;
+            let now = TimeStamp::now().to_secs();
+            let expired_leases = app_state.consumer_group_leases.remove_expired(now).await;
+            if expired_leases.is_empty() {
+                continue;
+            }
+
+            info!(
+                "Removing {} expired consumer group member(s)...",
+                expired_leases.len()
+            );
+            let system = app_state.system.read();
+            for lease in expired_leases {
+                system
+                    .leave_consumer_group_by_member(
+                        &Identifier::numeric(lease.stream_id).unwrap(),
+                        &Identifier::numeric(lease.topic_id).unwrap(),
+                        &Identifier::numeric(lease.consumer_group_id).unwrap(),
+                        lease.member_id,
+                    )
+                    .await
+                    .unwrap_or_else(|err| {
+                        error!(
+                            "Failed to remove expired member with ID: {} from consumer group with ID: {}. Error: {}",
+                            lease.member_id, lease.consumer_group_id, err
+                        );
+                    });
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[tokio::test]
+    async fn lease_should_be_created_and_renewed_by_its_owner() {
+        let leases = create_leases();
+        let user_id = 1;
+        let lease = leases.create(user_id, 1, 2, 3, |_| false).await;
+        assert_eq!(lease.user_id, user_id);
+        assert_eq!(lease.stream_id, 1);
+        assert_eq!(lease.topic_id, 2);
+        assert_eq!(lease.consumer_group_id, 3);
+        assert!(lease.expiry > TimeStamp::now().to_secs());
+
+        let renewed_lease = leases.renew(lease.member_id, user_id).await.unwrap();
+        assert_eq!(renewed_lease.member_id, lease.member_id);
+        assert!(renewed_lease.expiry >= lease.expiry);
+        assert!(leases.renew(lease.member_id, user_id + 1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn lease_should_be_removed_only_by_its_owner() {
+        let leases = create_leases();
+        let user_id = 1;
+        let lease = leases.create(user_id, 1, 2, 3, |_| false).await;
+        assert!(leases.remove(lease.member_id, user_id + 1).await.is_err());
+        assert!(leases.remove(lease.member_id, user_id).await.is_ok());
+        assert!(leases.renew(lease.member_id, user_id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn expired_leases_should_be_removed() {
+        let leases = create_leases();
+        let lease = leases.create(1, 1, 2, 3, |_| false).await;
+        assert!(leases.remove_expired(lease.expiry - 1).await.is_empty());
+
+        let expired_leases = leases.remove_expired(lease.expiry).await;
+        assert_eq!(expired_leases.len(), 1);
+        assert_eq!(expired_leases[0].member_id, lease.member_id);
+        assert!(leases.renew(lease.member_id, 1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn lease_should_not_be_created_with_member_id_of_connected_client() {
+        let leases = create_leases();
+        let client_ids = RefCell::new(Vec::new());
+        let lease = leases
+            .create(1, 1, 2, 3, |member_id| {
+                let mut client_ids = client_ids.borrow_mut();
+                if client_ids.len() == 3 {
+                    return false;
+                }
+                client_ids.push(member_id);
+                true
+            })
+            .await;
+
+        let client_ids = client_ids.into_inner();
+        assert_eq!(client_ids.len(), 3);
+        assert!(!client_ids.contains(&lease.member_id));
+    }
+
+    fn create_leases() -> ConsumerGroupLeases {
+        ConsumerGroupLeases::from_config(&HttpConsumerGroupsConfig::default())
+    }
+}
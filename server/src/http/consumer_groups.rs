@@ -1,3 +1,4 @@
+use crate::http::consumer_group_leases::ConsumerGroupMemberLease;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
@@ -5,7 +6,7 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post, put};
 use axum::{Extension, Json, Router};
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::identifier::Identifier;
@@ -23,9 +24,28 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/consumer-groups/:consumer_group_id",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-groups/:consumer_group_id/members",
+            post(join_consumer_group),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-groups/:consumer_group_id/members/:member_id",
+            put(renew_consumer_group_member).delete(leave_consumer_group),
+        )
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{consumer_group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("consumer_group_id" = String, Path, description = "Consumer group ID (numeric or name)"),
+    ),
+    responses((status = 200, body = ConsumerGroupDetails)),
+)]
 async fn get_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -46,6 +66,16 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    responses((status = 200, body = Vec<ConsumerGroup>)),
+)]
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -63,6 +93,17 @@ async fn get_consumer_groups(
     Ok(Json(consumer_groups))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = CreateConsumerGroup,
+    responses((status = 201)),
+)]
 async fn create_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -85,6 +126,17 @@ async fn create_consumer_group(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{consumer_group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("consumer_group_id" = String, Path, description = "Consumer group ID (numeric or name)"),
+    ),
+    responses((status = 204)),
+)]
 async fn delete_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -104,3 +156,116 @@ async fn delete_consumer_group(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{consumer_group_id}/members",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("consumer_group_id" = String, Path, description = "Consumer group ID (numeric or name)"),
+    ),
+    responses((status = 201, body = ConsumerGroupMemberLease)),
+)]
+async fn join_consumer_group(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, consumer_group_id)): Path<(String, String, String)>,
+) -> Result<(StatusCode, Json<ConsumerGroupMemberLease>), CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
+    let system = state.system.read();
+    let stream = system.get_stream(&stream_id)?;
+    let topic = stream.get_topic(&topic_id)?;
+    let consumer_group = topic.get_consumer_group(&consumer_group_id)?;
+    let consumer_group_id_value = consumer_group.read().await.consumer_group_id;
+    let client_manager = system.client_manager.read().await;
+    let lease = state
+        .consumer_group_leases
+        .create(
+            identity.user_id,
+            stream.stream_id,
+            topic.topic_id,
+            consumer_group_id_value,
+            |member_id| client_manager.get_client_by_id(member_id).is_ok(),
+        )
+        .await;
+    drop(client_manager);
+    if let Err(error) = system
+        .join_consumer_group_by_member(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+            &consumer_group_id,
+            lease.member_id,
+        )
+        .await
+    {
+        state
+            .consumer_group_leases
+            .remove(lease.member_id, identity.user_id)
+            .await?;
+        return Err(error.into());
+    }
+
+    Ok((StatusCode::CREATED, Json(lease.into())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{consumer_group_id}/members/{member_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("consumer_group_id" = String, Path, description = "Consumer group ID (numeric or name)"),
+        ("member_id" = u32, Path, description = "Consumer group member ID"),
+    ),
+    responses((status = 200, body = ConsumerGroupMemberLease)),
+)]
+async fn renew_consumer_group_member(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((_, _, _, member_id)): Path<(String, String, String, u32)>,
+) -> Result<Json<ConsumerGroupMemberLease>, CustomError> {
+    let lease = state
+        .consumer_group_leases
+        .renew(member_id, identity.user_id)
+        .await?;
+    Ok(Json(lease.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{consumer_group_id}/members/{member_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("consumer_group_id" = String, Path, description = "Consumer group ID (numeric or name)"),
+        ("member_id" = u32, Path, description = "Consumer group member ID"),
+    ),
+    responses((status = 204)),
+)]
+async fn leave_consumer_group(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((_, _, _, member_id)): Path<(String, String, String, u32)>,
+) -> Result<StatusCode, CustomError> {
+    let lease = state
+        .consumer_group_leases
+        .remove(member_id, identity.user_id)
+        .await?;
+    let system = state.system.read();
+    system
+        .leave_consumer_group_by_member(
+            &Identifier::numeric(lease.stream_id)?,
+            &Identifier::numeric(lease.topic_id)?,
+            &Identifier::numeric(lease.consumer_group_id)?,
+            lease.member_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -23,6 +23,18 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "consumer_offsets",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("id" = Option<String>, Query, description = "Consumer ID (numeric or name)"),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID"),
+    ),
+    responses((status = 200, body = ConsumerOffsetInfo)),
+)]
 async fn get_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -46,6 +58,17 @@ async fn get_consumer_offset(
     Ok(Json(offset))
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "consumer_offsets",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = StoreConsumerOffset,
+    responses((status = 204)),
+)]
 async fn store_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -65,6 +88,7 @@ async fn store_consumer_offset(
             &command.stream_id,
             &command.topic_id,
             command.offset,
+            command.transaction_id,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
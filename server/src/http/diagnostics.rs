@@ -9,7 +9,8 @@ use axum::{
 };
 use std::net::SocketAddr;
 use tokio::time::Instant;
-use tracing::debug;
+use tracing::field::Empty;
+use tracing::{debug, info_span, Instrument};
 
 pub async fn request_diagnostics(
     ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
@@ -31,8 +32,23 @@ pub async fn request_diagnostics(
         request_id,
         ip_address,
     });
+    // The user ID is recorded once the request is authenticated.
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        user_id = Empty,
+        stream_id = Empty,
+        topic_id = Empty
+    );
+    let (stream_id, topic_id) = get_stream_and_topic(request.uri().path());
+    if let Some(stream_id) = stream_id {
+        span.record("stream_id", stream_id);
+    }
+    if let Some(topic_id) = topic_id {
+        span.record("topic_id", topic_id);
+    }
     let now = Instant::now();
-    let result = Ok(next.run(request).await);
+    let result = Ok(next.run(request).instrument(span).await);
     let elapsed = now.elapsed();
     debug!(
         "Processed a request with ID: {request_id} from client with IP address: {ip_address} in {} ms.",
@@ -40,3 +56,16 @@ pub async fn request_diagnostics(
     );
     result
 }
+
+// Extracts the stream and topic IDs from the paths like: /streams/{stream_id}/topics/{topic_id}/...
+fn get_stream_and_topic(path: &str) -> (Option<&str>, Option<&str>) {
+    let mut parts = path.split('/').filter(|part| !part.is_empty());
+    let stream_id = match (parts.next(), parts.next()) {
+        (Some("streams"), Some(stream_id)) => stream_id,
+        _ => return (None, None),
+    };
+    match (parts.next(), parts.next()) {
+        (Some("topics"), Some(topic_id)) => (Some(stream_id), Some(topic_id)),
+        _ => (Some(stream_id), None),
+    }
+}
@@ -32,6 +32,8 @@ impl IntoResponse for CustomError {
                     Error::ConsumerGroupIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    Error::ProducerNotFound(_) => StatusCode::NOT_FOUND,
+                    Error::TransactionNotFound(_) => StatusCode::NOT_FOUND,
                     Error::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     Error::ResourceNotFound(_) => StatusCode::NOT_FOUND,
                     Error::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -41,6 +43,7 @@ impl IntoResponse for CustomError {
                     Error::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::Unauthenticated => StatusCode::UNAUTHORIZED,
                     Error::Unauthorized => StatusCode::FORBIDDEN,
+                    Error::ServerShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -70,6 +73,7 @@ impl ErrorResponse {
                 Error::StreamIdAlreadyExists(_) => Some("stream_id".to_string()),
                 Error::InvalidTopicId => Some("topic_id".to_string()),
                 Error::TopicIdAlreadyExists(_, _) => Some("topic_id".to_string()),
+                Error::InvalidTopicConfig(_) => Some("config".to_string()),
                 Error::InvalidOffset(_) => Some("offset".to_string()),
                 Error::InvalidConsumerGroupId => Some("consumer_group_id".to_string()),
                 Error::ConsumerGroupIdAlreadyExists(_, _) => Some("consumer_group_id".to_string()),
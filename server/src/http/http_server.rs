@@ -1,30 +1,46 @@
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::configs::reload;
+use crate::http::consumer_group_leases::{start_expired_leases_cleaner, ConsumerGroupLeases};
 use crate::http::diagnostics::request_diagnostics;
+use crate::http::error::CustomError;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
+use crate::http::send_queues::SendQueues;
 use crate::http::shared::AppState;
 use crate::http::*;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::systems::system::SharedSystem;
+use axum::extract::{Request, State};
 use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
 use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
+use iggy::error::Error;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use tokio::sync::watch;
+use tower::{Layer, ServiceExt};
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::{error, info};
 
 /// Starts the HTTP API server.
 /// Returns the address the server is listening on.
-pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    config: HttpConfig,
+    system: SharedSystem,
+    shutdown: GracefulShutdown,
+) -> SocketAddr {
     let api_name = if config.tls.enabled {
         "HTTP API (TLS)"
     } else {
         "HTTP API"
     };
 
+    let cors_layer = start_cors_updater(&config.cors, &system);
     let app_state = build_app_state(&config, system).await;
     let mut app = Router::new()
         .merge(system::router(app_state.clone(), &config.metrics))
@@ -36,18 +52,27 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
-        .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
+        .merge(transactions::router(app_state.clone()));
 
-    if config.cors.enabled {
-        app = app.layer(configure_cors(config.cors));
+    if config.openapi.enabled {
+        app = app.merge(openapi::router(&config.openapi));
     }
 
+    app = app.layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
+
+    app = app.layer(middleware::from_fn_with_state(cors_layer, cors));
+
     if config.metrics.enabled {
         app = app.layer(middleware::from_fn_with_state(app_state.clone(), metrics));
     }
 
     start_expired_tokens_cleaner(app_state.clone());
+    start_expired_leases_cleaner(app_state.clone(), config.consumer_groups.member_expiry);
     app = app.layer(middleware::from_fn(request_diagnostics));
+    app = app.layer(middleware::from_fn_with_state(
+        shutdown.clone(),
+        track_in_flight_request,
+    ));
 
     if !config.tls.enabled {
         let listener = tokio::net::TcpListener::bind(config.address).await.unwrap();
@@ -60,6 +85,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
                 listener,
                 app.into_make_service_with_connect_info::<SocketAddr>(),
             )
+            .with_graceful_shutdown(async move { shutdown.draining().await })
             .await
             {
                 error!("Failed to start {api_name} server, error {}", error);
@@ -82,8 +108,16 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
         info!("Started {api_name} on: {address}");
 
+        let handle = axum_server::Handle::new();
+        let shutdown_handle = handle.clone();
+        tokio::task::spawn(async move {
+            shutdown.draining().await;
+            shutdown_handle.graceful_shutdown(None);
+        });
+
         tokio::task::spawn(async move {
             if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
+                .handle(handle)
                 .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
             {
@@ -118,9 +152,69 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
 
     Arc::new(AppState {
         jwt_manager,
+        consumer_group_leases: ConsumerGroupLeases::from_config(&config.consumer_groups),
+        send_queues: SendQueues::default(),
+        openapi_endpoint: config
+            .openapi
+            .enabled
+            .then(|| config.openapi.endpoint.clone()),
         system,
     })
 }
+
+// The CORS layer can be changed when the config is reloaded, so it's resolved for each request.
+fn start_cors_updater(
+    config: &HttpCorsConfig,
+    system: &SharedSystem,
+) -> watch::Receiver<Option<CorsLayer>> {
+    let (sender, receiver) = watch::channel(create_cors_layer(config));
+    let mut config_changes = system.read().subscribe_to_config_changes();
+    if config_changes.is_some() {
+        tokio::spawn(async move {
+            while let Some(config) = reload::changed(&mut config_changes).await {
+                sender.send_replace(create_cors_layer(&config.cors));
+            }
+        });
+    }
+    receiver
+}
+
+async fn cors(
+    State(cors_layer): State<watch::Receiver<Option<CorsLayer>>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let cors_layer = cors_layer.borrow().clone();
+    match cors_layer {
+        Some(cors_layer) => match cors_layer.layer(next).oneshot(request).await {
+            Ok(response) => response,
+            Err(error) => match error {},
+        },
+        None => next.run(request).await,
+    }
+}
+
+// The requests received while draining are rejected, the in-flight ones are awaited before the shutdown.
+async fn track_in_flight_request(
+    State(shutdown): State<GracefulShutdown>,
+    request: Request,
+    next: Next,
+) -> Result<Response, CustomError> {
+    let Some(_request) = shutdown.start_request() else {
+        shutdown.client_notified();
+        return Err(CustomError::Error(Error::ServerShuttingDown));
+    };
+
+    Ok(next.run(request).await)
+}
+
+fn create_cors_layer(config: &HttpCorsConfig) -> Option<CorsLayer> {
+    match config.enabled {
+        true => Some(configure_cors(config.clone())),
+        false => None,
+    }
+}
+
 fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
     let allowed_origins = match config.allowed_origins {
         origins if origins.is_empty() => AllowOrigin::default(),
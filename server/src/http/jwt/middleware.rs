@@ -8,6 +8,7 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
+use tracing::Span;
 
 const AUTHORIZATION: &str = "authorization";
 const BEARER: &str = "Bearer ";
@@ -27,7 +28,8 @@ pub async fn jwt_auth(
     mut request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if UNAUTHORIZED_PATHS.contains(&request.uri().path()) {
+    let path = request.uri().path();
+    if UNAUTHORIZED_PATHS.contains(&path) || state.openapi_endpoint.as_deref() == Some(path) {
         return Ok(next.run(request).await);
     }
 
@@ -63,6 +65,7 @@ pub async fn jwt_auth(
         user_id: jwt_claims.claims.sub,
         ip_address: request_details.ip_address,
     };
+    Span::current().record("user_id", identity.user_id);
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
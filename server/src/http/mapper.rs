@@ -76,6 +76,7 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         partitions_count: topic.get_partitions().len() as u32,
         partitions: Vec::new(),
         message_expiry: topic.message_expiry,
+        config: topic.config_overrides.clone(),
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
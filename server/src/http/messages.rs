@@ -5,15 +5,38 @@ use crate::streaming;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::get;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use futures::Stream;
+use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::register_producer::RegisterProducer;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning, SendMessages};
+use iggy::models::messages::Message;
+use iggy::models::producer_info::ProducerInfo;
 use iggy::validatable::Validatable;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::convert::Infallible;
 use std::sync::Arc;
+use tokio::sync::watch;
+
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+#[derive(Debug, Deserialize)]
+struct StreamMessagesQuery {
+    partition_id: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConsumerGroupMemberQuery {
+    member_id: Option<u32>,
+}
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -21,35 +44,100 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/messages",
             get(poll_messages).post(send_messages),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/stream",
+            get(stream_messages),
+        )
+        .route("/producers", post(register_producer))
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("id" = Option<String>, Query, description = "Consumer ID (numeric or name)"),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID"),
+        ("kind" = Option<String>, Query, description = "Polling strategy kind: offset, timestamp, first, last or next"),
+        ("value" = Option<u64>, Query, description = "Polling strategy value"),
+        ("count" = Option<u32>, Query, description = "Number of messages to poll"),
+        ("auto_commit" = Option<bool>, Query, description = "Whether to commit the offset after polling the messages"),
+        ("isolation" = Option<String>, Query, description = "Isolation level: read_uncommitted or read_committed"),
+        ("member_id" = Option<u32>, Query, description = "Consumer group member ID, if polling as a consumer group member"),
+    ),
+    responses((status = 200, body = PolledMessages)),
+)]
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
+    Query(member): Query<ConsumerGroupMemberQuery>,
 ) -> Result<Json<streaming::models::messages::PolledMessages>, CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
-    let partition_id = query.partition_id.unwrap_or(0);
-    let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
-    let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
     let system = state.system.read();
+    let consumer = match member.member_id {
+        Some(member_id) => {
+            // The consumer group is taken from the member lease, which is renewed by polling.
+            let lease = state
+                .consumer_group_leases
+                .renew(member_id, identity.user_id)
+                .await?;
+            let stream = system.get_stream(&query.stream_id)?;
+            let topic = stream.get_topic(&query.topic_id)?;
+            if lease.stream_id != stream.stream_id || lease.topic_id != topic.topic_id {
+                return Err(Error::ConsumerGroupMemberNotFound(
+                    member_id,
+                    lease.consumer_group_id,
+                    topic.topic_id,
+                )
+                .into());
+            }
+            PollingConsumer::ConsumerGroup(lease.consumer_group_id, member_id)
+        }
+        None => {
+            let partition_id = query.partition_id.unwrap_or(0);
+            let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
+            PollingConsumer::Consumer(consumer_id, partition_id)
+        }
+    };
     let polled_messages = system
         .poll_messages(
             &Session::stateless(identity.user_id, identity.ip_address),
             consumer,
             &query.stream_id,
             &query.topic_id,
-            PollingArgs::new(query.strategy, query.count, query.auto_commit),
+            PollingArgs::new(
+                query.strategy,
+                query.count,
+                query.auto_commit,
+                query.isolation,
+            ),
         )
         .await?;
     Ok(Json(polled_messages))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = SendMessages,
+    responses(
+        (status = 201, description = "Messages appended"),
+        (status = 202, description = "Messages accepted without acknowledgement"),
+    ),
+)]
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -61,15 +149,235 @@ async fn send_messages(
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    if command.acknowledgement == Acknowledgement::None {
+        // The partition is resolved upfront, so that the messages are queued and appended in order for each partition.
+        let (stream_id, topic_id, partition_id) = state
+            .system
+            .read()
+            .get_partition_to_append_messages(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                &command.partitioning,
+            )
+            .await?;
+        command.partitioning = Partitioning::partition_id(partition_id);
+        state.send_queues.enqueue(
+            &state.system,
+            (stream_id, topic_id, partition_id),
+            session,
+            command,
+        );
+        return Ok(StatusCode::ACCEPTED);
+    }
+
     let system = state.system.read();
     system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
             &command.messages,
+            command.acknowledgement,
+            command.producer,
+            command.transaction_id,
         )
         .await?;
     Ok(StatusCode::CREATED)
 }
+
+#[utoipa::path(
+    post,
+    path = "/producers",
+    tag = "messages",
+    request_body = RegisterProducer,
+    responses((status = 200, body = ProducerInfo)),
+)]
+async fn register_producer(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<RegisterProducer>,
+) -> Result<Json<ProducerInfo>, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write();
+    let producer = system
+        .register_producer(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.producer_id,
+        )
+        .await?;
+    Ok(Json(ProducerInfo {
+        producer_id: producer.producer_id,
+        epoch: producer.epoch,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/stream",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("id" = Option<String>, Query, description = "Consumer ID (numeric or name)"),
+        ("partition_id" = u32, Query, description = "Partition ID"),
+        ("kind" = Option<String>, Query, description = "Polling strategy kind: offset, timestamp, first, last or next"),
+        ("value" = Option<u64>, Query, description = "Polling strategy value"),
+        ("count" = Option<u32>, Query, description = "Number of messages to poll"),
+        ("auto_commit" = Option<bool>, Query, description = "Whether to commit the offset after polling the messages"),
+        ("isolation" = Option<String>, Query, description = "Isolation level: read_uncommitted or read_committed"),
+        ("Last-Event-ID" = Option<u64>, Header, description = "Offset of the last received message, the stream resumes right after it"),
+    ),
+    responses((status = 200, description = "Server-Sent Events stream of messages", content_type = "text/event-stream")),
+)]
+async fn stream_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Query(partition): Query<StreamMessagesQuery>,
+    headers: HeaderMap,
+    mut query: Query<PollMessages>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    // The partition ID isn't defaulted like for polling, as the stream would silently skip the other partitions.
+    let Some(partition_id) = partition.partition_id else {
+        return Err(CustomError::Error(Error::PartitionIdRequired));
+    };
+
+    // The reconnecting client resumes right after the last received event, which ID is the message offset.
+    let mut strategy = query.0.strategy;
+    if let Some(last_event_id) = headers.get(LAST_EVENT_ID_HEADER) {
+        let offset = last_event_id
+            .to_str()
+            .ok()
+            .and_then(|last_event_id| last_event_id.parse::<u64>().ok())
+            .ok_or(Error::InvalidFormat)?;
+        strategy = PollingStrategy::offset(offset + 1);
+    }
+
+    let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let appended_messages = state
+        .system
+        .read()
+        .subscribe_to_appended_messages(&session, &query.stream_id, &query.topic_id, partition_id)
+        .await?;
+    let mut messages_stream = MessagesStream {
+        system: state.system.clone(),
+        session,
+        consumer: PollingConsumer::Consumer(consumer_id, partition_id),
+        partition_id,
+        stream_id: query.0.stream_id,
+        topic_id: query.0.topic_id,
+        strategy,
+        count: query.0.count,
+        auto_commit: query.0.auto_commit,
+        isolation: query.0.isolation,
+        appended_messages,
+        pending: VecDeque::new(),
+        closed: false,
+    };
+
+    // The first poll is done before the response is returned, so that the invalid
+    // stream, topic or partition and the missing permissions are reported as HTTP errors.
+    messages_stream.poll().await?;
+    let stream = futures::stream::unfold(messages_stream, |mut messages_stream| async move {
+        let event = messages_stream.next_event().await?;
+        Some((Ok(event), messages_stream))
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+struct MessagesStream {
+    system: SharedSystem,
+    session: Session,
+    consumer: PollingConsumer,
+    partition_id: u32,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    strategy: PollingStrategy,
+    count: u32,
+    auto_commit: bool,
+    isolation: IsolationLevel,
+    appended_messages: watch::Receiver<u64>,
+    pending: VecDeque<Arc<Message>>,
+    closed: bool,
+}
+
+impl MessagesStream {
+    async fn poll(&mut self) -> Result<(), CustomError> {
+        let system = self.system.read();
+        let polled_messages = system
+            .poll_messages(
+                &self.session,
+                self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                PollingArgs::new(self.strategy, self.count, self.auto_commit, self.isolation),
+            )
+            .await?;
+        if let Some(message) = polled_messages.messages.last() {
+            self.strategy = PollingStrategy::offset(message.offset + 1);
+        }
+        self.pending.extend(polled_messages.messages);
+        Ok(())
+    }
+
+    async fn wait_for_appended_messages(&mut self) -> Result<(), CustomError> {
+        if self.appended_messages.changed().await.is_ok() {
+            return Ok(());
+        }
+
+        // The partition has been deleted or replaced (e.g. by repartitioning), so the stream either subscribes to the new one or fails.
+        let system = self.system.read();
+        self.appended_messages = system
+            .subscribe_to_appended_messages(
+                &self.session,
+                &self.stream_id,
+                &self.topic_id,
+                self.partition_id,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<Event> {
+        if self.closed {
+            return None;
+        }
+
+        while self.pending.is_empty() {
+            if let Err(error) = self.poll().await {
+                self.closed = true;
+                return Some(Event::default().event("error").data(error.to_string()));
+            }
+
+            if !self.pending.is_empty() {
+                break;
+            }
+
+            if let Err(error) = self.wait_for_appended_messages().await {
+                self.closed = true;
+                return Some(Event::default().event("error").data(error.to_string()));
+            }
+        }
+
+        let message = self.pending.pop_front()?;
+        match Event::default()
+            .event("message")
+            .id(message.offset.to_string())
+            .json_data(message.as_ref())
+        {
+            Ok(event) => Some(event),
+            Err(error) => {
+                self.closed = true;
+                Some(Event::default().event("error").data(error.to_string()))
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod consumer_group_leases;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
@@ -7,10 +8,13 @@ pub mod jwt;
 mod mapper;
 pub mod messages;
 pub mod metrics;
+pub mod openapi;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod send_queues;
 mod shared;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
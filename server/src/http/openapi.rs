@@ -0,0 +1,209 @@
+use crate::configs::http::HttpOpenApiConfig;
+use crate::http::consumer_group_leases::ConsumerGroupMemberLease;
+use crate::http::*;
+use axum::routing::get;
+use axum::{Json, Router};
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::messages::register_producer::RegisterProducer;
+use iggy::messages::send_messages;
+use iggy::messages::send_messages::{
+    Acknowledgement, Partitioning, PartitioningKind, ProducerSequence, SendMessages,
+};
+use iggy::models::backup_info::BackupInfo;
+use iggy::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use iggy::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
+use iggy::models::messages::{Message, MessageState, PolledMessages};
+use iggy::models::partition::Partition;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use iggy::models::producer_info::ProducerInfo;
+use iggy::models::stats::Stats;
+use iggy::models::stream::{Stream, StreamDetails};
+use iggy::models::topic::{Topic, TopicDetails};
+use iggy::models::transaction_info::TransactionInfo;
+use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::models::user_status::UserStatus;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::system::set_log_level::{LogLevel, SetLogLevel};
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::repartition_topic::RepartitionTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+use iggy::users::change_password::ChangePassword;
+use iggy::users::create_user::CreateUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::logout_user::LogoutUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_user::UpdateUser;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Iggy HTTP API"),
+    paths(
+        system::get_name,
+        system::ping,
+        system::get_stats,
+        system::create_backup,
+        system::set_log_level,
+        system::reload_config,
+        system::get_client,
+        system::get_clients,
+        users::get_user,
+        users::get_users,
+        users::create_user,
+        users::update_user,
+        users::update_permissions,
+        users::change_password,
+        users::delete_user,
+        users::login_user,
+        users::logout_user,
+        users::refresh_token,
+        personal_access_tokens::get_personal_access_tokens,
+        personal_access_tokens::create_personal_access_token,
+        personal_access_tokens::delete_personal_access_token,
+        personal_access_tokens::login_with_personal_access_token,
+        streams::get_stream,
+        streams::get_streams,
+        streams::create_stream,
+        streams::update_stream,
+        streams::delete_stream,
+        streams::purge_stream,
+        streams::restore_stream,
+        topics::get_topic,
+        topics::get_topics,
+        topics::create_topic,
+        topics::update_topic,
+        topics::delete_topic,
+        topics::purge_topic,
+        topics::restore_topic,
+        topics::repartition_topic,
+        partitions::create_partitions,
+        partitions::delete_partitions,
+        consumer_groups::get_consumer_group,
+        consumer_groups::get_consumer_groups,
+        consumer_groups::create_consumer_group,
+        consumer_groups::delete_consumer_group,
+        consumer_groups::join_consumer_group,
+        consumer_groups::renew_consumer_group_member,
+        consumer_groups::leave_consumer_group,
+        consumer_offsets::get_consumer_offset,
+        consumer_offsets::store_consumer_offset,
+        messages::poll_messages,
+        messages::send_messages,
+        messages::stream_messages,
+        messages::register_producer,
+        transactions::begin_transaction,
+        transactions::commit_transaction,
+        transactions::abort_transaction,
+    ),
+    components(schemas(
+        Stats,
+        BackupInfo,
+        SetLogLevel,
+        LogLevel,
+        ClientInfo,
+        ClientInfoDetails,
+        ConsumerGroupInfo,
+        UserInfo,
+        UserInfoDetails,
+        UserStatus,
+        Permissions,
+        GlobalPermissions,
+        StreamPermissions,
+        TopicPermissions,
+        CreateUser,
+        UpdateUser,
+        UpdatePermissions,
+        ChangePassword,
+        LoginUser,
+        LogoutUser,
+        users::RefreshToken,
+        IdentityInfo,
+        IdentityTokens,
+        TokenInfo,
+        PersonalAccessTokenInfo,
+        RawPersonalAccessToken,
+        CreatePersonalAccessToken,
+        LoginWithPersonalAccessToken,
+        Stream,
+        StreamDetails,
+        CreateStream,
+        UpdateStream,
+        Topic,
+        TopicDetails,
+        CreateTopic,
+        UpdateTopic,
+        RepartitionTopic,
+        Partition,
+        CreatePartitions,
+        ConsumerGroup,
+        ConsumerGroupDetails,
+        ConsumerGroupMember,
+        ConsumerGroupMemberLease,
+        CreateConsumerGroup,
+        Consumer,
+        ConsumerOffsetInfo,
+        StoreConsumerOffset,
+        PolledMessages,
+        Message,
+        MessageState,
+        HeaderKey,
+        HeaderValue,
+        HeaderKind,
+        SendMessages,
+        send_messages::Message,
+        Partitioning,
+        PartitioningKind,
+        Acknowledgement,
+        ProducerSequence,
+        RegisterProducer,
+        ProducerInfo,
+        BeginTransaction,
+        CommitTransaction,
+        AbortTransaction,
+        TransactionInfo,
+    )),
+    modifiers(&BearerSecurity),
+    security(("bearer" = []))
+)]
+pub struct ApiDoc;
+
+struct BearerSecurity;
+
+impl Modify for BearerSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+pub fn router(config: &HttpOpenApiConfig) -> Router {
+    Router::new().route(&config.endpoint, get(get_openapi))
+}
+
+async fn get_openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
@@ -21,6 +21,17 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "partitions",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = CreatePartitions,
+    responses((status = 201)),
+)]
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -42,6 +53,17 @@ async fn create_partitions(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "partitions",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+        ("partitions_count" = u32, Query, description = "Number of partitions to delete"),
+    ),
+    responses((status = 204)),
+)]
 async fn delete_partitions(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
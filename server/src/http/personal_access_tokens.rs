@@ -32,6 +32,12 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/personal-access-tokens",
+    tag = "personal_access_tokens",
+    responses((status = 200, body = Vec<PersonalAccessTokenInfo>)),
+)]
 async fn get_personal_access_tokens(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -44,6 +50,13 @@ async fn get_personal_access_tokens(
     Ok(Json(personal_access_tokens))
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens",
+    tag = "personal_access_tokens",
+    request_body = CreatePersonalAccessToken,
+    responses((status = 200, body = RawPersonalAccessToken)),
+)]
 async fn create_personal_access_token(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -61,6 +74,13 @@ async fn create_personal_access_token(
     Ok(Json(RawPersonalAccessToken { token }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/personal-access-tokens/{name}",
+    tag = "personal_access_tokens",
+    params(("name" = String, Path, description = "Personal access token name")),
+    responses((status = 204)),
+)]
 async fn delete_personal_access_token(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -76,6 +96,14 @@ async fn delete_personal_access_token(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens/login",
+    tag = "personal_access_tokens",
+    request_body = LoginWithPersonalAccessToken,
+    responses((status = 200, body = IdentityInfo)),
+    security(()),
+)]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<LoginWithPersonalAccessToken>,
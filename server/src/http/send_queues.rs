@@ -0,0 +1,98 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::messages::send_messages::SendMessages;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tracing::error;
+
+/// Stream, topic and partition IDs.
+type PartitionKey = (u32, u32, u32);
+type Queues = Arc<Mutex<HashMap<PartitionKey, mpsc::UnboundedSender<QueuedMessages>>>>;
+
+struct QueuedMessages {
+    session: Session,
+    command: SendMessages,
+}
+
+/// The messages sent without acknowledgement are appended in the background by a single task per partition,
+/// so that they're appended in the same order as they were received, even though the requests are handled concurrently.
+/// The task is stopped once its queue is empty, and started again for the next messages sent to the partition.
+#[derive(Default)]
+pub struct SendQueues {
+    queues: Queues,
+}
+
+impl SendQueues {
+    pub fn enqueue(
+        &self,
+        system: &SharedSystem,
+        partition: PartitionKey,
+        session: Session,
+        command: SendMessages,
+    ) {
+        let messages = QueuedMessages { session, command };
+        let mut queues = self.queues.lock().unwrap();
+        let messages = match queues.get(&partition) {
+            Some(sender) => match sender.send(messages) {
+                Ok(()) => return,
+                Err(error) => error.0,
+            },
+            None => messages,
+        };
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let _ = sender.send(messages);
+        queues.insert(partition, sender);
+        tokio::spawn(Self::append_messages(
+            self.queues.clone(),
+            system.clone(),
+            partition,
+            receiver,
+        ));
+    }
+
+    async fn append_messages(
+        queues: Queues,
+        system: SharedSystem,
+        partition: PartitionKey,
+        mut receiver: UnboundedReceiver<QueuedMessages>,
+    ) {
+        loop {
+            let messages = match receiver.try_recv() {
+                Ok(messages) => messages,
+                Err(_) => {
+                    // The queue is checked again and removed under the lock, so that no messages can be sent to it in the meantime.
+                    let mut queues = queues.lock().unwrap();
+                    match receiver.try_recv() {
+                        Ok(messages) => messages,
+                        Err(_) => {
+                            queues.remove(&partition);
+                            return;
+                        }
+                    }
+                }
+            };
+
+            let session = messages.session;
+            let command = messages.command;
+            let system = system.read();
+            if let Err(error) = system
+                .append_messages(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.partitioning,
+                    &command.messages,
+                    command.acknowledgement,
+                    command.producer,
+                    command.transaction_id,
+                )
+                .await
+            {
+                error!("Failed to append messages without acknowledgement, session: {session}. Error: {error}");
+            }
+        }
+    }
+}
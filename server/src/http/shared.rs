@@ -1,10 +1,16 @@
+use crate::http::consumer_group_leases::ConsumerGroupLeases;
 use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::send_queues::SendQueues;
 use crate::streaming::systems::system::SharedSystem;
 use std::net::SocketAddr;
 use ulid::Ulid;
 
 pub struct AppState {
     pub jwt_manager: JwtManager,
+    pub consumer_group_leases: ConsumerGroupLeases,
+    pub send_queues: SendQueues,
+    /// The endpoint of the OpenAPI specification (if enabled), which is served without authentication.
+    pub openapi_endpoint: Option<String>,
     pub system: SharedSystem,
 }
 
@@ -5,7 +5,7 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::stream::{Stream, StreamDetails};
@@ -22,9 +22,17 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_stream).put(update_stream).delete(delete_stream),
         )
         .route("/streams/:stream_id/purge", delete(purge_stream))
+        .route("/streams/:stream_id/restore", post(restore_stream))
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    responses((status = 200, body = StreamDetails)),
+)]
 async fn get_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -40,6 +48,12 @@ async fn get_stream(
     Ok(Json(stream))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams",
+    tag = "streams",
+    responses((status = 200, body = Vec<Stream>)),
+)]
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -51,6 +65,13 @@ async fn get_streams(
     Ok(Json(streams))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams",
+    tag = "streams",
+    request_body = CreateStream,
+    responses((status = 201)),
+)]
 async fn create_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -68,6 +89,14 @@ async fn create_stream(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    request_body = UpdateStream,
+    responses((status = 204)),
+)]
 async fn update_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -87,6 +116,13 @@ async fn update_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    responses((status = 204)),
+)]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -103,6 +139,13 @@ async fn delete_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/purge",
+    tag = "streams",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    responses((status = 204)),
+)]
 async fn purge_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -118,3 +161,26 @@ async fn purge_stream(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/restore",
+    tag = "streams",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    responses((status = 204)),
+)]
+async fn restore_stream(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let mut system = state.system.write();
+    system
+        .restore_stream(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -5,10 +5,13 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
-use axum::routing::get;
+use axum::http::StatusCode;
+use axum::routing::{get, post, put};
 use axum::{Extension, Json, Router};
+use iggy::models::backup_info::BackupInfo;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
 use iggy::models::stats::Stats;
+use iggy::system::set_log_level::SetLogLevel;
 use std::sync::Arc;
 
 const NAME: &str = "Iggy HTTP";
@@ -16,9 +19,12 @@ const PONG: &str = "pong";
 
 pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Router {
     let mut router = Router::new()
-        .route("/", get(|| async { NAME }))
-        .route("/ping", get(|| async { PONG }))
+        .route("/", get(get_name))
+        .route("/ping", get(ping))
         .route("/stats", get(get_stats))
+        .route("/backups", post(create_backup))
+        .route("/log-level", put(set_log_level))
+        .route("/config/reload", post(reload_config))
         .route("/clients", get(get_clients))
         .route("/clients/:client_id", get(get_client));
     if metrics_config.enabled {
@@ -28,11 +34,39 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     router.with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "system",
+    responses((status = 200, description = "Server name", body = String)),
+    security(()),
+)]
+async fn get_name() -> &'static str {
+    NAME
+}
+
+#[utoipa::path(
+    get,
+    path = "/ping",
+    tag = "system",
+    responses((status = 200, description = "Pong", body = String)),
+    security(()),
+)]
+async fn ping() -> &'static str {
+    PONG
+}
+
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read();
     Ok(system.metrics.get_formatted_output())
 }
 
+#[utoipa::path(
+    get,
+    path = "/stats",
+    tag = "system",
+    responses((status = 200, body = Stats)),
+)]
 async fn get_stats(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -44,6 +78,67 @@ async fn get_stats(
     Ok(Json(stats))
 }
 
+#[utoipa::path(
+    post,
+    path = "/backups",
+    tag = "system",
+    responses((status = 200, body = BackupInfo)),
+)]
+async fn create_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<BackupInfo>, CustomError> {
+    let system = state.system.read();
+    let backup = system
+        .create_backup(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    Ok(Json(backup))
+}
+
+#[utoipa::path(
+    put,
+    path = "/log-level",
+    tag = "system",
+    request_body = SetLogLevel,
+    responses((status = 204)),
+)]
+async fn set_log_level(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetLogLevel>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read();
+    system.set_log_level(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        command.level,
+    )?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/config/reload",
+    tag = "system",
+    responses((status = 204)),
+)]
+async fn reload_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, CustomError> {
+    state
+        .system
+        .reload_config(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/clients/{client_id}",
+    tag = "system",
+    params(("client_id" = u32, Path, description = "Client ID")),
+    responses((status = 200, body = ClientInfoDetails)),
+)]
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -61,6 +156,12 @@ async fn get_client(
     Ok(Json(client))
 }
 
+#[utoipa::path(
+    get,
+    path = "/clients",
+    tag = "system",
+    responses((status = 200, body = Vec<ClientInfo>)),
+)]
 async fn get_clients(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
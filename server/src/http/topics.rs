@@ -5,11 +5,12 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::repartition_topic::RepartitionTopic;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
@@ -28,9 +29,27 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/restore",
+            post(restore_topic),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/repartition",
+            post(repartition_topic),
+        )
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    responses((status = 200, body = TopicDetails)),
+)]
 async fn get_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -48,6 +67,13 @@ async fn get_topic(
     Ok(Json(topic))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    responses((status = 200, body = Vec<Topic>)),
+)]
 async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -63,6 +89,14 @@ async fn get_topics(
     Ok(Json(topics))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(("stream_id" = String, Path, description = "Stream ID (numeric or name)")),
+    request_body = CreateTopic,
+    responses((status = 201)),
+)]
 async fn create_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -80,11 +114,23 @@ async fn create_topic(
             &command.name,
             command.partitions_count,
             command.message_expiry,
+            command.config,
         )
         .await?;
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = UpdateTopic,
+    responses((status = 204)),
+)]
 async fn update_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -102,11 +148,22 @@ async fn update_topic(
             &command.topic_id,
             &command.name,
             command.message_expiry,
+            command.config,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    responses((status = 204)),
+)]
 async fn delete_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -125,6 +182,16 @@ async fn delete_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/purge",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    responses((status = 204)),
+)]
 async fn purge_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -142,3 +209,69 @@ async fn purge_topic(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/restore",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    responses((status = 204)),
+)]
+async fn restore_topic(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let mut system = state.system.write();
+    system
+        .restore_topic(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/repartition",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Stream ID (numeric or name)"),
+        ("topic_id" = String, Path, description = "Topic ID (numeric or name)"),
+    ),
+    request_body = RepartitionTopic,
+    responses((status = 204)),
+)]
+async fn repartition_topic(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RepartitionTopic>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let layout = {
+        let system = state.system.read();
+        system
+            .prepare_topic_repartition(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                command.partitions_count,
+                command.key_header,
+            )
+            .await?
+    };
+    let mut system = state.system.write();
+    system.complete_topic_repartition(&session, layout).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
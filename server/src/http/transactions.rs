@@ -0,0 +1,100 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use iggy::models::transaction_info::TransactionInfo;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/transactions", post(begin_transaction))
+        .route(
+            "/transactions/:transaction_id/commit",
+            post(commit_transaction),
+        )
+        .route(
+            "/transactions/:transaction_id/abort",
+            post(abort_transaction),
+        )
+        .with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions",
+    tag = "transactions",
+    request_body = BeginTransaction,
+    responses((status = 200, body = TransactionInfo)),
+)]
+async fn begin_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<BeginTransaction>,
+) -> Result<Json<TransactionInfo>, CustomError> {
+    command.validate()?;
+    let system = state.system.read();
+    let transaction_id = system
+        .begin_transaction(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    Ok(Json(TransactionInfo { transaction_id }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions/{transaction_id}/commit",
+    tag = "transactions",
+    params(("transaction_id" = u64, Path, description = "Transaction ID")),
+    request_body = CommitTransaction,
+    responses((status = 204)),
+)]
+async fn commit_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+    Json(mut command): Json<CommitTransaction>,
+) -> Result<StatusCode, CustomError> {
+    command.transaction_id = transaction_id;
+    command.validate()?;
+    let system = state.system.read();
+    system
+        .commit_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.transaction_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions/{transaction_id}/abort",
+    tag = "transactions",
+    params(("transaction_id" = u64, Path, description = "Transaction ID")),
+    request_body = AbortTransaction,
+    responses((status = 204)),
+)]
+async fn abort_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+    Json(mut command): Json<AbortTransaction>,
+) -> Result<StatusCode, CustomError> {
+    command.transaction_id = transaction_id;
+    command.validate()?;
+    let system = state.system.read();
+    system
+        .abort_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.transaction_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
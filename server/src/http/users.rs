@@ -20,6 +20,7 @@ use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
+use utoipa::ToSchema;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -36,6 +37,13 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(("user_id" = String, Path, description = "User ID (numeric or username)")),
+    responses((status = 200, body = UserInfoDetails)),
+)]
 async fn get_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -53,6 +61,12 @@ async fn get_user(
     Ok(Json(user))
 }
 
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "users",
+    responses((status = 200, body = Vec<UserInfo>)),
+)]
 async fn get_users(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -65,6 +79,13 @@ async fn get_users(
     Ok(Json(users))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "users",
+    request_body = CreateUser,
+    responses((status = 204)),
+)]
 async fn create_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -84,6 +105,14 @@ async fn create_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(("user_id" = String, Path, description = "User ID (numeric or username)")),
+    request_body = UpdateUser,
+    responses((status = 204)),
+)]
 async fn update_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -104,6 +133,14 @@ async fn update_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/permissions",
+    tag = "users",
+    params(("user_id" = String, Path, description = "User ID (numeric or username)")),
+    request_body = UpdatePermissions,
+    responses((status = 204)),
+)]
 async fn update_permissions(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -123,6 +160,14 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/password",
+    tag = "users",
+    params(("user_id" = String, Path, description = "User ID (numeric or username)")),
+    request_body = ChangePassword,
+    responses((status = 204)),
+)]
 async fn change_password(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -143,6 +188,13 @@ async fn change_password(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(("user_id" = String, Path, description = "User ID (numeric or username)")),
+    responses((status = 204)),
+)]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -159,6 +211,14 @@ async fn delete_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/login",
+    tag = "users",
+    request_body = LoginUser,
+    responses((status = 200, body = IdentityInfo)),
+    security(()),
+)]
 async fn login_user(
     State(state): State<Arc<AppState>>,
     Json(command): Json<LoginUser>,
@@ -172,6 +232,13 @@ async fn login_user(
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/logout",
+    tag = "users",
+    request_body = LogoutUser,
+    responses((status = 204)),
+)]
 async fn logout_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -189,6 +256,14 @@ async fn logout_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/refresh-token",
+    tag = "users",
+    request_body = RefreshToken,
+    responses((status = 200, body = IdentityInfo)),
+    security(()),
+)]
 async fn refresh_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<RefreshToken>,
@@ -197,7 +272,7 @@ async fn refresh_token(
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
 
-#[derive(Debug, Deserialize)]
-struct RefreshToken {
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RefreshToken {
     refresh_token: String,
 }
@@ -16,5 +16,6 @@ pub mod http;
 pub mod logging;
 pub mod quic;
 pub mod server_error;
+pub mod shutdown;
 pub mod streaming;
 pub mod tcp;
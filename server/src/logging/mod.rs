@@ -1,4 +1,7 @@
-use crate::configs::system::LoggingConfig;
+mod rotation;
+
+use crate::configs::system::{LogFormat, LoggingConfig};
+use crate::logging::rotation::RotatingFileWriter;
 use crate::server_error::ServerError;
 use std::io::{self, Write};
 use std::path::PathBuf;
@@ -6,6 +9,9 @@ use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use tracing::{event, info, trace, Level};
 use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::{DefaultFields, Writer};
+use tracing_subscriber::fmt::FormatFields;
 use tracing_subscriber::{
     filter::LevelFilter, fmt, fmt::MakeWriter, prelude::*, reload, reload::Handle, Layer, Registry,
 };
@@ -24,6 +30,20 @@ impl Write for NullWriter {
     }
 }
 
+// The formatted fields of a span are stored once per fields formatter type, so if both stdout and file layers
+// used the same one, the fields recorded after creating the span (e.g. the stream ID) would be appended twice.
+#[derive(Default)]
+struct FileFields(DefaultFields);
+impl<'writer> FormatFields<'writer> for FileFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> std::fmt::Result {
+        self.0.format_fields(writer, fields)
+    }
+}
+
 // Wrapper around Arc<Mutex<Vec<String>>> to implement Write
 struct VecStringWriter(Arc<Mutex<Vec<String>>>);
 impl Write for VecStringWriter {
@@ -76,6 +96,28 @@ impl EarlyLogDumper for Logging {
 // Make reload::Layer::new more readable
 type ReloadHandle = Handle<Box<dyn Layer<Registry> + Send + Sync>, Registry>;
 
+/// Changes the level of both stdout and file logs at runtime by replacing their filtering layers.
+#[derive(Clone)]
+pub struct LogLevelHandle {
+    filtering_stdout_reload_handle: ReloadHandle,
+    filtering_file_reload_handle: ReloadHandle,
+}
+
+impl std::fmt::Debug for LogLevelHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogLevelHandle").finish_non_exhaustive()
+    }
+}
+
+impl LogLevelHandle {
+    pub fn set_level(&self, level: LevelFilter) -> Result<(), reload::Error> {
+        self.filtering_stdout_reload_handle
+            .modify(|layer| *layer = level.boxed())?;
+        self.filtering_file_reload_handle
+            .modify(|layer| *layer = level.boxed())
+    }
+}
+
 pub struct Logging {
     stdout_guard: Option<WorkerGuard>,
     stdout_reload_handle: Option<ReloadHandle>,
@@ -127,7 +169,8 @@ impl Logging {
         let file_layer = fmt::Layer::default()
             .with_target(true)
             .with_writer(VecStringMakeWriter(self.early_logs_buffer.clone()))
-            .with_ansi(true);
+            .with_ansi(true)
+            .fmt_fields(FileFields::default());
         let (file_layer, file_layer_reload_handle) = reload::Layer::new(file_layer.boxed());
         self.file_reload_handle = Some(file_layer_reload_handle);
         layers.push(file_layer.and_then(filtering_file_layer));
@@ -182,7 +225,10 @@ impl Logging {
 
         // Initialize non-blocking stdout layer
         let (_, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-        let stdout_layer = fmt::Layer::default().with_ansi(true).boxed();
+        let stdout_layer = match config.format {
+            LogFormat::Text => fmt::Layer::default().with_ansi(true).boxed(),
+            LogFormat::Json => Self::json_layer(fmt::Layer::default()),
+        };
         self.stdout_guard = Some(stdout_guard);
 
         self.stdout_reload_handle
@@ -198,7 +244,7 @@ impl Logging {
         let logs_subdirectory = PathBuf::from(config.path.clone());
         let logs_path = base_directory.join(logs_subdirectory.clone());
         let file_appender =
-            tracing_appender::rolling::hourly(logs_path.clone(), IGGY_LOG_FILE_PREFIX);
+            RotatingFileWriter::new(logs_path.clone(), IGGY_LOG_FILE_PREFIX, config)?;
         let (mut non_blocking_file, file_guard) = tracing_appender::non_blocking(file_appender);
 
         self.dump_to_file(&mut non_blocking_file);
@@ -206,8 +252,11 @@ impl Logging {
         let file_layer = fmt::layer()
             .with_target(true)
             .with_writer(non_blocking_file)
-            .with_ansi(false)
-            .boxed();
+            .with_ansi(false);
+        let file_layer = match config.format {
+            LogFormat::Text => file_layer.fmt_fields(FileFields::default()).boxed(),
+            LogFormat::Json => Self::json_layer(file_layer),
+        };
 
         self.file_guard = Some(file_guard);
         self.file_reload_handle
@@ -218,8 +267,8 @@ impl Logging {
         let level = filtering_level.to_string();
 
         let print = format!(
-            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated hourly. Log level is: {}.",
-            logs_path, level
+            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated after reaching: {} or every: {}, and retained for: {}. Log level is: {}, format is: {}.",
+            logs_path, config.max_size, config.rotation_interval, config.retention, level, config.format
         );
 
         match filtering_level {
@@ -234,6 +283,28 @@ impl Logging {
         Ok(())
     }
 
+    pub fn get_log_level_handle(&self) -> Option<LogLevelHandle> {
+        Some(LogLevelHandle {
+            filtering_stdout_reload_handle: self.filtering_stdout_reload_handle.clone()?,
+            filtering_file_reload_handle: self.filtering_file_reload_handle.clone()?,
+        })
+    }
+
+    // Each event is written as a single JSON object, including the fields of the current span
+    // (e.g. client ID, user ID, stream and topic) and the list of all its parent spans.
+    fn json_layer<W>(
+        layer: fmt::Layer<Registry, fmt::format::DefaultFields, fmt::format::Format, W>,
+    ) -> Box<dyn Layer<Registry> + Send + Sync>
+    where
+        W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+    {
+        layer
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed()
+    }
+
     // RUST_LOG always takes precedence over config
     fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
@@ -262,10 +333,6 @@ impl Logging {
             }
         }
     }
-
-    fn _install_log_rotation_handler(&self) {
-        todo!("Implement log rotation handler based on size and retention time");
-    }
 }
 
 impl Default for Logging {
@@ -0,0 +1,262 @@
+use crate::configs::system::LoggingConfig;
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const COMPRESSED_FILE_EXTENSION: &str = "gz";
+
+/// The log file writer, which rotates the file once it reaches the maximum size or when the rotation interval has elapsed.
+/// After each rotation, the rotated files older than the retention time are deleted, and then the oldest ones are deleted
+/// until the total size of the log files (including the maximum size of the current one) fits into the configured limit.
+/// It must not emit any logs itself, as it's invoked by the logging layer, thus the errors are printed to stderr.
+pub(crate) struct RotatingFileWriter {
+    directory: PathBuf,
+    file_name: String,
+    max_size: u64,
+    rotation_interval: Option<Duration>,
+    retention: Option<Duration>,
+    max_total_size: Option<u64>,
+    compress_rotated: bool,
+    file: File,
+    size: u64,
+    opened_at: SystemTime,
+}
+
+struct RotatedFile {
+    path: PathBuf,
+    size: u64,
+    modified_at: SystemTime,
+}
+
+impl RotatingFileWriter {
+    pub fn new(directory: PathBuf, file_name: &str, config: &LoggingConfig) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        let file = Self::open(&directory.join(file_name))?;
+        let metadata = file.metadata()?;
+        let writer = Self {
+            directory,
+            file_name: file_name.to_string(),
+            max_size: config.max_size.as_u64(),
+            rotation_interval: Self::get_optional_duration(config.rotation_interval.get_duration()),
+            retention: Self::get_optional_duration(config.retention.get_duration()),
+            max_total_size: match config.max_total_size.as_u64() {
+                0 => None,
+                max_total_size => Some(max_total_size),
+            },
+            compress_rotated: config.compress_rotated,
+            size: metadata.len(),
+            opened_at: metadata.created().unwrap_or_else(|_| SystemTime::now()),
+            file,
+        };
+        writer.delete_rotated_files();
+        Ok(writer)
+    }
+
+    fn get_optional_duration(duration: Duration) -> Option<Duration> {
+        match duration.is_zero() {
+            true => None,
+            false => Some(duration),
+        }
+    }
+
+    fn open(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn should_rotate(&self, length: usize) -> bool {
+        if self.size > 0 && self.size + length as u64 > self.max_size {
+            return true;
+        }
+
+        self.rotation_interval.is_some_and(|interval| {
+            self.opened_at.elapsed().unwrap_or_default() >= interval && self.size > 0
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let path = self.directory.join(&self.file_name);
+        let rotated_path = self.directory.join(format!(
+            "{}.{}",
+            self.file_name,
+            Utc::now().format("%Y-%m-%d-%H-%M-%S-%6f")
+        ));
+        fs::rename(&path, &rotated_path)?;
+        self.file = Self::open(&path)?;
+        self.size = 0;
+        self.opened_at = SystemTime::now();
+
+        if self.compress_rotated {
+            if let Err(error) = Self::compress(&rotated_path) {
+                eprintln!(
+                    "Failed to compress the rotated log file: {:?}. Error: {}",
+                    rotated_path, error
+                );
+            }
+        }
+
+        self.delete_rotated_files();
+        Ok(())
+    }
+
+    fn compress(path: &Path) -> io::Result<()> {
+        let mut compressed_path = path.as_os_str().to_owned();
+        compressed_path.push(format!(".{COMPRESSED_FILE_EXTENSION}"));
+        let mut file = File::open(path)?;
+        let mut encoder = GzEncoder::new(File::create(compressed_path)?, Compression::default());
+        io::copy(&mut file, &mut encoder)?;
+        encoder.finish()?;
+        fs::remove_file(path)
+    }
+
+    fn delete_rotated_files(&self) {
+        let mut rotated_files = match self.get_rotated_files() {
+            Ok(rotated_files) => rotated_files,
+            Err(error) => {
+                eprintln!(
+                    "Failed to read the log files from: {:?}. Error: {}",
+                    self.directory, error
+                );
+                return;
+            }
+        };
+
+        rotated_files.sort_by_key(|file| file.modified_at);
+        let now = SystemTime::now();
+        // The current file can grow up to the max size until the next rotation, thus its space is reserved upfront.
+        let mut total_size =
+            self.size.max(self.max_size) + rotated_files.iter().map(|file| file.size).sum::<u64>();
+        for file in rotated_files {
+            let expired = self.retention.is_some_and(|retention| {
+                now.duration_since(file.modified_at).unwrap_or_default() > retention
+            });
+            let exceeds_total_size = self
+                .max_total_size
+                .is_some_and(|max_total_size| total_size > max_total_size);
+            if !expired && !exceeds_total_size {
+                continue;
+            }
+
+            match fs::remove_file(&file.path) {
+                Ok(()) => total_size -= file.size,
+                Err(error) => eprintln!(
+                    "Failed to delete the rotated log file: {:?}. Error: {}",
+                    file.path, error
+                ),
+            }
+        }
+    }
+
+    fn get_rotated_files(&self) -> io::Result<Vec<RotatedFile>> {
+        let prefix = format!("{}.", self.file_name);
+        let mut rotated_files = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let entry = entry?;
+            if !entry.file_name().to_string_lossy().starts_with(&prefix) {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            rotated_files.push(RotatedFile {
+                path: entry.path(),
+                size: metadata.len(),
+                modified_at: metadata.modified()?,
+            });
+        }
+
+        Ok(rotated_files)
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate(buf.len()) {
+            if let Err(error) = self.rotate() {
+                eprintln!(
+                    "Failed to rotate the log file: {:?}. Error: {}",
+                    self.directory.join(&self.file_name),
+                    error
+                );
+            }
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use std::str::FromStr;
+
+    const FILE_NAME: &str = "test.log";
+
+    #[test]
+    fn log_file_should_be_rotated_and_compressed_after_exceeding_max_size() {
+        let directory = create_directory();
+        let config = create_config("100 B", "0", true);
+        let mut writer = RotatingFileWriter::new(directory.clone(), FILE_NAME, &config).unwrap();
+
+        writer.write_all(&[b'a'; 80]).unwrap();
+        writer.write_all(&[b'b'; 80]).unwrap();
+
+        let rotated_files = writer.get_rotated_files().unwrap();
+        assert_eq!(rotated_files.len(), 1);
+        assert_eq!(
+            rotated_files[0].path.extension().unwrap(),
+            COMPRESSED_FILE_EXTENSION
+        );
+        assert_eq!(fs::read(directory.join(FILE_NAME)).unwrap(), vec![b'b'; 80]);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn oldest_rotated_files_should_be_deleted_after_exceeding_max_total_size() {
+        let directory = create_directory();
+        let config = create_config("100 B", "250 B", false);
+        let mut writer = RotatingFileWriter::new(directory.clone(), FILE_NAME, &config).unwrap();
+
+        for _ in 0..5 {
+            writer.write_all(&[b'a'; 80]).unwrap();
+        }
+
+        let rotated_files = writer.get_rotated_files().unwrap();
+        let total_size = writer.size + rotated_files.iter().map(|file| file.size).sum::<u64>();
+        assert_eq!(rotated_files.len(), 1);
+        assert!(total_size <= 250);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    fn create_config(
+        max_size: &str,
+        max_total_size: &str,
+        compress_rotated: bool,
+    ) -> LoggingConfig {
+        LoggingConfig {
+            max_size: max_size.parse().unwrap(),
+            rotation_interval: IggyDuration::from_str("disabled").unwrap(),
+            max_total_size: max_total_size.parse().unwrap(),
+            compress_rotated,
+            ..LoggingConfig::default()
+        }
+    }
+
+    fn create_directory() -> PathBuf {
+        std::env::temp_dir().join(format!("iggy-logs-{}", uuid::Uuid::new_v4()))
+    }
+}
@@ -2,23 +2,29 @@ use anyhow::Result;
 use clap::Parser;
 use figlet_rs::FIGfont;
 use server::args::Args;
+use server::channels::commands::clean_deleted_resources::CleanDeletedResourcesExecutor;
 use server::channels::commands::clean_messages::CleanMessagesExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::clean_transactions::CleanTransactionsExecutor;
+use server::channels::commands::deliver_delayed_messages::DeliverDelayedMessagesExecutor;
+use server::channels::commands::reencrypt_messages::ReencryptMessagesExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
+use server::configs::reload::ConfigReloader;
 use server::configs::server::ServerConfig;
 use server::http::http_server;
 use server::logging::Logging;
 use server::quic::quic_server;
 use server::server_error::ServerError;
+use server::shutdown::GracefulShutdown;
 use server::streaming::persistence::persister::FileWithSyncPersister;
 use server::streaming::segments::storage::FileSegmentStorage;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
 use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::info;
+use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() -> Result<(), ServerError> {
@@ -37,43 +43,55 @@ async fn main() -> Result<(), ServerError> {
     let config_provider = config_provider::resolve(&args.config_provider)?;
     let config = ServerConfig::load(config_provider.as_ref()).await?;
 
+    if let Some(backup_path) = &args.restore {
+        System::restore_backup(&config.system, backup_path).await?;
+    }
+
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
 
     let mut system = System::new(config.system.clone(), None, config.personal_access_token);
+    system.set_log_level_handle(logging.get_log_level_handle());
+    system.set_config_reloader(Some(ConfigReloader::new(config_provider, &config)));
 
     system.init().await?;
     let system = SharedSystem::new(system);
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
         .install_handler(CleanMessagesExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor);
+        .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(CleanTransactionsExecutor)
+        .install_handler(DeliverDelayedMessagesExecutor)
+        .install_handler(ReencryptMessagesExecutor)
+        .install_handler(CleanDeletedResourcesExecutor);
 
     #[cfg(unix)]
-    let (mut ctrl_c, mut sigterm) = {
+    let (mut ctrl_c, mut sigterm, mut sighup) = {
         use tokio::signal::unix::{signal, SignalKind};
         (
             signal(SignalKind::interrupt())?,
             signal(SignalKind::terminate())?,
+            signal(SignalKind::hangup())?,
         )
     };
 
     #[cfg(windows)]
     let mut ctrl_c = tokio::signal::ctrl_c();
 
+    let shutdown = GracefulShutdown::new();
     let mut current_config = config.clone();
 
     if config.http.enabled {
-        let http_addr = http_server::start(config.http, system.clone()).await;
+        let http_addr = http_server::start(config.http, system.clone(), shutdown.clone()).await;
         current_config.http.address = http_addr.to_string();
     }
 
     if config.quic.enabled {
-        let quic_addr = quic_server::start(config.quic, system.clone());
+        let quic_addr = quic_server::start(config.quic, system.clone(), shutdown.clone());
         current_config.quic.address = quic_addr.to_string();
     }
 
     if config.tcp.enabled {
-        let tcp_addr = tcp_server::start(config.tcp, system.clone()).await;
+        let tcp_addr = tcp_server::start(config.tcp, system.clone(), shutdown.clone()).await;
         current_config.tcp.address = tcp_addr.to_string();
     }
 
@@ -90,12 +108,22 @@ async fn main() -> Result<(), ServerError> {
         elapsed_time.as_millis()
     );
     #[cfg(unix)]
-    tokio::select! {
-        _ = ctrl_c.recv() => {
-            info!("Received SIGINT. Shutting down Iggy server...");
-        },
-        _ = sigterm.recv() => {
-            info!("Received SIGTERM. Shutting down Iggy server...");
+    loop {
+        tokio::select! {
+            _ = ctrl_c.recv() => {
+                info!("Received SIGINT. Shutting down Iggy server...");
+                break;
+            },
+            _ = sigterm.recv() => {
+                info!("Received SIGTERM. Shutting down Iggy server...");
+                break;
+            },
+            _ = sighup.recv() => {
+                info!("Received SIGHUP. Reloading Iggy server config...");
+                if let Err(error) = system.apply_config_reload().await {
+                    error!("Failed to reload config. Error: {error}");
+                }
+            }
         }
     }
 
@@ -110,15 +138,34 @@ async fn main() -> Result<(), ServerError> {
     }
 
     let shutdown_timestamp = Instant::now();
+    let drain_timeout = config.shutdown.drain_timeout;
+    let in_flight_requests = shutdown.start_draining();
+    info!(
+        "Stopped accepting new connections, waiting up to {drain_timeout} for {in_flight_requests} in-flight request(s) to complete..."
+    );
+    let drained = shutdown
+        .wait_for_requests(drain_timeout.get_duration())
+        .await;
+    if !drained {
+        warn!(
+            "{} in-flight request(s) haven't completed within {drain_timeout}.",
+            shutdown.in_flight_requests()
+        );
+    }
+
     let mut system = system.write();
     let persister = Arc::new(FileWithSyncPersister);
     let storage = Arc::new(FileSegmentStorage::new(persister));
-    system.shutdown(storage).await?;
+    let flushed_messages = system.shutdown(storage).await?;
     let elapsed_time = shutdown_timestamp.elapsed();
 
     info!(
-        "Iggy server has shutdown successfully. Shutdown took {} ms.",
-        elapsed_time.as_millis()
+        "Iggy server has shutdown successfully. Shutdown took {} ms, drained requests: {}, notified clients: {}, flushed messages: {} from {} partition(s).",
+        elapsed_time.as_millis(),
+        in_flight_requests.saturating_sub(shutdown.in_flight_requests()),
+        shutdown.notified_clients(),
+        flushed_messages.messages_count,
+        flushed_messages.partitions_count
     );
     Ok(())
 }
@@ -1,41 +1,63 @@
 use crate::binary::command;
+use crate::binary::sender::Sender;
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
-use quinn::Endpoint;
+use iggy::error::Error;
+use quinn::{Connection, Endpoint, VarInt};
 use tracing::{debug, error, info};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(endpoint: Endpoint, system: SharedSystem, shutdown: GracefulShutdown) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
         let system = system.clone();
+        let shutdown = shutdown.clone();
         tokio::spawn(async move {
-            while let Some(incoming_connection) = endpoint.accept().await {
+            loop {
+                let incoming_connection = tokio::select! {
+                    incoming_connection = endpoint.accept() => incoming_connection,
+                    _ = shutdown.draining() => None,
+                };
+                let Some(incoming_connection) = incoming_connection else {
+                    break;
+                };
+
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
                 );
                 let system = system.clone();
+                let shutdown = shutdown.clone();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, system, shutdown).await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
             }
         });
     }
+
+    tokio::spawn(async move {
+        shutdown.draining().await;
+        endpoint.reject_new_connections();
+        info!("QUIC listener has stopped accepting new connections.");
+    });
 }
 
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    shutdown: GracefulShutdown,
 ) -> Result<(), ServerError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
@@ -47,7 +69,14 @@ async fn handle_connection(
             .await;
         let mut session = Session::from_client_id(client_id, address);
         loop {
-            let stream = connection.accept_bi().await;
+            let stream = tokio::select! {
+                stream = connection.accept_bi() => stream,
+                _ = shutdown.draining() => {
+                    notify_shutdown(&connection, &shutdown);
+                    system.read().delete_client(&address).await;
+                    return Ok(());
+                }
+            };
             let mut stream = match stream {
                 Err(quinn::ConnectionError::ApplicationClosed { .. }) => {
                     info!("Connection closed");
@@ -91,6 +120,16 @@ async fn handle_connection(
 
             let command = command.unwrap();
             debug!("Received a QUIC command: {command}, payload size: {length}");
+            let Some(_request) = shutdown.start_request() else {
+                let mut sender = QuicSender {
+                    send: stream.0,
+                    recv: stream.1,
+                };
+                let _ = sender.send_error_response(Error::ServerShuttingDown).await;
+                notify_shutdown(&connection, &shutdown);
+                system.read().delete_client(&address).await;
+                return Ok(());
+            };
 
             let result = command::handle(
                 &command,
@@ -111,3 +150,12 @@ async fn handle_connection(
     .await?;
     Ok(())
 }
+
+// The connection is closed with the dedicated error code, so that the client can fail over.
+fn notify_shutdown(connection: &Connection, shutdown: &GracefulShutdown) {
+    connection.close(
+        VarInt::from_u32(Error::ServerShuttingDown.as_code()),
+        b"Server is shutting down",
+    );
+    shutdown.client_notified();
+}
@@ -1,5 +1,6 @@
 use crate::configs::quic::QuicConfig;
 use crate::quic::listener;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use quinn::{Endpoint, IdleTimeout, VarInt};
@@ -12,7 +13,7 @@ use tracing::info;
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
-pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
+pub fn start(config: QuicConfig, system: SharedSystem, shutdown: GracefulShutdown) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let quic_config = configure_quic(&config);
     if let Err(error) = quic_config {
@@ -21,7 +22,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), config.address.parse().unwrap()).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, system, shutdown);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
@@ -0,0 +1,143 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
+use tokio::time;
+
+/// Coordinates the graceful shutdown of the TCP, QUIC and HTTP servers.
+/// Once the draining has started, the servers stop accepting new connections and requests,
+/// while the requests which are already being handled are allowed to complete.
+/// The connected clients are notified with the `ServerShuttingDown` error, so that they can fail over.
+#[derive(Debug, Clone)]
+pub struct GracefulShutdown {
+    inner: Arc<GracefulShutdownState>,
+}
+
+#[derive(Debug)]
+struct GracefulShutdownState {
+    draining: watch::Sender<bool>,
+    in_flight_requests: AtomicU64,
+    requests_completed: Notify,
+    notified_clients: AtomicU64,
+}
+
+/// Marks the request as in-flight until it's dropped.
+#[derive(Debug)]
+pub struct InFlightRequest {
+    shutdown: GracefulShutdown,
+}
+
+impl Default for GracefulShutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GracefulShutdown {
+    pub fn new() -> Self {
+        let (draining, _) = watch::channel(false);
+        Self {
+            inner: Arc::new(GracefulShutdownState {
+                draining,
+                in_flight_requests: AtomicU64::new(0),
+                requests_completed: Notify::new(),
+                notified_clients: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    pub fn is_draining(&self) -> bool {
+        *self.inner.draining.borrow()
+    }
+
+    /// Completes when the draining has started.
+    pub async fn draining(&self) {
+        let mut draining = self.inner.draining.subscribe();
+        let _ = draining.wait_for(|draining| *draining).await;
+    }
+
+    /// Returns `None` if the server is draining, in such a case the request must not be handled.
+    pub fn start_request(&self) -> Option<InFlightRequest> {
+        self.inner.in_flight_requests.fetch_add(1, Ordering::SeqCst);
+        let request = InFlightRequest {
+            shutdown: self.clone(),
+        };
+        match self.is_draining() {
+            true => None,
+            false => Some(request),
+        }
+    }
+
+    pub fn client_notified(&self) {
+        self.inner.notified_clients.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn notified_clients(&self) -> u64 {
+        self.inner.notified_clients.load(Ordering::SeqCst)
+    }
+
+    pub fn in_flight_requests(&self) -> u64 {
+        self.inner.in_flight_requests.load(Ordering::SeqCst)
+    }
+
+    /// Starts the draining and returns the number of the in-flight requests.
+    pub fn start_draining(&self) -> u64 {
+        self.inner.draining.send_replace(true);
+        self.in_flight_requests()
+    }
+
+    /// Waits until all the in-flight requests have completed, returns `false` if the timeout has elapsed before.
+    pub async fn wait_for_requests(&self, timeout: Duration) -> bool {
+        time::timeout(timeout, async {
+            loop {
+                let requests_completed = self.inner.requests_completed.notified();
+                if self.in_flight_requests() == 0 {
+                    return;
+                }
+                requests_completed.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        let state = &self.shutdown.inner;
+        if state.in_flight_requests.fetch_sub(1, Ordering::SeqCst) == 1 {
+            state.requests_completed.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_should_not_be_started_once_draining_has_started() {
+        let shutdown = GracefulShutdown::new();
+        let request = shutdown.start_request();
+        assert!(request.is_some());
+
+        let in_flight_requests = shutdown.start_draining();
+
+        assert_eq!(in_flight_requests, 1);
+        assert!(shutdown.start_request().is_none());
+        assert_eq!(shutdown.in_flight_requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn draining_should_complete_when_in_flight_requests_have_completed() {
+        let shutdown = GracefulShutdown::new();
+        let request = shutdown.start_request();
+        shutdown.start_draining();
+
+        assert!(!shutdown.wait_for_requests(Duration::from_millis(10)).await);
+
+        drop(request);
+
+        assert!(shutdown.wait_for_requests(Duration::from_secs(1)).await);
+    }
+}
@@ -13,7 +13,7 @@ static mut INSTANCE: Option<Arc<CacheMemoryTracker>> = None;
 #[derive(Debug)]
 pub struct CacheMemoryTracker {
     used_memory_bytes: AtomicU64,
-    limit_bytes: u64,
+    limit_bytes: AtomicU64,
 }
 
 type MessageSize = u64;
@@ -46,7 +46,7 @@ impl CacheMemoryTracker {
         let free_memory = sys.free_memory();
         let free_memory_percentage = free_memory as f64 / total_memory_bytes as f64 * 100.0;
         let used_memory_bytes = AtomicU64::new(0);
-        let limit_bytes = limit.into();
+        let limit_bytes: u64 = limit.into();
 
         info!(
             "Cache memory tracker started, cache: {} bytes, total memory: {} bytes, free memory: {} bytes, free memory percentage: {:.2}%",
@@ -55,7 +55,7 @@ impl CacheMemoryTracker {
 
         CacheMemoryTracker {
             used_memory_bytes,
-            limit_bytes,
+            limit_bytes: AtomicU64::new(limit_bytes),
         }
     }
 
@@ -95,7 +95,19 @@ impl CacheMemoryTracker {
         self.used_memory_bytes.load(Ordering::SeqCst)
     }
 
+    /// Changes the cache limit at runtime, if the new limit is lower than the current usage,
+    /// the cache will be shrunk gradually by evicting the oldest messages on the next appends.
+    pub fn set_limit(&self, limit: MemoryResourceQuota) {
+        let limit_bytes: u64 = limit.into();
+        self.limit_bytes.store(limit_bytes, Ordering::SeqCst);
+        info!(
+            "Cache memory tracker limit was set to: {} bytes",
+            limit_bytes
+        );
+    }
+
     pub fn will_fit_into_cache(&self, requested_size: u64) -> bool {
-        self.used_memory_bytes.load(Ordering::SeqCst) + requested_size <= self.limit_bytes
+        self.used_memory_bytes.load(Ordering::SeqCst) + requested_size
+            <= self.limit_bytes.load(Ordering::SeqCst)
     }
 }
@@ -0,0 +1,104 @@
+use iggy::error::Error;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use std::fmt::Debug;
+
+/// The provider of the master key, which wraps (encrypts) and unwraps (decrypts) the data keys of the streams.
+/// The master key itself is never exposed, so the provider might be backed by an external key management service.
+pub trait MasterKeyProvider: Send + Sync + Debug {
+    fn wrap_key(&self, data_key: &[u8]) -> Result<Vec<u8>, Error>;
+    fn unwrap_key(&self, wrapped_data_key: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Returns true if the data key has not been wrapped with the active master key (e.g. after its rotation).
+    fn requires_rewrap(&self, _wrapped_data_key: &[u8]) -> bool {
+        false
+    }
+}
+
+/// The master key provider holding the master key in memory, loaded either from the file or from the configuration.
+#[derive(Debug)]
+pub struct LocalMasterKeyProvider {
+    encryptor: Box<dyn Encryptor>,
+}
+
+impl LocalMasterKeyProvider {
+    pub fn new(encryptor: Box<dyn Encryptor>) -> Self {
+        Self { encryptor }
+    }
+
+    /// Loads the master key from the file containing the base64 encoded 256-bit key.
+    pub fn from_file(path: &str) -> Result<Self, Error> {
+        let key = std::fs::read_to_string(path)
+            .map_err(|_| Error::CannotLoadMasterKey(path.to_string()))?;
+        let encryptor = Aes256GcmEncryptor::from_base64_key(key.trim())?;
+        Ok(Self::new(Box::new(encryptor)))
+    }
+}
+
+impl MasterKeyProvider for LocalMasterKeyProvider {
+    fn wrap_key(&self, data_key: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encryptor.encrypt(data_key)
+    }
+
+    fn unwrap_key(&self, wrapped_data_key: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encryptor.decrypt(wrapped_data_key)
+    }
+
+    fn requires_rewrap(&self, wrapped_data_key: &[u8]) -> bool {
+        self.encryptor.requires_reencryption(wrapped_data_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::crypto::Aes256GcmKeyring;
+
+    #[test]
+    fn wrapped_data_key_should_be_unwrapped_only_with_the_same_master_key() {
+        let provider = LocalMasterKeyProvider::new(Box::new(
+            Aes256GcmEncryptor::new(&Aes256GcmEncryptor::generate_key()).unwrap(),
+        ));
+        let other_provider = LocalMasterKeyProvider::new(Box::new(
+            Aes256GcmEncryptor::new(&Aes256GcmEncryptor::generate_key()).unwrap(),
+        ));
+        let data_key = Aes256GcmEncryptor::generate_key();
+
+        let wrapped_data_key = provider.wrap_key(&data_key).unwrap();
+
+        assert_ne!(wrapped_data_key, data_key);
+        assert_eq!(provider.unwrap_key(&wrapped_data_key).unwrap(), data_key);
+        assert!(other_provider.unwrap_key(&wrapped_data_key).is_err());
+    }
+
+    #[test]
+    fn data_key_wrapped_with_previous_master_key_should_require_rewrap() {
+        let previous_key = Aes256GcmEncryptor::generate_key();
+        let previous_provider = LocalMasterKeyProvider::new(Box::new(Aes256GcmKeyring::new(
+            1,
+            Aes256GcmEncryptor::new(&previous_key).unwrap(),
+        )));
+        let mut keyring = Aes256GcmKeyring::new(
+            2,
+            Aes256GcmEncryptor::new(&Aes256GcmEncryptor::generate_key()).unwrap(),
+        );
+        keyring
+            .add_decryption_key(1, Aes256GcmEncryptor::new(&previous_key).unwrap())
+            .unwrap();
+        let provider = LocalMasterKeyProvider::new(Box::new(keyring));
+        let data_key = Aes256GcmEncryptor::generate_key();
+
+        let wrapped_data_key = previous_provider.wrap_key(&data_key).unwrap();
+        assert!(provider.requires_rewrap(&wrapped_data_key));
+        assert_eq!(provider.unwrap_key(&wrapped_data_key).unwrap(), data_key);
+
+        let rewrapped_data_key = provider.wrap_key(&data_key).unwrap();
+        assert!(!provider.requires_rewrap(&rewrapped_data_key));
+        assert_eq!(provider.unwrap_key(&rewrapped_data_key).unwrap(), data_key);
+    }
+
+    #[test]
+    fn master_key_should_not_be_loaded_from_missing_file() {
+        let provider = LocalMasterKeyProvider::from_file("/non/existing/master.key");
+
+        assert!(matches!(provider, Err(Error::CannotLoadMasterKey(_))));
+    }
+}
@@ -0,0 +1 @@
+pub mod master_key_provider;
@@ -2,16 +2,19 @@ pub mod cache;
 pub mod clients;
 mod deduplication;
 pub mod diagnostics;
+pub mod encryption;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod producers;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
 pub mod systems;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
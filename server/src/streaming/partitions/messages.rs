@@ -3,7 +3,10 @@ use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::utils::random_id;
 use iggy::error::Error;
+use iggy::messages::poll_messages::IsolationLevel;
+use iggy::messages::send_messages::ProducerSequence;
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
 use std::sync::Arc;
 use tracing::{trace, warn};
 
@@ -319,7 +322,63 @@ impl Partition {
         messages
     }
 
-    pub async fn append_messages(&mut self, mut messages: Vec<Message>) -> Result<(), Error> {
+    /// Filters out the messages, which are not visible to the consumer. The expired messages are never visible,
+    /// while the read-committed consumers can't see the messages appended within the open or aborted transactions.
+    /// The skipped messages are replaced with the following ones (if any), so that the consumer is never stuck on them.
+    pub async fn get_visible_messages(
+        &self,
+        messages: Vec<Arc<Message>>,
+        count: u32,
+        isolation: IsolationLevel,
+    ) -> Result<Vec<Arc<Message>>, Error> {
+        let read_committed = isolation == IsolationLevel::ReadCommitted;
+        let first_unstable_offset = match read_committed {
+            true => self.get_first_unstable_offset(),
+            false => None,
+        };
+        let now = TimeStamp::now().to_micros();
+        let mut visible_messages = Vec::with_capacity(messages.len());
+        let mut messages = messages;
+        while let Some(last_offset) = messages.last().map(|message| message.offset) {
+            for message in messages {
+                if first_unstable_offset.is_some_and(|offset| message.offset >= offset) {
+                    return Ok(visible_messages);
+                }
+
+                if message.is_expired(now)
+                    || (read_committed && self.is_transaction_aborted(message.offset))
+                {
+                    continue;
+                }
+
+                visible_messages.push(message);
+                if visible_messages.len() == count as usize {
+                    return Ok(visible_messages);
+                }
+            }
+
+            if last_offset >= self.current_offset {
+                break;
+            }
+
+            messages = self.get_messages_by_offset(last_offset + 1, count).await?;
+        }
+
+        Ok(visible_messages)
+    }
+
+    pub async fn append_messages(
+        &mut self,
+        mut messages: Vec<Message>,
+        producer: Option<&ProducerSequence>,
+        transaction_id: Option<u64>,
+    ) -> Result<(), Error> {
+        if let Some(producer) = producer {
+            if !self.should_append_producer_batch(producer)? {
+                return Ok(());
+            }
+        }
+
         {
             let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
 
@@ -365,6 +424,14 @@ impl Partition {
             message.offset = self.current_offset;
         }
 
+        let offsets_range = match (messages.first(), messages.last()) {
+            (Some(first), Some(last)) => Some((first.offset, last.offset)),
+            _ => None,
+        };
+        let messages_size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum::<u64>();
         let messages = messages.into_iter().map(Arc::new).collect::<Vec<_>>();
         {
             let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
@@ -375,7 +442,20 @@ impl Partition {
             cache.extend(messages);
         }
 
+        if let Some(producer) = producer {
+            self.update_producer_state(producer);
+        }
+
+        if let (Some(transaction_id), Some((start_offset, end_offset))) =
+            (transaction_id, offsets_range)
+        {
+            self.add_transaction_range(transaction_id, start_offset, end_offset)
+                .await?;
+        }
+
+        self.notify_appended_messages();
         self.unsaved_messages_count += messages_count;
+        self.unsaved_messages_size_bytes += messages_size_bytes;
         {
             let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
             if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
@@ -390,9 +470,37 @@ impl Partition {
                     .persist_messages(self.storage.segment.clone())
                     .await?;
                 self.unsaved_messages_count = 0;
+                self.unsaved_messages_size_bytes = 0;
             }
         }
 
+        // The producer states are saved only once the messages are persisted, so the batches lost before that can be retried.
+        if self.unsaved_messages_count == 0 {
+            self.persist_producer_states().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the buffered messages of the current segment on disk right away,
+    /// regardless of the `messages_required_to_save` threshold.
+    pub async fn persist_unsaved_messages(&mut self) -> Result<(), Error> {
+        if self.unsaved_messages_count == 0 {
+            return Ok(());
+        }
+
+        let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
+        trace!(
+            "Segment with start offset: {} for partition with ID: {} will be persisted on disk on demand...",
+            last_segment.start_offset,
+            self.partition_id
+        );
+        last_segment
+            .persist_messages(self.storage.segment.clone())
+            .await?;
+        self.unsaved_messages_count = 0;
+        self.unsaved_messages_size_bytes = 0;
+        self.persist_producer_states().await?;
         Ok(())
     }
 }
@@ -403,13 +511,20 @@ mod tests {
     use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
     use crate::streaming::partitions::create_messages;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::models::header::{HeaderKey, HeaderValue};
+    use iggy::models::messages::MessageState;
+    use std::collections::HashMap;
 
     #[tokio::test]
     async fn given_disabled_message_deduplication_all_messages_should_be_appended() {
         let mut partition = create_partition(false);
         let messages = create_messages();
         let messages_count = messages.len() as u32;
-        partition.append_messages(messages).await.unwrap();
+        partition
+            .append_messages(messages, None, None)
+            .await
+            .unwrap();
 
         let loaded_messages = partition
             .get_messages_by_offset(0, messages_count)
@@ -424,7 +539,10 @@ mod tests {
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         let unique_messages_count = 3;
-        partition.append_messages(messages).await.unwrap();
+        partition
+            .append_messages(messages, None, None)
+            .await
+            .unwrap();
 
         let loaded_messages = partition
             .get_messages_by_offset(0, messages_count)
@@ -433,6 +551,53 @@ mod tests {
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
+    #[tokio::test]
+    async fn expired_messages_should_not_be_visible() {
+        let mut partition = create_partition(false);
+        let now = TimeStamp::now().to_micros();
+        let messages = vec![
+            create_message(1, now, None),
+            create_message(2, now - 2_000_000, Some(1_000_000)),
+            create_message(3, now - 2_000_000, Some(10_000_000)),
+            create_message(4, now - 2_000_000, Some(1_000_000)),
+            create_message(5, now, None),
+        ];
+        partition
+            .append_messages(messages, None, None)
+            .await
+            .unwrap();
+
+        let loaded_messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+        let visible_messages = partition
+            .get_visible_messages(loaded_messages, 2, IsolationLevel::ReadUncommitted)
+            .await
+            .unwrap();
+
+        assert_eq!(visible_messages.len(), 2);
+        assert_eq!(visible_messages[0].id, 1);
+        assert_eq!(visible_messages[1].id, 3);
+
+        let loaded_messages = partition.get_messages_by_offset(3, 2).await.unwrap();
+        let visible_messages = partition
+            .get_visible_messages(loaded_messages, 2, IsolationLevel::ReadUncommitted)
+            .await
+            .unwrap();
+
+        assert_eq!(visible_messages.len(), 1);
+        assert_eq!(visible_messages[0].id, 5);
+    }
+
+    fn create_message(id: u128, timestamp: u64, ttl: Option<u64>) -> Message {
+        let payload = Bytes::from("message");
+        let headers = ttl.map(|ttl| {
+            HashMap::from([(
+                HeaderKey::message_ttl(),
+                HeaderValue::from_uint64(ttl).unwrap(),
+            )])
+        });
+        Message::empty(timestamp, MessageState::Available, id, payload, 0, headers)
+    }
+
     fn create_partition(deduplication_enabled: bool) -> Partition {
         let storage = Arc::new(get_test_system_storage());
         let stream_id = 1;
@@ -6,8 +6,10 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
+pub mod producer_states;
 pub mod segments;
 pub mod storage;
+pub mod transactions;
 
 #[allow(dead_code)]
 fn create_messages() -> Vec<Message> {
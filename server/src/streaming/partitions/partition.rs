@@ -7,9 +7,9 @@ use crate::streaming::storage::SystemStorage;
 use iggy::consumer::ConsumerKind;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::TimeStamp;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 
 #[derive(Debug)]
 pub struct Partition {
@@ -22,12 +22,18 @@ pub struct Partition {
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
     pub message_deduplicator: Option<MessageDeduplicator>,
     pub unsaved_messages_count: u32,
+    pub unsaved_messages_size_bytes: u64,
     pub should_increment_offset: bool,
     pub created_at: u64,
     pub(crate) message_expiry: Option<u32>,
     pub(crate) consumer_offsets: RwLock<HashMap<u32, ConsumerOffset>>,
     pub(crate) consumer_group_offsets: RwLock<HashMap<u32, ConsumerOffset>>,
+    pub(crate) producer_states: HashMap<u64, ProducerState>,
+    pub(crate) unsaved_producer_ids: HashSet<u64>,
+    pub(crate) open_transaction_ranges: BTreeMap<u64, TransactionRange>,
+    pub(crate) aborted_transaction_ranges: BTreeMap<u64, TransactionRange>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) appended_messages: watch::Sender<u64>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -70,6 +76,77 @@ impl ConsumerOffset {
     }
 }
 
+/// The last sequence number of the batch appended to the partition by the idempotent producer with the given epoch.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProducerState {
+    pub producer_id: u64,
+    pub epoch: u32,
+    pub sequence: u64,
+    pub key: String,
+}
+
+impl ProducerState {
+    pub fn new(
+        producer_id: u64,
+        epoch: u32,
+        sequence: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> ProducerState {
+        ProducerState {
+            key: format!(
+                "{}:{producer_id}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            producer_id,
+            epoch,
+            sequence,
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("producer_states:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
+/// The range of offsets of the messages appended to the partition within the transaction,
+/// which are hidden from the read-committed consumers while the transaction is open, or forever once it's aborted.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransactionRange {
+    pub transaction_id: u64,
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub aborted: bool,
+    pub key: String,
+}
+
+impl TransactionRange {
+    pub fn new(
+        transaction_id: u64,
+        start_offset: u64,
+        end_offset: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> TransactionRange {
+        TransactionRange {
+            key: format!(
+                "{}:{start_offset}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            transaction_id,
+            start_offset,
+            end_offset,
+            aborted: false,
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("transaction_ranges:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
 impl Partition {
     pub fn create(
         stream_id: u32,
@@ -89,6 +166,7 @@ impl Partition {
             ),
         };
 
+        let (appended_messages, _) = watch::channel(0);
         let mut partition = Partition {
             stream_id,
             topic_id,
@@ -97,29 +175,19 @@ impl Partition {
             message_expiry,
             cache: messages,
             cached_memory_tracker,
-            message_deduplicator: match config.message_deduplication.enabled {
-                true => Some(MessageDeduplicator::new(
-                    if config.message_deduplication.max_entries > 0 {
-                        Some(config.message_deduplication.max_entries)
-                    } else {
-                        None
-                    },
-                    {
-                        if config.message_deduplication.expiry.is_zero() {
-                            None
-                        } else {
-                            Some(config.message_deduplication.expiry)
-                        }
-                    },
-                )),
-                false => None,
-            },
+            message_deduplicator: Self::create_message_deduplicator(&config),
             segments: vec![],
             current_offset: 0,
             unsaved_messages_count: 0,
+            unsaved_messages_size_bytes: 0,
             should_increment_offset: false,
             consumer_offsets: RwLock::new(HashMap::new()),
             consumer_group_offsets: RwLock::new(HashMap::new()),
+            producer_states: HashMap::new(),
+            unsaved_producer_ids: HashSet::new(),
+            open_transaction_ranges: BTreeMap::new(),
+            aborted_transaction_ranges: BTreeMap::new(),
+            appended_messages,
             config,
             storage,
             created_at: TimeStamp::now().to_micros(),
@@ -141,6 +209,64 @@ impl Partition {
         partition
     }
 
+    /// Returns the receiver notified with the current offset whenever the messages are appended,
+    /// or become visible to the read-committed consumers, so that the streaming consumers don't need to keep polling.
+    pub fn subscribe_to_appended_messages(&self) -> watch::Receiver<u64> {
+        self.appended_messages.subscribe()
+    }
+
+    pub(crate) fn notify_appended_messages(&self) {
+        self.appended_messages.send_replace(self.current_offset);
+    }
+
+    /// Replaces the config and storage of the partition and its segments, e.g. when the topic config overrides change.
+    /// The deduplicator is recreated only if its settings changed, and the cache is purged once it gets disabled.
+    pub fn update_config(&mut self, config: Arc<SystemConfig>, storage: Arc<SystemStorage>) {
+        if self.config.message_deduplication != config.message_deduplication {
+            self.message_deduplicator = Self::create_message_deduplicator(&config);
+        }
+
+        match (config.cache.enabled, self.cache.as_mut()) {
+            (false, Some(cache)) => {
+                cache.purge();
+                self.cache = None;
+                self.cached_memory_tracker = None;
+            }
+            (true, None) => {
+                self.cached_memory_tracker = CacheMemoryTracker::initialize(&config.cache);
+                self.cache = Some(SmartCache::new());
+            }
+            _ => {}
+        }
+
+        for segment in self.segments.iter_mut() {
+            segment.config = config.clone();
+            segment.storage = storage.clone();
+        }
+        self.config = config;
+        self.storage = storage;
+    }
+
+    fn create_message_deduplicator(config: &SystemConfig) -> Option<MessageDeduplicator> {
+        match config.message_deduplication.enabled {
+            true => Some(MessageDeduplicator::new(
+                if config.message_deduplication.max_entries > 0 {
+                    Some(config.message_deduplication.max_entries)
+                } else {
+                    None
+                },
+                {
+                    if config.message_deduplication.expiry.is_zero() {
+                        None
+                    } else {
+                        Some(config.message_deduplication.expiry)
+                    }
+                },
+            )),
+            false => None,
+        }
+    }
+
     pub fn get_size_bytes(&self) -> u64 {
         self.segments
             .iter()
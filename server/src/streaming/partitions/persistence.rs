@@ -19,6 +19,7 @@ impl Partition {
     pub async fn purge(&mut self) -> Result<(), Error> {
         self.current_offset = 0;
         self.unsaved_messages_count = 0;
+        self.unsaved_messages_size_bytes = 0;
         self.should_increment_offset = false;
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
@@ -45,6 +46,12 @@ impl Partition {
                 self.partition_id,
             )
             .await?;
+        self.open_transaction_ranges.clear();
+        self.aborted_transaction_ranges.clear();
+        self.storage
+            .partition
+            .delete_transaction_ranges(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
         self.add_persisted_segment(0).await?;
         Ok(())
     }
@@ -0,0 +1,192 @@
+use crate::streaming::partitions::partition::{Partition, ProducerState};
+use iggy::error::Error;
+use iggy::messages::send_messages::ProducerSequence;
+use tracing::{trace, warn};
+
+impl Partition {
+    /// Checks the sequence number of the batch sent by the idempotent producer against the last one appended to the partition.
+    /// Returns `false` if the batch is the retry of the last appended one and should be ignored, or the error if the producer
+    /// has been fenced by a newer epoch, or the sequence is older than the last appended one or leaves a gap after it.
+    pub fn should_append_producer_batch(&self, producer: &ProducerSequence) -> Result<bool, Error> {
+        let state = match self.producer_states.get(&producer.producer_id) {
+            Some(state) => state,
+            None => return Ok(true),
+        };
+
+        if producer.epoch < state.epoch {
+            return Err(Error::ProducerFenced(producer.producer_id, producer.epoch));
+        }
+
+        if producer.epoch > state.epoch {
+            return Ok(true);
+        }
+
+        if producer.sequence == state.sequence {
+            warn!(
+                "Ignored the duplicated batch with sequence: {} from producer with ID: {} and epoch: {} for partition with ID: {}.",
+                producer.sequence, producer.producer_id, producer.epoch, self.partition_id
+            );
+            return Ok(false);
+        }
+
+        if producer.sequence < state.sequence {
+            return Err(Error::ProducerSequenceOutOfOrder(
+                producer.producer_id,
+                producer.sequence,
+                state.sequence,
+            ));
+        }
+
+        if producer.sequence > state.sequence + 1 {
+            return Err(Error::ProducerSequenceGap(
+                producer.producer_id,
+                state.sequence + 1,
+                producer.sequence,
+            ));
+        }
+
+        Ok(true)
+    }
+
+    /// Stores the sequence number of the appended batch, which will be persisted together with the buffered messages.
+    pub fn update_producer_state(&mut self, producer: &ProducerSequence) {
+        let state = ProducerState::new(
+            producer.producer_id,
+            producer.epoch,
+            producer.sequence,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+        );
+        self.producer_states.insert(producer.producer_id, state);
+        self.unsaved_producer_ids.insert(producer.producer_id);
+    }
+
+    pub async fn persist_producer_states(&mut self) -> Result<(), Error> {
+        for producer_id in self.unsaved_producer_ids.drain() {
+            if let Some(state) = self.producer_states.get(&producer_id) {
+                self.storage.partition.save_producer_state(state).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn load_producer_states(&mut self) -> Result<(), Error> {
+        trace!(
+            "Loading producer states for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let states = self
+            .storage
+            .partition
+            .load_producer_states(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        for state in states {
+            self.producer_states.insert(state.producer_id, state);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::sync::Arc;
+
+    #[test]
+    fn batch_from_unknown_producer_should_be_appended() {
+        let partition = create_partition();
+        let producer = producer_sequence(1, 1, 1);
+
+        assert!(partition.should_append_producer_batch(&producer).unwrap());
+    }
+
+    #[test]
+    fn batch_with_last_appended_sequence_should_be_ignored() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 1, 5));
+
+        assert!(!partition
+            .should_append_producer_batch(&producer_sequence(1, 1, 5))
+            .unwrap());
+        assert!(partition
+            .should_append_producer_batch(&producer_sequence(1, 1, 6))
+            .unwrap());
+    }
+
+    #[test]
+    fn batch_with_sequence_older_than_last_appended_should_be_rejected() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 1, 5));
+
+        let result = partition.should_append_producer_batch(&producer_sequence(1, 1, 3));
+
+        assert!(matches!(
+            result,
+            Err(Error::ProducerSequenceOutOfOrder(1, 3, 5))
+        ));
+    }
+
+    #[test]
+    fn batch_with_sequence_gap_should_be_rejected() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 1, 5));
+
+        let result = partition.should_append_producer_batch(&producer_sequence(1, 1, 7));
+
+        assert!(matches!(result, Err(Error::ProducerSequenceGap(1, 6, 7))));
+    }
+
+    #[test]
+    fn batch_from_previous_epoch_should_be_fenced() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 2, 1));
+
+        let result = partition.should_append_producer_batch(&producer_sequence(1, 1, 10));
+
+        assert!(matches!(result, Err(Error::ProducerFenced(1, 1))));
+    }
+
+    #[test]
+    fn batch_from_new_epoch_should_be_appended_regardless_of_sequence() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 1, 10));
+
+        assert!(partition
+            .should_append_producer_batch(&producer_sequence(1, 2, 1))
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn updated_producer_states_should_be_marked_as_unsaved_until_persisted() {
+        let mut partition = create_partition();
+        partition.update_producer_state(&producer_sequence(1, 1, 1));
+        partition.update_producer_state(&producer_sequence(2, 1, 1));
+        assert_eq!(partition.unsaved_producer_ids.len(), 2);
+
+        partition.persist_producer_states().await.unwrap();
+
+        assert!(partition.unsaved_producer_ids.is_empty());
+        assert_eq!(partition.producer_states.len(), 2);
+    }
+
+    fn producer_sequence(producer_id: u64, epoch: u32, sequence: u64) -> ProducerSequence {
+        ProducerSequence {
+            producer_id,
+            epoch,
+            sequence,
+        }
+    }
+
+    fn create_partition() -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        Partition::create(1, 2, 3, true, config, storage, None)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::reencryption::ReencryptedMessages;
 use crate::streaming::segments::segment::Segment;
 use iggy::error::Error;
+use iggy::utils::crypto::Encryptor;
 use tracing::info;
 
 pub struct DeletedSegment {
@@ -74,4 +76,63 @@ impl Partition {
         self.segments.retain(|s| s.start_offset != start_offset);
         Ok(deleted_segment)
     }
+
+    /// Returns the start offsets of the closed segments which might contain the messages to re-encrypt.
+    pub fn get_segments_to_reencrypt(&self) -> Vec<u64> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.is_closed && !segment.has_unencrypted_messages)
+            .map(|segment| segment.start_offset)
+            .collect()
+    }
+
+    /// Re-encrypts the messages of the closed segment with the active key of the encryptor, without modifying the partition,
+    /// so only the shared access is required while the segment is being read. Returns `None` if there's nothing to re-encrypt.
+    pub async fn prepare_reencrypted_segment(
+        &self,
+        start_offset: u64,
+        encryptor: &dyn Encryptor,
+    ) -> Result<Option<ReencryptedMessages>, Error> {
+        let Some(segment) = self
+            .segments
+            .iter()
+            .find(|s| s.start_offset == start_offset)
+        else {
+            return Ok(None);
+        };
+
+        segment.prepare_reencrypted_messages(encryptor).await
+    }
+
+    /// Replaces the messages of the segment with the re-encrypted ones and purges the cache if it contains any of them.
+    /// Returns the number of the re-encrypted messages.
+    pub async fn replace_reencrypted_segment(
+        &mut self,
+        start_offset: u64,
+        reencrypted_messages: ReencryptedMessages,
+    ) -> Result<u32, Error> {
+        let Some(segment) = self
+            .segments
+            .iter_mut()
+            .find(|s| s.start_offset == start_offset)
+        else {
+            return Err(Error::SegmentNotFound);
+        };
+
+        let reencrypted_messages_count = segment
+            .replace_reencrypted_messages(reencrypted_messages)
+            .await?;
+        if reencrypted_messages_count == 0 {
+            return Ok(0);
+        }
+
+        let end_offset = segment.end_offset;
+        if let Some(cache) = &mut self.cache {
+            if !cache.is_empty() && cache[0].offset <= end_offset {
+                cache.purge();
+            }
+        }
+
+        Ok(reencrypted_messages_count)
+    }
 }
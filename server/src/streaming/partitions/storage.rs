@@ -1,4 +1,6 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, ProducerState, TransactionRange,
+};
 use crate::streaming::segments::segment::{Segment, LOG_EXTENSION};
 use crate::streaming::storage::{PartitionStorage, Storage};
 use anyhow::Context;
@@ -130,6 +132,224 @@ impl PartitionStorage for FilePartitionStorage {
 
         Ok(())
     }
+
+    async fn save_producer_state(&self, state: &ProducerState) -> Result<(), Error> {
+        // Similar to the consumer offset, the stored value is just the epoch and sequence number in big-endian byte order.
+        let mut value = Vec::with_capacity(12);
+        value.extend(state.epoch.to_be_bytes());
+        value.extend(state.sequence.to_be_bytes());
+        if let Err(err) = self.db.insert(&state.key, value).with_context(|| {
+            format!(
+                "Failed to save producer state, epoch: {}, sequence: {}, key: {}",
+                state.epoch, state.sequence, state.key
+            )
+        }) {
+            return Err(Error::CannotSaveResource(err));
+        }
+
+        trace!(
+            "Stored producer state, epoch: {}, sequence: {} for producer with ID: {}",
+            state.epoch,
+            state.sequence,
+            state.producer_id
+        );
+        Ok(())
+    }
+
+    async fn load_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, Error> {
+        let mut states = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let state = match data.with_context(|| {
+                format!(
+                    "Failed to load producer state, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, value)) => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    let epoch = u32::from_be_bytes(value[..4].try_into().unwrap());
+                    let sequence = u64::from_be_bytes(value[4..12].try_into().unwrap());
+                    let producer_id = key.split(':').next_back().unwrap().parse::<u64>().unwrap();
+                    ProducerState {
+                        key,
+                        producer_id,
+                        epoch,
+                        sequence,
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            states.push(state);
+        }
+
+        Ok(states)
+    }
+
+    async fn delete_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+
+        for data in self.db.scan_prefix(&key_prefix) {
+            match data.with_context(|| {
+                format!(
+                    "Failed to delete producer state, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, _)) => {
+                    if let Err(err) = self
+                        .db
+                        .remove(&key)
+                        .with_context(|| format!("Failed to delete producer state, key: {:?}", key))
+                    {
+                        return Err(Error::CannotDeleteResource(err));
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_transaction_range(&self, range: &TransactionRange) -> Result<(), Error> {
+        // The stored value is the transaction ID, end offset and aborted flag in big-endian byte order.
+        let mut value = Vec::with_capacity(17);
+        value.extend(range.transaction_id.to_be_bytes());
+        value.extend(range.end_offset.to_be_bytes());
+        value.push(u8::from(range.aborted));
+        if let Err(err) = self.db.insert(&range.key, value).with_context(|| {
+            format!(
+                "Failed to save transaction range, transaction ID: {}, end offset: {}, key: {}",
+                range.transaction_id, range.end_offset, range.key
+            )
+        }) {
+            return Err(Error::CannotSaveResource(err));
+        }
+
+        trace!(
+            "Stored transaction range: {}..{}, aborted: {} for transaction with ID: {}",
+            range.start_offset,
+            range.end_offset,
+            range.aborted,
+            range.transaction_id
+        );
+        Ok(())
+    }
+
+    async fn delete_transaction_range(&self, range: &TransactionRange) -> Result<(), Error> {
+        if let Err(err) = self
+            .db
+            .remove(&range.key)
+            .with_context(|| format!("Failed to delete transaction range, key: {}", range.key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        trace!(
+            "Deleted transaction range: {}..{} for transaction with ID: {}",
+            range.start_offset,
+            range.end_offset,
+            range.transaction_id
+        );
+        Ok(())
+    }
+
+    async fn load_transaction_ranges(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<TransactionRange>, Error> {
+        let mut ranges = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            TransactionRange::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let range = match data.with_context(|| {
+                format!(
+                    "Failed to load transaction range, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, value)) => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    let transaction_id = u64::from_be_bytes(value[..8].try_into().unwrap());
+                    let end_offset = u64::from_be_bytes(value[8..16].try_into().unwrap());
+                    let aborted = value[16] == 1;
+                    let start_offset = key.split(':').next_back().unwrap().parse::<u64>().unwrap();
+                    TransactionRange {
+                        key,
+                        transaction_id,
+                        start_offset,
+                        end_offset,
+                        aborted,
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            ranges.push(range);
+        }
+
+        Ok(ranges)
+    }
+
+    async fn delete_transaction_ranges(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let key_prefix = format!(
+            "{}:",
+            TransactionRange::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+
+        for data in self.db.scan_prefix(&key_prefix) {
+            match data.with_context(|| {
+                format!(
+                    "Failed to delete transaction range, when searching by key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok((key, _)) => {
+                    if let Err(err) = self.db.remove(&key).with_context(|| {
+                        format!("Failed to delete transaction range, key: {:?}", key)
+                    }) {
+                        return Err(Error::CannotDeleteResource(err));
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -277,6 +497,8 @@ impl Storage<Partition> for FilePartitionStorage {
         }
 
         partition.load_consumer_offsets().await?;
+        partition.load_producer_states().await?;
+        partition.load_transaction_ranges().await?;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id, partition.stream_id, partition.topic_id, partition.current_offset
@@ -400,6 +622,38 @@ impl Storage<Partition> for FilePartitionStorage {
             ));
         }
 
+        if let Err(err) = self
+            .delete_producer_states(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete producer states for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(Error::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
+        if let Err(err) = self
+            .delete_transaction_ranges(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete transaction ranges for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(Error::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
         if fs::remove_dir_all(&partition.path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(Error::CannotDeletePartitionDirectory(
@@ -0,0 +1,232 @@
+use crate::streaming::partitions::partition::{Partition, TransactionRange};
+use iggy::error::Error;
+use tracing::{trace, warn};
+
+impl Partition {
+    /// Stores the range of offsets of the messages appended within the open transaction,
+    /// which hides them (and all the messages appended afterwards) from the read-committed consumers until the transaction is completed.
+    pub async fn add_transaction_range(
+        &mut self,
+        transaction_id: u64,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<(), Error> {
+        let range = TransactionRange::new(
+            transaction_id,
+            start_offset,
+            end_offset,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+        );
+        self.storage
+            .partition
+            .save_transaction_range(&range)
+            .await?;
+        self.open_transaction_ranges.insert(start_offset, range);
+        Ok(())
+    }
+
+    /// Makes the messages appended within the transaction visible to the read-committed consumers.
+    pub async fn commit_transaction(&mut self, transaction_id: u64) -> Result<(), Error> {
+        for range in self.remove_open_transaction_ranges(Some(transaction_id)) {
+            self.storage
+                .partition
+                .delete_transaction_range(&range)
+                .await?;
+        }
+
+        self.notify_appended_messages();
+        Ok(())
+    }
+
+    /// Hides the messages appended within the transaction from the read-committed consumers forever.
+    pub async fn abort_transaction(&mut self, transaction_id: u64) -> Result<(), Error> {
+        for range in self.remove_open_transaction_ranges(Some(transaction_id)) {
+            self.store_aborted_transaction_range(range).await?;
+        }
+
+        // The read-committed consumers waiting behind the aborted messages can move on.
+        self.notify_appended_messages();
+        Ok(())
+    }
+
+    /// Aborts all the transactions, which are still open e.g. after the server restart.
+    pub async fn abort_open_transactions(&mut self) -> Result<(), Error> {
+        for range in self.remove_open_transaction_ranges(None) {
+            warn!(
+                "Aborting the transaction with ID: {} for offsets: {}..{} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                range.transaction_id, range.start_offset, range.end_offset, self.partition_id, self.topic_id, self.stream_id
+            );
+            self.store_aborted_transaction_range(range).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the offset of the first message appended within the open transaction, if any.
+    /// Neither this message, nor any of the following ones are visible to the read-committed consumers.
+    pub fn get_first_unstable_offset(&self) -> Option<u64> {
+        self.open_transaction_ranges.keys().next().copied()
+    }
+
+    /// Returns the offset of the last message, which might be visible to the read-committed consumers.
+    pub fn get_last_stable_offset(&self) -> u64 {
+        match self.get_first_unstable_offset() {
+            Some(offset) => offset.saturating_sub(1),
+            None => self.current_offset,
+        }
+    }
+
+    pub fn is_transaction_aborted(&self, offset: u64) -> bool {
+        match self.aborted_transaction_ranges.range(..=offset).next_back() {
+            Some((_, range)) => offset <= range.end_offset,
+            None => false,
+        }
+    }
+
+    pub async fn load_transaction_ranges(&mut self) -> Result<(), Error> {
+        trace!(
+            "Loading transaction ranges for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let ranges = self
+            .storage
+            .partition
+            .load_transaction_ranges(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        let messages_count = self.get_messages_count();
+        for mut range in ranges {
+            // The messages appended within the transaction might have not been persisted before the server shutdown.
+            if messages_count == 0 || range.start_offset > self.current_offset {
+                self.storage
+                    .partition
+                    .delete_transaction_range(&range)
+                    .await?;
+                continue;
+            }
+
+            if range.end_offset > self.current_offset {
+                range.end_offset = self.current_offset;
+                self.storage
+                    .partition
+                    .save_transaction_range(&range)
+                    .await?;
+            }
+
+            match range.aborted {
+                true => self
+                    .aborted_transaction_ranges
+                    .insert(range.start_offset, range),
+                false => self
+                    .open_transaction_ranges
+                    .insert(range.start_offset, range),
+            };
+        }
+
+        Ok(())
+    }
+
+    fn remove_open_transaction_ranges(
+        &mut self,
+        transaction_id: Option<u64>,
+    ) -> Vec<TransactionRange> {
+        let start_offsets = self
+            .open_transaction_ranges
+            .values()
+            .filter(|range| transaction_id.is_none_or(|id| range.transaction_id == id))
+            .map(|range| range.start_offset)
+            .collect::<Vec<_>>();
+        start_offsets
+            .iter()
+            .filter_map(|start_offset| self.open_transaction_ranges.remove(start_offset))
+            .collect()
+    }
+
+    async fn store_aborted_transaction_range(
+        &mut self,
+        mut range: TransactionRange,
+    ) -> Result<(), Error> {
+        range.aborted = true;
+        self.storage
+            .partition
+            .save_transaction_range(&range)
+            .await?;
+        self.aborted_transaction_ranges
+            .insert(range.start_offset, range);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn messages_after_open_transaction_should_not_be_stable() {
+        let mut partition = create_partition(10);
+        partition.add_transaction_range(1, 4, 5).await.unwrap();
+
+        assert_eq!(partition.get_first_unstable_offset(), Some(4));
+        assert_eq!(partition.get_last_stable_offset(), 3);
+    }
+
+    #[tokio::test]
+    async fn committed_transaction_should_make_messages_stable() {
+        let mut partition = create_partition(10);
+        partition.add_transaction_range(1, 4, 5).await.unwrap();
+        partition.add_transaction_range(2, 6, 7).await.unwrap();
+
+        partition.commit_transaction(1).await.unwrap();
+
+        assert_eq!(partition.get_first_unstable_offset(), Some(6));
+        assert!(!partition.is_transaction_aborted(4));
+
+        partition.commit_transaction(2).await.unwrap();
+
+        assert_eq!(partition.get_first_unstable_offset(), None);
+        assert_eq!(partition.get_last_stable_offset(), 10);
+    }
+
+    #[tokio::test]
+    async fn aborted_transaction_should_hide_only_its_messages() {
+        let mut partition = create_partition(10);
+        partition.add_transaction_range(1, 4, 5).await.unwrap();
+        partition.add_transaction_range(1, 8, 9).await.unwrap();
+
+        partition.abort_transaction(1).await.unwrap();
+
+        assert_eq!(partition.get_first_unstable_offset(), None);
+        assert!(!partition.is_transaction_aborted(3));
+        assert!(partition.is_transaction_aborted(4));
+        assert!(partition.is_transaction_aborted(5));
+        assert!(!partition.is_transaction_aborted(6));
+        assert!(partition.is_transaction_aborted(9));
+        assert!(!partition.is_transaction_aborted(10));
+    }
+
+    #[tokio::test]
+    async fn all_open_transactions_should_be_aborted() {
+        let mut partition = create_partition(10);
+        partition.add_transaction_range(1, 4, 5).await.unwrap();
+        partition.add_transaction_range(2, 6, 7).await.unwrap();
+
+        partition.abort_open_transactions().await.unwrap();
+
+        assert!(partition.open_transaction_ranges.is_empty());
+        assert_eq!(partition.aborted_transaction_ranges.len(), 2);
+    }
+
+    fn create_partition(current_offset: u64) -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        let mut partition = Partition::create(1, 2, 3, true, config, storage, None);
+        partition.current_offset = current_offset;
+        partition
+    }
+}
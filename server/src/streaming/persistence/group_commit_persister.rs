@@ -0,0 +1,240 @@
+use crate::streaming::persistence::persister::Persister;
+use crate::streaming::utils::file;
+use async_trait::async_trait;
+use iggy::error::Error;
+use iggy::utils::duration::IggyDuration;
+use std::collections::HashSet;
+use std::io;
+use std::sync::{Arc, Mutex, Weak};
+use tokio::sync::{oneshot, Notify};
+use tokio::time;
+use tracing::{error, info};
+
+type CommitResult = Result<(), (io::ErrorKind, String)>;
+
+#[derive(Debug, Default)]
+struct PendingCommit {
+    paths: HashSet<String>,
+    size_bytes: u64,
+    waiters: Vec<oneshot::Sender<CommitResult>>,
+}
+
+#[derive(Debug)]
+struct GroupCommit {
+    pending: Mutex<PendingCommit>,
+    trigger: Notify,
+    max_size_bytes: u64,
+}
+
+/// Persister which writes through the inner persister without syncing, and acknowledges the writes
+/// only after a shared `fdatasync` of all the files modified since the previous commit.
+/// The commit happens periodically (every `interval`) or as soon as `max_size_bytes` are pending,
+/// so the appends to many partitions pay for a single round of syncs instead of one per write.
+#[derive(Debug)]
+pub struct GroupCommitPersister {
+    inner: Arc<dyn Persister>,
+    group_commit: Arc<GroupCommit>,
+}
+
+impl GroupCommitPersister {
+    pub fn new(inner: Arc<dyn Persister>, interval: IggyDuration, max_size_bytes: u64) -> Self {
+        let group_commit = Arc::new(GroupCommit {
+            pending: Mutex::new(PendingCommit::default()),
+            trigger: Notify::new(),
+            max_size_bytes,
+        });
+        let weak_group_commit = Arc::downgrade(&group_commit);
+        tokio::spawn(async move {
+            run(weak_group_commit, interval).await;
+        });
+        info!(
+            "Started group commit persister, interval: {interval}, max size: {max_size_bytes} bytes."
+        );
+        Self {
+            inner,
+            group_commit,
+        }
+    }
+
+    async fn commit(&self, path: &str, size_bytes: u64) -> Result<(), Error> {
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut pending = self.group_commit.pending.lock().unwrap();
+            pending.paths.insert(path.to_string());
+            pending.size_bytes += size_bytes;
+            pending.waiters.push(sender);
+            if pending.size_bytes >= self.group_commit.max_size_bytes {
+                self.group_commit.trigger.notify_one();
+            }
+        }
+
+        match receiver.await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err((kind, message))) => Err(io::Error::new(kind, message).into()),
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "group commit persister is not running",
+            )
+            .into()),
+        }
+    }
+}
+
+#[async_trait]
+impl Persister for GroupCommitPersister {
+    async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.inner.append(path, bytes).await?;
+        self.commit(path, bytes.len() as u64).await
+    }
+
+    async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.inner.overwrite(path, bytes).await?;
+        self.commit(path, bytes.len() as u64).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), Error> {
+        self.inner.delete(path).await
+    }
+
+    /// The group commit always syncs the files, so without fsync the writes go straight to the inner persister.
+    fn with_fsync(self: Arc<Self>, enforce_fsync: bool) -> Arc<dyn Persister> {
+        match enforce_fsync {
+            true => self,
+            false => self.inner.clone(),
+        }
+    }
+}
+
+async fn run(group_commit: Weak<GroupCommit>, interval: IggyDuration) {
+    let mut interval_timer = time::interval(interval.get_duration());
+    interval_timer.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+    loop {
+        let Some(group_commit) = group_commit.upgrade() else {
+            break;
+        };
+
+        tokio::select! {
+            _ = interval_timer.tick() => {},
+            _ = group_commit.trigger.notified() => {},
+        }
+
+        let pending = std::mem::take(&mut *group_commit.pending.lock().unwrap());
+        if pending.waiters.is_empty() {
+            continue;
+        }
+
+        let result = sync_files(pending.paths).await;
+        if let Err((_, message)) = &result {
+            error!("Group commit has failed. Error: {message}");
+        }
+        for waiter in pending.waiters {
+            let _ = waiter.send(result.clone());
+        }
+    }
+    info!("Stopped group commit persister.");
+}
+
+async fn sync_files(paths: HashSet<String>) -> CommitResult {
+    let syncs = paths.into_iter().map(|path| async move {
+        let file = match file::open(&path).await {
+            Ok(file) => file,
+            // The file has been deleted in the meantime, so there's nothing left to sync.
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error),
+        };
+        file.sync_data().await
+    });
+
+    for result in futures::future::join_all(syncs).await {
+        result.map_err(|error| (error.kind(), error.to_string()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::persistence::persister::FilePersister;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn should_acknowledge_appends_to_many_files_after_shared_commit() {
+        let persister = Arc::new(GroupCommitPersister::new(
+            Arc::new(FilePersister),
+            IggyDuration::new(Duration::from_millis(10)),
+            u64::MAX,
+        ));
+        let paths = (0..10)
+            .map(|_| {
+                std::env::temp_dir()
+                    .join(format!("iggy_group_commit_{}", uuid::Uuid::new_v4()))
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        let tasks = paths.iter().cloned().map(|path| {
+            let persister = persister.clone();
+            tokio::spawn(async move {
+                persister.overwrite(&path, &[]).await.unwrap();
+                for _ in 0..10 {
+                    persister.append(&path, &[1; 10]).await.unwrap();
+                }
+            })
+        });
+        futures::future::join_all(tasks).await;
+
+        for path in &paths {
+            assert_eq!(tokio::fs::metadata(path).await.unwrap().len(), 100);
+            persister.delete(path).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn should_commit_before_interval_when_max_size_is_reached() {
+        let persister = GroupCommitPersister::new(
+            Arc::new(FilePersister),
+            IggyDuration::new(Duration::from_secs(3600)),
+            1,
+        );
+        let path = std::env::temp_dir()
+            .join(format!("iggy_group_commit_{}", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string();
+
+        let result = time::timeout(Duration::from_secs(5), async {
+            persister.overwrite(&path, &[1]).await.unwrap();
+            persister.append(&path, &[2]).await.unwrap();
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), vec![1, 2]);
+        persister.delete(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_write_through_inner_persister_without_fsync() {
+        let persister = Arc::new(GroupCommitPersister::new(
+            Arc::new(FilePersister),
+            IggyDuration::new(Duration::from_secs(3600)),
+            u64::MAX,
+        ))
+        .with_fsync(false);
+        let path = std::env::temp_dir()
+            .join(format!("iggy_group_commit_{}", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string();
+
+        let result = time::timeout(Duration::from_secs(5), async {
+            persister.overwrite(&path, &[1]).await.unwrap();
+            persister.append(&path, &[2]).await.unwrap();
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), vec![1, 2]);
+        persister.delete(&path).await.unwrap();
+    }
+}
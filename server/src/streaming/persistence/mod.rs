@@ -1 +1,4 @@
+pub mod group_commit_persister;
 pub mod persister;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+pub mod uring_persister;
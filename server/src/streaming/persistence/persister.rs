@@ -2,6 +2,7 @@ use crate::streaming::utils::file;
 use async_trait::async_trait;
 use iggy::error::Error;
 use std::fmt::Debug;
+use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
@@ -10,6 +11,8 @@ pub trait Persister: Sync + Send {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
     async fn delete(&self, path: &str) -> Result<(), Error>;
+    /// Returns the persister of the same kind, which syncs (or not) the files after each write.
+    fn with_fsync(self: Arc<Self>, enforce_fsync: bool) -> Arc<dyn Persister>;
 }
 
 impl Debug for dyn Persister {
@@ -50,6 +53,13 @@ impl Persister for FilePersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    fn with_fsync(self: Arc<Self>, enforce_fsync: bool) -> Arc<dyn Persister> {
+        match enforce_fsync {
+            true => Arc::new(FileWithSyncPersister),
+            false => self,
+        }
+    }
 }
 
 #[async_trait]
@@ -72,4 +82,11 @@ impl Persister for FileWithSyncPersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    fn with_fsync(self: Arc<Self>, enforce_fsync: bool) -> Arc<dyn Persister> {
+        match enforce_fsync {
+            true => self,
+            false => Arc::new(FilePersister),
+        }
+    }
 }
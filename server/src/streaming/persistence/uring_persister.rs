@@ -0,0 +1,265 @@
+use crate::streaming::persistence::persister::Persister;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::error::Error;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io;
+use std::rc::Rc;
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use tokio_uring::buf::IoBuf;
+use tokio_uring::fs::{File, OpenOptions};
+use tracing::info;
+
+const MAX_OPEN_FILES: usize = 1024;
+const MAX_BATCH_SIZE: usize = 256;
+
+type OpenFiles = Rc<RefCell<HashMap<String, Rc<File>>>>;
+
+#[derive(Debug)]
+enum UringOperation {
+    Append,
+    Overwrite,
+    Delete,
+}
+
+#[derive(Debug)]
+struct UringRequest {
+    operation: UringOperation,
+    path: String,
+    bytes: Vec<u8>,
+    enforce_fsync: bool,
+    response: oneshot::Sender<io::Result<()>>,
+}
+
+/// Persister backed by io_uring, running on a dedicated thread with its own `tokio-uring` runtime.
+/// The files are kept open between the appends, and all the requests waiting in the queue
+/// are submitted to the ring together, so the writes to many partitions share a single syscall.
+#[derive(Debug)]
+pub struct UringPersister {
+    sender: Sender<UringRequest>,
+    enforce_fsync: bool,
+}
+
+impl UringPersister {
+    pub fn new(enforce_fsync: bool) -> Self {
+        let (sender, receiver) = flume::unbounded();
+        std::thread::Builder::new()
+            .name("iggy-io-uring".to_string())
+            .spawn(move || tokio_uring::start(run(receiver)))
+            .expect("Cannot start io_uring persister thread.");
+        info!("Started io_uring persister, enforce fsync: {enforce_fsync}.");
+        Self {
+            sender,
+            enforce_fsync,
+        }
+    }
+
+    async fn submit(
+        &self,
+        operation: UringOperation,
+        path: &str,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
+        let (response_sender, response_receiver) = oneshot::channel();
+        let request = UringRequest {
+            operation,
+            path: path.to_string(),
+            bytes: bytes.to_vec(),
+            enforce_fsync: self.enforce_fsync,
+            response: response_sender,
+        };
+        if self.sender.send_async(request).await.is_err() {
+            return Err(persister_stopped().into());
+        }
+
+        match response_receiver.await {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(persister_stopped().into()),
+        }
+    }
+}
+
+#[async_trait]
+impl Persister for UringPersister {
+    async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.submit(UringOperation::Append, path, bytes).await
+    }
+
+    async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.submit(UringOperation::Overwrite, path, bytes).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), Error> {
+        self.submit(UringOperation::Delete, path, &[]).await
+    }
+
+    /// The returned persister shares the io_uring thread, and only the fsync of its writes differs.
+    fn with_fsync(self: Arc<Self>, enforce_fsync: bool) -> Arc<dyn Persister> {
+        Arc::new(UringPersister {
+            sender: self.sender.clone(),
+            enforce_fsync,
+        })
+    }
+}
+
+async fn run(receiver: Receiver<UringRequest>) {
+    let files = OpenFiles::default();
+    while let Ok(request) = receiver.recv_async().await {
+        let requests = std::iter::once(request)
+            .chain(receiver.try_iter().take(MAX_BATCH_SIZE - 1))
+            .collect::<Vec<_>>();
+        // Each request is a separate task, so their operations are queued in the ring
+        // and submitted at once, when this loop yields while waiting for the next request.
+        for request in requests {
+            let files = files.clone();
+            tokio_uring::spawn(async move {
+                let result = execute(
+                    &files,
+                    request.operation,
+                    &request.path,
+                    request.bytes,
+                    request.enforce_fsync,
+                )
+                .await;
+                let _ = request.response.send(result);
+            });
+        }
+    }
+    info!("Stopped io_uring persister.");
+}
+
+async fn execute(
+    files: &OpenFiles,
+    operation: UringOperation,
+    path: &str,
+    bytes: Vec<u8>,
+    enforce_fsync: bool,
+) -> io::Result<()> {
+    match operation {
+        UringOperation::Append => {
+            let file = get_or_open(files, path).await?;
+            // The file is opened in append mode, so the kernel ignores the position and writes at the end.
+            write_all_at(&file, bytes, 0).await?;
+            if enforce_fsync {
+                file.sync_all().await?;
+            }
+        }
+        UringOperation::Overwrite => {
+            files.borrow_mut().remove(path);
+            let file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)
+                .await?;
+            write_all_at(&file, bytes, 0).await?;
+            if enforce_fsync {
+                file.sync_all().await?;
+            }
+            file.close().await?;
+        }
+        UringOperation::Delete => {
+            files.borrow_mut().remove(path);
+            tokio_uring::fs::remove_file(path).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_or_open(files: &OpenFiles, path: &str) -> io::Result<Rc<File>> {
+    if let Some(file) = files.borrow().get(path) {
+        return Ok(file.clone());
+    }
+
+    let file = Rc::new(OpenOptions::new().append(true).open(path).await?);
+    let mut files = files.borrow_mut();
+    if files.len() >= MAX_OPEN_FILES {
+        files.retain(|_, file| Rc::strong_count(file) > 1);
+    }
+    files.insert(path.to_string(), file.clone());
+    Ok(file)
+}
+
+async fn write_all_at(file: &File, bytes: Vec<u8>, mut position: u64) -> io::Result<()> {
+    let mut buffer = bytes;
+    let mut written_bytes = 0;
+    while written_bytes < buffer.len() {
+        let (result, slice) = file.write_at(buffer.slice(written_bytes..), position).await;
+        buffer = slice.into_inner();
+        let size = result?;
+        if size == 0 {
+            return Err(io::ErrorKind::WriteZero.into());
+        }
+        written_bytes += size;
+        position += size as u64;
+    }
+
+    Ok(())
+}
+
+fn persister_stopped() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "io_uring persister is not running",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_append_overwrite_and_delete_file() {
+        let persister = UringPersister::new(true);
+        let path = std::env::temp_dir()
+            .join(format!("iggy_uring_{}", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string();
+
+        persister.overwrite(&path, b"abc").await.unwrap();
+        persister.append(&path, b"def").await.unwrap();
+        persister.append(&path, b"ghi").await.unwrap();
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), b"abcdefghi");
+
+        persister.overwrite(&path, b"xyz").await.unwrap();
+        persister.append(&path, b"123").await.unwrap();
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), b"xyz123");
+
+        persister.delete(&path).await.unwrap();
+        assert!(tokio::fs::metadata(&path).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_append_concurrently_to_many_files() {
+        let persister = Arc::new(UringPersister::new(false));
+        let paths = (0..10)
+            .map(|_| {
+                std::env::temp_dir()
+                    .join(format!("iggy_uring_{}", uuid::Uuid::new_v4()))
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        for path in &paths {
+            persister.overwrite(path, &[]).await.unwrap();
+        }
+
+        let tasks = paths.iter().cloned().map(|path| {
+            let persister = persister.clone();
+            tokio::spawn(async move {
+                for _ in 0..100 {
+                    persister.append(&path, &[1; 10]).await.unwrap();
+                }
+            })
+        });
+        futures::future::join_all(tasks).await;
+
+        for path in &paths {
+            assert_eq!(tokio::fs::metadata(path).await.unwrap().len(), 1000);
+            persister.delete(path).await.unwrap();
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod producer;
+pub mod storage;
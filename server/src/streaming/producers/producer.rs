@@ -0,0 +1,21 @@
+use iggy::models::user_info::UserId;
+use serde::{Deserialize, Serialize};
+
+/// The registered idempotent producer. The epoch is incremented on each registration of the same producer ID,
+/// which fences the messages sent by its previous instances.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct Producer {
+    pub producer_id: u64,
+    pub epoch: u32,
+    pub user_id: UserId,
+}
+
+impl Producer {
+    pub fn new(producer_id: u64, user_id: UserId) -> Self {
+        Self {
+            producer_id,
+            epoch: 1,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::streaming::producers::producer::Producer;
+use crate::streaming::storage::{ProducerStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::Error;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "producers";
+
+#[derive(Debug)]
+pub struct FileProducerStorage {
+    db: Arc<Db>,
+}
+
+impl FileProducerStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileProducerStorage {}
+unsafe impl Sync for FileProducerStorage {}
+
+#[async_trait]
+impl ProducerStorage for FileProducerStorage {
+    async fn load_all(&self) -> Result<Vec<Producer>, Error> {
+        let mut producers = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let producer = match data.with_context(|| {
+                format!(
+                    "Failed to load producer, when searching by key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => {
+                    match rmp_serde::from_slice::<Producer>(&value).with_context(|| {
+                        format!(
+                            "Failed to deserialize producer, when searching by key: {}",
+                            KEY_PREFIX
+                        )
+                    }) {
+                        Ok(producer) => producer,
+                        Err(err) => {
+                            return Err(Error::CannotDeserializeResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            producers.push(producer);
+        }
+
+        producers.sort_by_key(|producer| producer.producer_id);
+        Ok(producers)
+    }
+}
+
+#[async_trait]
+impl Storage<Producer> for FileProducerStorage {
+    async fn load(&self, producer: &mut Producer) -> Result<(), Error> {
+        let key = get_key(producer.producer_id);
+        let producer_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load producer with key: {}", key))
+        {
+            Ok(producer_data) => {
+                if let Some(producer_data) = producer_data {
+                    producer_data
+                } else {
+                    return Err(Error::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(Error::CannotLoadResource(err));
+            }
+        };
+
+        let producer_data = rmp_serde::from_slice::<Producer>(&producer_data)
+            .with_context(|| format!("Failed to deserialize producer with key: {}", key));
+        match producer_data {
+            Ok(producer_data) => {
+                producer.epoch = producer_data.epoch;
+                producer.user_id = producer_data.user_id;
+                Ok(())
+            }
+            Err(err) => Err(Error::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, producer: &Producer) -> Result<(), Error> {
+        let key = get_key(producer.producer_id);
+        match rmp_serde::to_vec(&producer)
+            .with_context(|| format!("Failed to serialize producer with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to save producer with key: {}", key))
+                {
+                    return Err(Error::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(Error::CannotSerializeResource(err));
+            }
+        }
+
+        info!(
+            "Saved producer with ID: {} and epoch: {}.",
+            producer.producer_id, producer.epoch
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, producer: &Producer) -> Result<(), Error> {
+        let key = get_key(producer.producer_id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete producer with key: {}", key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        info!("Deleted producer with ID: {}.", producer.producer_id);
+        Ok(())
+    }
+}
+
+fn get_key(producer_id: u64) -> String {
+    format!("{}:{}", KEY_PREFIX, producer_id)
+}
@@ -1,6 +1,7 @@
 pub mod index;
 pub mod messages;
 pub mod persistence;
+pub mod reencryption;
 pub mod segment;
 pub mod storage;
 pub mod time_index;
@@ -0,0 +1,143 @@
+use crate::streaming::segments::index::Index;
+use crate::streaming::segments::segment::Segment;
+use bytes::Bytes;
+use iggy::error::Error;
+use iggy::models::messages::Message;
+use iggy::sizeable::Sizeable;
+use iggy::utils::checksum;
+use iggy::utils::crypto::Encryptor;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// The messages of a closed segment with the payloads re-encrypted with the active key of the encryptor.
+#[derive(Debug)]
+pub struct ReencryptedMessages {
+    pub messages: Vec<Arc<Message>>,
+    pub reencrypted_messages_count: u32,
+    pub unencrypted_messages_count: u32,
+}
+
+impl Segment {
+    /// Re-encrypts the payloads of the messages which were not encrypted with the active key of the encryptor,
+    /// by rewriting the log and index files of the segment. Only the closed segments are re-encrypted,
+    /// as the open one is still being appended to. Returns the number of the re-encrypted messages.
+    pub async fn reencrypt_messages(&mut self, encryptor: &dyn Encryptor) -> Result<u32, Error> {
+        let Some(reencrypted_messages) = self.prepare_reencrypted_messages(encryptor).await? else {
+            return Ok(0);
+        };
+
+        self.replace_reencrypted_messages(reencrypted_messages)
+            .await
+    }
+
+    /// Loads the messages of the closed segment and re-encrypts the payloads which were not encrypted with the active key,
+    /// without modifying the segment, so it only needs the shared access. Returns `None` if there's nothing to re-encrypt.
+    ///
+    /// The payloads which can't be decrypted (e.g. appended before the encryption was enabled) are kept as they are,
+    /// and the segment gets marked as having the unencrypted messages once replaced, so it's not checked again.
+    pub async fn prepare_reencrypted_messages(
+        &self,
+        encryptor: &dyn Encryptor,
+    ) -> Result<Option<ReencryptedMessages>, Error> {
+        if !self.is_closed || self.has_unencrypted_messages {
+            return Ok(None);
+        }
+
+        if let Some(unsaved_messages) = &self.unsaved_messages {
+            if !unsaved_messages.is_empty() {
+                return Ok(None);
+            }
+        }
+
+        let messages = self.get_all_messages().await?;
+        if !messages
+            .iter()
+            .any(|message| encryptor.requires_reencryption(&message.payload))
+        {
+            return Ok(None);
+        }
+
+        let mut reencrypted_messages_count = 0;
+        let mut unencrypted_messages_count = 0;
+        let mut reencrypted_messages = Vec::with_capacity(messages.len());
+        for message in messages {
+            if !encryptor.requires_reencryption(&message.payload) {
+                reencrypted_messages.push(message);
+                continue;
+            }
+
+            let Ok(payload) = encryptor.decrypt(&message.payload) else {
+                unencrypted_messages_count += 1;
+                reencrypted_messages.push(message);
+                continue;
+            };
+
+            let payload = Bytes::from(encryptor.encrypt(&payload)?);
+            let checksum = checksum::calculate(&payload);
+            reencrypted_messages.push(Arc::new(Message::create(
+                message.offset,
+                message.state,
+                message.timestamp,
+                message.id,
+                payload,
+                checksum,
+                message.headers.clone(),
+            )));
+            reencrypted_messages_count += 1;
+        }
+
+        Ok(Some(ReencryptedMessages {
+            messages: reencrypted_messages,
+            reencrypted_messages_count,
+            unencrypted_messages_count,
+        }))
+    }
+
+    /// Replaces the log and index files of the segment with the re-encrypted messages.
+    /// Returns the number of the re-encrypted messages.
+    pub async fn replace_reencrypted_messages(
+        &mut self,
+        reencrypted_messages: ReencryptedMessages,
+    ) -> Result<u32, Error> {
+        if reencrypted_messages.unencrypted_messages_count > 0 {
+            warn!(
+                "Found {} messages which can't be decrypted in segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}, segment will be skipped by the re-encryption.",
+                reencrypted_messages.unencrypted_messages_count,
+                self.start_offset,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+            self.has_unencrypted_messages = true;
+        }
+
+        if reencrypted_messages.reencrypted_messages_count == 0 {
+            return Ok(0);
+        }
+
+        let messages = reencrypted_messages.messages;
+        let storage = self.storage.segment.clone();
+        self.current_size_bytes = storage.replace_messages(self, &messages).await?;
+        if let Some(indexes) = &mut self.indexes {
+            indexes.clear();
+            let mut position = 0;
+            for message in &messages {
+                indexes.push(Index {
+                    relative_offset: (message.offset - self.start_offset) as u32,
+                    position,
+                });
+                position += message.get_size_bytes();
+            }
+        }
+
+        info!(
+            "Re-encrypted {} messages in segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}.",
+            reencrypted_messages.reencrypted_messages_count,
+            self.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(reencrypted_messages.reencrypted_messages_count)
+    }
+}
@@ -24,6 +24,7 @@ pub struct Segment {
     pub time_index_path: String,
     pub current_size_bytes: u32,
     pub is_closed: bool,
+    pub(crate) has_unencrypted_messages: bool,
     pub(crate) message_expiry: Option<u32>,
     pub(crate) unsaved_messages: Option<Vec<Arc<Message>>>,
     pub(crate) config: Arc<SystemConfig>,
@@ -66,6 +67,7 @@ impl Segment {
             },
             unsaved_messages: None,
             is_closed: false,
+            has_unencrypted_messages: false,
             config,
             storage,
         }
@@ -15,7 +15,7 @@ use std::collections::HashMap;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
@@ -35,6 +35,39 @@ impl FileSegmentStorage {
     }
 }
 
+impl FileSegmentStorage {
+    /// Finishes the interrupted replacement of the segment messages (see `replace_messages`).
+    /// If the temporary log file is still present, the original log and index weren't replaced yet,
+    /// so the temporary files are removed. Otherwise, the log has been already replaced, thus only the index is renamed.
+    async fn recover_replaced_messages(&self, segment: &Segment) -> Result<(), Error> {
+        let log_path = get_replacement_path(&segment.log_path);
+        let index_path = get_replacement_path(&segment.index_path);
+        if Path::new(&log_path).exists() {
+            warn!(
+                "Rolling back the interrupted replacement of messages for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+            );
+            tokio::fs::remove_file(&log_path).await?;
+            if Path::new(&index_path).exists() {
+                tokio::fs::remove_file(&index_path).await?;
+            }
+            return Ok(());
+        }
+
+        if !Path::new(&index_path).exists() {
+            return Ok(());
+        }
+
+        warn!(
+            "Completing the interrupted replacement of messages for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        tokio::fs::rename(&index_path, &segment.index_path).await?;
+        file::sync_directory(&get_directory_path(&segment.index_path)).await?;
+        Ok(())
+    }
+}
+
 unsafe impl Send for FileSegmentStorage {}
 unsafe impl Sync for FileSegmentStorage {}
 
@@ -46,6 +79,7 @@ impl Storage<Segment> for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
+        self.recover_replaced_messages(segment).await?;
         let log_file = file::open(&segment.log_path).await?;
         let file_size = log_file.metadata().await.unwrap().len() as u32;
         segment.current_size_bytes = file_size;
@@ -230,6 +264,81 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(messages_size)
     }
 
+    async fn replace_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error> {
+        let messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+
+        let mut log_bytes = Vec::with_capacity(messages_size as usize);
+        let mut index_bytes = Vec::with_capacity(messages.len() * 4);
+        for message in messages {
+            index_bytes.put_u32_le(log_bytes.len() as u32);
+            message.extend(&mut log_bytes);
+        }
+
+        // Both files are written and synced next to the original ones first, and then renamed, log before index.
+        // The temporary index file is removed by the last rename, so if it's still present while loading the segment,
+        // the replacement is either rolled back (the log wasn't renamed yet) or completed (see `recover_replaced_messages`).
+        let log_path = get_replacement_path(&segment.log_path);
+        let index_path = get_replacement_path(&segment.index_path);
+        if let Err(err) = write_synced(&log_path, &log_bytes)
+            .await
+            .with_context(|| format!("Failed to save messages to file: {}", log_path))
+        {
+            return Err(Error::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = write_synced(&index_path, &index_bytes)
+            .await
+            .with_context(|| format!("Failed to save index to file: {}", index_path))
+        {
+            return Err(Error::CannotSaveIndexToSegment(err));
+        }
+
+        let directory_path = get_directory_path(&segment.log_path);
+        if let Err(err) = file::sync_directory(&directory_path)
+            .await
+            .with_context(|| format!("Failed to sync segment directory: {}", directory_path))
+        {
+            return Err(Error::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = tokio::fs::rename(&log_path, &segment.log_path)
+            .await
+            .with_context(|| format!("Failed to replace segment log: {}", segment.log_path))
+        {
+            return Err(Error::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = file::sync_directory(&directory_path)
+            .await
+            .with_context(|| format!("Failed to sync segment directory: {}", directory_path))
+        {
+            return Err(Error::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = tokio::fs::rename(&index_path, &segment.index_path)
+            .await
+            .with_context(|| format!("Failed to replace segment index: {}", segment.index_path))
+        {
+            return Err(Error::CannotSaveIndexToSegment(err));
+        }
+
+        if let Err(err) = file::sync_directory(&directory_path)
+            .await
+            .with_context(|| format!("Failed to sync segment directory: {}", directory_path))
+        {
+            return Err(Error::CannotSaveIndexToSegment(err));
+        }
+
+        Ok(messages_size)
+    }
+
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error> {
         let mut message_ids = Vec::new();
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
@@ -512,6 +621,28 @@ impl SegmentStorage for FileSegmentStorage {
     }
 }
 
+fn get_replacement_path(path: &str) -> String {
+    format!("{path}.tmp")
+}
+
+fn get_directory_path(path: &str) -> String {
+    Path::new(path)
+        .parent()
+        .map(|parent| parent.to_string_lossy().to_string())
+        .unwrap_or_else(|| ".".to_string())
+}
+
+async fn write_synced(path: &str, bytes: &[u8]) -> Result<(), std::io::Error> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await
+}
+
 async fn load_messages_by_range(
     segment: &Segment,
     index_range: &IndexRange,
@@ -1,8 +1,12 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, ProducerState, TransactionRange,
+};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::producers::producer::Producer;
+use crate::streaming::producers::storage::FileProducerStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
@@ -12,8 +16,11 @@ use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::delayed_messages::DelayedMessage;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::storage::FileTransactionStorage;
+use crate::streaming::transactions::transaction::Transaction;
 use crate::streaming::users::storage::FileUserStorage;
 use crate::streaming::users::user::User;
 use async_trait::async_trait;
@@ -53,7 +60,20 @@ pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
 }
 
 #[async_trait]
-pub trait StreamStorage: Storage<Stream> {}
+pub trait ProducerStorage: Storage<Producer> {
+    async fn load_all(&self) -> Result<Vec<Producer>, Error>;
+}
+
+#[async_trait]
+pub trait TransactionStorage: Storage<Transaction> {
+    async fn load_all(&self) -> Result<Vec<Transaction>, Error>;
+}
+
+#[async_trait]
+pub trait StreamStorage: Storage<Stream> {
+    async fn save_data_key(&self, stream: &Stream, wrapped_data_key: &[u8]) -> Result<(), Error>;
+    async fn load_data_key(&self, stream: &Stream) -> Result<Option<Vec<u8>>, Error>;
+}
 
 #[async_trait]
 pub trait TopicStorage: Storage<Topic> {
@@ -68,6 +88,17 @@ pub trait TopicStorage: Storage<Topic> {
         topic: &Topic,
         consumer_group: &ConsumerGroup,
     ) -> Result<(), Error>;
+    async fn save_delayed_message(
+        &self,
+        topic: &Topic,
+        delayed_message: &DelayedMessage,
+    ) -> Result<(), Error>;
+    async fn load_delayed_messages(&self, topic: &Topic) -> Result<Vec<DelayedMessage>, Error>;
+    async fn delete_delayed_message(
+        &self,
+        topic: &Topic,
+        delayed_message: &DelayedMessage,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -87,6 +118,33 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<(), Error>;
+    async fn save_producer_state(&self, state: &ProducerState) -> Result<(), Error>;
+    async fn load_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, Error>;
+    async fn delete_producer_states(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error>;
+    async fn save_transaction_range(&self, range: &TransactionRange) -> Result<(), Error>;
+    async fn delete_transaction_range(&self, range: &TransactionRange) -> Result<(), Error>;
+    async fn load_transaction_ranges(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<TransactionRange>, Error>;
+    async fn delete_transaction_ranges(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -106,6 +164,11 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<u32, Error>;
+    async fn replace_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error>;
@@ -136,10 +199,13 @@ pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
     pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
+    pub producer: Arc<dyn ProducerStorage>,
+    pub transaction: Arc<dyn TransactionStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
     pub segment: Arc<dyn SegmentStorage>,
+    pub persister: Arc<dyn Persister>,
 }
 
 impl SystemStorage {
@@ -148,10 +214,29 @@ impl SystemStorage {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
             personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
+            producer: Arc::new(FileProducerStorage::new(db.clone())),
+            transaction: Arc::new(FileTransactionStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone())),
             segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            persister,
+        }
+    }
+
+    /// Returns the copy of the storage, which segments are written with the given persister.
+    pub fn with_persister(&self, persister: Arc<dyn Persister>) -> Self {
+        Self {
+            info: self.info.clone(),
+            user: self.user.clone(),
+            personal_access_token: self.personal_access_token.clone(),
+            producer: self.producer.clone(),
+            transaction: self.transaction.clone(),
+            stream: self.stream.clone(),
+            topic: self.topic.clone(),
+            partition: self.partition.clone(),
+            segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            persister,
         }
     }
 }
@@ -174,6 +259,18 @@ impl Debug for dyn PersonalAccessTokenStorage {
     }
 }
 
+impl Debug for dyn ProducerStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProducerStorage")
+    }
+}
+
+impl Debug for dyn TransactionStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TransactionStorage")
+    }
+}
+
 impl Debug for dyn StreamStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "StreamStorage")
@@ -201,6 +298,7 @@ impl Debug for dyn SegmentStorage {
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::streaming::partitions::partition::Partition;
+    use crate::streaming::persistence::persister::FilePersister;
     use crate::streaming::segments::index::{Index, IndexRange};
     use crate::streaming::segments::segment::Segment;
     use crate::streaming::segments::time_index::TimeIndex;
@@ -214,6 +312,8 @@ pub(crate) mod tests {
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
     struct TestPersonalAccessTokenStorage {}
+    struct TestProducerStorage {}
+    struct TestTransactionStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
     struct TestPartitionStorage {}
@@ -312,6 +412,50 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<Producer> for TestProducerStorage {
+        async fn load(&self, _producer: &mut Producer) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save(&self, _producer: &Producer) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete(&self, _producer: &Producer) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ProducerStorage for TestProducerStorage {
+        async fn load_all(&self) -> Result<Vec<Producer>, Error> {
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait]
+    impl Storage<Transaction> for TestTransactionStorage {
+        async fn load(&self, _transaction: &mut Transaction) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save(&self, _transaction: &Transaction) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete(&self, _transaction: &Transaction) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl TransactionStorage for TestTransactionStorage {
+        async fn load_all(&self) -> Result<Vec<Transaction>, Error> {
+            Ok(vec![])
+        }
+    }
+
     #[async_trait]
     impl Storage<Stream> for TestStreamStorage {
         async fn load(&self, _stream: &mut Stream) -> Result<(), Error> {
@@ -327,7 +471,20 @@ pub(crate) mod tests {
         }
     }
 
-    impl StreamStorage for TestStreamStorage {}
+    #[async_trait]
+    impl StreamStorage for TestStreamStorage {
+        async fn save_data_key(
+            &self,
+            _stream: &Stream,
+            _wrapped_data_key: &[u8],
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_data_key(&self, _stream: &Stream) -> Result<Option<Vec<u8>>, Error> {
+            Ok(None)
+        }
+    }
 
     #[async_trait]
     impl Storage<Topic> for TestTopicStorage {
@@ -365,6 +522,29 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn save_delayed_message(
+            &self,
+            _topic: &Topic,
+            _delayed_message: &DelayedMessage,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_delayed_messages(
+            &self,
+            _topic: &Topic,
+        ) -> Result<Vec<DelayedMessage>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_delayed_message(
+            &self,
+            _topic: &Topic,
+            _delayed_message: &DelayedMessage,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -407,6 +587,54 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn save_producer_state(&self, _state: &ProducerState) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_producer_states(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<ProducerState>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_producer_states(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save_transaction_range(&self, _range: &TransactionRange) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete_transaction_range(&self, _range: &TransactionRange) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_transaction_ranges(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<TransactionRange>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_transaction_ranges(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -450,6 +678,14 @@ pub(crate) mod tests {
             Ok(0)
         }
 
+        async fn replace_messages(
+            &self,
+            _segment: &Segment,
+            _messages: &[Arc<Message>],
+        ) -> Result<u32, Error> {
+            Ok(0)
+        }
+
         async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, Error> {
             Ok(vec![])
         }
@@ -506,10 +742,13 @@ pub(crate) mod tests {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
             personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
+            producer: Arc::new(TestProducerStorage {}),
+            transaction: Arc::new(TestTransactionStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
             segment: Arc::new(TestSegmentStorage {}),
+            persister: Arc::new(FilePersister {}),
         }
     }
 }
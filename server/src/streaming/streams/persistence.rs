@@ -18,6 +18,10 @@ impl Stream {
             topic.delete().await?;
         }
 
+        for topic in self.get_deleted_topics() {
+            topic.delete().await?;
+        }
+
         self.storage.stream.delete(self).await
     }
 
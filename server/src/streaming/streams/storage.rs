@@ -28,12 +28,43 @@ impl FileStreamStorage {
 unsafe impl Send for FileStreamStorage {}
 unsafe impl Sync for FileStreamStorage {}
 
-impl StreamStorage for FileStreamStorage {}
+#[async_trait]
+impl StreamStorage for FileStreamStorage {
+    async fn save_data_key(&self, stream: &Stream, wrapped_data_key: &[u8]) -> Result<(), Error> {
+        let key = get_data_key_db_key(stream.stream_id);
+        if let Err(err) = self
+            .db
+            .insert(&key, wrapped_data_key)
+            .with_context(|| format!("Failed to insert data key with key: {}", key))
+        {
+            return Err(Error::CannotSaveResource(err));
+        }
+
+        info!("Saved data key for stream with ID: {}.", stream.stream_id);
+        Ok(())
+    }
+
+    async fn load_data_key(&self, stream: &Stream) -> Result<Option<Vec<u8>>, Error> {
+        let key = get_data_key_db_key(stream.stream_id);
+        match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load data key with key: {}", key))
+        {
+            Ok(wrapped_data_key) => Ok(wrapped_data_key.map(|key| key.to_vec())),
+            Err(err) => Err(Error::CannotLoadResource(err)),
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 struct StreamData {
     name: String,
     created_at: u64,
+    #[serde(default)]
+    deleted_at: Option<u64>,
+    #[serde(default)]
+    aliases: Vec<String>,
 }
 
 #[async_trait]
@@ -77,6 +108,8 @@ impl Storage<Stream> for FileStreamStorage {
 
         stream.name = stream_data.name;
         stream.created_at = stream_data.created_at;
+        stream.deleted_at = stream_data.deleted_at;
+        stream.aliases = stream_data.aliases;
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -120,6 +153,11 @@ impl Storage<Stream> for FileStreamStorage {
 
         join_all(load_topics).await;
         for topic in loaded_topics.lock().await.drain(..) {
+            if topic.is_soft_deleted() {
+                stream.deleted_topics.insert(topic.topic_id, topic);
+                continue;
+            }
+
             if stream.topics.contains_key(&topic.topic_id) {
                 error!(
                     "Topic with ID: '{}' already exists for stream with ID: {}.",
@@ -140,6 +178,15 @@ impl Storage<Stream> for FileStreamStorage {
             stream.topics.insert(topic.topic_id, topic);
         }
 
+        // The aliases are resolved once all the topics are loaded, as the current names take precedence over them.
+        for topic in stream.topics.values() {
+            for alias in &topic.aliases {
+                if !stream.topics_ids.contains_key(alias) {
+                    stream.topics_aliases.insert(alias.clone(), topic.topic_id);
+                }
+            }
+        }
+
         info!(
             "Loaded stream: '{}' with ID: {} from disk.",
             &stream.name, &stream.stream_id
@@ -169,6 +216,8 @@ impl Storage<Stream> for FileStreamStorage {
         match rmp_serde::to_vec(&StreamData {
             name: stream.name.clone(),
             created_at: stream.created_at,
+            deleted_at: stream.deleted_at,
+            aliases: stream.aliases.clone(),
         })
         .with_context(|| format!("Failed to serialize stream with key: {}", key))
         {
@@ -201,6 +250,16 @@ impl Storage<Stream> for FileStreamStorage {
         {
             return Err(Error::CannotDeleteResource(err));
         }
+        // Once the data key is gone, the server can't decrypt the data encrypted with it anymore,
+        // but the backups of the database still contain the wrapped data key.
+        let data_key_db_key = get_data_key_db_key(stream.stream_id);
+        if let Err(err) = self
+            .db
+            .remove(&data_key_db_key)
+            .with_context(|| format!("Failed to delete data key with key: {}", data_key_db_key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
         if fs::remove_dir_all(&stream.path).await.is_err() {
             return Err(Error::CannotDeleteStreamDirectory(stream.stream_id));
         }
@@ -212,3 +271,7 @@ impl Storage<Stream> for FileStreamStorage {
 fn get_key(stream_id: u32) -> String {
     format!("streams:{}", stream_id)
 }
+
+fn get_data_key_db_key(stream_id: u32) -> String {
+    format!("{}:data_key", get_key(stream_id))
+}
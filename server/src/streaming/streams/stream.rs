@@ -1,6 +1,7 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
+use iggy::utils::crypto::Encryptor;
 use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -9,11 +10,17 @@ use std::sync::Arc;
 pub struct Stream {
     pub stream_id: u32,
     pub name: String,
+    /// The previous names of the stream, which still resolve to it, unless taken by another stream.
+    pub aliases: Vec<String>,
     pub path: String,
     pub topics_path: String,
     pub created_at: u64,
+    pub deleted_at: Option<u64>,
     pub(crate) topics: HashMap<u32, Topic>,
     pub(crate) topics_ids: HashMap<String, u32>,
+    pub(crate) topics_aliases: HashMap<String, u32>,
+    pub(crate) deleted_topics: HashMap<u32, Topic>,
+    pub(crate) encryptor: Option<Box<dyn Encryptor>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -35,13 +42,18 @@ impl Stream {
         Stream {
             stream_id: id,
             name: name.to_string(),
+            aliases: Vec::new(),
             path,
             topics_path,
             config,
             topics: HashMap::new(),
             topics_ids: HashMap::new(),
+            topics_aliases: HashMap::new(),
+            deleted_topics: HashMap::new(),
+            encryptor: None,
             storage,
             created_at: TimeStamp::now().to_micros(),
+            deleted_at: None,
         }
     }
 
@@ -52,6 +64,10 @@ impl Stream {
         }
         size_bytes
     }
+
+    pub fn is_soft_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,8 @@ use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
 use tracing::info;
 
 impl Stream {
@@ -16,8 +18,9 @@ impl Stream {
         name: &str,
         partitions_count: u32,
         message_expiry: Option<u32>,
+        config: HashMap<String, String>,
     ) -> Result<(), Error> {
-        if self.topics.contains_key(&id) {
+        if self.topics.contains_key(&id) || self.deleted_topics.contains_key(&id) {
             return Err(Error::TopicIdAlreadyExists(id, self.stream_id));
         }
 
@@ -26,7 +29,8 @@ impl Stream {
             return Err(Error::TopicNameAlreadyExists(name, self.stream_id));
         }
 
-        let topic = Topic::create(
+        self.release_topic_alias(&name).await?;
+        let mut topic = Topic::create(
             self.stream_id,
             id,
             &name,
@@ -35,6 +39,7 @@ impl Stream {
             self.storage.clone(),
             message_expiry,
         )?;
+        topic.update_config_overrides(config).await?;
         topic.persist().await?;
         info!(
             "Created topic: {} with ID: {}, partitions: {}",
@@ -51,6 +56,7 @@ impl Stream {
         id: &Identifier,
         name: &str,
         message_expiry: Option<u32>,
+        config: Option<HashMap<String, String>>,
     ) -> Result<(), Error> {
         let topic_id;
         {
@@ -58,6 +64,12 @@ impl Stream {
             topic_id = topic.topic_id;
         }
 
+        // The overrides are validated upfront, so that the topic is not left partially updated.
+        if let Some(config) = &config {
+            self.config.with_topic_overrides(config)?;
+            self.get_topic(id)?.validate_key_ranges(config)?;
+        }
+
         let updated_name = text::to_lowercase_non_whitespace(name);
 
         {
@@ -76,10 +88,22 @@ impl Stream {
             topic.name.clone()
         };
 
+        // The previous name is kept as the alias, so the clients still referring to it are not broken by the rename.
+        let renamed = old_topic_name != updated_name;
+        if renamed {
+            self.release_topic_alias(&updated_name).await?;
+        }
+
         {
-            self.topics_ids.remove(&old_topic_name.clone());
-            self.topics_ids.insert(updated_name.clone(), topic_id);
-            let topic = self.get_topic_mut(id)?;
+            if renamed {
+                self.topics_ids.remove(&old_topic_name);
+                self.topics_ids.insert(updated_name.clone(), topic_id);
+                self.topics_aliases.insert(old_topic_name.clone(), topic_id);
+            }
+            let topic = self.get_topic_by_id_mut(topic_id)?;
+            if renamed {
+                topic.aliases.push(old_topic_name);
+            }
             topic.name = updated_name;
             topic.message_expiry = message_expiry;
             for partition in topic.partitions.values_mut() {
@@ -90,6 +114,10 @@ impl Stream {
                 }
             }
 
+            if let Some(config) = config {
+                topic.update_config_overrides(config).await?;
+            }
+
             topic.persist().await?;
             info!("Updated topic: {} with ID: {}", topic.name, id);
         }
@@ -125,7 +153,10 @@ impl Stream {
     }
 
     fn get_topic_by_name(&self, name: &str) -> Result<&Topic, Error> {
-        let topic_id = self.topics_ids.get(name);
+        let topic_id = self
+            .topics_ids
+            .get(name)
+            .or_else(|| self.topics_aliases.get(name));
         if topic_id.is_none() {
             return Err(Error::TopicNameNotFound(name.to_string(), self.stream_id));
         }
@@ -143,7 +174,10 @@ impl Stream {
     }
 
     fn get_topic_by_name_mut(&mut self, name: &str) -> Result<&mut Topic, Error> {
-        let topic_id = self.topics_ids.get(name);
+        let topic_id = self
+            .topics_ids
+            .get(name)
+            .or_else(|| self.topics_aliases.get(name));
         if topic_id.is_none() {
             return Err(Error::TopicNameNotFound(name.to_string(), self.stream_id));
         }
@@ -165,9 +199,154 @@ impl Stream {
 
         let topic = self.topics.remove(&topic_id).unwrap();
         self.topics_ids.remove(&topic_name);
+        self.topics_aliases.retain(|_, id| *id != topic_id);
 
         Ok(topic)
     }
+
+    /// Hides the topic and makes it read-only until it's either restored or permanently deleted,
+    /// once the grace period has elapsed. The unsaved messages are persisted before.
+    pub async fn soft_delete_topic(&mut self, id: &Identifier) -> Result<&Topic, Error> {
+        let topic_id;
+        let topic_name;
+        {
+            let topic = self.get_topic(id)?;
+            topic_id = topic.topic_id;
+            topic_name = topic.name.clone();
+        }
+
+        let mut topic = self.topics.remove(&topic_id).unwrap();
+        self.topics_ids.remove(&topic_name);
+        self.topics_aliases.retain(|_, id| *id != topic_id);
+        topic.deleted_at = Some(TimeStamp::now().to_micros());
+        let persisted = match topic.persist_messages(self.storage.segment.clone()).await {
+            Ok(_) => topic.persist().await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = persisted {
+            topic.deleted_at = None;
+            self.topics_ids.insert(topic_name, topic_id);
+            self.insert_topic_aliases(&topic);
+            self.topics.insert(topic_id, topic);
+            return Err(error);
+        }
+
+        info!(
+            "Soft-deleted topic: {} with ID: {} for stream with ID: {}, it can be restored for: {}.",
+            topic_name, topic_id, self.stream_id, self.config.soft_delete.grace_period
+        );
+        Ok(self.deleted_topics.entry(topic_id).or_insert(topic))
+    }
+
+    pub async fn restore_topic(&mut self, id: &Identifier) -> Result<&Topic, Error> {
+        let topic_id = self.get_deleted_topic_id(id)?;
+        {
+            let topic = self.deleted_topics.get(&topic_id).unwrap();
+            if self.topics_ids.contains_key(&topic.name) {
+                return Err(Error::TopicNameAlreadyExists(
+                    topic.name.clone(),
+                    self.stream_id,
+                ));
+            }
+        }
+
+        let name = self.deleted_topics.get(&topic_id).unwrap().name.clone();
+        self.release_topic_alias(&name).await?;
+        let mut topic = self.deleted_topics.remove(&topic_id).unwrap();
+        let deleted_at = topic.deleted_at.take();
+        if let Err(error) = topic.persist().await {
+            topic.deleted_at = deleted_at;
+            self.deleted_topics.insert(topic_id, topic);
+            return Err(error);
+        }
+
+        info!(
+            "Restored topic: {} with ID: {} for stream with ID: {}",
+            topic.name, topic_id, self.stream_id
+        );
+        self.topics_ids.insert(topic.name.clone(), topic_id);
+        self.insert_topic_aliases(&topic);
+        Ok(self.topics.entry(topic_id).or_insert(topic))
+    }
+
+    /// Makes the name available for another topic, by removing it from the aliases of the topic previously named so.
+    async fn release_topic_alias(&mut self, name: &str) -> Result<(), Error> {
+        let Some(topic_id) = self.topics_aliases.get(name).copied() else {
+            return Ok(());
+        };
+
+        if let Some(topic) = self.topics.get_mut(&topic_id) {
+            let aliases = topic.aliases.clone();
+            topic.aliases.retain(|alias| alias != name);
+            if let Err(error) = topic.persist().await {
+                topic.aliases = aliases;
+                return Err(error);
+            }
+        }
+
+        self.topics_aliases.remove(name);
+        Ok(())
+    }
+
+    /// Resolves the aliases of the topic, except the ones which have been taken by the other topics in the meantime.
+    fn insert_topic_aliases(&mut self, topic: &Topic) {
+        for alias in &topic.aliases {
+            if !self.topics_ids.contains_key(alias) && !self.topics_aliases.contains_key(alias) {
+                self.topics_aliases.insert(alias.clone(), topic.topic_id);
+            }
+        }
+    }
+
+    pub fn get_deleted_topics(&self) -> Vec<&Topic> {
+        self.deleted_topics.values().collect()
+    }
+
+    pub fn get_deleted_topic(&self, identifier: &Identifier) -> Result<&Topic, Error> {
+        let topic_id = self.get_deleted_topic_id(identifier)?;
+        Ok(self.deleted_topics.get(&topic_id).unwrap())
+    }
+
+    /// Finds the soft-deleted topic, if there are multiple ones with the same name, the most recently deleted one is returned.
+    fn get_deleted_topic_id(&self, identifier: &Identifier) -> Result<u32, Error> {
+        match identifier.kind {
+            IdKind::Numeric => {
+                let id = identifier.get_u32_value().unwrap();
+                match self.deleted_topics.contains_key(&id) {
+                    true => Ok(id),
+                    false => Err(Error::TopicIdNotFound(id, self.stream_id)),
+                }
+            }
+            IdKind::String => {
+                let name = identifier.get_string_value().unwrap();
+                self.deleted_topics
+                    .values()
+                    .filter(|topic| topic.name == name)
+                    .max_by_key(|topic| topic.deleted_at)
+                    .map(|topic| topic.topic_id)
+                    .ok_or(Error::TopicNameNotFound(name, self.stream_id))
+            }
+        }
+    }
+
+    /// Permanently deletes the soft-deleted topics, which were deleted before the provided timestamp.
+    pub async fn delete_soft_deleted_topics(&mut self, deleted_before: u64) -> Result<u32, Error> {
+        let expired_topics = self
+            .deleted_topics
+            .values()
+            .filter(|topic| topic.deleted_at.unwrap_or_default() <= deleted_before)
+            .map(|topic| topic.topic_id)
+            .collect::<Vec<u32>>();
+        for topic_id in &expired_topics {
+            let topic = self.deleted_topics.get(topic_id).unwrap();
+            if topic.delete().await.is_err() {
+                return Err(Error::CannotDeleteTopic(*topic_id, self.stream_id));
+            }
+
+            self.deleted_topics.remove(topic_id);
+        }
+
+        Ok(expired_topics.len() as u32)
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +354,7 @@ mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::topics::config;
     use std::sync::Arc;
 
     #[tokio::test]
@@ -188,7 +368,7 @@ mod tests {
         let storage = Arc::new(get_test_system_storage());
         let mut stream = Stream::create(stream_id, stream_name, config, storage);
         stream
-            .create_topic(topic_id, topic_name, 1, message_expiry)
+            .create_topic(topic_id, topic_name, 1, message_expiry, HashMap::new())
             .await
             .unwrap();
 
@@ -204,4 +384,94 @@ mod tests {
         assert_eq!(topic.topic_id, topic_id);
         assert_eq!(topic.name, topic_name);
     }
+
+    #[tokio::test]
+    async fn renamed_topic_should_be_found_by_previous_name_until_it_is_taken() {
+        let topic_id = 1;
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(get_test_system_storage());
+        let mut stream = Stream::create(1, "test_stream", config, storage);
+        stream
+            .create_topic(topic_id, "test", 1, None, HashMap::new())
+            .await
+            .unwrap();
+
+        stream
+            .update_topic(&Identifier::named("test").unwrap(), "renamed", None, None)
+            .await
+            .unwrap();
+
+        let topic = stream
+            .get_topic(&Identifier::named("test").unwrap())
+            .unwrap();
+        assert_eq!(topic.topic_id, topic_id);
+        assert_eq!(topic.name, "renamed");
+        assert_eq!(topic.aliases, vec!["test".to_string()]);
+
+        stream
+            .create_topic(2, "test", 1, None, HashMap::new())
+            .await
+            .unwrap();
+
+        let topic = stream
+            .get_topic(&Identifier::named("test").unwrap())
+            .unwrap();
+        assert_eq!(topic.topic_id, 2);
+        let topic = stream
+            .get_topic(&Identifier::numeric(topic_id).unwrap())
+            .unwrap();
+        assert!(topic.aliases.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_apply_topic_config_overrides_to_partitions() {
+        let topic_id = 1;
+        let topic_name = "test_topic";
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(get_test_system_storage());
+        let mut stream = Stream::create(1, "test_stream", config, storage);
+        let overrides = HashMap::from([
+            (
+                config::PARTITION_MESSAGES_REQUIRED_TO_SAVE.to_string(),
+                "10".to_string(),
+            ),
+            (
+                config::MESSAGE_DEDUPLICATION_ENABLED.to_string(),
+                "true".to_string(),
+            ),
+        ]);
+        stream
+            .create_topic(topic_id, topic_name, 2, None, overrides.clone())
+            .await
+            .unwrap();
+
+        let id = Identifier::numeric(topic_id).unwrap();
+        let topic = stream.get_topic(&id).unwrap();
+        assert_eq!(topic.config_overrides, overrides);
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            assert_eq!(partition.config.partition.messages_required_to_save, 10);
+            assert!(partition.message_deduplicator.is_some());
+        }
+
+        let invalid_overrides =
+            HashMap::from([(config::SEGMENT_SIZE.to_string(), "10 GB".to_string())]);
+        assert!(stream
+            .update_topic(&id, "renamed_topic", None, Some(invalid_overrides))
+            .await
+            .is_err());
+        assert_eq!(stream.get_topic(&id).unwrap().name, topic_name);
+
+        stream
+            .update_topic(&id, topic_name, None, Some(HashMap::new()))
+            .await
+            .unwrap();
+        let topic = stream.get_topic(&id).unwrap();
+        assert!(topic.config_overrides.is_empty());
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            assert_eq!(partition.config.partition.messages_required_to_save, 1000);
+            assert!(partition.message_deduplicator.is_none());
+        }
+    }
 }
@@ -0,0 +1,266 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::partition::ProducerState;
+use crate::streaming::partitions::storage::FilePartitionStorage;
+use crate::streaming::session::Session;
+use crate::streaming::storage::PartitionStorage;
+use crate::streaming::systems::system::System;
+use anyhow::{anyhow, Context};
+use iggy::error::Error;
+use iggy::models::backup_info::BackupInfo;
+use iggy::utils::timestamp::TimeStamp;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs::{create_dir_all, read_dir, File};
+use tokio::io::AsyncReadExt;
+use tokio::time::Instant;
+use tracing::info;
+
+const BACKUP_INFO_FILE: &str = "backup.toml";
+
+/// A segment file opened while its partition was locked, along with its size at that moment.
+/// Segment files are append-only, so copying exactly `size` bytes from the already opened handle
+/// gives a consistent image of the file, even if it's written to (or deleted) in the meantime.
+struct SegmentFileSnapshot {
+    file: File,
+    size: u64,
+    relative_path: PathBuf,
+}
+
+impl System {
+    pub async fn create_backup(&self, session: &Session) -> Result<BackupInfo, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.create_backup(session.user_id)?;
+        let db = match self.db.as_ref() {
+            Some(db) => db.clone(),
+            None => {
+                return Err(Error::CannotCreateBackup(anyhow!(
+                    "Metadata database is not available"
+                )))
+            }
+        };
+
+        let created_at = TimeStamp::now().to_micros();
+        let name = format!("backup_{created_at}");
+        let backup_path = self.config.get_backup_path(&name);
+        if create_dir_all(&backup_path).await.is_err() {
+            return Err(Error::CannotCreateBackupDirectory(backup_path));
+        }
+
+        info!("Creating backup: {name} at path: {backup_path}...");
+        let now = Instant::now();
+
+        // The metadata goes first, so the restored consumer offsets never point beyond the restored messages.
+        let database_path = format!("{}/{}", backup_path, self.config.database.path);
+        let backup_db = tokio::task::spawn_blocking(move || -> Result<sled::Db, anyhow::Error> {
+            let backup_db = sled::open(&database_path)
+                .with_context(|| format!("Failed to open backup database at: {database_path}"))?;
+            backup_db.import(db.export());
+            Ok(backup_db)
+        })
+        .await
+        .map_err(|error| Error::CannotCreateBackup(error.into()))?
+        .map_err(Error::CannotCreateBackup)?;
+
+        let (snapshots, producer_states) = self.snapshot_segment_files(&backup_path).await?;
+        // The exported producer states may miss the flushed messages, so they're replaced with the ones taken together
+        // with the segments, then the retries of the batches appended before the backup are still ignored after restoring it.
+        let backup_storage = FilePartitionStorage::new(Arc::new(backup_db.clone()));
+        for state in &producer_states {
+            backup_storage.save_producer_state(state).await?;
+        }
+        backup_db
+            .flush_async()
+            .await
+            .with_context(|| "Failed to flush backup database")
+            .map_err(Error::CannotCreateBackup)?;
+
+        let files_count = snapshots.len() as u32;
+        let mut size_bytes = 0;
+        for snapshot in snapshots {
+            size_bytes += copy_snapshot(snapshot, &backup_path)
+                .await
+                .map_err(Error::CannotCreateBackup)?;
+        }
+
+        let backup = BackupInfo {
+            name,
+            path: backup_path,
+            created_at,
+            size_bytes,
+            files_count,
+        };
+        let backup_info = toml::to_string(&backup)
+            .with_context(|| "Failed to serialize backup info")
+            .map_err(Error::CannotCreateBackup)?;
+        tokio::fs::write(format!("{}/{}", backup.path, BACKUP_INFO_FILE), backup_info)
+            .await
+            .with_context(|| "Failed to save backup info")
+            .map_err(Error::CannotCreateBackup)?;
+
+        info!(
+            "Created backup: {} with {} segment files ({} bytes) in {} ms.",
+            backup.name,
+            backup.files_count,
+            backup.size_bytes,
+            now.elapsed().as_millis()
+        );
+        Ok(backup)
+    }
+
+    /// Flushes the buffered messages of each partition and opens its segment files while holding the partition lock,
+    /// returning them along with the producer states matching the flushed messages.
+    /// The lock is released right after, so the actual copying doesn't block the producers.
+    async fn snapshot_segment_files(
+        &self,
+        backup_path: &str,
+    ) -> Result<(Vec<SegmentFileSnapshot>, Vec<ProducerState>), Error> {
+        let system_path = self.config.get_system_path();
+        let mut snapshots = Vec::new();
+        let mut producer_states = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let mut partition = partition.write().await;
+                    let partition_path = get_relative_path(&system_path, &partition.path)
+                        .map_err(Error::CannotCreateBackup)?;
+                    let partition_backup_path = Path::new(backup_path).join(partition_path);
+                    if create_dir_all(&partition_backup_path).await.is_err() {
+                        return Err(Error::CannotCreateBackupDirectory(
+                            partition_backup_path.to_string_lossy().to_string(),
+                        ));
+                    }
+
+                    for segment in partition.get_segments_mut() {
+                        segment
+                            .persist_messages(self.storage.segment.clone())
+                            .await?;
+                        for path in [
+                            &segment.log_path,
+                            &segment.index_path,
+                            &segment.time_index_path,
+                        ] {
+                            let snapshot = snapshot_file(&system_path, path)
+                                .await
+                                .map_err(Error::CannotCreateBackup)?;
+                            snapshots.push(snapshot);
+                        }
+                    }
+                    partition.unsaved_messages_count = 0;
+                    partition.unsaved_messages_size_bytes = 0;
+                    partition.persist_producer_states().await?;
+                    producer_states.extend(partition.producer_states.values().cloned());
+                }
+            }
+        }
+
+        Ok((snapshots, producer_states))
+    }
+
+    /// Restores the server data (metadata database and segments) from the backup created with `create_backup`.
+    /// It has to be invoked before the system is initialized, and the data directory must not contain any data yet.
+    pub async fn restore_backup(config: &SystemConfig, backup_path: &str) -> Result<(), Error> {
+        let backup_info_path = format!("{backup_path}/{BACKUP_INFO_FILE}");
+        if !Path::new(&backup_info_path).exists() {
+            return Err(Error::CannotRestoreBackup(anyhow!(
+                "Backup info file: {backup_info_path} was not found"
+            )));
+        }
+
+        for path in [config.get_database_path(), config.get_streams_path()] {
+            if Path::new(&path).exists() {
+                return Err(Error::CannotRestoreBackup(anyhow!(
+                    "Data already exists at: {path}, remove it before restoring the backup"
+                )));
+            }
+        }
+
+        info!(
+            "Restoring backup from path: {backup_path} to: {}...",
+            config.get_system_path()
+        );
+        let now = Instant::now();
+        let system_path = PathBuf::from(config.get_system_path());
+        let mut directories = vec![PathBuf::new()];
+        while let Some(directory) = directories.pop() {
+            let target_directory = system_path.join(&directory);
+            create_dir_all(&target_directory)
+                .await
+                .with_context(|| format!("Failed to create directory: {target_directory:?}"))
+                .map_err(Error::CannotRestoreBackup)?;
+            let mut entries = read_dir(Path::new(backup_path).join(&directory))
+                .await
+                .with_context(|| format!("Failed to read backup directory: {directory:?}"))
+                .map_err(Error::CannotRestoreBackup)?;
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .with_context(|| format!("Failed to read backup directory: {directory:?}"))
+                .map_err(Error::CannotRestoreBackup)?
+            {
+                let relative_path = directory.join(entry.file_name());
+                if relative_path == Path::new(BACKUP_INFO_FILE) {
+                    continue;
+                }
+
+                if entry.path().is_dir() {
+                    directories.push(relative_path);
+                    continue;
+                }
+
+                tokio::fs::copy(entry.path(), system_path.join(&relative_path))
+                    .await
+                    .with_context(|| format!("Failed to restore file: {relative_path:?}"))
+                    .map_err(Error::CannotRestoreBackup)?;
+            }
+        }
+
+        info!(
+            "Restored backup from path: {backup_path} in {} ms.",
+            now.elapsed().as_millis()
+        );
+        Ok(())
+    }
+}
+
+fn get_relative_path(system_path: &str, path: &str) -> Result<PathBuf, anyhow::Error> {
+    Path::new(path)
+        .strip_prefix(system_path)
+        .map(|path| path.to_path_buf())
+        .with_context(|| format!("Path: {path} is not located in: {system_path}"))
+}
+
+async fn snapshot_file(
+    system_path: &str,
+    path: &str,
+) -> Result<SegmentFileSnapshot, anyhow::Error> {
+    let file = File::open(path)
+        .await
+        .with_context(|| format!("Failed to open segment file: {path}"))?;
+    let size = file
+        .metadata()
+        .await
+        .with_context(|| format!("Failed to read metadata of segment file: {path}"))?
+        .len();
+    Ok(SegmentFileSnapshot {
+        file,
+        size,
+        relative_path: get_relative_path(system_path, path)?,
+    })
+}
+
+async fn copy_snapshot(
+    snapshot: SegmentFileSnapshot,
+    backup_path: &str,
+) -> Result<u64, anyhow::Error> {
+    let path = Path::new(backup_path).join(&snapshot.relative_path);
+    let mut file = File::create(&path)
+        .await
+        .with_context(|| format!("Failed to create backup file: {path:?}"))?;
+    let copied_bytes = tokio::io::copy(&mut snapshot.file.take(snapshot.size), &mut file)
+        .await
+        .with_context(|| format!("Failed to copy segment file to: {path:?}"))?;
+    file.sync_all()
+        .await
+        .with_context(|| format!("Failed to sync backup file: {path:?}"))?;
+    Ok(copied_bytes)
+}
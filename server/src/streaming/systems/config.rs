@@ -0,0 +1,118 @@
+use crate::configs::reload::{ConfigReloader, ReloadableConfig};
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use anyhow::anyhow;
+use iggy::error::Error;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tracing::{info, warn};
+use tracing_subscriber::filter::LevelFilter;
+
+impl SharedSystem {
+    /// Reloads the config on behalf of the user. The system lock is released while the config is being loaded
+    /// (e.g. from the remote HTTP endpoint), so the other operations aren't blocked in the meantime.
+    pub async fn reload_config(&self, session: &Session) -> Result<(), Error> {
+        {
+            let system = self.read();
+            system.ensure_authenticated(session)?;
+            system.permissioner.reload_config(session.user_id)?;
+        }
+        self.apply_config_reload().await?;
+        info!("Config was reloaded by user with ID: {}.", session.user_id);
+        Ok(())
+    }
+
+    /// Reloads the config and applies the new log level and cache size, the changes of the other reloadable sections
+    /// are applied by the background jobs and the HTTP server which are subscribed to the config changes.
+    pub async fn apply_config_reload(&self) -> Result<(), Error> {
+        let config_reloader = self.read().get_config_reloader()?;
+        let (previous_config, config) = config_reloader
+            .reload()
+            .await
+            .map_err(|error| Error::CannotReloadConfig(error.into()))?;
+        self.read().apply_reloaded_config(&previous_config, &config)
+    }
+}
+
+impl System {
+    pub fn set_config_reloader(&mut self, config_reloader: Option<ConfigReloader>) {
+        self.config_reloader = config_reloader.map(Arc::new);
+    }
+
+    pub fn subscribe_to_config_changes(&self) -> Option<watch::Receiver<ReloadableConfig>> {
+        self.config_reloader
+            .as_ref()
+            .map(|config_reloader| config_reloader.subscribe())
+    }
+
+    fn get_config_reloader(&self) -> Result<Arc<ConfigReloader>, Error> {
+        self.config_reloader.clone().ok_or_else(|| {
+            Error::CannotReloadConfig(anyhow!("Config reloader has not been initialized"))
+        })
+    }
+
+    fn apply_reloaded_config(
+        &self,
+        previous_config: &ReloadableConfig,
+        config: &ReloadableConfig,
+    ) -> Result<(), Error> {
+        if previous_config.log_level != config.log_level {
+            match LevelFilter::from_str(&config.log_level.to_uppercase()) {
+                Ok(filter) => {
+                    self.apply_log_level(filter)?;
+                    info!("Log level was set to: {filter}.");
+                }
+                Err(_) => warn!(
+                    "Invalid log level in config: {}, the current level will be kept.",
+                    config.log_level
+                ),
+            }
+        }
+
+        if previous_config.cache.size != config.cache.size {
+            if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+                memory_tracker.set_limit(config.cache.size.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the message expiry from the reloaded retention policy, used for the topics created without the expiry.
+    pub(crate) fn get_default_message_expiry(&self) -> Option<u32> {
+        self.config_reloader.as_ref().map(|config_reloader| {
+            config_reloader
+                .current()
+                .retention_policy
+                .message_expiry
+                .as_secs()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    #[tokio::test]
+    async fn config_should_not_be_reloaded_by_unauthenticated_session() {
+        let system = System::create(
+            Arc::new(SystemConfig::default()),
+            get_test_system_storage(),
+            None,
+            PersonalAccessTokenConfig::default(),
+        );
+        let system = SharedSystem::new(system);
+        let session = Session::from_client_id(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+
+        let result = system.reload_config(&session).await;
+
+        assert!(matches!(result, Err(Error::Unauthenticated)));
+    }
+}
@@ -211,4 +211,38 @@ impl System {
             .leave_consumer_group(client_id, stream_id_value, topic_id_value, group_id)
             .await
     }
+
+    // Stateless (HTTP) sessions are not tracked by the client manager,
+    // so the member ID is provided by the caller instead of being taken from the session.
+    pub async fn join_consumer_group_by_member(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        member_id: u32,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .join_consumer_group(session.user_id, stream.stream_id, topic.topic_id)?;
+        topic
+            .join_consumer_group(consumer_group_id, member_id)
+            .await
+    }
+
+    pub async fn leave_consumer_group_by_member(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        member_id: u32,
+    ) -> Result<(), Error> {
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        topic
+            .leave_consumer_group(consumer_group_id, member_id)
+            .await
+    }
 }
@@ -13,6 +13,7 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         offset: u64,
+        transaction_id: Option<u64>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
@@ -23,6 +24,26 @@ impl System {
             topic.topic_id,
         )?;
 
+        if let Some(transaction_id) = transaction_id {
+            let mut transactions = self.transactions.write().await;
+            let transaction =
+                Self::get_session_transaction(&mut transactions, session, transaction_id)?;
+            let partition = topic.resolve_partition(consumer).await?.read().await;
+            if offset > partition.current_offset {
+                return Err(Error::InvalidOffset(offset));
+            }
+
+            Self::stage_transaction_consumer_offset(
+                transaction,
+                consumer,
+                stream.stream_id,
+                topic.topic_id,
+                partition.partition_id,
+                offset,
+            );
+            return Ok(());
+        }
+
         topic.store_consumer_offset(consumer, offset).await
     }
 
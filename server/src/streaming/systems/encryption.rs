@@ -0,0 +1,118 @@
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use tracing::info;
+
+impl System {
+    /// Returns the encryptor of the stream if it has its own data key, otherwise the server-wide one (if enabled).
+    pub(crate) fn get_encryptor<'a>(&'a self, stream: &'a Stream) -> Option<&'a dyn Encryptor> {
+        stream.encryptor.as_deref().or(self.encryptor.as_deref())
+    }
+
+    pub(crate) async fn create_data_key(&self, stream: &mut Stream) -> Result<(), Error> {
+        let Some(master_key_provider) = &self.master_key_provider else {
+            return Ok(());
+        };
+
+        let data_key = Aes256GcmEncryptor::generate_key();
+        let wrapped_data_key = master_key_provider.wrap_key(&data_key)?;
+        self.storage
+            .stream
+            .save_data_key(stream, &wrapped_data_key)
+            .await?;
+        stream.encryptor = Some(Box::new(Aes256GcmEncryptor::new(&data_key)?));
+        info!("Created data key for stream with ID: {}.", stream.stream_id);
+        Ok(())
+    }
+
+    pub(crate) async fn load_data_key(&self, stream: &mut Stream) -> Result<(), Error> {
+        let Some(master_key_provider) = &self.master_key_provider else {
+            return Ok(());
+        };
+
+        let Some(wrapped_data_key) = self.storage.stream.load_data_key(stream).await? else {
+            return Ok(());
+        };
+
+        let data_key = master_key_provider.unwrap_key(&wrapped_data_key)?;
+        // The data key itself is never rotated, only re-wrapped once the master key gets rotated,
+        // so the messages of the stream don't have to be re-encrypted.
+        if master_key_provider.requires_rewrap(&wrapped_data_key) {
+            let wrapped_data_key = master_key_provider.wrap_key(&data_key)?;
+            self.storage
+                .stream
+                .save_data_key(stream, &wrapped_data_key)
+                .await?;
+            info!(
+                "Re-wrapped data key with the active master key for stream with ID: {}.",
+                stream.stream_id
+            );
+        }
+        stream.encryptor = Some(Box::new(Aes256GcmEncryptor::new(&data_key)?));
+        info!("Loaded data key for stream with ID: {}.", stream.stream_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::system::{EncryptionConfig, SystemConfig};
+    use crate::streaming::session::Session;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use crate::streaming::systems::system::System;
+    use crate::streaming::users::user::User;
+    use iggy::identifier::Identifier;
+    use iggy::utils::crypto::Aes256GcmEncryptor;
+    use iggy::utils::text;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn each_stream_should_have_its_own_data_key_when_per_stream_keys_are_enabled() {
+        let config = Arc::new(SystemConfig {
+            encryption: EncryptionConfig {
+                enabled: true,
+                key: text::as_base64(&Aes256GcmEncryptor::generate_key()),
+                key_id: 1,
+                per_stream_keys: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let mut system = System::create(
+            config,
+            get_test_system_storage(),
+            None,
+            PersonalAccessTokenConfig::default(),
+        );
+        let root = User::root();
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.permissioner.init_permissions_for_user(root);
+        system.create_stream(&session, 1, "test1").await.unwrap();
+        system.create_stream(&session, 2, "test2").await.unwrap();
+
+        let first_stream = system.get_stream(&Identifier::numeric(1).unwrap()).unwrap();
+        let second_stream = system.get_stream(&Identifier::numeric(2).unwrap()).unwrap();
+        assert!(first_stream.encryptor.is_some());
+        assert!(second_stream.encryptor.is_some());
+
+        let first_encryptor = system.get_encryptor(first_stream).unwrap();
+        let second_encryptor = system.get_encryptor(second_stream).unwrap();
+        let data = b"Hello World!";
+        let encrypted_data = first_encryptor.encrypt(data).unwrap();
+        assert_eq!(first_encryptor.decrypt(&encrypted_data).unwrap(), data);
+        assert!(second_encryptor.decrypt(&encrypted_data).is_err());
+        assert!(system
+            .encryptor
+            .as_ref()
+            .unwrap()
+            .decrypt(&encrypted_data)
+            .is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::logging::LogLevelHandle;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::anyhow;
+use iggy::error::Error;
+use iggy::system::set_log_level::LogLevel;
+use tracing::info;
+use tracing_subscriber::filter::LevelFilter;
+
+impl System {
+    pub fn set_log_level_handle(&mut self, log_level_handle: Option<LogLevelHandle>) {
+        self.log_level_handle = log_level_handle;
+    }
+
+    /// Changes the level of the stdout and file logs until the server restart, when the configured level is used again.
+    pub fn set_log_level(&self, session: &Session, level: LogLevel) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.set_log_level(session.user_id)?;
+        let filter = match level {
+            LogLevel::Off => LevelFilter::OFF,
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        };
+        self.apply_log_level(filter)?;
+        info!(
+            "Log level was set to: {level} by user with ID: {}.",
+            session.user_id
+        );
+        Ok(())
+    }
+
+    pub(crate) fn apply_log_level(&self, filter: LevelFilter) -> Result<(), Error> {
+        let Some(log_level_handle) = self.log_level_handle.as_ref() else {
+            return Err(Error::CannotSetLogLevel(anyhow!(
+                "Logging has not been initialized"
+            )));
+        };
+
+        log_level_handle
+            .set_level(filter)
+            .map_err(|error| Error::CannotSetLogLevel(error.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    #[test]
+    fn log_level_should_not_be_set_by_unauthenticated_session() {
+        let system = System::create(
+            Arc::new(SystemConfig::default()),
+            get_test_system_storage(),
+            None,
+            PersonalAccessTokenConfig::default(),
+        );
+        let session = Session::from_client_id(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+
+        let result = system.set_log_level(&session, LogLevel::Debug);
+
+        assert!(matches!(result, Err(Error::Unauthenticated)));
+    }
+}
@@ -6,11 +6,13 @@ use crate::streaming::systems::system::System;
 use bytes::Bytes;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning, ProducerSequence};
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
 use std::sync::Arc;
+use tokio::sync::watch;
 use tracing::{error, trace};
 
 impl System {
@@ -48,7 +50,13 @@ impl System {
         };
 
         let mut polled_messages = topic
-            .get_messages(consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                args.isolation,
+            )
             .await?;
 
         if polled_messages.messages.is_empty() {
@@ -61,11 +69,10 @@ impl System {
             topic.store_consumer_offset(consumer, offset).await?;
         }
 
-        if self.encryptor.is_none() {
+        let Some(encryptor) = self.get_encryptor(stream) else {
             return Ok(polled_messages);
-        }
+        };
 
-        let encryptor = self.encryptor.as_ref().unwrap();
         let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
         for message in polled_messages.messages.iter() {
             let payload = encryptor.decrypt(&message.payload);
@@ -94,6 +101,43 @@ impl System {
         Ok(polled_messages)
     }
 
+    /// Returns the receiver notified whenever the messages are appended to the partition, which is used by the streaming consumers.
+    pub async fn subscribe_to_appended_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<watch::Receiver<u64>, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .poll_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+        let partition = topic.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        Ok(partition.subscribe_to_appended_messages())
+    }
+
+    /// Returns the stream, topic and partition IDs the messages will be appended to,
+    /// so that the messages sent without acknowledgement can be queued for their partition.
+    pub async fn get_partition_to_append_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+    ) -> Result<(u32, u32, u32), Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .append_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+        let partition_id = topic.calculate_partition_id(partitioning).await?;
+        Ok((stream.stream_id, topic.topic_id, partition_id))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -101,34 +145,48 @@ impl System {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &Vec<send_messages::Message>,
+        acknowledgement: Acknowledgement,
+        mut producer: Option<ProducerSequence>,
+        transaction_id: Option<u64>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
             .append_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+        if let Some(producer) = &producer {
+            self.ensure_producer_epoch(producer)?;
+        }
 
         let mut received_messages = Vec::with_capacity(messages.len());
+        let mut delayed_messages = Vec::new();
         let mut batch_size_bytes = 0u64;
+        let now = TimeStamp::now().to_micros();
 
         // For large batches it would be better to use par_iter() from rayon.
         for message in messages {
-            let encrypted_message;
-            let message = match self.encryptor {
-                Some(ref encryptor) => {
-                    let payload = encryptor.encrypt(message.payload.as_ref())?;
-                    encrypted_message = send_messages::Message {
-                        id: message.id,
-                        length: payload.len() as u32,
-                        payload: Bytes::from(payload),
-                        headers: message.headers.clone(),
-                    };
-                    &encrypted_message
-                }
-                None => message,
+            let payload = match self.get_encryptor(stream) {
+                Some(encryptor) => Bytes::from(encryptor.encrypt(message.payload.as_ref())?),
+                None => message.payload.clone(),
+            };
+            let message = send_messages::Message {
+                id: message.id,
+                length: payload.len() as u32,
+                payload,
+                headers: message.headers.clone(),
+                deliver_at: message.deliver_at,
             };
+            // The messages to be delivered in the future are staged in the topic, and don't take any space in cache yet.
+            if message
+                .deliver_at
+                .is_some_and(|deliver_at| deliver_at > now)
+            {
+                delayed_messages.push(message);
+                continue;
+            }
+
             batch_size_bytes += message.get_size_bytes() as u64;
-            received_messages.push(Message::from_message(message));
+            received_messages.push(Message::from_message(&message));
         }
 
         // If there's enough space in cache, do nothing.
@@ -138,12 +196,63 @@ impl System {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
-        topic
-            .append_messages(partitioning, received_messages)
-            .await?;
+        if !delayed_messages.is_empty() {
+            // The idempotent producer's batch is checked before its delayed messages are staged, and the retried batch is ignored as a whole.
+            // The producer state is then updated by the staged messages, so the rest of the batch is appended without checking it again.
+            if !topic
+                .delay_messages(partitioning, delayed_messages, producer.as_ref())
+                .await?
+            {
+                return Ok(());
+            }
+            producer = None;
+        }
+        if !received_messages.is_empty() {
+            match transaction_id {
+                Some(transaction_id) => {
+                    let mut transactions = self.transactions.write().await;
+                    let transaction =
+                        Self::get_session_transaction(&mut transactions, session, transaction_id)?;
+                    transaction.add_topic(stream.stream_id, topic.topic_id);
+                    topic
+                        .append_messages(
+                            partitioning,
+                            received_messages,
+                            acknowledgement,
+                            producer,
+                            Some(transaction_id),
+                        )
+                        .await?;
+                }
+                None => {
+                    topic
+                        .append_messages(
+                            partitioning,
+                            received_messages,
+                            acknowledgement,
+                            producer,
+                            None,
+                        )
+                        .await?;
+                }
+            }
+        }
         self.metrics.increment_messages(messages.len() as u64);
         Ok(())
     }
+
+    /// Appends the delayed messages, which are due, to their partitions across all the topics.
+    pub async fn deliver_delayed_messages(&self) -> Result<u32, Error> {
+        let now = TimeStamp::now().to_micros();
+        let mut delivered_messages = 0;
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                delivered_messages += topic.deliver_due_messages(now).await?;
+            }
+        }
+
+        Ok(delivered_messages)
+    }
 }
 
 #[derive(Debug)]
@@ -151,14 +260,21 @@ pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub isolation: IsolationLevel,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        isolation: IsolationLevel,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            isolation,
         }
     }
 }
@@ -0,0 +1,167 @@
+use crate::streaming::producers::producer::Producer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::messages::send_messages::ProducerSequence;
+use tracing::{error, info};
+
+impl System {
+    pub(crate) async fn load_producers(&mut self) -> Result<(), Error> {
+        info!("Loading producers...");
+        let producers = self.storage.producer.load_all().await?;
+        let producers_count = producers.len();
+        for producer in producers {
+            self.producers.insert(producer.producer_id, producer);
+        }
+        info!("Initialized {} producer(s).", producers_count);
+        Ok(())
+    }
+
+    /// Registers the new idempotent producer if the provided ID is 0, otherwise increments the epoch of the existing one,
+    /// so that the batches sent by its previous instances are rejected.
+    pub async fn register_producer(
+        &mut self,
+        session: &Session,
+        producer_id: u64,
+    ) -> Result<Producer, Error> {
+        self.ensure_authenticated(session)?;
+        let producer = match producer_id {
+            0 => {
+                let producer_id = self.producers.keys().max().copied().unwrap_or(0) + 1;
+                info!("Registering producer with ID: {producer_id}...");
+                Producer::new(producer_id, session.user_id)
+            }
+            _ => {
+                let producer = self.producers.get(&producer_id).copied();
+                if producer.is_none() {
+                    error!("Producer with ID: {producer_id} was not found.");
+                    return Err(Error::ProducerNotFound(producer_id));
+                }
+
+                let mut producer = producer.unwrap();
+                if producer.user_id != session.user_id {
+                    error!(
+                        "Producer with ID: {producer_id} was registered by another user than: {}.",
+                        session.user_id
+                    );
+                    return Err(Error::Unauthorized);
+                }
+
+                info!("Registering the new epoch of producer with ID: {producer_id}...");
+                producer.epoch += 1;
+                producer
+            }
+        };
+
+        self.storage.producer.save(&producer).await?;
+        self.producers.insert(producer.producer_id, producer);
+        info!(
+            "Registered producer with ID: {} and epoch: {}.",
+            producer.producer_id, producer.epoch
+        );
+        Ok(producer)
+    }
+
+    pub(crate) fn ensure_producer_epoch(&self, producer: &ProducerSequence) -> Result<(), Error> {
+        let registered_producer = self.producers.get(&producer.producer_id);
+        if registered_producer.is_none() {
+            return Err(Error::ProducerNotFound(producer.producer_id));
+        }
+
+        let registered_producer = registered_producer.unwrap();
+        if registered_producer.epoch != producer.epoch {
+            return Err(Error::ProducerFenced(producer.producer_id, producer.epoch));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn registering_producer_with_zero_id_should_create_new_producer() {
+        let mut system = create_system();
+        let session = create_session(1);
+
+        let first = system.register_producer(&session, 0).await.unwrap();
+        let second = system.register_producer(&session, 0).await.unwrap();
+
+        assert_eq!(first.producer_id, 1);
+        assert_eq!(first.epoch, 1);
+        assert_eq!(second.producer_id, 2);
+        assert_eq!(second.epoch, 1);
+    }
+
+    #[tokio::test]
+    async fn registering_existing_producer_should_fence_previous_epoch() {
+        let mut system = create_system();
+        let session = create_session(1);
+        let producer = system.register_producer(&session, 0).await.unwrap();
+
+        let producer = system
+            .register_producer(&session, producer.producer_id)
+            .await
+            .unwrap();
+
+        assert_eq!(producer.epoch, 2);
+        let previous_epoch = ProducerSequence {
+            producer_id: producer.producer_id,
+            epoch: 1,
+            sequence: 1,
+        };
+        assert!(matches!(
+            system.ensure_producer_epoch(&previous_epoch),
+            Err(Error::ProducerFenced(_, 1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn registering_unknown_producer_should_fail() {
+        let mut system = create_system();
+        let session = create_session(1);
+
+        let result = system.register_producer(&session, 10).await;
+
+        assert!(matches!(result, Err(Error::ProducerNotFound(10))));
+    }
+
+    #[tokio::test]
+    async fn registering_producer_of_another_user_should_fail() {
+        let mut system = create_system();
+        let producer = system
+            .register_producer(&create_session(1), 0)
+            .await
+            .unwrap();
+
+        let result = system
+            .register_producer(&create_session(2), producer.producer_id)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    fn create_session(user_id: u32) -> Session {
+        Session::new(
+            1,
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        )
+    }
+
+    fn create_system() -> System {
+        System::create(
+            Arc::new(SystemConfig::default()),
+            get_test_system_storage(),
+            None,
+            PersonalAccessTokenConfig::default(),
+        )
+    }
+}
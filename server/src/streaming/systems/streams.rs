@@ -5,11 +5,18 @@ use futures::future::join_all;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
+use iggy::utils::timestamp::TimeStamp;
 use std::sync::Arc;
 use tokio::fs::read_dir;
 use tokio::sync::Mutex;
 use tracing::{error, info};
 
+#[derive(Debug, Default)]
+pub struct DeletedResources {
+    pub streams_count: u32,
+    pub topics_count: u32,
+}
+
 impl System {
     pub(crate) async fn load_streams(&mut self) -> Result<(), Error> {
         info!("Loading streams from disk...");
@@ -55,17 +62,40 @@ impl System {
         }
 
         join_all(load_streams).await;
-        for stream in loaded_streams.lock().await.drain(..) {
-            if self.streams.contains_key(&stream.stream_id) {
+        for mut stream in loaded_streams.lock().await.drain(..) {
+            if self.streams.contains_key(&stream.stream_id)
+                || self.deleted_streams.contains_key(&stream.stream_id)
+            {
                 error!("Stream with ID: '{}' already exists.", &stream.stream_id);
                 continue;
             }
 
+            if stream.is_soft_deleted() {
+                if let Err(error) = self.load_data_key(&mut stream).await {
+                    error!(
+                        "Failed to load data key for stream with ID: {}. Error: {}",
+                        stream.stream_id, error
+                    );
+                    return Err(error);
+                }
+
+                self.deleted_streams.insert(stream.stream_id, stream);
+                continue;
+            }
+
             if self.streams_ids.contains_key(&stream.name) {
                 error!("Stream with name: '{}' already exists.", &stream.name);
                 continue;
             }
 
+            if let Err(error) = self.load_data_key(&mut stream).await {
+                error!(
+                    "Failed to load data key for stream with ID: {}. Error: {}",
+                    stream.stream_id, error
+                );
+                return Err(error);
+            }
+
             self.metrics.increment_streams(1);
             self.metrics.increment_topics(stream.get_topics_count());
             self.metrics
@@ -80,7 +110,20 @@ impl System {
             self.streams.insert(stream.stream_id, stream);
         }
 
-        info!("Loaded {} stream(s) from disk.", self.streams.len());
+        // The aliases are resolved once all the streams are loaded, as the current names take precedence over them.
+        for stream in self.streams.values() {
+            for alias in &stream.aliases {
+                if !self.streams_ids.contains_key(alias) {
+                    self.streams_aliases.insert(alias.clone(), stream.stream_id);
+                }
+            }
+        }
+
+        info!(
+            "Loaded {} stream(s) and {} soft-deleted stream(s) from disk.",
+            self.streams.len(),
+            self.deleted_streams.len()
+        );
         Ok(())
     }
 
@@ -121,7 +164,10 @@ impl System {
     }
 
     fn get_stream_by_name(&self, name: &str) -> Result<&Stream, Error> {
-        let stream_id = self.streams_ids.get(name);
+        let stream_id = self
+            .streams_ids
+            .get(name)
+            .or_else(|| self.streams_aliases.get(name));
         if stream_id.is_none() {
             return Err(Error::StreamNameNotFound(name.to_string()));
         }
@@ -141,7 +187,10 @@ impl System {
     fn get_stream_by_name_mut(&mut self, name: &str) -> Result<&mut Stream, Error> {
         let stream_id;
         {
-            let id = self.streams_ids.get_mut(name);
+            let id = self
+                .streams_ids
+                .get(name)
+                .or_else(|| self.streams_aliases.get(name));
             if id.is_none() {
                 return Err(Error::StreamNameNotFound(name.to_string()));
             }
@@ -169,7 +218,7 @@ impl System {
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.create_stream(session.user_id)?;
-        if self.streams.contains_key(&stream_id) {
+        if self.streams.contains_key(&stream_id) || self.deleted_streams.contains_key(&stream_id) {
             return Err(Error::StreamIdAlreadyExists(stream_id));
         }
 
@@ -178,8 +227,13 @@ impl System {
             return Err(Error::StreamNameAlreadyExists(name.to_string()));
         }
 
-        let stream = Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
+        self.release_stream_alias(&name).await?;
+        let mut stream =
+            Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
         stream.persist().await?;
+        if self.config.encryption.per_stream_keys {
+            self.create_data_key(&mut stream).await?;
+        }
         info!("Created stream with ID: {}, name: '{}'.", stream_id, name);
         self.streams_ids.insert(name, stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
@@ -212,17 +266,28 @@ impl System {
             }
         }
 
-        let old_name;
+        let old_name = self.get_stream(id)?.name.clone();
+        if old_name == updated_name {
+            return Ok(());
+        }
+
+        // The previous name is kept as the alias, so the clients still referring to it are not broken by the rename.
+        self.release_stream_alias(&updated_name).await?;
         {
-            let stream = self.get_stream_mut(id)?;
-            old_name = stream.name.clone();
+            let stream = self.get_stream_by_id_mut(stream_id)?;
             stream.name = updated_name.clone();
-            stream.persist().await?;
+            stream.aliases.push(old_name.clone());
+            if let Err(error) = stream.persist().await {
+                stream.name = old_name;
+                stream.aliases.pop();
+                return Err(error);
+            }
         }
 
         {
             self.streams_ids.remove(&old_name);
             self.streams_ids.insert(updated_name.clone(), stream_id);
+            self.streams_aliases.insert(old_name.clone(), stream_id);
         }
 
         info!(
@@ -243,6 +308,10 @@ impl System {
         self.permissioner
             .delete_stream(session.user_id, stream_id)?;
         let stream_name = stream.name.clone();
+        if self.config.soft_delete.enabled {
+            return self.soft_delete_stream(stream_id).await;
+        }
+
         if stream.delete().await.is_err() {
             return Err(Error::CannotDeleteStream(stream_id));
         }
@@ -258,6 +327,7 @@ impl System {
 
         self.streams.remove(&stream_id);
         self.streams_ids.remove(&stream_name);
+        self.streams_aliases.retain(|_, id| *id != stream_id);
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
@@ -265,6 +335,178 @@ impl System {
         Ok(stream_id)
     }
 
+    /// Hides the stream and makes it read-only until it's either restored or permanently deleted,
+    /// once the grace period has elapsed. The unsaved messages are persisted before.
+    async fn soft_delete_stream(&mut self, stream_id: u32) -> Result<u32, Error> {
+        let mut stream = self.streams.remove(&stream_id).unwrap();
+        self.streams_ids.remove(&stream.name);
+        self.streams_aliases.retain(|_, id| *id != stream_id);
+        stream.deleted_at = Some(TimeStamp::now().to_micros());
+        let persisted = match stream.persist_messages(self.storage.segment.clone()).await {
+            Ok(_) => stream.persist().await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = persisted {
+            stream.deleted_at = None;
+            self.streams_ids.insert(stream.name.clone(), stream_id);
+            self.insert_stream_aliases(&stream);
+            self.streams.insert(stream_id, stream);
+            return Err(error);
+        }
+
+        self.metrics.decrement_streams(1);
+        self.metrics.decrement_topics(stream.get_topics_count());
+        self.metrics
+            .decrement_partitions(stream.get_partitions_count());
+        self.metrics
+            .decrement_messages(stream.get_messages_count().await);
+        self.metrics
+            .decrement_segments(stream.get_segments_count().await);
+
+        info!(
+            "Soft-deleted stream with ID: {}, name: '{}', it can be restored for: {}.",
+            stream_id, stream.name, self.config.soft_delete.grace_period
+        );
+        self.deleted_streams.insert(stream_id, stream);
+        let client_manager = self.client_manager.read().await;
+        client_manager
+            .delete_consumer_groups_for_stream(stream_id)
+            .await;
+        Ok(stream_id)
+    }
+
+    pub async fn restore_stream(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream_id = self.get_deleted_stream_id(id)?;
+        self.permissioner
+            .restore_stream(session.user_id, stream_id)?;
+        {
+            let stream = self.deleted_streams.get(&stream_id).unwrap();
+            if self.streams_ids.contains_key(&stream.name) {
+                return Err(Error::StreamNameAlreadyExists(stream.name.clone()));
+            }
+        }
+
+        let name = self.deleted_streams.get(&stream_id).unwrap().name.clone();
+        self.release_stream_alias(&name).await?;
+        let mut stream = self.deleted_streams.remove(&stream_id).unwrap();
+        let deleted_at = stream.deleted_at.take();
+        if let Err(error) = stream.persist().await {
+            stream.deleted_at = deleted_at;
+            self.deleted_streams.insert(stream_id, stream);
+            return Err(error);
+        }
+
+        self.metrics.increment_streams(1);
+        self.metrics.increment_topics(stream.get_topics_count());
+        self.metrics
+            .increment_partitions(stream.get_partitions_count());
+        self.metrics
+            .increment_messages(stream.get_messages_count().await);
+        self.metrics
+            .increment_segments(stream.get_segments_count().await);
+
+        info!(
+            "Restored stream with ID: {}, name: '{}'.",
+            stream_id, stream.name
+        );
+        self.streams_ids.insert(stream.name.clone(), stream_id);
+        self.insert_stream_aliases(&stream);
+        self.streams.insert(stream_id, stream);
+        Ok(())
+    }
+
+    /// Makes the name available for another stream, by removing it from the aliases of the stream previously named so.
+    async fn release_stream_alias(&mut self, name: &str) -> Result<(), Error> {
+        let Some(stream_id) = self.streams_aliases.get(name).copied() else {
+            return Ok(());
+        };
+
+        if let Some(stream) = self.streams.get_mut(&stream_id) {
+            let aliases = stream.aliases.clone();
+            stream.aliases.retain(|alias| alias != name);
+            if let Err(error) = stream.persist().await {
+                stream.aliases = aliases;
+                return Err(error);
+            }
+        }
+
+        self.streams_aliases.remove(name);
+        Ok(())
+    }
+
+    /// Resolves the aliases of the stream, except the ones which have been taken by the other streams in the meantime.
+    fn insert_stream_aliases(&mut self, stream: &Stream) {
+        for alias in &stream.aliases {
+            if !self.streams_ids.contains_key(alias) && !self.streams_aliases.contains_key(alias) {
+                self.streams_aliases.insert(alias.clone(), stream.stream_id);
+            }
+        }
+    }
+
+    /// Finds the soft-deleted stream, if there are multiple ones with the same name, the most recently deleted one is returned.
+    fn get_deleted_stream_id(&self, identifier: &Identifier) -> Result<u32, Error> {
+        match identifier.kind {
+            IdKind::Numeric => {
+                let stream_id = identifier.get_u32_value().unwrap();
+                match self.deleted_streams.contains_key(&stream_id) {
+                    true => Ok(stream_id),
+                    false => Err(Error::StreamIdNotFound(stream_id)),
+                }
+            }
+            IdKind::String => {
+                let name = identifier.get_string_value().unwrap();
+                self.deleted_streams
+                    .values()
+                    .filter(|stream| stream.name == name)
+                    .max_by_key(|stream| stream.deleted_at)
+                    .map(|stream| stream.stream_id)
+                    .ok_or(Error::StreamNameNotFound(name))
+            }
+        }
+    }
+
+    /// Permanently deletes the soft-deleted streams and topics, whose grace period has elapsed.
+    pub async fn delete_expired_soft_deleted_resources(
+        &mut self,
+        grace_period_secs: u32,
+    ) -> Result<DeletedResources, Error> {
+        let deleted_before = TimeStamp::now()
+            .to_micros()
+            .saturating_sub(grace_period_secs as u64 * 1_000_000);
+        let mut deleted_resources = DeletedResources::default();
+        let expired_streams = self
+            .deleted_streams
+            .values()
+            .filter(|stream| stream.deleted_at.unwrap_or_default() <= deleted_before)
+            .map(|stream| stream.stream_id)
+            .collect::<Vec<u32>>();
+        for stream_id in expired_streams {
+            let stream = self.deleted_streams.get(&stream_id).unwrap();
+            if stream.delete().await.is_err() {
+                return Err(Error::CannotDeleteStream(stream_id));
+            }
+
+            info!(
+                "Permanently deleted soft-deleted stream with ID: {}, name: '{}'.",
+                stream_id, stream.name
+            );
+            self.deleted_streams.remove(&stream_id);
+            deleted_resources.streams_count += 1;
+        }
+
+        for stream in self.streams.values_mut() {
+            deleted_resources.topics_count +=
+                stream.delete_soft_deleted_topics(deleted_before).await?;
+        }
+
+        Ok(deleted_resources)
+    }
+
     pub async fn purge_stream(
         &self,
         session: &Session,
@@ -281,7 +523,7 @@ impl System {
 mod tests {
     use super::*;
     use crate::configs::server::PersonalAccessTokenConfig;
-    use crate::configs::system::SystemConfig;
+    use crate::configs::system::{SoftDeleteConfig, SystemConfig};
     use crate::streaming::storage::tests::get_test_system_storage;
     use crate::streaming::users::user::User;
     use std::net::{Ipv4Addr, SocketAddr};
@@ -318,4 +560,97 @@ mod tests {
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
     }
+
+    #[tokio::test]
+    async fn renamed_stream_should_be_found_by_previous_name_until_it_is_taken() {
+        let stream_id = 1;
+        let config = Arc::new(SystemConfig::default());
+        let storage = get_test_system_storage();
+        let mut system =
+            System::create(config, storage, None, PersonalAccessTokenConfig::default());
+        let root = User::root();
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.permissioner.init_permissions_for_user(root);
+        system
+            .create_stream(&session, stream_id, "test")
+            .await
+            .unwrap();
+
+        system
+            .update_stream(&session, &Identifier::named("test").unwrap(), "renamed")
+            .await
+            .unwrap();
+
+        let stream = system
+            .get_stream(&Identifier::named("test").unwrap())
+            .unwrap();
+        assert_eq!(stream.stream_id, stream_id);
+        assert_eq!(stream.name, "renamed");
+        assert_eq!(stream.aliases, vec!["test".to_string()]);
+
+        system.create_stream(&session, 2, "test").await.unwrap();
+
+        let stream = system
+            .get_stream(&Identifier::named("test").unwrap())
+            .unwrap();
+        assert_eq!(stream.stream_id, 2);
+        let stream = system
+            .get_stream(&Identifier::numeric(stream_id).unwrap())
+            .unwrap();
+        assert!(stream.aliases.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_soft_delete_and_restore_stream() {
+        let stream_id = 1;
+        let stream_name = "test";
+        let config = Arc::new(SystemConfig {
+            soft_delete: SoftDeleteConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let storage = get_test_system_storage();
+        let mut system =
+            System::create(config, storage, None, PersonalAccessTokenConfig::default());
+        let root = User::root();
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.permissioner.init_permissions_for_user(root);
+        system
+            .create_stream(&session, stream_id, stream_name)
+            .await
+            .unwrap();
+
+        system
+            .delete_stream(&session, &Identifier::numeric(stream_id).unwrap())
+            .await
+            .unwrap();
+        assert!(system
+            .get_stream(&Identifier::numeric(stream_id).unwrap())
+            .is_err());
+        assert!(system
+            .create_stream(&session, stream_id, "other")
+            .await
+            .is_err());
+
+        system
+            .restore_stream(&session, &Identifier::named(stream_name).unwrap())
+            .await
+            .unwrap();
+        let stream = system
+            .get_stream(&Identifier::numeric(stream_id).unwrap())
+            .unwrap();
+        assert_eq!(stream.name, stream_name);
+        assert!(!stream.is_soft_deleted());
+        assert!(system.deleted_streams.is_empty());
+    }
 }
@@ -1,18 +1,30 @@
+use crate::configs::reload::ConfigReloader;
 use crate::configs::server::PersonalAccessTokenConfig;
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{EncryptionConfig, PersisterKind, SystemConfig};
+use crate::logging::LogLevelHandle;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::encryption::master_key_provider::{
+    LocalMasterKeyProvider, MasterKeyProvider,
+};
+use crate::streaming::persistence::group_commit_persister::GroupCommitPersister;
 use crate::streaming::persistence::persister::*;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::uring_persister::UringPersister;
+use crate::streaming::producers::producer::Producer;
 use crate::streaming::session::Session;
 use crate::streaming::storage::{SegmentStorage, SystemStorage};
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::transactions::transaction::Transaction;
 use crate::streaming::users::permissioner::Permissioner;
 use iggy::error::Error;
-use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use iggy::utils::crypto::{Aes256GcmKeyring, Encryptor};
+use iggy::utils::timestamp::TimeStamp;
 use sled::Db;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::fs::{create_dir, remove_dir_all};
 use tokio::sync::RwLock;
@@ -37,6 +49,10 @@ impl SharedSystem {
         self.system.read()
     }
 
+    pub fn try_read(&self) -> Option<SharedReadLock<System>> {
+        self.system.try_read()
+    }
+
     pub fn write(&self) -> SharedWriteLock<System> {
         self.system.write()
     }
@@ -56,14 +72,28 @@ pub struct System {
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) streams: HashMap<u32, Stream>,
     pub(crate) streams_ids: HashMap<String, u32>,
+    pub(crate) streams_aliases: HashMap<String, u32>,
+    pub(crate) deleted_streams: HashMap<u32, Stream>,
+    pub(crate) producers: HashMap<u64, Producer>,
+    pub(crate) transactions: RwLock<HashMap<u64, Transaction>>,
+    pub(crate) next_transaction_id: AtomicU64,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: Arc<RwLock<ClientManager>>,
     pub(crate) encryptor: Option<Box<dyn Encryptor>>,
+    pub(crate) master_key_provider: Option<Box<dyn MasterKeyProvider>>,
     pub(crate) metrics: Metrics,
     pub(crate) db: Option<Arc<Db>>,
+    pub(crate) log_level_handle: Option<LogLevelHandle>,
+    pub(crate) config_reloader: Option<Arc<ConfigReloader>>,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
+#[derive(Debug, Default)]
+pub struct FlushedMessages {
+    pub messages_count: u64,
+    pub partitions_count: u32,
+}
+
 /// For each cache eviction, we want to remove more than the size we need.
 /// This is done on purpose to avoid evicting messages on every write.
 const CACHE_OVER_EVICTION_FACTOR: u64 = 5;
@@ -84,9 +114,27 @@ impl System {
                 Arc::new(db.unwrap())
             }
         };
-        let persister: Arc<dyn Persister> = match config.partition.enforce_fsync {
-            true => Arc::new(FileWithSyncPersister {}),
-            false => Arc::new(FilePersister {}),
+        // With group commit enabled, the writes are synced in batches instead of one by one.
+        let enforce_fsync = config.partition.enforce_fsync && !config.partition.group_commit;
+        let persister: Arc<dyn Persister> = match config.partition.persister {
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            PersisterKind::IoUring => Arc::new(UringPersister::new(enforce_fsync)),
+            #[cfg(not(all(target_os = "linux", feature = "io-uring")))]
+            PersisterKind::IoUring => {
+                tracing::warn!(
+                    "io_uring persister is not available in this build, using file persister."
+                );
+                Self::create_file_persister(enforce_fsync)
+            }
+            PersisterKind::File => Self::create_file_persister(enforce_fsync),
+        };
+        let persister: Arc<dyn Persister> = match config.partition.group_commit {
+            true => Arc::new(GroupCommitPersister::new(
+                persister,
+                config.partition.group_commit_interval,
+                config.partition.group_commit_max_size.as_u64(),
+            )),
+            false => persister,
         };
         Self::create(
             config,
@@ -96,6 +144,13 @@ impl System {
         )
     }
 
+    fn create_file_persister(enforce_fsync: bool) -> Arc<dyn Persister> {
+        match enforce_fsync {
+            true => Arc::new(FileWithSyncPersister {}),
+            false => Arc::new(FilePersister {}),
+        }
+    }
+
     pub fn create(
         config: Arc<SystemConfig>,
         storage: SystemStorage,
@@ -108,23 +163,52 @@ impl System {
         );
         System {
             encryptor: match config.encryption.enabled {
-                true => Some(Box::new(
-                    Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
-                )),
+                true => Some(Box::new(Self::create_keyring(&config.encryption).unwrap())),
+                false => None,
+            },
+            master_key_provider: match config.encryption.enabled {
+                true => Some(Self::create_master_key_provider(&config.encryption).unwrap()),
                 false => None,
             },
             config,
             streams: HashMap::new(),
             streams_ids: HashMap::new(),
+            streams_aliases: HashMap::new(),
+            deleted_streams: HashMap::new(),
+            producers: HashMap::new(),
+            transactions: RwLock::new(HashMap::new()),
+            // Seeded with the current timestamp, so that the IDs are not reused after the server restart.
+            next_transaction_id: AtomicU64::new(TimeStamp::now().to_micros()),
             storage: Arc::new(storage),
             client_manager: Arc::new(RwLock::new(ClientManager::default())),
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
             db,
+            log_level_handle: None,
+            config_reloader: None,
             personal_access_token: pat_config,
         }
     }
 
+    fn create_keyring(config: &EncryptionConfig) -> Result<Aes256GcmKeyring, Error> {
+        let decryption_keys = config
+            .decryption_keys
+            .iter()
+            .map(|key| (key.id, key.key.as_str()))
+            .collect::<Vec<_>>();
+        Aes256GcmKeyring::from_base64_keys(config.key_id, &config.key, &decryption_keys)
+    }
+
+    fn create_master_key_provider(
+        config: &EncryptionConfig,
+    ) -> Result<Box<dyn MasterKeyProvider>, Error> {
+        let master_key_provider = match config.master_key_path.is_empty() {
+            true => LocalMasterKeyProvider::new(Box::new(Self::create_keyring(config)?)),
+            false => LocalMasterKeyProvider::from_file(&config.master_key_path)?,
+        };
+        Ok(Box::new(master_key_provider))
+    }
+
     pub async fn init(&mut self) -> Result<(), Error> {
         let system_path = self.config.get_system_path();
 
@@ -153,14 +237,39 @@ impl System {
         let now = Instant::now();
         self.load_version().await?;
         self.load_users().await?;
+        self.load_producers().await?;
         self.load_streams().await?;
+        self.recover_transactions().await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
 
-    pub async fn shutdown(&mut self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
+    /// Flushes the unsaved messages of all the partitions using the provided storage, which should enforce fsync.
+    pub async fn shutdown(
+        &mut self,
+        storage: Arc<dyn SegmentStorage>,
+    ) -> Result<FlushedMessages, Error> {
+        let mut flushed_messages = FlushedMessages::default();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    let unsaved_messages_count = partition
+                        .get_segments()
+                        .iter()
+                        .filter_map(|segment| segment.unsaved_messages.as_ref())
+                        .map(|messages| messages.len() as u64)
+                        .sum::<u64>();
+                    if unsaved_messages_count > 0 {
+                        flushed_messages.partitions_count += 1;
+                        flushed_messages.messages_count += unsaved_messages_count;
+                    }
+                }
+            }
+        }
+
         self.persist_messages(storage.clone()).await?;
-        Ok(())
+        Ok(flushed_messages)
     }
 
     pub async fn persist_messages(&self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
@@ -193,7 +302,10 @@ impl System {
                     tokio::task::spawn(async move {
                         let memory_tracker = CacheMemoryTracker::get_instance().unwrap();
                         let mut partition_guard = partition.write().await;
-                        let cache = &mut partition_guard.cache.as_mut().unwrap();
+                        // The cache might be disabled for the topic by its config overrides.
+                        let Some(cache) = partition_guard.cache.as_mut() else {
+                            return;
+                        };
                         let size_to_remove = (cache.current_size() as f64
                             / memory_tracker.usage_bytes() as f64
                             * size_to_clean as f64)
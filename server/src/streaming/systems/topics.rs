@@ -1,8 +1,11 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::repartition::TopicLayout;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::header::HeaderKey;
+use std::collections::HashMap;
 
 impl System {
     pub fn find_topic(
@@ -31,6 +34,7 @@ impl System {
         Ok(stream.get_topics())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
         &mut self,
         session: &Session,
@@ -39,6 +43,7 @@ impl System {
         name: &str,
         partitions_count: u32,
         message_expiry: Option<u32>,
+        config: HashMap<String, String>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -47,8 +52,9 @@ impl System {
                 .create_topic(session.user_id, stream.stream_id)?;
         }
 
+        let message_expiry = message_expiry.or_else(|| self.get_default_message_expiry());
         self.get_stream_mut(stream_id)?
-            .create_topic(topic_id, name, partitions_count, message_expiry)
+            .create_topic(topic_id, name, partitions_count, message_expiry, config)
             .await?;
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
@@ -63,6 +69,7 @@ impl System {
         topic_id: &Identifier,
         name: &str,
         message_expiry: Option<u32>,
+        config: Option<HashMap<String, String>>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -73,7 +80,7 @@ impl System {
         }
 
         self.get_stream_mut(stream_id)?
-            .update_topic(topic_id, name, message_expiry)
+            .update_topic(topic_id, name, message_expiry, config)
             .await?;
         Ok(())
     }
@@ -94,6 +101,24 @@ impl System {
             stream_id_value = stream.stream_id;
         }
 
+        if self.config.soft_delete.enabled {
+            let stream = self.get_stream_mut(stream_id)?;
+            let topic = stream.soft_delete_topic(topic_id).await?;
+            let topic_id_value = topic.topic_id;
+            let partitions_count = topic.get_partitions_count();
+            let messages_count = topic.get_messages_count().await;
+            let segments_count = topic.get_segments_count().await;
+            self.metrics.decrement_topics(1);
+            self.metrics.decrement_partitions(partitions_count);
+            self.metrics.decrement_messages(messages_count);
+            self.metrics.decrement_segments(segments_count);
+            let client_manager = self.client_manager.read().await;
+            client_manager
+                .delete_consumer_groups_for_topic(stream_id_value, topic_id_value)
+                .await;
+            return Ok(());
+        }
+
         let topic = self
             .get_stream_mut(stream_id)?
             .delete_topic(topic_id)
@@ -112,6 +137,34 @@ impl System {
         Ok(())
     }
 
+    pub async fn restore_topic(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        {
+            let stream = self.get_stream(stream_id)?;
+            let topic = stream.get_deleted_topic(topic_id)?;
+            self.permissioner
+                .restore_topic(session.user_id, stream.stream_id, topic.topic_id)?;
+        }
+
+        let topic = self
+            .get_stream_mut(stream_id)?
+            .restore_topic(topic_id)
+            .await?;
+        let partitions_count = topic.get_partitions_count();
+        let messages_count = topic.get_messages_count().await;
+        let segments_count = topic.get_segments_count().await;
+        self.metrics.increment_topics(1);
+        self.metrics.increment_partitions(partitions_count);
+        self.metrics.increment_messages(messages_count);
+        self.metrics.increment_segments(segments_count);
+        Ok(())
+    }
+
     pub async fn purge_topic(
         &self,
         session: &Session,
@@ -124,4 +177,60 @@ impl System {
             .purge_topic(session.user_id, stream.stream_id, topic.topic_id)?;
         topic.purge().await
     }
+
+    /// Prepares the new layout of the topic partitions, which requires only the shared access to the system,
+    /// so the topic stays readable while its messages are copied.
+    pub async fn prepare_topic_repartition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+        key_header: HeaderKey,
+    ) -> Result<TopicLayout, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .repartition_topic(session.user_id, stream.stream_id, topic.topic_id)?;
+        topic.prepare_layout(partitions_count, key_header).await
+    }
+
+    /// Switches the topic over to its new layout, after copying the messages appended since it was prepared.
+    pub async fn complete_topic_repartition(
+        &mut self,
+        session: &Session,
+        layout: TopicLayout,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream_id = Identifier::numeric(layout.stream_id)?;
+        let topic_id = Identifier::numeric(layout.topic_id)?;
+        let topic = match self.get_stream_mut(&stream_id) {
+            Ok(stream) => stream.get_topic_mut(&topic_id),
+            Err(error) => Err(error),
+        };
+        let topic = match topic {
+            Ok(topic) => topic,
+            Err(error) => {
+                layout.discard().await;
+                return Err(error);
+            }
+        };
+
+        let partitions = topic.complete_layout(layout).await?;
+        topic.reassign_consumer_groups().await;
+        self.metrics
+            .decrement_partitions(partitions.old_partitions_count);
+        self.metrics
+            .increment_partitions(partitions.new_partitions_count);
+        self.metrics
+            .decrement_segments(partitions.old_segments_count);
+        self.metrics
+            .increment_segments(partitions.new_segments_count);
+        self.metrics
+            .decrement_messages(partitions.old_messages_count);
+        self.metrics
+            .increment_messages(partitions.new_messages_count);
+        Ok(())
+    }
 }
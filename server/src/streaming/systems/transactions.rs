@@ -0,0 +1,352 @@
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::transaction::{Transaction, TransactionConsumerOffset};
+use iggy::consumer::ConsumerKind;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tracing::{error, info, warn};
+
+impl System {
+    pub async fn begin_transaction(&self, session: &Session) -> Result<u64, Error> {
+        self.ensure_authenticated(session)?;
+        let transaction_id = self.next_transaction_id.fetch_add(1, Ordering::SeqCst);
+        let transaction = Transaction::new(
+            transaction_id,
+            session.user_id,
+            TimeStamp::now().to_micros(),
+        );
+        self.transactions
+            .write()
+            .await
+            .insert(transaction_id, transaction);
+        info!(
+            "Began transaction with ID: {transaction_id} for user with ID: {}.",
+            session.user_id
+        );
+        Ok(transaction_id)
+    }
+
+    /// Makes the messages and the consumer offsets stored within the transaction visible.
+    /// The transaction is saved before being applied to the partitions, so that it can be completed after the server restart.
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let mut transactions = self.transactions.write().await;
+        Self::get_session_transaction(&mut transactions, session, transaction_id)?;
+        let transaction = transactions.remove(&transaction_id).unwrap();
+        self.storage.transaction.save(&transaction).await?;
+        self.apply_transaction(&transaction).await?;
+        self.storage.transaction.delete(&transaction).await?;
+        info!("Committed transaction with ID: {transaction_id}.");
+        Ok(())
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let mut transactions = self.transactions.write().await;
+        Self::get_session_transaction(&mut transactions, session, transaction_id)?;
+        let transaction = transactions.remove(&transaction_id).unwrap();
+        self.discard_transaction(&transaction).await?;
+        info!("Aborted transaction with ID: {transaction_id}.");
+        Ok(())
+    }
+
+    /// Aborts the transactions, which have not been completed within the configured timeout.
+    pub async fn abort_expired_transactions(&self, timeout_secs: u32) -> Result<u32, Error> {
+        let now = TimeStamp::now().to_micros();
+        let mut transactions = self.transactions.write().await;
+        let expired_transactions = transactions
+            .values()
+            .filter(|transaction| transaction.is_expired(now, timeout_secs))
+            .map(|transaction| transaction.transaction_id)
+            .collect::<Vec<_>>();
+        let mut aborted_transactions = 0;
+        for transaction_id in expired_transactions {
+            let transaction = transactions.remove(&transaction_id).unwrap();
+            warn!("Transaction with ID: {transaction_id} has expired and will be aborted.");
+            self.discard_transaction(&transaction).await?;
+            aborted_transactions += 1;
+        }
+
+        Ok(aborted_transactions)
+    }
+
+    pub(crate) fn get_session_transaction<'a>(
+        transactions: &'a mut HashMap<u64, Transaction>,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<&'a mut Transaction, Error> {
+        let transaction = transactions.get_mut(&transaction_id);
+        if transaction.is_none() {
+            error!("Transaction with ID: {transaction_id} was not found.");
+            return Err(Error::TransactionNotFound(transaction_id));
+        }
+
+        let transaction = transaction.unwrap();
+        if transaction.user_id != session.user_id {
+            error!(
+                "Transaction with ID: {transaction_id} was begun by another user than: {}.",
+                session.user_id
+            );
+            return Err(Error::Unauthorized);
+        }
+
+        Ok(transaction)
+    }
+
+    /// Completes the transactions committed before the server shutdown and aborts all the other ones.
+    pub(crate) async fn recover_transactions(&mut self) -> Result<(), Error> {
+        info!("Recovering transactions...");
+        let transactions = self.storage.transaction.load_all().await?;
+        let committed_transactions = transactions.len();
+        for transaction in transactions {
+            self.apply_transaction(&transaction).await?;
+            self.storage.transaction.delete(&transaction).await?;
+        }
+
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    partition.write().await.abort_open_transactions().await?;
+                }
+            }
+        }
+
+        info!("Recovered {committed_transactions} committed transaction(s).");
+        Ok(())
+    }
+
+    async fn apply_transaction(&self, transaction: &Transaction) -> Result<(), Error> {
+        for (stream_id, topic_id) in &transaction.topics {
+            let Some(topic) = self.find_transaction_topic(transaction, *stream_id, *topic_id)
+            else {
+                continue;
+            };
+            for partition in topic.get_partitions() {
+                partition
+                    .write()
+                    .await
+                    .commit_transaction(transaction.transaction_id)
+                    .await?;
+            }
+        }
+
+        for consumer_offset in &transaction.consumer_offsets {
+            let Some(topic) = self.find_transaction_topic(
+                transaction,
+                consumer_offset.stream_id,
+                consumer_offset.topic_id,
+            ) else {
+                continue;
+            };
+            let Some(partition) = topic.partitions.get(&consumer_offset.partition_id) else {
+                continue;
+            };
+            let consumer = match consumer_offset.kind {
+                ConsumerKind::Consumer => PollingConsumer::Consumer(
+                    consumer_offset.consumer_id,
+                    consumer_offset.partition_id,
+                ),
+                ConsumerKind::ConsumerGroup => PollingConsumer::ConsumerGroup(
+                    consumer_offset.consumer_id,
+                    consumer_offset.member_id,
+                ),
+            };
+            partition
+                .read()
+                .await
+                .store_consumer_offset(consumer, consumer_offset.offset)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn discard_transaction(&self, transaction: &Transaction) -> Result<(), Error> {
+        for (stream_id, topic_id) in &transaction.topics {
+            let Some(topic) = self.find_transaction_topic(transaction, *stream_id, *topic_id)
+            else {
+                continue;
+            };
+            for partition in topic.get_partitions() {
+                partition
+                    .write()
+                    .await
+                    .abort_transaction(transaction.transaction_id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_transaction_topic(
+        &self,
+        transaction: &Transaction,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Option<&Topic> {
+        let topic = self
+            .get_stream(&Identifier::numeric(stream_id).ok()?)
+            .and_then(|stream| stream.get_topic(&Identifier::numeric(topic_id)?));
+        if topic.is_err() {
+            warn!(
+                "Topic with ID: {topic_id} for stream with ID: {stream_id} used in transaction with ID: {} was not found.",
+                transaction.transaction_id
+            );
+        }
+
+        topic.ok()
+    }
+
+    pub(crate) fn stage_transaction_consumer_offset(
+        transaction: &mut Transaction,
+        consumer: PollingConsumer,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        offset: u64,
+    ) {
+        let (kind, consumer_id, member_id) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (ConsumerKind::Consumer, consumer_id, 0),
+            PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
+                (ConsumerKind::ConsumerGroup, consumer_group_id, member_id)
+            }
+        };
+        transaction.stage_consumer_offset(TransactionConsumerOffset {
+            kind,
+            consumer_id,
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+            member_id,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn began_transactions_should_have_unique_ids() {
+        let system = create_system();
+        let session = create_session(1);
+
+        let first = system.begin_transaction(&session).await.unwrap();
+        let second = system.begin_transaction(&session).await.unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(system.transactions.read().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn committed_transaction_should_be_removed() {
+        let system = create_system();
+        let session = create_session(1);
+        let transaction_id = system.begin_transaction(&session).await.unwrap();
+
+        system
+            .commit_transaction(&session, transaction_id)
+            .await
+            .unwrap();
+
+        assert!(system.transactions.read().await.is_empty());
+        assert!(matches!(
+            system.commit_transaction(&session, transaction_id).await,
+            Err(Error::TransactionNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn transaction_of_another_user_should_not_be_completed() {
+        let system = create_system();
+        let transaction_id = system.begin_transaction(&create_session(1)).await.unwrap();
+
+        let result = system
+            .abort_transaction(&create_session(2), transaction_id)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unauthorized)));
+        assert_eq!(system.transactions.read().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn expired_transactions_should_be_aborted() {
+        let system = create_system();
+        let session = create_session(1);
+        let transaction_id = system.begin_transaction(&session).await.unwrap();
+        system
+            .transactions
+            .write()
+            .await
+            .get_mut(&transaction_id)
+            .unwrap()
+            .created_at = 0;
+        system.begin_transaction(&session).await.unwrap();
+
+        let aborted_transactions = system.abort_expired_transactions(60).await.unwrap();
+
+        assert_eq!(aborted_transactions, 1);
+        assert!(!system
+            .transactions
+            .read()
+            .await
+            .contains_key(&transaction_id));
+    }
+
+    #[test]
+    fn staged_consumer_group_offset_should_keep_member_and_partition() {
+        let mut transaction = Transaction::new(1, 1, 0);
+
+        System::stage_transaction_consumer_offset(
+            &mut transaction,
+            PollingConsumer::ConsumerGroup(1, 7),
+            1,
+            2,
+            3,
+            10,
+        );
+
+        let consumer_offset = transaction.consumer_offsets[0];
+        assert_eq!(consumer_offset.kind, ConsumerKind::ConsumerGroup);
+        assert_eq!(consumer_offset.consumer_id, 1);
+        assert_eq!(consumer_offset.member_id, 7);
+        assert_eq!(consumer_offset.partition_id, 3);
+    }
+
+    fn create_session(user_id: u32) -> Session {
+        Session::new(
+            1,
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        )
+    }
+
+    fn create_system() -> System {
+        System::create(
+            Arc::new(SystemConfig::default()),
+            get_test_system_storage(),
+            None,
+            PersonalAccessTokenConfig::default(),
+        )
+    }
+}
@@ -30,7 +30,7 @@ impl Topic {
         })
     }
 
-    async fn resolve_partition(
+    pub(crate) async fn resolve_partition(
         &self,
         consumer: PollingConsumer,
     ) -> Result<&RwLock<Partition>, Error> {
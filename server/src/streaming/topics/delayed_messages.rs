@@ -0,0 +1,264 @@
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use iggy::messages::send_messages;
+use iggy::messages::send_messages::{Acknowledgement, Partitioning, ProducerSequence};
+use iggy::models::messages::Message;
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+use tracing::{trace, warn};
+
+/// The message staged in the topic until its delivery time, when it's appended to the partition
+/// resolved at the time of sending, and becomes visible to the consumers.
+#[derive(Debug)]
+pub struct DelayedMessage {
+    pub deliver_at: u64,
+    pub sequence: u64,
+    pub partition_id: u32,
+    pub message: send_messages::Message,
+}
+
+impl Topic {
+    pub async fn get_delayed_messages_count(&self) -> usize {
+        self.delayed_messages.read().await.len()
+    }
+
+    /// Stages the messages, which will be appended to the partition once their delivery time has come.
+    /// The batch of the idempotent producer is checked against the producer state of the partition before being staged,
+    /// and `false` is returned if it's the retry of the already appended batch, which is ignored.
+    pub async fn delay_messages(
+        &self,
+        partitioning: &Partitioning,
+        messages: Vec<send_messages::Message>,
+        producer: Option<&ProducerSequence>,
+    ) -> Result<bool, Error> {
+        if !self.has_partitions() {
+            return Err(Error::NoPartitions(self.topic_id, self.stream_id));
+        }
+
+        if messages.is_empty() {
+            return Ok(true);
+        }
+
+        let partition_id = self.calculate_partition_id(partitioning).await?;
+        let Some(partition) = self.partitions.get(&partition_id) else {
+            return Err(Error::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        };
+
+        if let Some(producer) = producer {
+            if !partition
+                .read()
+                .await
+                .should_append_producer_batch(producer)?
+            {
+                return Ok(false);
+            }
+        }
+
+        let mut delayed_messages = self.delayed_messages.write().await;
+        for message in messages {
+            let delayed_message = DelayedMessage {
+                deliver_at: message.deliver_at.unwrap_or_default(),
+                sequence: self
+                    .next_delayed_message_sequence
+                    .fetch_add(1, Ordering::SeqCst),
+                partition_id,
+                message,
+            };
+            self.storage
+                .topic
+                .save_delayed_message(self, &delayed_message)
+                .await?;
+            delayed_messages.insert(
+                (delayed_message.deliver_at, delayed_message.sequence),
+                delayed_message,
+            );
+        }
+        drop(delayed_messages);
+
+        // The partition isn't locked while staging the messages, as the due messages are appended to it under the lock of the staged ones.
+        if let Some(producer) = producer {
+            partition.write().await.update_producer_state(producer);
+        }
+
+        Ok(true)
+    }
+
+    /// Appends the staged messages, which are due, to their partitions and returns the number of delivered messages.
+    /// The message is removed from the storage only once it's appended, thus it might be delivered more than once after the server failure.
+    pub async fn deliver_due_messages(&self, now: u64) -> Result<u32, Error> {
+        let mut delayed_messages = self.delayed_messages.write().await;
+        let mut due_messages = BTreeMap::<u32, Vec<(u64, u64)>>::new();
+        for (key, delayed_message) in delayed_messages.range(..=(now, u64::MAX)) {
+            due_messages
+                .entry(delayed_message.partition_id)
+                .or_default()
+                .push(*key);
+        }
+
+        let mut delivered_messages = 0;
+        for (partition_id, keys) in due_messages {
+            let messages = keys
+                .iter()
+                .map(|key| Message::from_message(&delayed_messages[key].message))
+                .collect();
+            match self
+                .append_messages_to_partition(
+                    partition_id,
+                    messages,
+                    Acknowledgement::default(),
+                    None,
+                    None,
+                )
+                .await
+            {
+                Ok(()) => delivered_messages += keys.len() as u32,
+                Err(Error::PartitionNotFound(_, _, _)) => warn!(
+                    "Partition with ID: {} for topic with ID: {} and stream with ID: {} was not found, {} delayed message(s) will be dropped.",
+                    partition_id, self.topic_id, self.stream_id, keys.len()
+                ),
+                Err(error) => return Err(error),
+            }
+
+            for key in keys {
+                if let Some(delayed_message) = delayed_messages.remove(&key) {
+                    self.storage
+                        .topic
+                        .delete_delayed_message(self, &delayed_message)
+                        .await?;
+                }
+            }
+        }
+
+        if delivered_messages > 0 {
+            trace!(
+                "Delivered {} delayed message(s) for topic with ID: {} and stream with ID: {}.",
+                delivered_messages,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        Ok(delivered_messages)
+    }
+
+    /// Removes all the staged messages, which will never be delivered.
+    pub async fn delete_delayed_messages(&self) -> Result<(), Error> {
+        let mut delayed_messages = self.delayed_messages.write().await;
+        for delayed_message in delayed_messages.values() {
+            self.storage
+                .topic
+                .delete_delayed_message(self, delayed_message)
+                .await?;
+        }
+        delayed_messages.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn delayed_messages_should_be_delivered_only_when_due() {
+        let topic = create_topic();
+        let partitioning = Partitioning::partition_id(1);
+        topic
+            .delay_messages(
+                &partitioning,
+                vec![create_message(1, 200), create_message(2, 100)],
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(topic.deliver_due_messages(50).await.unwrap(), 0);
+        assert_eq!(topic.get_messages_count().await, 0);
+
+        assert_eq!(topic.deliver_due_messages(150).await.unwrap(), 1);
+        assert_eq!(topic.get_messages_count().await, 1);
+        assert_eq!(topic.get_delayed_messages_count().await, 1);
+
+        assert_eq!(topic.deliver_due_messages(200).await.unwrap(), 1);
+        assert_eq!(topic.get_messages_count().await, 2);
+        assert_eq!(topic.get_delayed_messages_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn delayed_messages_for_missing_partition_should_be_rejected() {
+        let topic = create_topic();
+        let partitioning = Partitioning::partition_id(10);
+
+        let result = topic
+            .delay_messages(&partitioning, vec![create_message(1, 100)], None)
+            .await;
+
+        assert!(matches!(result, Err(Error::PartitionNotFound(10, _, _))));
+        assert_eq!(topic.get_delayed_messages_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn deleted_delayed_messages_should_not_be_delivered() {
+        let topic = create_topic();
+        let partitioning = Partitioning::partition_id(1);
+        topic
+            .delay_messages(&partitioning, vec![create_message(1, 100)], None)
+            .await
+            .unwrap();
+
+        topic.delete_delayed_messages().await.unwrap();
+
+        assert_eq!(topic.deliver_due_messages(100).await.unwrap(), 0);
+        assert_eq!(topic.get_messages_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn retried_batch_of_idempotent_producer_should_not_be_delayed_again() {
+        let topic = create_topic();
+        let partitioning = Partitioning::partition_id(1);
+        let producer = ProducerSequence {
+            producer_id: 1,
+            epoch: 1,
+            sequence: 1,
+        };
+
+        for _ in 0..2 {
+            topic
+                .delay_messages(&partitioning, vec![create_message(1, 100)], Some(&producer))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(topic.get_delayed_messages_count().await, 1);
+        let result = topic
+            .delay_messages(
+                &partitioning,
+                vec![create_message(2, 100)],
+                Some(&ProducerSequence {
+                    sequence: 3,
+                    ..producer
+                }),
+            )
+            .await;
+        assert!(matches!(result, Err(Error::ProducerSequenceGap(1, 2, 3))));
+    }
+
+    fn create_message(id: u128, deliver_at: u64) -> send_messages::Message {
+        send_messages::Message::new(Some(id), Bytes::from("message"), None)
+            .with_deliver_at(deliver_at)
+    }
+
+    fn create_topic() -> Topic {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        Topic::create(1, 2, "test", 3, config, storage, None).unwrap()
+    }
+}
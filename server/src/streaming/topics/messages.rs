@@ -4,8 +4,10 @@ use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use iggy::error::Error;
-use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::poll_messages::{IsolationLevel, PollingKind, PollingStrategy};
+use iggy::messages::send_messages::{
+    Acknowledgement, Partitioning, PartitioningKind, ProducerSequence,
+};
 use iggy::models::messages::Message;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
@@ -28,6 +30,7 @@ impl Topic {
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        isolation: IsolationLevel,
     ) -> Result<PolledMessages, Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
@@ -53,10 +56,17 @@ impl Topic {
             PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
 
+        let messages = partition
+            .get_visible_messages(messages, count, isolation)
+            .await?;
+        let current_offset = match isolation {
+            IsolationLevel::ReadCommitted => partition.get_last_stable_offset(),
+            IsolationLevel::ReadUncommitted => partition.current_offset,
+        };
         Ok(PolledMessages {
             messages,
             partition_id,
-            current_offset: partition.current_offset,
+            current_offset,
         })
     }
 
@@ -64,6 +74,9 @@ impl Topic {
         &self,
         partitioning: &Partitioning,
         messages: Vec<Message>,
+        acknowledgement: Acknowledgement,
+        producer: Option<ProducerSequence>,
+        transaction_id: Option<u64>,
     ) -> Result<(), Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
@@ -73,24 +86,45 @@ impl Topic {
             return Ok(());
         }
 
+        let partition_id = self.calculate_partition_id(partitioning).await?;
+        self.append_messages_to_partition(
+            partition_id,
+            messages,
+            acknowledgement,
+            producer,
+            transaction_id,
+        )
+        .await
+    }
+
+    pub(crate) async fn calculate_partition_id(
+        &self,
+        partitioning: &Partitioning,
+    ) -> Result<u32, Error> {
         let partition_id = match partitioning.kind {
-            PartitioningKind::Balanced => self.get_next_partition_id(),
+            PartitioningKind::Balanced => self.get_balanced_partition_id().await,
             PartitioningKind::PartitionId => {
                 u32::from_le_bytes(partitioning.value[..partitioning.length as usize].try_into()?)
             }
             PartitioningKind::MessagesKey => {
                 self.calculate_partition_id_by_messages_key_hash(&partitioning.value)
             }
+            PartitioningKind::StickyBatch => self.get_sticky_partition_id(),
+            PartitioningKind::LeastLoaded => self.get_least_loaded_partition_id().await,
+            PartitioningKind::KeyRange => {
+                self.get_partition_id_by_key_range(&partitioning.value)?
+            }
         };
-
-        self.append_messages_to_partition(partition_id, messages)
-            .await
+        Ok(partition_id)
     }
 
-    async fn append_messages_to_partition(
+    pub(crate) async fn append_messages_to_partition(
         &self,
         partition_id: u32,
         messages: Vec<Message>,
+        acknowledgement: Acknowledgement,
+        producer: Option<ProducerSequence>,
+        transaction_id: Option<u64>,
     ) -> Result<(), Error> {
         let partition = self.partitions.get(&partition_id);
         if partition.is_none() {
@@ -103,11 +137,16 @@ impl Topic {
 
         let partition = partition.unwrap();
         let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
+        partition
+            .append_messages(messages, producer.as_ref(), transaction_id)
+            .await?;
+        if acknowledgement == Acknowledgement::Persisted {
+            partition.persist_unsaved_messages().await?;
+        }
         Ok(())
     }
 
-    fn get_next_partition_id(&self) -> u32 {
+    pub(crate) fn get_next_partition_id(&self) -> u32 {
         let mut partition_id = self.current_partition_id.fetch_add(1, Ordering::SeqCst);
         let partitions_count = self.partitions.len() as u32;
         if partition_id > partitions_count {
@@ -120,8 +159,14 @@ impl Topic {
     }
 
     fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
+        Self::get_partition_id_by_messages_key_hash(messages_key, self.get_partitions_count())
+    }
+
+    pub(crate) fn get_partition_id_by_messages_key_hash(
+        messages_key: &[u8],
+        partitions_count: u32,
+    ) -> u32 {
         let messages_key_hash = hash::calculate_32(messages_key);
-        let partitions_count = self.get_partitions_count();
         let mut partition_id = messages_key_hash % partitions_count;
         if partition_id == 0 {
             partition_id = partitions_count;
@@ -136,7 +181,7 @@ impl Topic {
     }
 
     pub(crate) async fn load_messages_from_disk_to_cache(&mut self) -> Result<(), Error> {
-        if !self.config.cache.enabled {
+        if !self.effective_config.cache.enabled {
             return Ok(());
         }
         let path = self.config.get_system_path();
@@ -283,7 +328,13 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(
+                    &partitioning,
+                    messages,
+                    Acknowledgement::default(),
+                    None,
+                    None,
+                )
                 .await
                 .unwrap();
         }
@@ -319,7 +370,13 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(
+                    &partitioning,
+                    messages,
+                    Acknowledgement::default(),
+                    None,
+                    None,
+                )
                 .await
                 .unwrap();
         }
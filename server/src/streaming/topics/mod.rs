@@ -1,9 +1,12 @@
 pub mod consumer_group;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod delayed_messages;
 pub mod messages;
+pub mod partitioning;
 pub mod partitions;
 pub mod persistence;
+pub mod repartition;
 pub mod segments;
 pub mod storage;
 pub mod topic;
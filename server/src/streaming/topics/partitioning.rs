@@ -0,0 +1,283 @@
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use iggy::topics::config;
+use iggy::topics::partitioning_strategy::PartitioningStrategy;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tracing::trace;
+
+impl Topic {
+    /// Picks the partition for the balanced partitioning using the strategy configured for the topic.
+    pub(crate) async fn get_balanced_partition_id(&self) -> u32 {
+        match self.effective_config.topic.partitioning {
+            PartitioningStrategy::RoundRobin => self.get_next_partition_id(),
+            PartitioningStrategy::StickyBatch => self.get_sticky_partition_id(),
+            PartitioningStrategy::LeastLoaded => self.get_least_loaded_partition_id().await,
+        }
+    }
+
+    /// Returns the same partition until the sticky batch interval elapses, and then rotates to the next one using the round-robin algorithm.
+    pub(crate) fn get_sticky_partition_id(&self) -> u32 {
+        let now = TimeStamp::now().to_micros();
+        let partition_id = self.sticky_partition_id.load(Ordering::SeqCst);
+        if now < self.sticky_partition_expiry.load(Ordering::SeqCst)
+            && self.partitions.contains_key(&partition_id)
+        {
+            return partition_id;
+        }
+
+        let partition_id = self.get_next_partition_id();
+        let interval = self
+            .effective_config
+            .topic
+            .sticky_batch_interval
+            .get_duration()
+            .as_micros() as u64;
+        self.sticky_partition_id
+            .store(partition_id, Ordering::SeqCst);
+        self.sticky_partition_expiry
+            .store(now + interval, Ordering::SeqCst);
+        trace!("Sticky partition ID: {}", partition_id);
+        partition_id
+    }
+
+    /// Returns the partition with the least bytes of the messages which aren't saved on disk yet.
+    /// The partitions are checked starting from the next one using the round-robin algorithm, so the equally loaded partitions take turns.
+    pub(crate) async fn get_least_loaded_partition_id(&self) -> u32 {
+        let partitions_count = self.get_partitions_count();
+        let start_partition_id = self.get_next_partition_id();
+        let mut least_loaded_partition_id = start_partition_id;
+        let mut least_unsaved_size_bytes = u64::MAX;
+        for index in 0..partitions_count {
+            let partition_id = (start_partition_id - 1 + index) % partitions_count + 1;
+            let Some(partition) = self.partitions.get(&partition_id) else {
+                continue;
+            };
+
+            // The partition being appended to right now is skipped, rather than waited for.
+            let Ok(partition) = partition.try_read() else {
+                continue;
+            };
+
+            if partition.unsaved_messages_size_bytes < least_unsaved_size_bytes {
+                least_unsaved_size_bytes = partition.unsaved_messages_size_bytes;
+                least_loaded_partition_id = partition_id;
+            }
+        }
+
+        trace!(
+            "Least loaded partition ID: {}, unsaved bytes: {}",
+            least_loaded_partition_id,
+            least_unsaved_size_bytes
+        );
+        least_loaded_partition_id
+    }
+
+    /// Returns the partition for the key range the messages key falls into, the keys lower than the first boundary
+    /// are mapped to the first partition, and the keys beyond the last partition are mapped to the last one.
+    pub(crate) fn get_partition_id_by_key_range(&self, messages_key: &[u8]) -> Result<u32, Error> {
+        if self.key_ranges.is_empty() {
+            return Err(Error::TopicKeyRangesNotSet(self.topic_id, self.stream_id));
+        }
+
+        let range =
+            self.key_ranges
+                .partition_point(|boundary| boundary.as_slice() <= messages_key) as u32;
+        let partition_id = (range + 1).min(self.get_partitions_count());
+        trace!(
+            "Calculated partition ID: {} for messages key: {:?} by key range",
+            partition_id,
+            messages_key
+        );
+        Ok(partition_id)
+    }
+
+    /// Checks that the key ranges in the given config overrides match the current partitions of the topic.
+    pub(crate) fn validate_key_ranges(
+        &self,
+        overrides: &HashMap<String, String>,
+    ) -> Result<(), Error> {
+        match overrides.get(config::TOPIC_KEY_RANGES) {
+            Some(key_ranges) => self.validate_key_ranges_count(
+                config::parse_key_ranges(key_ranges)?.len(),
+                self.get_partitions_count(),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// The key ranges split the keys between the partitions, so there must be one boundary less than the partitions.
+    pub(crate) fn validate_key_ranges_count(
+        &self,
+        boundaries_count: usize,
+        partitions_count: u32,
+    ) -> Result<(), Error> {
+        if boundaries_count == 0 || boundaries_count as u32 + 1 == partitions_count {
+            return Ok(());
+        }
+
+        Err(Error::InvalidKeyRangesCount(
+            boundaries_count as u32,
+            partitions_count,
+            self.topic_id,
+            self.stream_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::messages::send_messages::Acknowledgement;
+    use iggy::models::messages::{Message, MessageState};
+    use std::sync::Arc;
+
+    #[test]
+    fn given_sticky_batch_strategy_the_same_partition_should_be_returned_until_interval_elapses() {
+        let mut topic = init_topic(3);
+        topic
+            .set_config_overrides(HashMap::from([
+                (
+                    config::TOPIC_PARTITIONING.to_string(),
+                    "sticky_batch".to_string(),
+                ),
+                (
+                    config::TOPIC_STICKY_BATCH_INTERVAL.to_string(),
+                    "1h".to_string(),
+                ),
+            ]))
+            .unwrap();
+
+        let partition_id = topic.get_sticky_partition_id();
+        for _ in 0..10 {
+            assert_eq!(topic.get_sticky_partition_id(), partition_id);
+        }
+
+        topic.sticky_partition_expiry.store(0, Ordering::SeqCst);
+        assert_ne!(topic.get_sticky_partition_id(), partition_id);
+    }
+
+    #[tokio::test]
+    async fn given_least_loaded_strategy_the_partition_with_least_unsaved_bytes_should_be_returned()
+    {
+        let topic = init_topic(3);
+        for (partition_id, messages_count) in [(1, 3), (2, 1), (3, 2)] {
+            let messages = (1..=messages_count)
+                .map(|id| {
+                    Message::empty(
+                        1,
+                        MessageState::Available,
+                        id as u128,
+                        Bytes::from("test"),
+                        1,
+                        None,
+                    )
+                })
+                .collect::<Vec<_>>();
+            topic
+                .append_messages_to_partition(
+                    partition_id,
+                    messages,
+                    Acknowledgement::default(),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        for _ in 0..3 {
+            assert_eq!(topic.get_least_loaded_partition_id().await, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn given_least_loaded_strategy_persisted_messages_should_not_be_counted_as_unsaved() {
+        let topic = init_topic(3);
+        let messages = vec![Message::empty(
+            1,
+            MessageState::Available,
+            1,
+            Bytes::from("test"),
+            1,
+            None,
+        )];
+        topic
+            .append_messages_to_partition(1, messages, Acknowledgement::default(), None, None)
+            .await
+            .unwrap();
+
+        topic
+            .persist_messages(topic.storage.segment.clone())
+            .await
+            .unwrap();
+
+        for partition in topic.get_partitions() {
+            assert_eq!(partition.read().await.unsaved_messages_size_bytes, 0);
+        }
+    }
+
+    #[test]
+    fn given_key_ranges_messages_key_should_be_mapped_to_the_partition_of_its_range() {
+        let mut topic = init_topic(3);
+        assert!(topic.get_partition_id_by_key_range(b"a").is_err());
+
+        topic
+            .set_config_overrides(HashMap::from([(
+                config::TOPIC_KEY_RANGES.to_string(),
+                "g;n".to_string(),
+            )]))
+            .unwrap();
+
+        assert_eq!(topic.get_partition_id_by_key_range(b"a").unwrap(), 1);
+        assert_eq!(topic.get_partition_id_by_key_range(b"f").unwrap(), 1);
+        assert_eq!(topic.get_partition_id_by_key_range(b"g").unwrap(), 2);
+        assert_eq!(topic.get_partition_id_by_key_range(b"m").unwrap(), 2);
+        assert_eq!(topic.get_partition_id_by_key_range(b"n").unwrap(), 3);
+        assert_eq!(topic.get_partition_id_by_key_range(b"z").unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn given_key_ranges_partitions_count_should_be_greater_by_one_than_boundaries_count() {
+        let mut topic = init_topic(3);
+        let overrides = |key_ranges: &str| {
+            HashMap::from([(config::TOPIC_KEY_RANGES.to_string(), key_ranges.to_string())])
+        };
+
+        for key_ranges in ["g", "g;n;t"] {
+            assert!(matches!(
+                topic.update_config_overrides(overrides(key_ranges)).await,
+                Err(Error::InvalidKeyRangesCount(..))
+            ));
+        }
+        assert!(topic.key_ranges.is_empty());
+
+        topic
+            .update_config_overrides(overrides("g;n"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            topic.add_persisted_partitions(1).await,
+            Err(Error::InvalidKeyRangesCount(..))
+        ));
+        assert!(matches!(
+            topic.delete_persisted_partitions(1).await,
+            Err(Error::InvalidKeyRangesCount(..))
+        ));
+        assert_eq!(topic.get_partitions_count(), 3);
+    }
+
+    fn init_topic(partitions_count: u32) -> Topic {
+        let storage = Arc::new(get_test_system_storage());
+        let stream_id = 1;
+        let id = 2;
+        let name = "test";
+        let config = Arc::new(SystemConfig::default());
+
+        Topic::create(stream_id, id, name, partitions_count, config, storage, None).unwrap()
+    }
+}
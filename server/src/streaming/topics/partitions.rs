@@ -4,7 +4,7 @@ use iggy::error::Error;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-const MAX_PARTITIONS_COUNT: u32 = 100_000;
+pub(crate) const MAX_PARTITIONS_COUNT: u32 = 100_000;
 
 impl Topic {
     pub fn has_partitions(&self) -> bool {
@@ -32,8 +32,8 @@ impl Topic {
                 self.topic_id,
                 partition_id,
                 true,
-                self.config.clone(),
-                self.storage.clone(),
+                self.effective_config.clone(),
+                self.effective_storage.clone(),
                 self.message_expiry,
             );
             self.partitions
@@ -45,6 +45,7 @@ impl Topic {
     }
 
     pub async fn add_persisted_partitions(&mut self, count: u32) -> Result<Vec<u32>, Error> {
+        self.validate_key_ranges_count(self.key_ranges.len(), self.get_partitions_count() + count)?;
         let partition_ids = self.add_partitions(count)?;
         for partition_id in &partition_ids {
             let partition = self.partitions.get(partition_id).unwrap();
@@ -67,6 +68,8 @@ impl Topic {
             count = current_partitions_count;
         }
 
+        self.validate_key_ranges_count(self.key_ranges.len(), current_partitions_count - count)?;
+
         let mut segments_count = 0;
         let mut messages_count = 0;
         for partition_id in current_partitions_count - count + 1..=current_partitions_count {
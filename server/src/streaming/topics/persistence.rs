@@ -8,6 +8,7 @@ use tokio::sync::RwLock;
 impl Topic {
     pub async fn load(&mut self) -> Result<(), Error> {
         let storage = self.storage.clone();
+        self.recover_partitions().await?;
         storage.topic.load(self).await?;
         let consumer_groups = storage.topic.load_consumer_groups(self).await?;
         for consumer_group in consumer_groups {
@@ -47,6 +48,9 @@ impl Topic {
             for segment in partition.get_segments_mut() {
                 segment.persist_messages(storage.clone()).await?;
             }
+            partition.unsaved_messages_count = 0;
+            partition.unsaved_messages_size_bytes = 0;
+            partition.persist_producer_states().await?;
         }
 
         Ok(())
@@ -57,6 +61,6 @@ impl Topic {
             let mut partition = partition.write().await;
             partition.purge().await?;
         }
-        Ok(())
+        self.delete_delayed_messages().await
     }
 }
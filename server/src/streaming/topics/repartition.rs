@@ -0,0 +1,594 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::topics::partitions::MAX_PARTITIONS_COUNT;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::file;
+use iggy::consumer::ConsumerKind;
+use iggy::error::Error;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+const STAGING_PATH_PREFIX: &str = "repartition_";
+const REPLACED_PATH_PREFIX: &str = "replaced_";
+const DELETED_PATH_PREFIX: &str = "deleted_";
+const MESSAGES_BATCH_SIZE: u32 = 1000;
+
+/// The new partitions of the topic, which are filled with its messages in the staging directory
+/// while the current partitions stay readable, and replace them once the repartitioning is completed.
+#[derive(Debug)]
+pub struct TopicLayout {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partitions_count: u32,
+    key_header: HeaderKey,
+    path: String,
+    sources: Vec<SourcePartition>,
+    partitions: Vec<Partition>,
+    delayed_partition_ids: Vec<((u64, u64), u32)>,
+}
+
+/// The current partition of the topic, along with the offset of its next message to be copied to the new layout.
+#[derive(Debug)]
+struct SourcePartition {
+    partition_id: u32,
+    partition: Arc<RwLock<Partition>>,
+    next_offset: Option<u64>,
+}
+
+#[derive(Debug)]
+struct Cursor {
+    source_index: usize,
+    next_offset: u64,
+    end_offset: u64,
+    messages: VecDeque<Arc<Message>>,
+}
+
+/// The number of the partitions, segments and messages in the topic before and after the repartitioning.
+#[derive(Debug, Default)]
+pub struct ReplacedPartitions {
+    pub old_partitions_count: u32,
+    pub old_segments_count: u32,
+    pub old_messages_count: u64,
+    pub new_partitions_count: u32,
+    pub new_segments_count: u32,
+    pub new_messages_count: u64,
+}
+
+impl TopicLayout {
+    /// Removes the staging directory along with the messages copied so far.
+    pub async fn discard(self) {
+        discard_directory(&self.path).await;
+    }
+
+    /// Returns the new partition of the message with the given headers, by the hash of its key read from the key header.
+    fn get_target_partition_id(
+        &self,
+        message_id: u128,
+        headers: Option<&HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<u32, Error> {
+        match headers.and_then(|headers| headers.get(&self.key_header)) {
+            Some(key) => Ok(Topic::get_partition_id_by_messages_key_hash(
+                &key.value,
+                self.partitions_count,
+            )),
+            None => Err(Error::MessageKeyHeaderNotFound(
+                message_id,
+                self.key_header.as_str().to_string(),
+                self.topic_id,
+                self.stream_id,
+            )),
+        }
+    }
+}
+
+impl Topic {
+    /// Creates the new partitions in the staging directory and copies the stable messages of the current partitions to them,
+    /// merged by their timestamps and routed by the hash of the key read from the given header.
+    /// It only needs the shared access to the topic, so the messages can still be polled and appended in the meantime.
+    /// All the messages must have the key header, otherwise the repartitioning fails, as their order couldn't be kept.
+    /// The topic with the key ranges can't be repartitioned, until they are reset.
+    pub async fn prepare_layout(
+        &self,
+        partitions_count: u32,
+        key_header: HeaderKey,
+    ) -> Result<TopicLayout, Error> {
+        if !self.has_partitions() {
+            return Err(Error::NoPartitions(self.topic_id, self.stream_id));
+        }
+
+        if partitions_count == 0 || partitions_count > MAX_PARTITIONS_COUNT {
+            return Err(Error::TooManyPartitions);
+        }
+
+        // The messages are routed by the hash of their key, so the topic would no longer follow its key ranges.
+        if !self.key_ranges.is_empty() {
+            return Err(Error::TopicHasKeyRanges(self.topic_id, self.stream_id));
+        }
+
+        let mut staging_config = (*self.effective_config).clone();
+        staging_config.partition.path =
+            format!("{STAGING_PATH_PREFIX}{}", TimeStamp::now().to_micros());
+        // The staged partitions are only appended to, so their messages don't need to be cached or deduplicated.
+        staging_config.cache.enabled = false;
+        staging_config.message_deduplication.enabled = false;
+        let staging_config = Arc::new(staging_config);
+        let path = staging_config.get_partitions_path(self.stream_id, self.topic_id);
+        if fs::create_dir_all(&path).await.is_err() {
+            return Err(Error::CannotCreatePartitionsDirectory(
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        let mut sources = self
+            .partitions
+            .iter()
+            .map(|(partition_id, partition)| SourcePartition {
+                partition_id: *partition_id,
+                partition: partition.clone(),
+                next_offset: None,
+            })
+            .collect::<Vec<_>>();
+        sources.sort_by_key(|source| source.partition_id);
+        let mut layout = TopicLayout {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partitions_count,
+            key_header,
+            path,
+            sources,
+            partitions: Vec::with_capacity(partitions_count as usize),
+            delayed_partition_ids: Vec::new(),
+        };
+
+        for partition_id in 1..=partitions_count {
+            let partition = Partition::create(
+                self.stream_id,
+                self.topic_id,
+                partition_id,
+                true,
+                staging_config.clone(),
+                self.effective_storage.clone(),
+                self.message_expiry,
+            );
+            layout.partitions.push(partition);
+        }
+
+        if let Err(error) = self.stage_layout(&mut layout).await {
+            layout.discard().await;
+            return Err(error);
+        }
+
+        Ok(layout)
+    }
+
+    /// Copies the messages appended since the layout was prepared and replaces the current partitions with the new ones.
+    /// The consumer offsets of the current partitions are deleted, as they don't apply to the new partitions,
+    /// and the delayed messages are routed to the new partitions by their key, just like the appended ones.
+    pub async fn complete_layout(
+        &mut self,
+        mut layout: TopicLayout,
+    ) -> Result<ReplacedPartitions, Error> {
+        if let Err(error) = self.finish_layout(&mut layout).await {
+            layout.discard().await;
+            return Err(error);
+        }
+
+        self.replace_partitions(layout).await
+    }
+
+    async fn stage_layout(&self, layout: &mut TopicLayout) -> Result<(), Error> {
+        for partition in &layout.partitions {
+            if fs::create_dir(&partition.path).await.is_err() {
+                return Err(Error::CannotCreatePartitionDirectory(
+                    partition.partition_id,
+                    self.stream_id,
+                    self.topic_id,
+                ));
+            }
+
+            for segment in partition.get_segments() {
+                segment.persist().await?;
+            }
+        }
+
+        let messages_count = Self::copy_messages_to_layout(layout).await?;
+        info!(
+            "Copied {} messages to the staged {} partitions for topic with ID: {} for stream with ID: {}.",
+            messages_count, layout.partitions_count, self.topic_id, self.stream_id
+        );
+        Ok(())
+    }
+
+    async fn finish_layout(&self, layout: &mut TopicLayout) -> Result<(), Error> {
+        if layout.sources.len() != self.partitions.len() {
+            return Err(Error::TopicPartitionsChanged(self.topic_id, self.stream_id));
+        }
+
+        for source in &layout.sources {
+            let Some(partition) = self.partitions.get(&source.partition_id) else {
+                return Err(Error::TopicPartitionsChanged(self.topic_id, self.stream_id));
+            };
+            if !Arc::ptr_eq(partition, &source.partition) {
+                return Err(Error::TopicPartitionsChanged(self.topic_id, self.stream_id));
+            }
+
+            let partition = partition.read().await;
+            if partition.get_first_unstable_offset().is_some() {
+                return Err(Error::TopicHasOpenTransactions(
+                    self.topic_id,
+                    self.stream_id,
+                ));
+            }
+
+            // The partition has been purged since the messages were copied.
+            if let Some(next_offset) = source.next_offset {
+                if partition.get_messages_count() == 0 || partition.current_offset + 1 < next_offset
+                {
+                    return Err(Error::TopicPartitionsChanged(self.topic_id, self.stream_id));
+                }
+            }
+        }
+
+        let messages_count = Self::copy_messages_to_layout(layout).await?;
+        for partition in layout.partitions.iter_mut() {
+            partition.persist_unsaved_messages().await?;
+        }
+
+        let mut delayed_partition_ids = Vec::new();
+        for (key, delayed_message) in self.delayed_messages.read().await.iter() {
+            let partition_id = layout.get_target_partition_id(
+                delayed_message.message.id,
+                delayed_message.message.headers.as_ref(),
+            )?;
+            delayed_partition_ids.push((*key, partition_id));
+        }
+        layout.delayed_partition_ids = delayed_partition_ids;
+
+        info!(
+            "Copied {} remaining messages to the staged {} partitions for topic with ID: {} for stream with ID: {}.",
+            messages_count, layout.partitions_count, self.topic_id, self.stream_id
+        );
+        Ok(())
+    }
+
+    /// Moves the current partitions directory aside and the staged one into its place, then loads the new partitions.
+    /// The current partitions are deleted only once the new ones are loaded, and until then, the replacement
+    /// is rolled back on failure, or when the topic is loaded after the server has stopped in the meantime.
+    async fn replace_partitions(
+        &mut self,
+        layout: TopicLayout,
+    ) -> Result<ReplacedPartitions, Error> {
+        let mut replaced_partitions = ReplacedPartitions {
+            old_partitions_count: self.get_partitions_count(),
+            new_partitions_count: layout.partitions_count,
+            ..Default::default()
+        };
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            replaced_partitions.old_segments_count += partition.get_segments_count();
+            replaced_partitions.old_messages_count += partition.get_messages_count();
+        }
+
+        let staging_path = layout.path;
+        let delayed_partition_ids = layout.delayed_partition_ids;
+        drop(layout.partitions);
+        let timestamp = TimeStamp::now().to_micros();
+        let replaced_path = format!("{}/{REPLACED_PATH_PREFIX}{timestamp}", self.path);
+        if fs::rename(&self.partitions_path, &replaced_path)
+            .await
+            .is_err()
+        {
+            discard_directory(&staging_path).await;
+            return Err(Error::CannotCreatePartitionsDirectory(
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        if fs::rename(&staging_path, &self.partitions_path)
+            .await
+            .is_err()
+            || file::sync_directory(&self.path).await.is_err()
+        {
+            self.rollback_partitions(&replaced_path, &staging_path)
+                .await;
+            return Err(Error::CannotCreatePartitionsDirectory(
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        let partitions = match self
+            .load_replacing_partitions(&mut replaced_partitions)
+            .await
+        {
+            Ok(partitions) => partitions,
+            Err(error) => {
+                self.rollback_partitions(&replaced_path, &staging_path)
+                    .await;
+                return Err(error);
+            }
+        };
+
+        let old_partitions = std::mem::replace(&mut self.partitions, partitions);
+        let deleted_path = format!("{}/{DELETED_PATH_PREFIX}{timestamp}", self.path);
+        let deleted_path = match fs::rename(&replaced_path, &deleted_path).await {
+            Ok(_) => deleted_path,
+            Err(_) => replaced_path,
+        };
+        for (partition_id, partition) in old_partitions {
+            if partition_id <= replaced_partitions.new_partitions_count {
+                continue;
+            }
+
+            // The partition, which has no counterpart in the new layout, is deleted along with its metadata.
+            let mut partition = partition.write().await;
+            partition.path = format!("{deleted_path}/{partition_id}");
+            if let Err(error) = partition.delete().await {
+                error!(
+                    "Cannot delete the replaced partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}",
+                    partition_id, self.topic_id, self.stream_id, error
+                );
+            }
+        }
+        discard_directory(&deleted_path).await;
+        self.reroute_delayed_messages(delayed_partition_ids).await?;
+
+        self.current_partition_id.store(1, Ordering::SeqCst);
+        info!(
+            "Replaced {} partitions with {} new partitions for topic with ID: {} for stream with ID: {}.",
+            replaced_partitions.old_partitions_count,
+            replaced_partitions.new_partitions_count,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(replaced_partitions)
+    }
+
+    /// Creates and loads the new partitions from the partitions directory.
+    /// The state of the current partitions in the database (e.g. consumer offsets) is deleted first,
+    /// so it isn't loaded by the new partitions having the same IDs.
+    async fn load_replacing_partitions(
+        &self,
+        replaced_partitions: &mut ReplacedPartitions,
+    ) -> Result<HashMap<u32, Arc<RwLock<Partition>>>, Error> {
+        for partition_id in self.partitions.keys() {
+            self.delete_partition_state(*partition_id).await?;
+        }
+
+        let mut partitions = HashMap::new();
+        for partition_id in 1..=replaced_partitions.new_partitions_count {
+            let mut partition = Partition::create(
+                self.stream_id,
+                self.topic_id,
+                partition_id,
+                false,
+                self.effective_config.clone(),
+                self.effective_storage.clone(),
+                self.message_expiry,
+            );
+            partition.persist().await?;
+            partition.load().await?;
+            replaced_partitions.new_segments_count += partition.get_segments_count();
+            replaced_partitions.new_messages_count += partition.get_messages_count();
+            partitions.insert(partition_id, Arc::new(RwLock::new(partition)));
+        }
+
+        Ok(partitions)
+    }
+
+    async fn delete_partition_state(&self, partition_id: u32) -> Result<(), Error> {
+        let storage = &self.storage.partition;
+        for kind in [ConsumerKind::Consumer, ConsumerKind::ConsumerGroup] {
+            storage
+                .delete_consumer_offsets(kind, self.stream_id, self.topic_id, partition_id)
+                .await?;
+        }
+        storage
+            .delete_producer_states(self.stream_id, self.topic_id, partition_id)
+            .await?;
+        storage
+            .delete_transaction_ranges(self.stream_id, self.topic_id, partition_id)
+            .await
+    }
+
+    /// Moves the new partitions directory back to the staging one and the replaced one back into its place.
+    async fn rollback_partitions(&self, replaced_path: &str, staging_path: &str) {
+        if fs::metadata(&self.partitions_path).await.is_ok()
+            && fs::rename(&self.partitions_path, staging_path)
+                .await
+                .is_err()
+        {
+            discard_directory(&self.partitions_path).await;
+        }
+
+        if let Err(error) = fs::rename(replaced_path, &self.partitions_path).await {
+            error!(
+                "Cannot restore the replaced partitions at path: {} for topic with ID: {} for stream with ID: {}. Error: {}",
+                replaced_path, self.topic_id, self.stream_id, error
+            );
+        }
+        discard_directory(staging_path).await;
+    }
+
+    async fn reroute_delayed_messages(
+        &mut self,
+        delayed_partition_ids: Vec<((u64, u64), u32)>,
+    ) -> Result<(), Error> {
+        let mut delayed_messages = std::mem::take(self.delayed_messages.get_mut());
+        for (key, partition_id) in delayed_partition_ids {
+            let Some(delayed_message) = delayed_messages.get_mut(&key) else {
+                continue;
+            };
+
+            delayed_message.partition_id = partition_id;
+            if let Err(error) = self
+                .storage
+                .topic
+                .save_delayed_message(self, delayed_message)
+                .await
+            {
+                *self.delayed_messages.get_mut() = delayed_messages;
+                return Err(error);
+            }
+        }
+        *self.delayed_messages.get_mut() = delayed_messages;
+        Ok(())
+    }
+
+    /// Rolls back the partitions replacement, which hasn't been completed before the server has stopped,
+    /// and deletes the leftover staged or replaced partitions.
+    pub(crate) async fn recover_partitions(&self) -> Result<(), Error> {
+        let Ok(mut dir_entries) = fs::read_dir(&self.path).await else {
+            return Ok(());
+        };
+
+        let mut replaced_path = None;
+        while let Some(dir_entry) = dir_entries.next_entry().await? {
+            let name = dir_entry.file_name().to_string_lossy().to_string();
+            let path = format!("{}/{name}", self.path);
+            if name.starts_with(REPLACED_PATH_PREFIX) {
+                replaced_path = Some(path);
+            } else if name.starts_with(STAGING_PATH_PREFIX) || name.starts_with(DELETED_PATH_PREFIX)
+            {
+                discard_directory(&path).await;
+            }
+        }
+
+        let Some(replaced_path) = replaced_path else {
+            return Ok(());
+        };
+
+        warn!(
+            "Rolling back the incomplete repartitioning of topic with ID: {} for stream with ID: {}...",
+            self.topic_id, self.stream_id
+        );
+        if fs::metadata(&self.partitions_path).await.is_ok() {
+            fs::remove_dir_all(&self.partitions_path).await?;
+        }
+        fs::rename(&replaced_path, &self.partitions_path).await?;
+        file::sync_directory(&self.path).await?;
+        Ok(())
+    }
+
+    /// Copies the stable messages, which haven't been copied yet, from the current partitions to the staged ones.
+    /// The messages of the aborted transactions are skipped.
+    async fn copy_messages_to_layout(layout: &mut TopicLayout) -> Result<u64, Error> {
+        let mut cursors = Vec::with_capacity(layout.sources.len());
+        for (source_index, source) in layout.sources.iter().enumerate() {
+            let partition = source.partition.read().await;
+            if partition.get_messages_count() == 0 {
+                continue;
+            }
+
+            let end_offset = match partition.get_first_unstable_offset() {
+                Some(0) => continue,
+                Some(offset) => offset - 1,
+                None => partition.current_offset,
+            };
+            let next_offset = match source.next_offset {
+                Some(offset) => offset,
+                None => partition.segments[0].start_offset,
+            };
+            if next_offset > end_offset {
+                continue;
+            }
+
+            cursors.push(Cursor {
+                source_index,
+                next_offset,
+                end_offset,
+                messages: VecDeque::new(),
+            });
+        }
+
+        let mut messages_count = 0;
+        let mut pending_messages = (0..layout.partitions_count)
+            .map(|_| Vec::new())
+            .collect::<Vec<Vec<Message>>>();
+        loop {
+            for cursor in cursors.iter_mut() {
+                if !cursor.messages.is_empty() || cursor.next_offset > cursor.end_offset {
+                    continue;
+                }
+
+                let partition = layout.sources[cursor.source_index].partition.read().await;
+                let count = (cursor.end_offset - cursor.next_offset + 1)
+                    .min(MESSAGES_BATCH_SIZE as u64) as u32;
+                let messages = partition
+                    .get_messages_by_offset(cursor.next_offset, count)
+                    .await?;
+                cursor.next_offset = match messages.last() {
+                    Some(message) => message.offset + 1,
+                    None => cursor.end_offset + 1,
+                };
+                cursor
+                    .messages
+                    .extend(messages.into_iter().filter(|message| {
+                        message.offset <= cursor.end_offset
+                            && !partition.is_transaction_aborted(message.offset)
+                    }));
+            }
+
+            // The oldest message goes first, so the timestamps within the new partitions stay ordered.
+            let Some(cursor) = cursors
+                .iter_mut()
+                .filter(|cursor| !cursor.messages.is_empty())
+                .min_by_key(|cursor| cursor.messages[0].timestamp)
+            else {
+                break;
+            };
+
+            let message = cursor.messages.pop_front().unwrap();
+            let partition_id =
+                layout.get_target_partition_id(message.id, message.headers.as_ref())?;
+            let pending = &mut pending_messages[partition_id as usize - 1];
+            pending.push(Message::create(
+                0,
+                message.state,
+                message.timestamp,
+                message.id,
+                message.payload.clone(),
+                message.checksum,
+                message.headers.clone(),
+            ));
+            messages_count += 1;
+            if pending.len() >= MESSAGES_BATCH_SIZE as usize {
+                let messages = std::mem::take(pending);
+                layout.partitions[partition_id as usize - 1]
+                    .append_messages(messages, None, None)
+                    .await?;
+            }
+        }
+
+        for (index, messages) in pending_messages.into_iter().enumerate() {
+            if !messages.is_empty() {
+                layout.partitions[index]
+                    .append_messages(messages, None, None)
+                    .await?;
+            }
+        }
+
+        for cursor in cursors {
+            layout.sources[cursor.source_index].next_offset = Some(cursor.next_offset);
+        }
+
+        Ok(messages_count)
+    }
+}
+
+async fn discard_directory(path: &str) {
+    if let Err(error) = fs::remove_dir_all(path).await {
+        error!(
+            "Cannot delete the partitions at path: {}. Error: {}",
+            path, error
+        );
+    }
+}
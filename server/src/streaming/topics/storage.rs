@@ -1,19 +1,24 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::{Storage, TopicStorage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::delayed_messages::DelayedMessage;
 use crate::streaming::topics::topic::Topic;
 use anyhow::Context;
 use async_trait::async_trait;
+use bytes::BufMut;
 use futures::future::join_all;
+use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::Error;
+use iggy::messages::send_messages;
 use serde::{Deserialize, Serialize};
 use sled::Db;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::create_dir;
 use tokio::sync::{Mutex, RwLock};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[derive(Debug)]
 pub struct FileTopicStorage {
@@ -140,6 +145,97 @@ impl TopicStorage for FileTopicStorage {
             }
         }
     }
+
+    async fn save_delayed_message(
+        &self,
+        topic: &Topic,
+        delayed_message: &DelayedMessage,
+    ) -> Result<(), Error> {
+        let key = get_delayed_message_key(
+            topic.stream_id,
+            topic.topic_id,
+            delayed_message.deliver_at,
+            delayed_message.sequence,
+        );
+        let mut data = Vec::with_capacity(4 + delayed_message.message.get_size_bytes() as usize);
+        data.put_u32_le(delayed_message.partition_id);
+        data.extend(delayed_message.message.as_bytes());
+        if let Err(err) = self
+            .db
+            .insert(&key, data)
+            .with_context(|| format!("Failed to insert delayed message with key: {}", key))
+        {
+            return Err(Error::CannotSaveResource(err));
+        }
+
+        Ok(())
+    }
+
+    async fn load_delayed_messages(&self, topic: &Topic) -> Result<Vec<DelayedMessage>, Error> {
+        info!(
+            "Loading delayed messages for topic with ID: {} for stream with ID: {} from disk...",
+            topic.topic_id, topic.stream_id
+        );
+
+        let key_prefix = get_delayed_messages_key_prefix(topic.stream_id, topic.topic_id);
+        let mut delayed_messages = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", key_prefix)) {
+            let (key, value) = match data.with_context(|| {
+                format!(
+                    "Failed to load delayed message when searching for key: {}",
+                    key_prefix
+                )
+            }) {
+                Ok(data) => data,
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+
+            let key = String::from_utf8_lossy(&key);
+            let sequence = key
+                .rsplit(':')
+                .next()
+                .and_then(|sequence| sequence.parse().ok());
+            if sequence.is_none() || value.len() < 4 {
+                error!("Invalid delayed message with key: '{}'.", key);
+                continue;
+            }
+
+            let partition_id = u32::from_le_bytes(value[..4].try_into()?);
+            let message = send_messages::Message::from_bytes(&value[4..])?;
+            delayed_messages.push(DelayedMessage {
+                deliver_at: message.deliver_at.unwrap_or_default(),
+                sequence: sequence.unwrap(),
+                partition_id,
+                message,
+            });
+        }
+
+        Ok(delayed_messages)
+    }
+
+    async fn delete_delayed_message(
+        &self,
+        topic: &Topic,
+        delayed_message: &DelayedMessage,
+    ) -> Result<(), Error> {
+        let key = get_delayed_message_key(
+            topic.stream_id,
+            topic.topic_id,
+            delayed_message.deliver_at,
+            delayed_message.sequence,
+        );
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete delayed message with key: {}", key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -147,6 +243,12 @@ struct TopicData {
     name: String,
     created_at: u64,
     message_expiry: Option<u32>,
+    #[serde(default)]
+    deleted_at: Option<u64>,
+    #[serde(default)]
+    config: HashMap<String, String>,
+    #[serde(default)]
+    aliases: Vec<String>,
 }
 
 #[async_trait]
@@ -187,6 +289,15 @@ impl Storage<Topic> for FileTopicStorage {
         topic.name = topic_data.name;
         topic.created_at = topic_data.created_at;
         topic.message_expiry = topic_data.message_expiry;
+        topic.deleted_at = topic_data.deleted_at;
+        topic.aliases = topic_data.aliases;
+        // The overrides might no longer be valid, e.g. if the cache was disabled for the whole server since then.
+        if let Err(error) = topic.set_config_overrides(topic_data.config) {
+            warn!(
+                "Ignoring invalid config overrides for topic with ID: {} for stream with ID: {}. Error: {}",
+                topic.topic_id, topic.stream_id, error
+            );
+        }
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}", 
@@ -216,8 +327,8 @@ impl Storage<Topic> for FileTopicStorage {
                 topic.topic_id,
                 partition_id,
                 false,
-                topic.config.clone(),
-                topic.storage.clone(),
+                topic.effective_config.clone(),
+                topic.effective_storage.clone(),
                 topic.message_expiry,
             );
             unloaded_partitions.push(partition);
@@ -252,6 +363,14 @@ impl Storage<Topic> for FileTopicStorage {
         }
 
         self.load_consumer_groups(topic).await?;
+        let delayed_messages = self.load_delayed_messages(topic).await?;
+        let staged_messages = topic.delayed_messages.get_mut();
+        for delayed_message in delayed_messages {
+            staged_messages.insert(
+                (delayed_message.deliver_at, delayed_message.sequence),
+                delayed_message,
+            );
+        }
         topic.load_messages_from_disk_to_cache().await?;
 
         info!(
@@ -285,6 +404,9 @@ impl Storage<Topic> for FileTopicStorage {
             name: topic.name.clone(),
             created_at: topic.created_at,
             message_expiry: topic.message_expiry,
+            deleted_at: topic.deleted_at,
+            config: topic.config_overrides.clone(),
+            aliases: topic.aliases.clone(),
         })
         .with_context(|| format!("Failed to serialize topic with key: {}", key))
         {
@@ -339,6 +461,9 @@ impl Storage<Topic> for FileTopicStorage {
             let consumer_group = consumer_group.read().await;
             self.delete_consumer_group(topic, &consumer_group).await?;
         }
+        for delayed_message in topic.delayed_messages.read().await.values() {
+            self.delete_delayed_message(topic, delayed_message).await?;
+        }
         if fs::remove_dir_all(&topic.path).await.is_err() {
             return Err(Error::CannotDeleteTopicDirectory(
                 topic.topic_id,
@@ -371,3 +496,21 @@ fn get_consumer_group_key(stream_id: u32, topic_id: u32, consumer_group_id: u32)
 fn get_consumer_groups_key_prefix(stream_id: u32, topic_id: u32) -> String {
     format!("streams:{}:topics:{}:consumer_groups", stream_id, topic_id)
 }
+
+fn get_delayed_message_key(
+    stream_id: u32,
+    topic_id: u32,
+    deliver_at: u64,
+    sequence: u64,
+) -> String {
+    format!(
+        "{}:{}:{}",
+        get_delayed_messages_key_prefix(stream_id, topic_id),
+        deliver_at,
+        sequence
+    )
+}
+
+fn get_delayed_messages_key_prefix(stream_id: u32, topic_id: u32) -> String {
+    format!("streams:{}:topics:{}:delayed_messages", stream_id, topic_id)
+}
@@ -2,10 +2,12 @@ use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::delayed_messages::DelayedMessage;
 use iggy::error::Error;
+use iggy::topics::config;
 use iggy::utils::timestamp::TimeStamp;
-use std::collections::HashMap;
-use std::sync::atomic::AtomicU32;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -14,16 +16,27 @@ pub struct Topic {
     pub stream_id: u32,
     pub topic_id: u32,
     pub name: String,
+    /// The previous names of the topic, which still resolve to it, unless taken by another topic.
+    pub aliases: Vec<String>,
     pub path: String,
     pub partitions_path: String,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: HashMap<u32, Arc<RwLock<Partition>>>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) config_overrides: HashMap<String, String>,
+    pub(crate) effective_config: Arc<SystemConfig>,
+    pub(crate) effective_storage: Arc<SystemStorage>,
     pub(crate) consumer_groups: HashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) consumer_groups_ids: HashMap<String, u32>,
     pub(crate) current_partition_id: AtomicU32,
+    pub(crate) sticky_partition_id: AtomicU32,
+    pub(crate) sticky_partition_expiry: AtomicU64,
+    pub(crate) key_ranges: Vec<Vec<u8>>,
+    pub(crate) delayed_messages: RwLock<BTreeMap<(u64, u64), DelayedMessage>>,
+    pub(crate) next_delayed_message_sequence: AtomicU64,
     pub message_expiry: Option<u32>,
     pub created_at: u64,
+    pub deleted_at: Option<u64>,
 }
 
 impl Topic {
@@ -51,13 +64,23 @@ impl Topic {
             stream_id,
             topic_id,
             name: name.to_string(),
+            aliases: Vec::new(),
             partitions: HashMap::new(),
             path,
             partitions_path,
+            effective_storage: storage.clone(),
             storage,
+            config_overrides: HashMap::new(),
+            effective_config: config.clone(),
             consumer_groups: HashMap::new(),
             consumer_groups_ids: HashMap::new(),
             current_partition_id: AtomicU32::new(1),
+            sticky_partition_id: AtomicU32::new(0),
+            sticky_partition_expiry: AtomicU64::new(0),
+            key_ranges: Vec::new(),
+            delayed_messages: RwLock::new(BTreeMap::new()),
+            // Seeded with the current timestamp, so that the sequences are not reused after the server restart.
+            next_delayed_message_sequence: AtomicU64::new(TimeStamp::now().to_micros()),
             message_expiry: match message_expiry {
                 Some(expiry) => match expiry {
                     0 => None,
@@ -70,6 +93,7 @@ impl Topic {
             },
             config,
             created_at: TimeStamp::now().to_micros(),
+            deleted_at: None,
         };
         topic.add_partitions(partitions_count)?;
         Ok(topic)
@@ -84,6 +108,54 @@ impl Topic {
         size_bytes
     }
 
+    /// Replaces the config overrides of the topic and applies them to all its partitions and their segments.
+    pub async fn update_config_overrides(
+        &mut self,
+        overrides: HashMap<String, String>,
+    ) -> Result<(), Error> {
+        self.validate_key_ranges(&overrides)?;
+        self.set_config_overrides(overrides)?;
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            partition.update_config(
+                self.effective_config.clone(),
+                self.effective_storage.clone(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Sets the config overrides of the topic, which are used by the partitions created from now on.
+    /// The fsync override is applied to the system persister, so the topic keeps its kind (e.g. io_uring or group commit).
+    pub(crate) fn set_config_overrides(
+        &mut self,
+        overrides: HashMap<String, String>,
+    ) -> Result<(), Error> {
+        let effective_config = self.config.with_topic_overrides(&overrides)?;
+        self.effective_storage = match overrides.contains_key(config::PARTITION_ENFORCE_FSYNC) {
+            true => Arc::new(
+                self.storage.with_persister(
+                    self.storage
+                        .persister
+                        .clone()
+                        .with_fsync(effective_config.partition.enforce_fsync),
+                ),
+            ),
+            false => self.storage.clone(),
+        };
+        self.key_ranges = match overrides.get(config::TOPIC_KEY_RANGES) {
+            Some(key_ranges) => config::parse_key_ranges(key_ranges)?,
+            None => Vec::new(),
+        };
+        self.effective_config = Arc::new(effective_config);
+        self.config_overrides = overrides;
+        Ok(())
+    }
+
+    pub fn is_soft_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     pub fn get_partitions(&self) -> Vec<Arc<RwLock<Partition>>> {
         self.partitions.values().map(Arc::clone).collect()
     }
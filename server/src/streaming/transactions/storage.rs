@@ -0,0 +1,144 @@
+use crate::streaming::storage::{Storage, TransactionStorage};
+use crate::streaming::transactions::transaction::Transaction;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::Error;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+// Only the committed transactions are saved, until they are applied to all the partitions and consumer offsets.
+const KEY_PREFIX: &str = "transactions";
+
+#[derive(Debug)]
+pub struct FileTransactionStorage {
+    db: Arc<Db>,
+}
+
+impl FileTransactionStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileTransactionStorage {}
+unsafe impl Sync for FileTransactionStorage {}
+
+#[async_trait]
+impl TransactionStorage for FileTransactionStorage {
+    async fn load_all(&self) -> Result<Vec<Transaction>, Error> {
+        let mut transactions = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let transaction = match data.with_context(|| {
+                format!(
+                    "Failed to load transaction, when searching by key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => {
+                    match rmp_serde::from_slice::<Transaction>(&value).with_context(|| {
+                        format!(
+                            "Failed to deserialize transaction, when searching by key: {}",
+                            KEY_PREFIX
+                        )
+                    }) {
+                        Ok(transaction) => transaction,
+                        Err(err) => {
+                            return Err(Error::CannotDeserializeResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(Error::CannotLoadResource(err));
+                }
+            };
+            transactions.push(transaction);
+        }
+
+        transactions.sort_by_key(|transaction| transaction.transaction_id);
+        Ok(transactions)
+    }
+}
+
+#[async_trait]
+impl Storage<Transaction> for FileTransactionStorage {
+    async fn load(&self, transaction: &mut Transaction) -> Result<(), Error> {
+        let key = get_key(transaction.transaction_id);
+        let transaction_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load transaction with key: {}", key))
+        {
+            Ok(transaction_data) => {
+                if let Some(transaction_data) = transaction_data {
+                    transaction_data
+                } else {
+                    return Err(Error::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(Error::CannotLoadResource(err));
+            }
+        };
+
+        let transaction_data = rmp_serde::from_slice::<Transaction>(&transaction_data)
+            .with_context(|| format!("Failed to deserialize transaction with key: {}", key));
+        match transaction_data {
+            Ok(transaction_data) => {
+                transaction.user_id = transaction_data.user_id;
+                transaction.created_at = transaction_data.created_at;
+                transaction.topics = transaction_data.topics;
+                transaction.consumer_offsets = transaction_data.consumer_offsets;
+                Ok(())
+            }
+            Err(err) => Err(Error::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, transaction: &Transaction) -> Result<(), Error> {
+        let key = get_key(transaction.transaction_id);
+        match rmp_serde::to_vec(&transaction)
+            .with_context(|| format!("Failed to serialize transaction with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to save transaction with key: {}", key))
+                {
+                    return Err(Error::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(Error::CannotSerializeResource(err));
+            }
+        }
+
+        info!(
+            "Saved committed transaction with ID: {}.",
+            transaction.transaction_id
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, transaction: &Transaction) -> Result<(), Error> {
+        let key = get_key(transaction.transaction_id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete transaction with key: {}", key))
+        {
+            return Err(Error::CannotDeleteResource(err));
+        }
+
+        info!(
+            "Deleted committed transaction with ID: {}.",
+            transaction.transaction_id
+        );
+        Ok(())
+    }
+}
+
+fn get_key(transaction_id: u64) -> String {
+    format!("{}:{}", KEY_PREFIX, transaction_id)
+}
@@ -0,0 +1,108 @@
+use iggy::consumer::ConsumerKind;
+use iggy::models::user_info::UserId;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The open transaction, which groups the messages appended to the partitions of one or more topics
+/// and the consumer offsets to be stored once the transaction is committed.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Transaction {
+    pub transaction_id: u64,
+    pub user_id: UserId,
+    pub created_at: u64,
+    /// The stream and topic IDs, to which the messages have been appended within the transaction.
+    pub topics: BTreeSet<(u32, u32)>,
+    pub consumer_offsets: Vec<TransactionConsumerOffset>,
+}
+
+/// The consumer offset stored within the transaction, which is applied only once the transaction is committed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TransactionConsumerOffset {
+    pub kind: ConsumerKind,
+    pub consumer_id: u32,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub offset: u64,
+    /// The ID of the consumer group member which stored the offset (if stored by the consumer group).
+    #[serde(default)]
+    pub member_id: u32,
+}
+
+impl Transaction {
+    pub fn new(transaction_id: u64, user_id: UserId, created_at: u64) -> Self {
+        Self {
+            transaction_id,
+            user_id,
+            created_at,
+            topics: BTreeSet::new(),
+            consumer_offsets: Vec::new(),
+        }
+    }
+
+    pub fn is_expired(&self, now: u64, timeout_secs: u32) -> bool {
+        now > self.created_at + timeout_secs as u64 * 1_000_000
+    }
+
+    pub fn add_topic(&mut self, stream_id: u32, topic_id: u32) {
+        self.topics.insert((stream_id, topic_id));
+    }
+
+    /// Stages the consumer offset, replacing the one previously staged for the same consumer and partition.
+    pub fn stage_consumer_offset(&mut self, consumer_offset: TransactionConsumerOffset) {
+        if let Some(staged_offset) = self.consumer_offsets.iter_mut().find(|staged_offset| {
+            staged_offset.kind == consumer_offset.kind
+                && staged_offset.consumer_id == consumer_offset.consumer_id
+                && staged_offset.stream_id == consumer_offset.stream_id
+                && staged_offset.topic_id == consumer_offset.topic_id
+                && staged_offset.partition_id == consumer_offset.partition_id
+        }) {
+            staged_offset.offset = consumer_offset.offset;
+            return;
+        }
+
+        self.consumer_offsets.push(consumer_offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_should_be_expired_after_timeout() {
+        let created_at = 1_000_000;
+        let transaction = Transaction::new(1, 1, created_at);
+
+        assert!(!transaction.is_expired(created_at + 10 * 1_000_000, 10));
+        assert!(transaction.is_expired(created_at + 10 * 1_000_000 + 1, 10));
+    }
+
+    #[test]
+    fn staged_consumer_offset_should_replace_previous_one_for_the_same_consumer_and_partition() {
+        let mut transaction = Transaction::new(1, 1, 0);
+        transaction.stage_consumer_offset(consumer_offset(1, 1, 10));
+        transaction.stage_consumer_offset(consumer_offset(1, 2, 20));
+        transaction.stage_consumer_offset(consumer_offset(1, 1, 30));
+
+        assert_eq!(transaction.consumer_offsets.len(), 2);
+        assert_eq!(transaction.consumer_offsets[0].offset, 30);
+        assert_eq!(transaction.consumer_offsets[1].offset, 20);
+    }
+
+    fn consumer_offset(
+        consumer_id: u32,
+        partition_id: u32,
+        offset: u64,
+    ) -> TransactionConsumerOffset {
+        TransactionConsumerOffset {
+            kind: ConsumerKind::Consumer,
+            consumer_id,
+            stream_id: 1,
+            topic_id: 1,
+            partition_id,
+            offset,
+            member_id: 0,
+        }
+    }
+}
@@ -19,4 +19,13 @@ impl Permissioner {
     ) -> Result<(), Error> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn repartition_topic(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), Error> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
 }
@@ -51,6 +51,10 @@ impl Permissioner {
         self.manage_stream(user_id, stream_id)
     }
 
+    pub fn restore_stream(&self, user_id: u32, stream_id: u32) -> Result<(), Error> {
+        self.manage_stream(user_id, stream_id)
+    }
+
     fn manage_stream(&self, user_id: u32, stream_id: u32) -> Result<(), Error> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
@@ -14,6 +14,10 @@ pub async fn write(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().create(true).write(true).open(path).await
 }
 
+pub async fn sync_directory(path: &str) -> Result<(), std::io::Error> {
+    File::open(path).await?.sync_all().await
+}
+
 pub async fn folder_size<P>(path: P) -> std::io::Result<u64>
 where
     P: Into<PathBuf> + AsRef<Path>,
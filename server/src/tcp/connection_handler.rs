@@ -1,28 +1,40 @@
 use crate::binary::command;
 use crate::binary::sender::Sender;
 use crate::server_error::ServerError;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
+use iggy::error::Error;
 use std::io::ErrorKind;
 use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::time;
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
+const SHUTDOWN_NOTICE_TIMEOUT: Duration = Duration::from_secs(1);
 
 pub(crate) async fn handle_connection(
     address: SocketAddr,
     sender: &mut dyn Sender,
     system: SharedSystem,
+    shutdown: GracefulShutdown,
 ) -> Result<(), ServerError> {
     let client_id = system.read().add_client(&address, Transport::Tcp).await;
 
     let mut session = Session::from_client_id(client_id, address);
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = sender.read(&mut initial_buffer).await?;
+        let read_length = tokio::select! {
+            read_length = sender.read(&mut initial_buffer) => read_length?,
+            _ = shutdown.draining() => {
+                notify_shutdown(sender, &shutdown).await;
+                return Ok(());
+            }
+        };
         if read_length != INITIAL_BYTES_LENGTH {
             error!(
                 "Unable to read the TCP request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes.",
@@ -36,6 +48,11 @@ pub(crate) async fn handle_connection(
         sender.read(&mut command_buffer).await?;
         let command = Command::from_bytes(&command_buffer)?;
         debug!("Received a TCP command: {command}, payload size: {length}");
+        let Some(_request) = shutdown.start_request() else {
+            notify_shutdown(sender, &shutdown).await;
+            return Ok(());
+        };
+
         let result = command::handle(&command, sender, &mut session, system.clone()).await;
         if result.is_err() {
             error!("Error when handling the TCP request: {:?}", result.err());
@@ -45,6 +62,25 @@ pub(crate) async fn handle_connection(
     }
 }
 
+// The notice is sent instead of the response to the next request. The connection is kept open for a while,
+// so that the client can read the notice, instead of getting the connection reset when sending the next request.
+async fn notify_shutdown(sender: &mut dyn Sender, shutdown: &GracefulShutdown) {
+    if sender
+        .send_error_response(Error::ServerShuttingDown)
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    shutdown.client_notified();
+    let mut buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let _ = time::timeout(SHUTDOWN_NOTICE_TIMEOUT, async {
+        while sender.read(&mut buffer).await.is_ok() {}
+    })
+    .await;
+}
+
 pub(crate) fn handle_error(error: ServerError) {
     match error {
         ServerError::IoError(error) => match error.kind() {
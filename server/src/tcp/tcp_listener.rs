@@ -1,3 +1,4 @@
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
 use crate::tcp::tcp_sender::TcpSender;
@@ -6,7 +7,7 @@ use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tracing::{error, info};
 
-pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
+pub async fn start(address: &str, system: SharedSystem, shutdown: GracefulShutdown) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -26,18 +27,26 @@ pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
         });
 
         loop {
-            match listener.accept().await {
+            let connection = tokio::select! {
+                connection = listener.accept() => connection,
+                _ = shutdown.draining() => {
+                    info!("TCP listener has stopped accepting new connections.");
+                    break;
+                }
+            };
+            match connection {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP connection: {}", address);
                     let system = system.clone();
                     let mut sender = TcpSender { stream };
+                    let shutdown = shutdown.clone();
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                            handle_connection(address, &mut sender, system.clone(), shutdown).await
                         {
                             handle_error(error);
-                            system.read().delete_client(&address).await;
                         }
+                        system.read().delete_client(&address).await;
                     });
                 }
                 Err(error) => error!("Unable to accept TCP socket, error: {}", error),
@@ -1,4 +1,5 @@
 use crate::configs::tcp::TcpConfig;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::{tcp_listener, tcp_tls_listener};
 use std::net::SocketAddr;
@@ -6,7 +7,11 @@ use tracing::info;
 
 /// Starts the TCP server.
 /// Returns the address the server is listening on.
-pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    config: TcpConfig,
+    system: SharedSystem,
+    shutdown: GracefulShutdown,
+) -> SocketAddr {
     let server_name = if config.tls.enabled {
         "Iggy TCP TLS"
     } else {
@@ -14,8 +19,8 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     };
     info!("Initializing {server_name} server...");
     let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, system).await,
-        false => tcp_listener::start(&config.address, system).await,
+        true => tcp_tls_listener::start(&config.address, config.tls, system, shutdown).await,
+        false => tcp_listener::start(&config.address, system, shutdown).await,
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
@@ -1,6 +1,7 @@
 use std::net::SocketAddr;
 
 use crate::configs::tcp::TcpTlsConfig;
+use crate::shutdown::GracefulShutdown;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
@@ -10,7 +11,12 @@ use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
 use tracing::{error, info};
 
-pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSystem) -> SocketAddr {
+pub(crate) async fn start(
+    address: &str,
+    config: TcpTlsConfig,
+    system: SharedSystem,
+    shutdown: GracefulShutdown,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -46,20 +52,28 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
         });
 
         loop {
-            match listener.accept().await {
+            let connection = tokio::select! {
+                connection = listener.accept() => connection,
+                _ = shutdown.draining() => {
+                    info!("TCP TLS listener has stopped accepting new connections.");
+                    break;
+                }
+            };
+            match connection {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP TLS connection: {}", address);
                     let acceptor = acceptor.clone();
                     let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
                     let mut sender = TcpTlsSender { stream };
+                    let shutdown = shutdown.clone();
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                            handle_connection(address, &mut sender, system.clone(), shutdown).await
                         {
                             handle_error(error);
-                            system.read().delete_client(&address).await;
                         }
+                        system.read().delete_client(&address).await;
                     });
                 }
                 Err(error) => error!("Unable to accept TCP TLS socket, error: {}", error),
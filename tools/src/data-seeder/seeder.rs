@@ -2,7 +2,7 @@ use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acknowledgement, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -54,6 +54,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "orders".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await?;
 
@@ -64,6 +65,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "users".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await?;
 
@@ -74,6 +76,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "notifications".to_string(),
                 partitions_count: 3,
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await?;
 
@@ -84,6 +87,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "payments".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await?;
 
@@ -94,6 +98,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "deliveries".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                config: HashMap::new(),
             })
             .await?;
     }
@@ -142,7 +147,10 @@ async fn send_messages(client: &IggyClient) -> Result<(), Error> {
                         stream_id: Identifier::numeric(stream_id)?,
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
+                        acknowledgement: Acknowledgement::default(),
+                        producer: None,
                         messages,
+                        transaction_id: None,
                     })
                     .await?;
                 messages = Vec::new();